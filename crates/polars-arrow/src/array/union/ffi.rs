@@ -36,14 +36,14 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
         let dtype = array.dtype().clone();
         let fields = Self::get_fields(&dtype);
 
-        let mut types = unsafe { array.buffer::<i8>(0) }?;
+        // The buffers are already sliced to the array's offset and length.
+        let types = unsafe { array.buffer::<i8>(0) }?;
         let offsets = if Self::is_sparse(&dtype) {
             None
         } else {
             Some(unsafe { array.buffer::<i32>(1) }?)
         };
 
-        let length = array.array().len();
         let offset = array.array().offset();
         let fields = (0..fields.len())
             .map(|index| {
@@ -52,10 +52,12 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
             })
             .collect::<PolarsResult<Vec<Box<dyn Array>>>>()?;
 
-        if offset > 0 {
-            types.slice(offset, length);
-        };
-
-        Self::try_new(dtype, types, fields, offsets)
+        let mut out = Self::try_new(dtype, types, fields, offsets)?;
+        // The children of a sparse union are not sliced, so the offset is needed to find the
+        // slot of a value.
+        if out.offsets.is_none() {
+            out.offset = offset;
+        }
+        Ok(out)
     }
 }
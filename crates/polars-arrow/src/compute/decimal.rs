@@ -1,8 +1,11 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use atoi::FromRadix10SignedChecked;
+use ethnum::{I256, U256};
 use num_traits::Euclid;
 
+use crate::types::i256;
+
 static TRIM_DECIMAL_ZEROS: AtomicBool = AtomicBool::new(false);
 
 pub fn get_trim_decimal_zeros() -> bool {
@@ -24,17 +27,64 @@ fn split_decimal_bytes(bytes: &[u8]) -> (Option<&[u8]>, Option<&[u8]>) {
     (lhs, rhs)
 }
 
+/// Split a number in scientific notation (eg "1.5e-3") into its mantissa and exponent.
+/// Returns None if the exponent is not a well-formed integer.
+fn split_exponent(bytes: &[u8]) -> Option<(&[u8], Option<i32>)> {
+    match bytes.iter().position(|x| matches!(x, b'e' | b'E')) {
+        Some(i) => {
+            let exp = &bytes[i + 1..];
+            let (n, len) = i32::from_radix_10_signed_checked(exp);
+            let exp = n.filter(|_| len == exp.len() && len > 0)?;
+            Some((&bytes[..i], Some(exp)))
+        },
+        None => Some((bytes, None)),
+    }
+}
+
 /// Parse a single i128 from bytes, ensuring the entire slice is read.
 fn parse_integer_checked(bytes: &[u8]) -> Option<i128> {
     let (n, len) = i128::from_radix_10_signed_checked(bytes);
     n.filter(|_| len == bytes.len())
 }
 
-/// Assuming bytes are a well-formed decimal number (with or without a separator),
-/// infer the scale of the number.  If no separator is present, the scale is 0.
+/// Assuming bytes are a well-formed decimal number (with or without a separator, and
+/// optionally in scientific notation), infer the scale of the number.  If no separator is
+/// present, the scale is 0.
 pub fn infer_scale(bytes: &[u8]) -> u8 {
-    let (_lhs, rhs) = split_decimal_bytes(bytes);
-    rhs.map_or(0, |x| x.len() as u8)
+    unclamped_scale(bytes).clamp(0, 38) as u8
+}
+
+/// Like [`infer_scale`], but for the 76 digits of a 256-bit decimal.
+pub fn infer_scale_256(bytes: &[u8]) -> u8 {
+    unclamped_scale(bytes).clamp(0, 76) as u8
+}
+
+fn unclamped_scale(bytes: &[u8]) -> i64 {
+    let (mantissa, exp) = split_exponent(bytes).unwrap_or((bytes, None));
+    let (_lhs, rhs) = split_decimal_bytes(mantissa);
+    rhs.map_or(0, |x| x.len() as i64) - exp.unwrap_or(0) as i64
+}
+
+/// Deserialize the mantissa of a number in scientific notation, `mantissa * 10^exp` at
+/// `scale` is the mantissa at `scale + exp`.
+fn deserialize_decimal_exponent(
+    mantissa: &[u8],
+    precision: Option<u8>,
+    scale: u8,
+    exp: i32,
+) -> Option<i128> {
+    let shifted = scale as i64 + exp as i64;
+    if shifted > 38 {
+        return None;
+    }
+    if shifted >= 0 {
+        return deserialize_decimal(mantissa, precision, shifted as u8);
+    }
+    // The scale can't hold all digits of the mantissa, so truncate them.
+    let v = deserialize_decimal(mantissa, None, 0)?;
+    let v = POW10.get(-shifted as usize).map_or(0, |d| v / d);
+    let precision_max = POW10[precision.unwrap_or(38) as usize];
+    (v.abs() < precision_max).then_some(v)
 }
 
 /// Deserialize bytes to a single i128 representing a decimal, at a specified precision
@@ -45,13 +95,18 @@ pub fn infer_scale(bytes: &[u8]) -> u8 {
 /// mixed integer/decimal sequences to be parsed as decimals.  All trailing zeros are assumed to
 /// be significant, whether or not a separator is present: 1200 requires precision >= 4, while 1200.200
 /// requires precision >= 7 and scale >= 3.  Returns None if the number is not well-formed, or does not
-/// fit. Only b'.' is allowed as a decimal separator (issue #6698).
+/// fit. Only b'.' is allowed as a decimal separator (issue #6698). Numbers in scientific
+/// notation (eg "1.5e3") are accepted, the exponent shifts the decimal separator.
 #[inline]
 pub(crate) fn deserialize_decimal(
     mut bytes: &[u8],
     precision: Option<u8>,
     scale: u8,
 ) -> Option<i128> {
+    if let (mantissa, Some(exp)) = split_exponent(bytes)? {
+        return deserialize_decimal_exponent(mantissa, precision, scale, exp);
+    }
+
     // While parse_integer_checked will parse positive/negative numbers, we want to
     // handle the sign ourselves, and so check for it initially, then handle it
     // at the end.
@@ -116,6 +171,63 @@ pub(crate) fn deserialize_decimal(
     }
 }
 
+/// Deserialize bytes to a single i256 representing a decimal, at a specified precision
+/// (optional, at most 76) and scale. Accepts the same numbers as [`deserialize_decimal`],
+/// digits beyond the scale are truncated. Returns None if the number is not well-formed, or
+/// does not fit.
+pub fn deserialize_decimal256(bytes: &[u8], precision: Option<u8>, scale: u8) -> Option<i256> {
+    let (mantissa, exp) = split_exponent(bytes)?;
+    let (negative, mantissa) = match mantissa.first() {
+        Some(s @ (b'+' | b'-')) => (*s == b'-', &mantissa[1..]),
+        _ => (false, mantissa),
+    };
+    let (lhs, rhs) = split_decimal_bytes(mantissa);
+    let (lhs, rhs) = (lhs.unwrap_or_default(), rhs.unwrap_or_default());
+    if lhs.is_empty() && rhs.is_empty() {
+        return None;
+    }
+
+    // The digits are an integer that is shifted to the scale.
+    let shift = scale as i64 + exp.unwrap_or(0) as i64 - rhs.len() as i64;
+    let n_digits = lhs.len() + rhs.len();
+    let n_keep = (n_digits as i64 + shift.min(0)).max(0) as usize;
+    let mut v = I256::ZERO;
+    for (i, d) in lhs.iter().chain(rhs).enumerate() {
+        if !d.is_ascii_digit() {
+            return None;
+        }
+        if i < n_keep {
+            v = v
+                .checked_mul(I256::new(10))?
+                .checked_add(I256::new((d - b'0') as i128))?;
+        }
+    }
+    if shift > 0 {
+        v = v.checked_mul(I256::new(10).checked_pow(shift as u32)?)?;
+    }
+
+    let precision_max = I256::new(10).pow(precision.unwrap_or(76).min(76) as u32);
+    (v < precision_max).then_some(i256(if negative { -v } else { v }))
+}
+
+/// Format a 256-bit decimal with the given scale.
+pub fn format_decimal256(v: i256, scale: usize, trim_zeros: bool) -> String {
+    let factor = U256::new(10).pow(scale as u32);
+    let abs = v.0.unsigned_abs();
+    let (div, rem) = (abs / factor, abs % factor);
+
+    let sign = if v.0.is_negative() { "-" } else { "" };
+    if scale == 0 {
+        return format!("{sign}{div}");
+    }
+    let mut out = format!("{sign}{div}.{rem:0>scale$}");
+    if trim_zeros {
+        let len = out.trim_end_matches('0').trim_end_matches('.').len();
+        out.truncate(len);
+    }
+    out
+}
+
 const BUF_LEN: usize = 48;
 
 #[derive(Clone, Copy)]
@@ -243,6 +355,36 @@ pub fn format_decimal(v: i128, scale: usize, trim_zeros: bool) -> FormatBuffer {
 #[cfg(test)]
 mod test {
     use super::*;
+
+    #[test]
+    fn test_decimal256_roundtrip() {
+        let v = deserialize_decimal256(
+            b"-12345678901234567890123456789012345678901234.5678",
+            None,
+            6,
+        )
+        .unwrap();
+        assert_eq!(
+            format_decimal256(v, 6, false),
+            "-12345678901234567890123456789012345678901234.567800"
+        );
+        assert_eq!(
+            format_decimal256(v, 6, true),
+            "-12345678901234567890123456789012345678901234.5678"
+        );
+
+        let v = deserialize_decimal256(b"-0.5e-1", None, 3).unwrap();
+        assert_eq!(format_decimal256(v, 3, false), "-0.050");
+        assert_eq!(
+            deserialize_decimal256(b"1.999", None, 1).unwrap(),
+            i256(I256::new(19))
+        );
+        assert_eq!(deserialize_decimal256(b"1e76", None, 0), None);
+        assert_eq!(deserialize_decimal256(b"100", Some(2), 0), None);
+        assert_eq!(deserialize_decimal256(b"1.2.3", None, 0), None);
+        assert_eq!(deserialize_decimal256(b"", None, 0), None);
+        assert_eq!(infer_scale_256(b"1.5e-60"), 61);
+    }
     #[test]
     fn test_decimal() {
         let precision = Some(8);
@@ -348,4 +490,24 @@ mod test {
         assert_eq!(deserialize_decimal(val, Some(12), 5), Some(120001000)); // excess precision, excess scale
         assert_eq!(deserialize_decimal(val, None, 35), None); // scale causes insufficient precision
     }
+
+    #[test]
+    fn test_decimal_scientific_notation() {
+        assert_eq!(deserialize_decimal(b"1.5e3", None, 2), Some(150000));
+        assert_eq!(deserialize_decimal(b"1.5E+3", Some(6), 2), Some(150000));
+        assert_eq!(deserialize_decimal(b"1.5e3", Some(5), 2), None);
+        assert_eq!(deserialize_decimal(b"-12.5e-1", None, 2), Some(-125));
+        assert_eq!(deserialize_decimal(b"12345e-2", Some(5), 2), Some(12345));
+        // Digits beyond the scale are truncated.
+        assert_eq!(deserialize_decimal(b"1.2345e-1", None, 2), Some(12));
+        assert_eq!(deserialize_decimal(b"5e-3", None, 2), Some(0));
+        assert_eq!(deserialize_decimal(b"1e40", None, 0), None);
+        assert_eq!(deserialize_decimal(b"1e", None, 2), None);
+        assert_eq!(deserialize_decimal(b"1e2.5", None, 2), None);
+        assert_eq!(deserialize_decimal(b"e2", None, 2), None);
+
+        assert_eq!(infer_scale(b"1.25e-3"), 5);
+        assert_eq!(infer_scale(b"1.25e3"), 0);
+        assert_eq!(infer_scale(b"1.25e1"), 1);
+    }
 }
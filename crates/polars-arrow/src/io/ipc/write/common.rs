@@ -254,6 +254,16 @@ fn set_variadic_buffer_counts(counts: &mut Vec<i64>, array: &dyn Array) {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             set_variadic_buffer_counts(counts, array.values().as_ref())
         },
+        ArrowDataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            set_variadic_buffer_counts(counts, array.field().as_ref())
+        },
+        ArrowDataType::Union(_, _, _) => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            for array in array.fields() {
+                set_variadic_buffer_counts(counts, array.as_ref())
+            }
+        },
         // Don't traverse dictionary values as those are set when the `Dictionary` IPC struct
         // is read.
         ArrowDataType::Dictionary(_, _, _) => (),
@@ -1,5 +1,6 @@
 pub mod no_nulls;
 pub mod nulls;
+pub mod positional;
 pub mod quantile_filter;
 mod window;
 
@@ -29,6 +30,7 @@ type Len = usize;
 pub enum RollingFnParams {
     Quantile(RollingQuantileParams),
     Var(RollingVarParams),
+    Kurtosis(RollingKurtosisParams),
 }
 
 fn det_offsets(i: Idx, window_size: WindowSize, _len: Len) -> (usize, usize) {
@@ -95,3 +97,65 @@ pub struct RollingQuantileParams {
     pub prob: f64,
     pub method: QuantileMethod,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RollingKurtosisParams {
+    pub fisher: bool,
+    pub bias: bool,
+}
+
+impl Default for RollingKurtosisParams {
+    fn default() -> Self {
+        Self {
+            fisher: true,
+            bias: true,
+        }
+    }
+}
+
+impl RollingKurtosisParams {
+    fn from_fn_params(params: Option<RollingFnParams>) -> Self {
+        match params {
+            None => Self::default(),
+            Some(RollingFnParams::Kurtosis(params)) => params,
+            Some(_) => unreachable!("expected Kurtosis params"),
+        }
+    }
+}
+
+// Kurtosis of the values in a window, following the (scipy) semantics of `MomentSeries::kurtosis`.
+// The central moments are computed from scratch, as updating fourth-order power sums
+// incrementally loses precision too quickly.
+fn compute_kurtosis<T, I>(values: I, params: RollingKurtosisParams) -> Option<T>
+where
+    T: Float,
+    I: Iterator<Item = T> + Clone,
+{
+    let (count, sum) = values
+        .clone()
+        .fold((0usize, T::zero()), |(count, sum), v| (count + 1, sum + v));
+    if count == 0 {
+        return None;
+    }
+    let n: T = NumCast::from(count).unwrap();
+    let mean = sum / n;
+    let (m2, m4) = values.fold((T::zero(), T::zero()), |(m2, m4), v| {
+        let d2 = (v - mean) * (v - mean);
+        (m2 + d2, m4 + d2 * d2)
+    });
+    let (m2, m4) = (m2 / n, m4 / n);
+
+    let one = T::one();
+    let two: T = NumCast::from(2).unwrap();
+    let three: T = NumCast::from(3).unwrap();
+    let is_zero = m2 <= (T::epsilon() * mean) * (T::epsilon() * mean);
+    let vals = if is_zero { T::nan() } else { m4 / (m2 * m2) };
+    let out = if !params.bias && !is_zero && n > three {
+        three
+            + one / (n - two) / (n - three) * ((n * n - one) * vals - three * (n - one) * (n - one))
+    } else {
+        vals
+    };
+    Some(if params.fisher { out - three } else { out })
+}
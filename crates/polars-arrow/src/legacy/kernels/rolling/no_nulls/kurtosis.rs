@@ -0,0 +1,80 @@
+use polars_error::polars_ensure;
+
+use super::*;
+
+pub struct KurtosisWindow<'a, T> {
+    slice: &'a [T],
+    params: RollingKurtosisParams,
+}
+
+impl<'a, T: NativeType + Float> RollingAggWindowNoNulls<'a, T> for KurtosisWindow<'a, T> {
+    fn new(slice: &'a [T], _start: usize, _end: usize, params: Option<RollingFnParams>) -> Self {
+        Self {
+            slice,
+            params: RollingKurtosisParams::from_fn_params(params),
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        compute_kurtosis(
+            self.slice.get_unchecked(start..end).iter().copied(),
+            self.params,
+        )
+    }
+}
+
+pub fn rolling_kurtosis<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float,
+{
+    polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported for 'rolling_kurtosis'");
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<KurtosisWindow<_>, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_kurtosis() {
+        let values = &[1.0f64, 2.0, 3.0, 4.0, 10.0, 1.0];
+
+        let out = rolling_kurtosis(values, 4, 4, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out[..3], [None, None, None]);
+        // excess kurtosis of [1, 2, 3, 4] is 1.64 - 3
+        assert!((out[3].unwrap() + 1.36).abs() < 1e-12);
+
+        let params = Some(RollingFnParams::Kurtosis(RollingKurtosisParams {
+            fisher: false,
+            bias: true,
+        }));
+        let out = rolling_kurtosis(values, 4, 4, false, None, params).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        assert!((out.value(3) - 1.64).abs() < 1e-12);
+
+        // constant windows have an undefined kurtosis
+        let values = &[2.0f64, 2.0, 2.0];
+        let out = rolling_kurtosis(values, 2, 2, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        assert!(out.value(1).is_nan() && out.value(2).is_nan());
+    }
+}
@@ -1,13 +1,17 @@
+mod kurtosis;
 mod mean;
 mod min_max;
+mod product;
 mod quantile;
 mod sum;
 mod variance;
 use std::fmt::Debug;
 
+pub use kurtosis::*;
 pub use mean::*;
 pub use min_max::*;
 use num_traits::{Float, Num, NumCast};
+pub use product::*;
 pub use quantile::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -0,0 +1,164 @@
+use polars_error::polars_ensure;
+
+use super::*;
+
+pub struct ProductWindow<'a, T> {
+    slice: &'a [T],
+    // product of the non-zero values in the window
+    prod: T,
+    zero_count: usize,
+    last_start: usize,
+    last_end: usize,
+    // if we don't recompute every 'n' iterations
+    // we get a accumulated error/drift
+    last_recompute: u8,
+}
+
+impl<T: NativeType + IsFloat + Num> ProductWindow<'_, T> {
+    fn compute_product(&mut self, start: usize, end: usize) {
+        self.prod = T::one();
+        self.zero_count = 0;
+        for value in &self.slice[start..end] {
+            if value.is_zero() {
+                self.zero_count += 1;
+            } else {
+                self.prod = self.prod * *value;
+            }
+        }
+    }
+}
+
+impl<'a, T: NativeType + IsFloat + Num> RollingAggWindowNoNulls<'a, T> for ProductWindow<'a, T> {
+    fn new(slice: &'a [T], start: usize, end: usize, _params: Option<RollingFnParams>) -> Self {
+        let mut out = Self {
+            slice,
+            prod: T::one(),
+            zero_count: 0,
+            last_start: start,
+            last_end: end,
+            last_recompute: 0,
+        };
+        out.compute_product(start, end);
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        // if we exceed the end, we have a completely new window
+        // so we recompute
+        let recompute_prod = if start >= self.last_end || self.last_recompute > 128 {
+            true
+        } else {
+            // remove elements that should leave the window
+            let mut recompute_prod = false;
+            for idx in self.last_start..start {
+                // SAFETY:
+                // we are in bounds
+                let leaving_value = *self.slice.get_unchecked(idx);
+
+                if leaving_value.is_zero() {
+                    self.zero_count -= 1;
+                } else if T::is_float()
+                    && leaving_value.is_finite()
+                    && self.prod.is_finite()
+                    && !self.prod.is_zero()
+                {
+                    self.prod = self.prod / leaving_value;
+                } else {
+                    // integer products may have wrapped around, and non-finite
+                    // (or underflowed) float products cannot be divided out
+                    recompute_prod = true;
+                    break;
+                }
+            }
+            recompute_prod
+        };
+        self.last_start = start;
+
+        if recompute_prod {
+            self.last_recompute = 0;
+            self.compute_product(start, end);
+        } else {
+            self.last_recompute += 1;
+            for idx in self.last_end..end {
+                let value = *self.slice.get_unchecked(idx);
+                if value.is_zero() {
+                    self.zero_count += 1;
+                } else {
+                    self.prod = self.prod * value;
+                }
+            }
+        }
+        self.last_end = end;
+
+        // multiply (rather than return zero) so that NaN and infinite values propagate
+        if self.zero_count > 0 {
+            Some(self.prod * T::zero())
+        } else {
+            Some(self.prod)
+        }
+    }
+}
+
+pub fn rolling_product<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    _params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat + Num,
+{
+    polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported for 'rolling_product'");
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<ProductWindow<_>, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        None,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_product() {
+        let values = &[1.0f64, 2.0, 3.0, 0.0, 5.0, 6.0];
+
+        let out = rolling_product(values, 2, 2, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[None, Some(2.0), Some(6.0), Some(0.0), Some(0.0), Some(30.0)]
+        );
+
+        let out = rolling_product(values, 3, 1, true, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(2.0),
+                Some(6.0),
+                Some(0.0),
+                Some(0.0),
+                Some(0.0),
+                Some(30.0)
+            ]
+        );
+
+        let values = &[2i64, -3, 4, 5];
+        let out = rolling_product(values, 3, 1, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(2), Some(-6), Some(-24), Some(-60)]);
+    }
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+pub struct KurtosisWindow<'a, T> {
+    slice: &'a [T],
+    validity: &'a Bitmap,
+    params: RollingKurtosisParams,
+    last_start: usize,
+    last_end: usize,
+    null_count: usize,
+}
+
+impl<'a, T: NativeType + IsFloat + Float> RollingAggWindowNulls<'a, T> for KurtosisWindow<'a, T> {
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+    ) -> Self {
+        Self {
+            slice,
+            validity,
+            params: RollingKurtosisParams::from_fn_params(params),
+            last_start: start,
+            last_end: end,
+            null_count: 0,
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        self.last_start = start;
+        self.last_end = end;
+        self.null_count = self.validity.null_count_range(start, end - start);
+
+        let values = (start..end)
+            .filter(|idx| self.validity.get_bit_unchecked(*idx))
+            .map(|idx| *self.slice.get_unchecked(idx));
+        compute_kurtosis(values, self.params)
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        ((self.last_end - self.last_start) - self.null_count) >= min_periods
+    }
+}
+
+pub fn rolling_kurtosis<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat + Float,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    if center {
+        rolling_apply_agg_window::<KurtosisWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets_center,
+            params,
+        )
+    } else {
+        rolling_apply_agg_window::<KurtosisWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets,
+            params,
+        )
+    }
+}
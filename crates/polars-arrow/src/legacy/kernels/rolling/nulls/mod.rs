@@ -1,11 +1,15 @@
+mod kurtosis;
 mod mean;
 mod min_max;
+mod product;
 mod quantile;
 mod sum;
 mod variance;
 
+pub use kurtosis::*;
 pub use mean::*;
 pub use min_max::*;
+pub use product::*;
 pub use quantile::*;
 pub use sum::*;
 pub use variance::*;
@@ -202,6 +206,38 @@ mod test {
         assert_eq!(out, &[0.0, 0.0, 1.0, 4.222222222222222]);
     }
 
+    #[test]
+    fn test_rolling_product_nulls() {
+        let arr = get_null_arr();
+        let arr = &arr;
+
+        let out = rolling_product(arr, 2, 1, false, None, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), Some(1.0), Some(-1.0), Some(-4.0)]);
+
+        let out = rolling_product(arr, 3, 2, false, None, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(-1.0), Some(-4.0)]);
+    }
+
+    #[test]
+    fn test_rolling_kurtosis_nulls() {
+        let arr = get_null_arr();
+        let arr = &arr;
+        let params = Some(RollingFnParams::Kurtosis(RollingKurtosisParams {
+            fisher: false,
+            bias: true,
+        }));
+
+        let out = rolling_kurtosis(arr, 4, 3, false, None, params);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out[..3], [None, None, None]);
+        assert!((out[3].unwrap() - 1.5).abs() < 1e-12);
+    }
+
     #[test]
     fn test_rolling_max_no_nulls() {
         let buf = Buffer::from(vec![1.0, 2.0, 3.0, 4.0]);
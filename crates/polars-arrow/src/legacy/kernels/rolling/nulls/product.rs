@@ -0,0 +1,169 @@
+use num_traits::Num;
+
+use super::*;
+
+pub struct ProductWindow<'a, T> {
+    slice: &'a [T],
+    validity: &'a Bitmap,
+    // product of the valid, non-zero values in the window
+    prod: T,
+    zero_count: usize,
+    last_start: usize,
+    last_end: usize,
+    // if we don't recompute every 'n' iterations
+    // we get a accumulated error/drift
+    last_recompute: u8,
+    null_count: usize,
+}
+
+impl<T: NativeType + IsFloat + Num> ProductWindow<'_, T> {
+    // compute product from the entire window
+    unsafe fn compute_product_and_null_count(&mut self, start: usize, end: usize) {
+        self.prod = T::one();
+        self.zero_count = 0;
+        self.null_count = 0;
+        for idx in start..end {
+            if self.validity.get_bit_unchecked(idx) {
+                let value = *self.slice.get_unchecked(idx);
+                if value.is_zero() {
+                    self.zero_count += 1;
+                } else {
+                    self.prod = self.prod * value;
+                }
+            } else {
+                self.null_count += 1;
+            }
+        }
+    }
+}
+
+impl<'a, T: NativeType + IsFloat + Num> RollingAggWindowNulls<'a, T> for ProductWindow<'a, T> {
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+    ) -> Self {
+        let mut out = Self {
+            slice,
+            validity,
+            prod: T::one(),
+            zero_count: 0,
+            last_start: start,
+            last_end: end,
+            last_recompute: 0,
+            null_count: 0,
+        };
+        out.compute_product_and_null_count(start, end);
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        // if we exceed the end, we have a completely new window
+        // so we recompute
+        let recompute_prod = if start >= self.last_end || self.last_recompute > 128 {
+            true
+        } else {
+            // remove elements that should leave the window
+            let mut recompute_prod = false;
+            for idx in self.last_start..start {
+                // SAFETY:
+                // we are in bounds
+                let valid = self.validity.get_bit_unchecked(idx);
+                if valid {
+                    let leaving_value = *self.slice.get_unchecked(idx);
+
+                    if leaving_value.is_zero() {
+                        self.zero_count -= 1;
+                    } else if T::is_float()
+                        && leaving_value.is_finite()
+                        && self.prod.is_finite()
+                        && !self.prod.is_zero()
+                    {
+                        self.prod = self.prod / leaving_value;
+                    } else {
+                        recompute_prod = true;
+                        break;
+                    }
+                } else {
+                    // null value leaving the window
+                    self.null_count -= 1;
+                }
+            }
+            recompute_prod
+        };
+
+        self.last_start = start;
+
+        if recompute_prod {
+            self.last_recompute = 0;
+            self.compute_product_and_null_count(start, end);
+        } else {
+            self.last_recompute += 1;
+            for idx in self.last_end..end {
+                let valid = self.validity.get_bit_unchecked(idx);
+
+                if valid {
+                    let value = *self.slice.get_unchecked(idx);
+                    if value.is_zero() {
+                        self.zero_count += 1;
+                    } else {
+                        self.prod = self.prod * value;
+                    }
+                } else {
+                    // null value entering the window
+                    self.null_count += 1;
+                }
+            }
+        }
+        self.last_end = end;
+
+        if (self.last_end - self.last_start) == self.null_count {
+            None
+        } else if self.zero_count > 0 {
+            Some(self.prod * T::zero())
+        } else {
+            Some(self.prod)
+        }
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        ((self.last_end - self.last_start) - self.null_count) >= min_periods
+    }
+}
+
+pub fn rolling_product<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    _params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat + Num,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    if center {
+        rolling_apply_agg_window::<ProductWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets_center,
+            None,
+        )
+    } else {
+        rolling_apply_agg_window::<ProductWindow<_>, _, _>(
+            arr.values().as_slice(),
+            arr.validity().as_ref().unwrap(),
+            window_size,
+            min_periods,
+            det_offsets,
+            None,
+        )
+    }
+}
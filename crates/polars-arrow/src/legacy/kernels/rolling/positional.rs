@@ -0,0 +1,366 @@
+//! Rolling aggregations whose output depends on the positions of the values in the window
+//! (ranks, arg-extrema) or whose output type differs from the input type (n_unique).
+use std::cmp::Ordering;
+
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::total_ord::{TotalEq, TotalHash, TotalOrd, TotalOrdWrap};
+use polars_utils::IdxSize;
+
+use super::*;
+
+pub trait RollingAggWindowPositional<'a, T: NativeType> {
+    type Out: NativeType;
+
+    fn new(slice: &'a [T], validity: Option<&'a Bitmap>, params: Option<RollingFnParams>) -> Self;
+
+    /// Update the window to `start..end` and compute the aggregation for the value at `idx`.
+    ///
+    /// # Safety
+    /// `start`, `end` and `idx` must be in bounds of the slice and the validity.
+    unsafe fn update(&mut self, start: usize, end: usize, idx: usize) -> Option<Self::Out>;
+}
+
+#[inline]
+unsafe fn is_valid_unchecked(validity: Option<&Bitmap>, idx: usize) -> bool {
+    validity.map_or(true, |validity| validity.get_bit_unchecked(idx))
+}
+
+/// The rank of the current value within the window, as a fraction of the number
+/// of valid values in the window. Ties get their average rank.
+pub struct RankWindow<'a, T> {
+    slice: &'a [T],
+    validity: Option<&'a Bitmap>,
+}
+
+impl<'a, T: NativeType + TotalOrd> RollingAggWindowPositional<'a, T> for RankWindow<'a, T> {
+    type Out = f64;
+
+    fn new(slice: &'a [T], validity: Option<&'a Bitmap>, _params: Option<RollingFnParams>) -> Self {
+        Self { slice, validity }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize, idx: usize) -> Option<f64> {
+        if !is_valid_unchecked(self.validity, idx) {
+            return None;
+        }
+        let current = self.slice.get_unchecked(idx);
+
+        let mut count = 0usize;
+        let mut n_lt = 0usize;
+        let mut n_eq = 0usize;
+        for i in start..end {
+            if is_valid_unchecked(self.validity, i) {
+                count += 1;
+                match self.slice.get_unchecked(i).tot_cmp(current) {
+                    Ordering::Less => n_lt += 1,
+                    Ordering::Equal => n_eq += 1,
+                    Ordering::Greater => {},
+                }
+            }
+        }
+        // windows that are closed on the right don't contain the current value
+        if idx < start || idx >= end {
+            count += 1;
+            n_eq += 1;
+        }
+        let rank = n_lt as f64 + (n_eq + 1) as f64 / 2.0;
+        Some(rank / count as f64)
+    }
+}
+
+/// The number of unique values in the window, where null counts as a value.
+pub struct NUniqueWindow<'a, T> {
+    slice: &'a [T],
+    validity: Option<&'a Bitmap>,
+    counts: PlHashMap<TotalOrdWrap<T>, IdxSize>,
+    null_count: usize,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<T: NativeType + TotalHash + TotalEq> NUniqueWindow<'_, T> {
+    unsafe fn insert(&mut self, idx: usize) {
+        if is_valid_unchecked(self.validity, idx) {
+            let value = TotalOrdWrap(*self.slice.get_unchecked(idx));
+            *self.counts.entry(value).or_insert(0) += 1;
+        } else {
+            self.null_count += 1;
+        }
+    }
+
+    unsafe fn remove(&mut self, idx: usize) {
+        if is_valid_unchecked(self.validity, idx) {
+            let value = TotalOrdWrap(*self.slice.get_unchecked(idx));
+            let count = self.counts.get_mut(&value).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(&value);
+            }
+        } else {
+            self.null_count -= 1;
+        }
+    }
+}
+
+impl<'a, T: NativeType + TotalHash + TotalEq> RollingAggWindowPositional<'a, T>
+    for NUniqueWindow<'a, T>
+{
+    type Out = IdxSize;
+
+    fn new(slice: &'a [T], validity: Option<&'a Bitmap>, _params: Option<RollingFnParams>) -> Self {
+        Self {
+            slice,
+            validity,
+            counts: PlHashMap::new(),
+            null_count: 0,
+            last_start: 0,
+            last_end: 0,
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize, _idx: usize) -> Option<IdxSize> {
+        // if we exceed the end, we have a completely new window
+        if start >= self.last_end {
+            self.counts.clear();
+            self.null_count = 0;
+            for i in start..end {
+                self.insert(i);
+            }
+        } else {
+            for i in self.last_start..start {
+                self.remove(i);
+            }
+            for i in self.last_end..end {
+                self.insert(i);
+            }
+        }
+        self.last_start = start;
+        self.last_end = end;
+
+        Some((self.counts.len() + (self.null_count > 0) as usize) as IdxSize)
+    }
+}
+
+macro_rules! arg_extremum_window {
+    ($name:ident, $compare_fn:ident, $better:ident, $doc:literal) => {
+        #[doc = $doc]
+        pub struct $name<'a, T> {
+            slice: &'a [T],
+            validity: Option<&'a Bitmap>,
+            // index of the extremum in `slice`
+            m_idx: Option<usize>,
+            last_end: usize,
+        }
+
+        impl<T: NativeType + IsFloat + PartialOrd> $name<'_, T> {
+            // only replaces the current extremum if `idx` is strictly better,
+            // so that we keep the first occurrence
+            unsafe fn visit(&mut self, idx: usize) {
+                if !is_valid_unchecked(self.validity, idx) {
+                    return;
+                }
+                let value = self.slice.get_unchecked(idx);
+                match self.m_idx {
+                    Some(m) if !$compare_fn(value, self.slice.get_unchecked(m)).$better() => {},
+                    _ => self.m_idx = Some(idx),
+                }
+            }
+        }
+
+        impl<'a, T: NativeType + IsFloat + PartialOrd> RollingAggWindowPositional<'a, T>
+            for $name<'a, T>
+        {
+            type Out = IdxSize;
+
+            fn new(
+                slice: &'a [T],
+                validity: Option<&'a Bitmap>,
+                _params: Option<RollingFnParams>,
+            ) -> Self {
+                Self {
+                    slice,
+                    validity,
+                    m_idx: None,
+                    last_end: 0,
+                }
+            }
+
+            unsafe fn update(&mut self, start: usize, end: usize, _idx: usize) -> Option<IdxSize> {
+                let recompute = match self.m_idx {
+                    Some(m) => m < start || start >= self.last_end,
+                    None => true,
+                };
+                if recompute {
+                    // the extremum left the window, we must look at all values again
+                    self.m_idx = None;
+                    for i in start..end {
+                        self.visit(i);
+                    }
+                } else {
+                    for i in self.last_end..end {
+                        self.visit(i);
+                    }
+                }
+                self.last_end = end;
+
+                self.m_idx.map(|m| (m - start) as IdxSize)
+            }
+        }
+    };
+}
+
+arg_extremum_window!(
+    ArgMinWindow,
+    compare_fn_nan_min,
+    is_lt,
+    "The index of the first minimum within the window."
+);
+arg_extremum_window!(
+    ArgMaxWindow,
+    compare_fn_nan_max,
+    is_gt,
+    "The index of the first maximum within the window."
+);
+
+/// Apply a positional aggregation over fixed windows. Windows with fewer than
+/// `min_periods` valid values are null.
+pub fn rolling_apply_positional<'a, Agg, T>(
+    arr: &'a PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    Agg: RollingAggWindowPositional<'a, T>,
+    T: NativeType,
+{
+    let len = arr.len();
+    let validity = arr.validity();
+    let det_offsets_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    let mut agg_window = Agg::new(arr.values().as_slice(), validity, params);
+
+    let out = (0..len).map(|idx| {
+        let (start, end) = det_offsets_fn(idx, window_size, len);
+        let null_count =
+            validity.map_or(0, |validity| validity.null_count_range(start, end - start));
+        if end - start - null_count < min_periods {
+            None
+        } else {
+            // SAFETY:
+            // we are in bounds
+            unsafe { agg_window.update(start, end, idx) }
+        }
+    });
+    Box::new(PrimitiveArray::<Agg::Out>::from_trusted_len_iter(out))
+}
+
+pub fn rolling_rank<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + TotalOrd,
+{
+    rolling_apply_positional::<RankWindow<_>, _>(arr, window_size, min_periods, center, params)
+}
+
+pub fn rolling_n_unique<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + TotalHash + TotalEq,
+{
+    rolling_apply_positional::<NUniqueWindow<_>, _>(arr, window_size, min_periods, center, params)
+}
+
+pub fn rolling_arg_min<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat + PartialOrd,
+{
+    rolling_apply_positional::<ArgMinWindow<_>, _>(arr, window_size, min_periods, center, params)
+}
+
+pub fn rolling_arg_max<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat + PartialOrd,
+{
+    rolling_apply_positional::<ArgMaxWindow<_>, _>(arr, window_size, min_periods, center, params)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_rank() {
+        let arr = PrimitiveArray::from_slice([1, 3, 2, 2]);
+        let out = rolling_rank(&arr, 3, 1, false, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), Some(1.0), Some(2.0 / 3.0), Some(0.5)]);
+    }
+
+    #[test]
+    fn test_rolling_n_unique() {
+        let arr = PrimitiveArray::from([Some(1), None, Some(1), Some(2), None]);
+        let out = rolling_n_unique(&arr, 3, 1, false, None);
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1), Some(2), Some(2), Some(3), Some(3)]);
+
+        let out = rolling_n_unique(&arr, 3, 2, false, None);
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(2), Some(3), Some(3)]);
+    }
+
+    #[test]
+    fn test_rolling_arg_min_max() {
+        let arr = PrimitiveArray::from_slice([1.0, 3.0, 3.0, 2.0, 1.0]);
+
+        let out = rolling_arg_max(&arr, 3, 3, false, None);
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(1), Some(0), Some(0)]);
+
+        let out = rolling_arg_min(&arr, 3, 1, false, None);
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(0), Some(0), Some(0), Some(2), Some(2)]);
+    }
+}
@@ -4,7 +4,7 @@ pub use crate::legacy::array::*;
 pub use crate::legacy::index::*;
 pub use crate::legacy::kernels::rolling::no_nulls::QuantileMethod;
 pub use crate::legacy::kernels::rolling::{
-    RollingFnParams, RollingQuantileParams, RollingVarParams,
+    RollingFnParams, RollingKurtosisParams, RollingQuantileParams, RollingVarParams,
 };
 pub use crate::legacy::kernels::{Ambiguous, NonExistent};
 
@@ -1,13 +1,110 @@
+use polars_utils::floor_divmod::FloorDivMod;
+
 use super::*;
+use crate::chunked_array::arity::broadcast_try_binary_elementwise;
+use crate::chunked_array::logical::DECIMAL_MAX_ABS;
+
+/// Check that a result didn't overflow. Overflows raise an error if `strict`, otherwise they
+/// become null.
+#[inline]
+fn check_overflow(v: Option<i128>, strict: bool, op: &str) -> PolarsResult<Option<i128>> {
+    match v.filter(|v| v.unsigned_abs() < DECIMAL_MAX_ABS) {
+        Some(v) => Ok(Some(v)),
+        None => {
+            polars_ensure!(
+                !strict,
+                ComputeError: "decimal overflow in {}: the result doesn't fit in precision 38", op
+            );
+            Ok(None)
+        },
+    }
+}
+
+fn checked_binary<F>(
+    lhs: &DecimalChunked,
+    rhs: &DecimalChunked,
+    scale: usize,
+    op: F,
+) -> PolarsResult<DecimalChunked>
+where
+    F: Fn(i128, i128) -> PolarsResult<Option<i128>>,
+{
+    let out: Int128Chunked =
+        broadcast_try_binary_elementwise(&lhs.0, &rhs.0, |a, b| match (a, b) {
+            (Some(a), Some(b)) => op(a, b),
+            _ => Ok(None),
+        })?;
+    Ok(out.into_decimal_unchecked(None, scale))
+}
+
+impl DecimalChunked {
+    /// Add two decimals at the largest of both scales. If `strict`, a result that doesn't fit
+    /// in a decimal raises an error, otherwise it becomes null.
+    pub fn checked_add(&self, rhs: &Self, strict: bool) -> PolarsResult<Self> {
+        let scale = self.scale().max(rhs.scale());
+        let l = 10i128.pow((scale - self.scale()) as u32);
+        let r = 10i128.pow((scale - rhs.scale()) as u32);
+        checked_binary(self, rhs, scale, |a, b| {
+            let v = a
+                .checked_mul(l)
+                .zip(b.checked_mul(r))
+                .and_then(|(a, b)| a.checked_add(b));
+            check_overflow(v, strict, "addition")
+        })
+    }
+
+    /// Subtract two decimals at the largest of both scales. If `strict`, a result that doesn't
+    /// fit in a decimal raises an error, otherwise it becomes null.
+    pub fn checked_sub(&self, rhs: &Self, strict: bool) -> PolarsResult<Self> {
+        let scale = self.scale().max(rhs.scale());
+        let l = 10i128.pow((scale - self.scale()) as u32);
+        let r = 10i128.pow((scale - rhs.scale()) as u32);
+        checked_binary(self, rhs, scale, |a, b| {
+            let v = a
+                .checked_mul(l)
+                .zip(b.checked_mul(r))
+                .and_then(|(a, b)| a.checked_sub(b));
+            check_overflow(v, strict, "subtraction")
+        })
+    }
+
+    /// Multiply two decimals, the scale of the result is the sum of both scales. If `strict`, a
+    /// result that doesn't fit in a decimal raises an error, otherwise it becomes null.
+    pub fn checked_mul(&self, rhs: &Self, strict: bool) -> PolarsResult<Self> {
+        let scale = self.scale() + rhs.scale();
+        polars_ensure!(
+            scale <= 38,
+            InvalidOperation: "decimal multiplication would need a scale of {}, the maximum is 38",
+            scale
+        );
+        checked_binary(self, rhs, scale, |a, b| {
+            check_overflow(a.checked_mul(b), strict, "multiplication")
+        })
+    }
+
+    /// Divide two decimals, the result has the scale given by [`decimal_div_scale`] and is
+    /// rounded towards negative infinity. Division by zero results in null. If `strict`, a
+    /// result that doesn't fit in a decimal raises an error, otherwise it becomes null.
+    pub fn checked_div(&self, rhs: &Self, strict: bool) -> PolarsResult<Self> {
+        let scale = decimal_div_scale(self.scale());
+        let factor = 10i128.checked_pow((scale + rhs.scale() - self.scale()) as u32);
+        checked_binary(self, rhs, scale, |a, b| {
+            if b == 0 {
+                return Ok(None);
+            }
+            let v = factor
+                .and_then(|f| a.checked_mul(f))
+                .map(|a| a.wrapping_floor_div_mod(b).0);
+            check_overflow(v, strict, "division")
+        })
+    }
+}
 
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let scale = self.scale().max(rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 + &rhs.0).into_decimal_unchecked(None, scale))
+        self.checked_add(rhs, true)
     }
 }
 
@@ -15,10 +112,7 @@ impl Sub for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let scale = self.scale().max(rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.0 - &rhs.0).into_decimal_unchecked(None, scale))
+        self.checked_sub(rhs, true)
     }
 }
 
@@ -26,8 +120,7 @@ impl Mul for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let scale = self.scale() + rhs.scale();
-        Ok((&self.0 * &rhs.0).into_decimal_unchecked(None, scale))
+        self.checked_mul(rhs, true)
     }
 }
 
@@ -35,9 +128,39 @@ impl Div for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn div(self, rhs: Self) -> Self::Output {
-        // Follow postgres and MySQL adding a fixed scale increment of 4
-        let scale = self.scale() + 4;
-        let lhs = self.to_scale(scale + rhs.scale())?;
-        Ok((&lhs.0 / &rhs.0).into_decimal_unchecked(None, scale))
+        self.checked_div(rhs, true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dec(values: &[Option<i128>], scale: usize) -> DecimalChunked {
+        Int128Chunked::from_slice_options(PlSmallStr::EMPTY, values)
+            .into_decimal_unchecked(None, scale)
+    }
+
+    #[test]
+    fn test_decimal_checked_arithmetic() {
+        let a = dec(&[Some(150), Some(-150), None], 2);
+        let b = dec(&[Some(2), Some(4), Some(1)], 0);
+
+        let out = (&a + &b).unwrap();
+        assert_eq!(out.scale(), 2);
+        assert_eq!(Vec::from(&out.0), &[Some(350), Some(250), None]);
+
+        // Division keeps 4 extra digits and rounds towards negative infinity.
+        let c = dec(&[Some(1), Some(-1), Some(1)], 0);
+        let d = dec(&[Some(3), Some(3), Some(0)], 0);
+        let out = (&c / &d).unwrap();
+        assert_eq!(out.scale(), 4);
+        assert_eq!(Vec::from(&out.0), &[Some(3333), Some(-3334), None]);
+
+        let big = dec(&[Some(10i128.pow(37) * 9), Some(1)], 0);
+        assert!((&big + &big).is_err());
+        assert!((&big * &big).is_err());
+        let out = big.checked_add(&big, false).unwrap();
+        assert_eq!(Vec::from(&out.0), &[None, Some(2)]);
     }
 }
@@ -0,0 +1,206 @@
+use ethnum::I256;
+
+use super::*;
+
+/// Check that a result didn't overflow the maximum precision of 76.
+#[inline]
+fn check_overflow(v: Option<I256>, op: &str) -> PolarsResult<Option<i256>> {
+    match v.filter(|v| fits_precision_256(*v, DECIMAL256_MAX_PRECISION)) {
+        Some(v) => Ok(Some(i256(v))),
+        None => polars_bail!(
+            ComputeError: "decimal overflow in {}: the result doesn't fit in precision 76", op
+        ),
+    }
+}
+
+fn checked_binary<F>(
+    lhs: &Decimal256Chunked,
+    rhs: &Decimal256Chunked,
+    scale: usize,
+    op: F,
+) -> PolarsResult<Decimal256Chunked>
+where
+    F: Fn(I256, I256) -> PolarsResult<Option<i256>>,
+{
+    let apply = |a: Option<i256>, b: Option<i256>| match (a, b) {
+        (Some(a), Some(b)) => op(a.0, b.0),
+        _ => Ok(None),
+    };
+    let out = match (lhs.len(), rhs.len()) {
+        (l, r) if l == r => lhs
+            .iter_i256()
+            .zip(rhs.iter_i256())
+            .map(|(a, b)| apply(a, b))
+            .collect::<PolarsResult<Vec<_>>>()?,
+        (1, _) => {
+            let a = lhs.iter_i256().next().unwrap();
+            rhs.iter_i256()
+                .map(|b| apply(a, b))
+                .collect::<PolarsResult<Vec<_>>>()?
+        },
+        (_, 1) => {
+            let b = rhs.iter_i256().next().unwrap();
+            lhs.iter_i256()
+                .map(|a| apply(a, b))
+                .collect::<PolarsResult<Vec<_>>>()?
+        },
+        (l, r) => polars_bail!(
+            ShapeMismatch: "cannot apply a decimal operation on columns of lengths {} and {}", l, r
+        ),
+    };
+    Ok(Decimal256Chunked::from_i256s(
+        lhs.name().clone(),
+        out,
+        None,
+        scale,
+    ))
+}
+
+impl Decimal256Chunked {
+    /// Add two decimals at the largest of both scales.
+    pub fn checked_add(&self, rhs: &Self) -> PolarsResult<Self> {
+        let scale = self.scale().max(rhs.scale());
+        let l = pow10_256(scale - self.scale());
+        let r = pow10_256(scale - rhs.scale());
+        checked_binary(self, rhs, scale, |a, b| {
+            let v = a
+                .checked_mul(l)
+                .zip(b.checked_mul(r))
+                .and_then(|(a, b)| a.checked_add(b));
+            check_overflow(v, "addition")
+        })
+    }
+
+    /// Subtract two decimals at the largest of both scales.
+    pub fn checked_sub(&self, rhs: &Self) -> PolarsResult<Self> {
+        let scale = self.scale().max(rhs.scale());
+        let l = pow10_256(scale - self.scale());
+        let r = pow10_256(scale - rhs.scale());
+        checked_binary(self, rhs, scale, |a, b| {
+            let v = a
+                .checked_mul(l)
+                .zip(b.checked_mul(r))
+                .and_then(|(a, b)| a.checked_sub(b));
+            check_overflow(v, "subtraction")
+        })
+    }
+
+    /// Multiply two decimals, the scale of the result is the sum of both scales.
+    pub fn checked_mul(&self, rhs: &Self) -> PolarsResult<Self> {
+        let scale = self.scale() + rhs.scale();
+        polars_ensure!(
+            scale <= DECIMAL256_MAX_PRECISION,
+            InvalidOperation: "decimal multiplication would need a scale of {}, the maximum is 76",
+            scale
+        );
+        checked_binary(self, rhs, scale, |a, b| {
+            check_overflow(a.checked_mul(b), "multiplication")
+        })
+    }
+
+    /// Divide two decimals, the result keeps 4 more digits than the dividend (at most 76) and
+    /// is rounded towards negative infinity. Division by zero results in null.
+    pub fn checked_div(&self, rhs: &Self) -> PolarsResult<Self> {
+        let scale = (self.scale() + 4).min(DECIMAL256_MAX_PRECISION);
+        let factor = I256::new(10).checked_pow((scale + rhs.scale() - self.scale()) as u32);
+        checked_binary(self, rhs, scale, |a, b| {
+            if b == I256::ZERO {
+                return Ok(None);
+            }
+            let v = factor.and_then(|f| a.checked_mul(f)).map(|a| {
+                let (q, r) = (a / b, a % b);
+                // Round towards negative infinity if the signs differ.
+                if r != I256::ZERO && (r < I256::ZERO) != (b < I256::ZERO) {
+                    q - I256::ONE
+                } else {
+                    q
+                }
+            });
+            check_overflow(v, "division")
+        })
+    }
+}
+
+impl Add for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+    }
+}
+
+impl Sub for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+    }
+}
+
+impl Mul for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+    }
+}
+
+impl Div for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dec(values: &[Option<i128>], scale: usize) -> Decimal256Chunked {
+        Decimal256Chunked::from_i256s(
+            PlSmallStr::EMPTY,
+            values.iter().map(|v| v.map(|v| i256(I256::new(v)))),
+            None,
+            scale,
+        )
+    }
+
+    fn values(ca: &Decimal256Chunked) -> Vec<Option<I256>> {
+        ca.iter_i256().map(|v| v.map(|v| v.0)).collect()
+    }
+
+    #[test]
+    fn test_decimal256_checked_arithmetic() {
+        let a = dec(&[Some(150), Some(-150), None], 2);
+        let b = dec(&[Some(2), Some(4), Some(1)], 0);
+
+        let out = (&a + &b).unwrap();
+        assert_eq!(out.scale(), 2);
+        assert_eq!(
+            values(&out),
+            &[Some(I256::new(350)), Some(I256::new(250)), None]
+        );
+
+        // Division keeps 4 extra digits and rounds towards negative infinity.
+        let c = dec(&[Some(1), Some(-1), Some(1)], 0);
+        let d = dec(&[Some(3), Some(3), Some(0)], 0);
+        let out = (&c / &d).unwrap();
+        assert_eq!(out.scale(), 4);
+        assert_eq!(
+            values(&out),
+            &[Some(I256::new(3333)), Some(I256::new(-3334)), None]
+        );
+
+        // Products beyond 38 digits still fit, but not beyond 76 digits.
+        let big = dec(&[Some(10i128.pow(37) * 9)], 0);
+        let out = (&big * &big).unwrap();
+        assert_eq!(
+            values(&out),
+            &[Some(
+                I256::new(10i128.pow(37) * 9) * I256::new(10i128.pow(37) * 9)
+            )]
+        );
+        assert!((&out * &out).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 //! Implementations of arithmetic operations on ChunkedArrays.
 #[cfg(feature = "dtype-decimal")]
 mod decimal;
+#[cfg(feature = "dtype-decimal")]
+mod decimal256;
 mod numeric;
 
 use std::ops::{Add, Div, Mul, Rem, Sub};
@@ -20,7 +20,7 @@ where
         inner_type: DataType,
     ) -> Self {
         assert!(
-            inner_type.is_numeric() || inner_type.is_temporal(),
+            inner_type.is_numeric() || inner_type.is_temporal() || inner_type.is_float16(),
             "inner type must be primitive"
         );
         let values = MutablePrimitiveArray::<T::Native>::with_capacity(values_capacity);
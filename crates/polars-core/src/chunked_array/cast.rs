@@ -91,6 +91,12 @@ fn cast_impl_inner(
         Duration(tu) => out.into_duration(*tu),
         #[cfg(feature = "dtype-time")]
         Time => out.into_time(),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => out
+            .f32()?
+            .apply_values(round_to_f16)
+            .into_float16()
+            .into_series(),
         _ => out,
     };
 
@@ -489,6 +495,8 @@ impl ChunkCast for ListChunked {
                     ))
                 }
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => self.clone().into_map()?.cast_with_options(dtype, options),
             _ => {
                 polars_bail!(
                     InvalidOperation: "cannot cast List type (inner: '{:?}', to: '{:?}')",
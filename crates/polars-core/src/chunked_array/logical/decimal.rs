@@ -6,6 +6,15 @@ use crate::prelude::*;
 
 pub type DecimalChunked = Logical<DecimalType, Int128Type>;
 
+/// The scale of the result of dividing a decimal with the given scale, which is also the scale
+/// of its `mean`, `var` and `std`. Follows postgres and MySQL in adding a fixed increment of 4.
+pub fn decimal_div_scale(scale: usize) -> usize {
+    (scale + 4).min(38)
+}
+
+/// Decimal values must stay below `10^38` in absolute value to fit the maximum precision.
+pub(crate) const DECIMAL_MAX_ABS: u128 = 10u128.pow(38);
+
 impl Int128Chunked {
     fn update_chunks_dtype(&mut self, precision: Option<usize>, scale: usize) {
         // physical i128 type doesn't exist
@@ -86,6 +95,11 @@ impl LogicalType for DecimalChunked {
         cast_options: CastOptions,
     ) -> PolarsResult<Series> {
         let (precision_src, scale_src) = (self.precision(), self.scale());
+        #[cfg(feature = "dtype-interval")]
+        if dtype.is_interval() && scale_src == 0 {
+            // the physical representation of an interval
+            return Ok(self.0.clone().into_interval().into_series());
+        }
         if let &DataType::Decimal(precision_dst, scale_dst) = dtype {
             let scale_dst = scale_dst.unwrap_or(scale_src);
             // for now, let's just allow same-scale conversions
@@ -0,0 +1,408 @@
+use arrow::array::MutableBinaryViewArray;
+use arrow::compute::decimal::{deserialize_decimal256, format_decimal256, infer_scale_256};
+use ethnum::{AsI256, I256, U256};
+
+use super::*;
+use crate::prelude::*;
+
+pub type Decimal256Chunked = Logical<Decimal256Type, BinaryType>;
+
+/// The maximum precision of a [`DataType::Decimal256`].
+pub const DECIMAL256_MAX_PRECISION: usize = 76;
+
+/// Encode a 256-bit decimal into its physical representation.
+///
+/// The value is stored as big-endian bytes with the sign bit flipped, so that the byte order
+/// of the physical binary values is the order of the decimals.
+#[inline]
+pub fn i256_to_physical(v: i256) -> [u8; 32] {
+    (v.0 ^ I256::MIN).to_be_bytes()
+}
+
+/// Decode the physical representation of a 256-bit decimal.
+#[inline]
+pub fn i256_from_physical(bytes: &[u8]) -> i256 {
+    let bytes: [u8; 32] = bytes.try_into().expect("decimal256 values are 32 bytes");
+    i256(I256::from_be_bytes(bytes) ^ I256::MIN)
+}
+
+#[inline]
+pub(crate) fn pow10_256(exp: usize) -> I256 {
+    I256::new(10).pow(exp as u32)
+}
+
+/// Whether a physical 256-bit value fits in the given precision.
+#[inline]
+pub(crate) fn fits_precision_256(v: I256, precision: usize) -> bool {
+    v.unsigned_abs() < U256::new(10).pow(precision as u32)
+}
+
+/// Change the scale of a physical 256-bit value, digits beyond the new scale are truncated.
+#[inline]
+pub(crate) fn rescale_256(v: I256, from: usize, to: usize) -> Option<I256> {
+    if to >= from {
+        v.checked_mul(pow10_256(to - from))
+    } else {
+        Some(v / pow10_256(from - to))
+    }
+}
+
+pub(crate) fn decimal256_array_to_physical(arr: &dyn Array) -> ArrayRef {
+    let arr = arr.as_any().downcast_ref::<PrimitiveArray<i256>>().unwrap();
+    MutableBinaryViewArray::<[u8]>::from_iter(arr.iter().map(|v| v.map(|v| i256_to_physical(*v))))
+        .freeze()
+        .boxed()
+}
+
+impl BinaryChunked {
+    #[inline]
+    pub fn into_decimal256_unchecked(
+        self,
+        precision: Option<usize>,
+        scale: usize,
+    ) -> Decimal256Chunked {
+        let mut dt = Decimal256Chunked::new_logical(self);
+        dt.2 = Some(DataType::Decimal256(precision, Some(scale)));
+        dt
+    }
+}
+
+impl LogicalType for Decimal256Chunked {
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.0.get_unchecked(i) {
+            Some(v) => AnyValue::Decimal256(i256_from_physical(v), self.scale()),
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        use DataType::*;
+        let scale_src = self.scale();
+        let out = match dtype {
+            Decimal256(precision, scale) => {
+                check_precision_256(*precision)?;
+                let scale = scale.unwrap_or(scale_src);
+                let max_precision = precision.unwrap_or(DECIMAL256_MAX_PRECISION);
+                Decimal256Chunked::from_i256s(
+                    self.name().clone(),
+                    self.iter_i256().map(|v| {
+                        rescale_256(v?.0, scale_src, scale)
+                            .filter(|v| fits_precision_256(*v, max_precision))
+                            .map(i256)
+                    }),
+                    *precision,
+                    scale,
+                )
+                .into_series()
+            },
+            Decimal(precision, scale) => {
+                let scale = scale.unwrap_or(scale_src);
+                let max_precision = precision.unwrap_or(38);
+                let out: Int128Chunked = self
+                    .iter_i256()
+                    .map(|v| {
+                        rescale_256(v?.0, scale_src, scale)
+                            .filter(|v| fits_precision_256(*v, max_precision))
+                            .map(|v| v.as_i128())
+                    })
+                    .collect_ca(self.name().clone());
+                out.into_decimal_unchecked(*precision, scale).into_series()
+            },
+            String => {
+                let out: StringChunked = self
+                    .iter_i256()
+                    .map(|v| v.map(|v| format_decimal256(v, scale_src, false)))
+                    .collect_ca(self.name().clone());
+                return Ok(out.into_series());
+            },
+            Float32 | Float64 => {
+                let factor = 10f64.powi(scale_src as i32);
+                let out: Float64Chunked = self
+                    .iter_i256()
+                    .map(|v| v.map(|v| v.0.as_f64() / factor))
+                    .collect_ca(self.name().clone());
+                return out.cast_with_options(dtype, cast_options);
+            },
+            dt if dt.is_integer() => {
+                // Integers go through a 128-bit decimal with a scale of zero.
+                let out = self.cast_with_options(&Decimal(None, Some(0)), cast_options)?;
+                return out.cast_with_options(dtype, cast_options);
+            },
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        };
+        if cast_options.strict() && out.null_count() != self.null_count() {
+            polars_bail!(
+                ComputeError: "decimal values of {} don't fit in {}", self.dtype(), dtype
+            );
+        }
+        Ok(out)
+    }
+}
+
+impl Decimal256Chunked {
+    /// Create a [`Decimal256Chunked`] from optional physical 256-bit values.
+    pub fn from_i256s<I>(name: PlSmallStr, iter: I, precision: Option<usize>, scale: usize) -> Self
+    where
+        I: IntoIterator<Item = Option<i256>>,
+    {
+        let arr = MutableBinaryViewArray::<[u8]>::from_iter(
+            iter.into_iter().map(|v| v.map(i256_to_physical)),
+        );
+        BinaryChunked::with_chunk(name, arr.freeze()).into_decimal256_unchecked(precision, scale)
+    }
+
+    /// Iterate over the physical 256-bit values.
+    pub fn iter_i256(&self) -> impl Iterator<Item = Option<i256>> + '_ {
+        self.0.iter().map(|v| v.map(i256_from_physical))
+    }
+
+    pub fn precision(&self) -> Option<usize> {
+        match self.2.as_ref().unwrap() {
+            DataType::Decimal256(precision, _) => *precision,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn scale(&self) -> usize {
+        match self.2.as_ref().unwrap() {
+            DataType::Decimal256(_, scale) => scale.unwrap_or_else(|| unreachable!()),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Convert a chunk to an Arrow array of the Decimal256 type.
+    pub(crate) fn chunk_to_arrow(&self, chunk_idx: usize) -> ArrayRef {
+        let arr = self.0.downcast_get(chunk_idx).unwrap();
+        let values = arr
+            .iter()
+            .map(|v| v.map(i256_from_physical).unwrap_or_default())
+            .collect::<Vec<_>>();
+        PrimitiveArray::new(
+            ArrowDataType::Decimal256(
+                self.precision().unwrap_or(DECIMAL256_MAX_PRECISION),
+                self.scale(),
+            ),
+            values.into(),
+            arr.validity().cloned(),
+        )
+        .boxed()
+    }
+}
+
+fn check_precision_256(precision: Option<usize>) -> PolarsResult<()> {
+    polars_ensure!(
+        precision.map_or(true, |p| p > 0 && p <= DECIMAL256_MAX_PRECISION),
+        InvalidOperation: "decimal precision should be <= 76 & >= 1"
+    );
+    Ok(())
+}
+
+/// Cast a [`Series`] to a [`DataType::Decimal256`]. Values that don't fit become null, a scale
+/// of `None` is inferred from strings and otherwise kept from decimals.
+pub(crate) fn cast_to_decimal256(
+    s: &Series,
+    precision: Option<usize>,
+    scale: Option<usize>,
+) -> PolarsResult<Decimal256Chunked> {
+    check_precision_256(precision)?;
+    let max_precision = precision.unwrap_or(DECIMAL256_MAX_PRECISION);
+    let fit = |v: Option<I256>| {
+        v.filter(|v| fits_precision_256(*v, max_precision))
+            .map(i256)
+    };
+    let name = s.name().clone();
+    let out = match s.dtype() {
+        DataType::Decimal256(_, _) => {
+            let ca = s.decimal256().unwrap();
+            let dtype = DataType::Decimal256(precision, scale);
+            return Ok(ca
+                .cast_with_options(&dtype, CastOptions::NonStrict)?
+                .decimal256()
+                .unwrap()
+                .clone());
+        },
+        DataType::Decimal(_, _) => {
+            let ca = s.decimal().unwrap();
+            let scale_src = ca.scale();
+            let scale = scale.unwrap_or(scale_src);
+            let iter = ca
+                .physical()
+                .iter()
+                .map(|v| fit(v.and_then(|v| rescale_256(I256::new(v), scale_src, scale))));
+            Decimal256Chunked::from_i256s(name, iter, precision, scale)
+        },
+        DataType::String => {
+            let ca = s.str().unwrap();
+            let scale = match scale {
+                Some(scale) => scale,
+                None => ca
+                    .iter()
+                    .flatten()
+                    .map(|v| infer_scale_256(v.as_bytes()) as usize)
+                    .max()
+                    .unwrap_or(0),
+            };
+            let iter = ca.iter().map(|v| {
+                v.and_then(|v| {
+                    deserialize_decimal256(v.as_bytes(), Some(max_precision as u8), scale as u8)
+                })
+            });
+            Decimal256Chunked::from_i256s(name, iter, precision, scale)
+        },
+        dt if dt.is_float() => {
+            let Some(scale) = scale else {
+                polars_bail!(
+                    InvalidOperation: "casting floats to decimal256 requires a scale"
+                );
+            };
+            let ca = s.cast(&DataType::Float64)?;
+            let factor = 10f64.powi(scale as i32);
+            let iter = ca.f64().unwrap().iter().map(|v| {
+                // The conversion saturates, so values out of range are filtered by the precision.
+                fit(v
+                    .map(|v| v * factor)
+                    .filter(|v| v.is_finite())
+                    .map(|v| v.as_i256()))
+            });
+            Decimal256Chunked::from_i256s(name, iter, precision, scale)
+        },
+        dt if dt.is_integer() || dt.is_bool() => {
+            let scale = scale.unwrap_or(0);
+            let ca = s.cast(&DataType::Decimal(None, Some(0)))?;
+            let iter = ca
+                .decimal()
+                .unwrap()
+                .physical()
+                .iter()
+                .map(|v| fit(v.and_then(|v| rescale_256(I256::new(v), 0, scale))));
+            Decimal256Chunked::from_i256s(name, iter, precision, scale)
+        },
+        dt => polars_bail!(
+            InvalidOperation: "casting from {} to decimal256 not supported", dt
+        ),
+    };
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dec(values: &[Option<&str>], precision: Option<usize>, scale: usize) -> Decimal256Chunked {
+        let s = Series::new(PlSmallStr::EMPTY, values);
+        cast_to_decimal256(&s, precision, Some(scale)).unwrap()
+    }
+
+    #[test]
+    fn test_decimal256_physical_order() {
+        let values = [
+            i256(I256::MIN),
+            i256(I256::new(-10)),
+            i256(I256::new(-1)),
+            i256(I256::ZERO),
+            i256(I256::new(1)),
+            i256(I256::MAX),
+        ];
+        for w in values.windows(2) {
+            assert!(i256_to_physical(w[0]) < i256_to_physical(w[1]));
+        }
+        for v in values {
+            assert_eq!(i256_from_physical(&i256_to_physical(v)), v);
+        }
+    }
+
+    #[test]
+    fn test_decimal256_cast() {
+        let big = "1234567890123456789012345678901234567890.5";
+        let ca = dec(&[Some(big), Some("-0.25"), None], Some(50), 2);
+        assert_eq!(ca.dtype(), &DataType::Decimal256(Some(50), Some(2)));
+
+        let out = ca.cast(&DataType::String).unwrap();
+        assert_eq!(
+            Vec::from(out.str().unwrap()),
+            &[
+                Some("1234567890123456789012345678901234567890.50"),
+                Some("-0.25"),
+                None
+            ]
+        );
+
+        // Only the small value fits in 128 bits.
+        let out = ca
+            .cast_with_options(&DataType::Decimal(None, Some(2)), CastOptions::NonStrict)
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.decimal().unwrap().physical()),
+            &[None, Some(-25), None]
+        );
+        assert!(ca
+            .cast_with_options(&DataType::Decimal(None, Some(2)), CastOptions::Strict)
+            .is_err());
+
+        let out = ca.cast(&DataType::Float64).unwrap();
+        assert_eq!(out.f64().unwrap().get(1), Some(-0.25));
+
+        // Values that don't fit in the precision become null.
+        let ca = dec(&[Some(big), Some("1.5")], Some(3), 1);
+        assert_eq!(ca.null_count(), 1);
+    }
+
+    #[test]
+    fn test_decimal256_promotion_and_reduce() {
+        let s = Series::new(
+            PlSmallStr::EMPTY,
+            &["5", "-3.25", "100000000000000000000000000000000000000"],
+        );
+        let s = s.cast(&DataType::Decimal(Some(45), Some(2))).unwrap();
+        assert_eq!(s.dtype(), &DataType::Decimal256(Some(45), Some(2)));
+
+        let sum = s.sum_reduce().unwrap();
+        assert_eq!(
+            sum.value().to_string(),
+            "100000000000000000000000000000000000001.75"
+        );
+        let min = s.min_reduce().unwrap();
+        assert_eq!(min.value(), &AnyValue::Decimal256(i256(I256::new(-325)), 2));
+        let sorted = s.sort(Default::default()).unwrap();
+        assert_eq!(sorted.get(2).unwrap(), s.get(2).unwrap());
+
+        // Strict casts fail on values that don't fit.
+        assert!(s
+            .strict_cast(&DataType::Decimal(Some(40), Some(4)))
+            .is_err());
+        // The maximum precision is 76.
+        assert!(s.cast(&DataType::Decimal(Some(77), Some(2))).is_err());
+    }
+
+    #[test]
+    fn test_decimal256_arrow_roundtrip() {
+        let ca = dec(&[Some("-1.5"), None, Some("10")], Some(40), 1);
+        let s = ca.into_series();
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert_eq!(arr.dtype(), &ArrowDataType::Decimal256(40, 1));
+        let back = Series::from_arrow(PlSmallStr::EMPTY, arr).unwrap();
+        assert_eq!(back.dtype(), s.dtype());
+        assert!(back.equals_missing(&s));
+    }
+}
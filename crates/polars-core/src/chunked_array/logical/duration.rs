@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "dtype-interval")]
+use crate::chunked_array::ops::arity::unary_elementwise_values;
 use crate::prelude::*;
 
 pub type DurationChunked = Logical<DurationType, Int64Type>;
@@ -54,6 +56,18 @@ impl LogicalType for DurationChunked {
                 };
                 Ok(out.into_duration(to_unit).into_series())
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let to_ns = match self.time_unit() {
+                    Nanoseconds => 1,
+                    Microseconds => 1_000,
+                    Milliseconds => 1_000_000,
+                };
+                let out: Int128Chunked = unary_elementwise_values(&self.0, |v| {
+                    interval_to_i128(months_days_ns::new(0, 0, v * to_ns))
+                });
+                Ok(out.into_interval().into_series())
+            },
             dt if dt.is_numeric() => self.0.cast_with_options(dtype, cast_options),
             dt => {
                 polars_bail!(
@@ -0,0 +1,220 @@
+use std::fmt::Write;
+
+use super::*;
+use crate::prelude::*;
+
+pub type Float16Chunked = Logical<Float16Type, Float32Type>;
+
+/// Round an `f32` to the nearest value that is representable as an `f16`.
+#[inline]
+pub fn round_to_f16(v: f32) -> f32 {
+    f16::from_f32(v).to_f32()
+}
+
+/// The shortest decimal representation that rounds back to the same `f16`.
+///
+/// The widened `f32` of an `f16` carries spurious digits, e.g. `0.1` is held as
+/// `0.099975586`, which we don't want to show when formatting.
+pub fn f16_shortest_repr(v: f32) -> f32 {
+    if !v.is_finite() {
+        return v;
+    }
+    let bits = f16::from_f32(v).to_bits();
+    for digits in 0..5 {
+        let candidate: f32 = format!("{v:.digits$e}").parse().unwrap();
+        if f16::from_f32(candidate).to_bits() == bits {
+            return candidate;
+        }
+    }
+    v
+}
+
+pub(crate) fn float16_array_to_physical(arr: &dyn Array) -> ArrayRef {
+    let arr = arr.as_any().downcast_ref::<PrimitiveArray<f16>>().unwrap();
+    let values = arr.values().iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+    PrimitiveArray::new(
+        ArrowDataType::Float32,
+        values.into(),
+        arr.validity().cloned(),
+    )
+    .boxed()
+}
+
+impl Float32Chunked {
+    /// Reinterpret as [`Float16Chunked`]. The values are expected to be
+    /// representable as `f16` already, see [`round_to_f16`].
+    pub fn into_float16(self) -> Float16Chunked {
+        Float16Chunked::new_logical(self)
+    }
+}
+
+impl LogicalType for Float16Chunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Float16
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.0.get_unchecked(i) {
+            Some(v) => AnyValue::Float16(f16::from_f32(v)),
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            DataType::String => {
+                let out = self.0.apply_into_string_amortized(|v, buf| {
+                    write!(buf, "{}", f16_shortest_repr(v)).unwrap()
+                });
+                Ok(out.into_series())
+            },
+            dt => self.0.cast_with_options(dt, cast_options),
+        }
+    }
+}
+
+impl Float16Chunked {
+    /// Create a [`Float16Chunked`] from optional `f16` values.
+    pub fn from_f16s<I>(name: PlSmallStr, iter: I) -> Self
+    where
+        I: IntoIterator<Item = Option<f16>>,
+    {
+        Float32Chunked::from_iter_options(name, iter.into_iter().map(|v| v.map(|v| v.to_f32())))
+            .into_float16()
+    }
+
+    /// Convert a chunk to an Arrow array of the Float16 type.
+    pub(crate) fn chunk_to_arrow(&self, chunk_idx: usize) -> ArrayRef {
+        let arr = self.0.downcast_get(chunk_idx).unwrap();
+        let values = arr
+            .values()
+            .iter()
+            .map(|v| f16::from_f32(*v))
+            .collect::<Vec<_>>();
+        PrimitiveArray::new(
+            ArrowDataType::Float16,
+            values.into(),
+            arr.validity().cloned(),
+        )
+        .boxed()
+    }
+}
+
+/// Cast a [`Series`] to [`Float16Chunked`], going through `f32` and rounding to
+/// the nearest `f16`.
+pub(crate) fn cast_to_float16(s: &Series, options: CastOptions) -> PolarsResult<Float16Chunked> {
+    let out = s.cast_with_options(&DataType::Float32, options)?;
+    Ok(out.f32()?.apply_values(round_to_f16).into_float16())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_float16_cast_rounds() {
+        let s = Series::new("a".into(), [0.1f64, 1.0 / 3.0, 70000.0, f64::NAN]);
+        let out = s.cast(&DataType::Float16).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float16);
+        let phys = out.to_physical_repr();
+        let values = phys.f32().unwrap();
+        assert_eq!(values.get(0), Some(0.099975586));
+        assert_eq!(values.get(1), Some(0.33325195));
+        assert_eq!(values.get(2), Some(f32::INFINITY));
+        assert!(values.get(3).unwrap().is_nan());
+
+        let out = out.cast(&DataType::String).unwrap();
+        assert_eq!(
+            Vec::from(out.str().unwrap()),
+            &[Some("0.1"), Some("0.3333"), Some("inf"), Some("NaN")]
+        );
+    }
+
+    #[test]
+    fn test_float16_arrow_roundtrip() {
+        let ca = Float16Chunked::from_f16s(
+            "a".into(),
+            [Some(f16::from_f32(1.5)), None, Some(f16::from_f32(-2.0))],
+        );
+        let s = ca.into_series();
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert_eq!(arr.dtype(), &ArrowDataType::Float16);
+        let back = Series::from_arrow("a".into(), arr).unwrap();
+        assert_eq!(back.dtype(), &DataType::Float16);
+        assert!(back.equals_missing(&s));
+        assert_eq!(back.get(0).unwrap(), AnyValue::Float16(f16::from_f32(1.5)));
+    }
+
+    #[test]
+    fn test_float16_arrow_field() {
+        let s =
+            Float16Chunked::from_f16s("a".into(), [Some(f16::from_f32(1.5)), None]).into_series();
+        let arr = s.to_arrow(0, CompatLevel::newest());
+
+        // only fields written by Polars as Float16 are read as such
+        let field = s.field().to_arrow(CompatLevel::newest());
+        assert_eq!(Field::from(&field).dtype(), &DataType::Float16);
+        let back = Series::try_from((&field, arr.clone())).unwrap();
+        assert!(back.equals_missing(&s));
+
+        let field = ArrowField::new("a".into(), ArrowDataType::Float16, true);
+        assert_eq!(Field::from(&field).dtype(), &DataType::Float32);
+        let back = Series::try_from((&field, arr)).unwrap();
+        assert_eq!(back.dtype(), &DataType::Float32);
+        assert_eq!(back.get(0).unwrap(), AnyValue::Float32(1.5));
+    }
+
+    #[test]
+    fn test_float16_arithmetic_and_aggregates() {
+        let s = Series::new("a".into(), [1.0f32, 2.0, 2048.0])
+            .cast(&DataType::Float16)
+            .unwrap();
+        let one = Series::new("b".into(), [1.0f32; 3])
+            .cast(&DataType::Float16)
+            .unwrap();
+
+        // 2049 is not representable as f16, so the sum rounds back to 2048.
+        let out = (&s + &one).unwrap();
+        assert_eq!(out.dtype(), &DataType::Float16);
+        assert_eq!(
+            out.get(2).unwrap(),
+            AnyValue::Float16(f16::from_f32(2048.0))
+        );
+
+        let max = s.max_reduce().unwrap();
+        assert_eq!(max.dtype(), &DataType::Float16);
+        assert_eq!(max.value(), &AnyValue::Float16(f16::from_f32(2048.0)));
+        let sum = s.sum_reduce().unwrap();
+        assert_eq!(sum.dtype(), &DataType::Float32);
+        assert_eq!(sum.value(), &AnyValue::Float32(2051.0));
+        assert_eq!(s.mean(), Some(2051.0 / 3.0));
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-array")]
+    fn test_float16_nested() {
+        let values = Series::new("".into(), [1.5f32, 0.1])
+            .cast(&DataType::Float16)
+            .unwrap();
+        let list = Series::new("l".into(), [Some(values.clone()), None]);
+        assert_eq!(list.dtype(), &DataType::List(Box::new(DataType::Float16)));
+
+        let avs = [AnyValue::List(values), AnyValue::Null];
+        let dtype = DataType::Array(Box::new(DataType::Float16), 2);
+        let arr = Series::from_any_values_and_dtype("a".into(), &avs, &dtype, true).unwrap();
+        assert_eq!(arr.dtype(), &dtype);
+        let arrow = arr.to_arrow(0, CompatLevel::newest());
+        let back = Series::from_arrow("a".into(), arrow).unwrap();
+        assert!(back.equals_missing(&arr));
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt::Write;
+
+use arrow::datatypes::IntervalUnit;
+
+use super::*;
+use crate::chunked_array::ops::arity::unary_elementwise;
+use crate::prelude::*;
+
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+const DAYS_SIGN: u32 = 1 << 31;
+const NS_SIGN: u64 = 1 << 63;
+
+/// Pack an interval into the physical `i128` representation.
+///
+/// The months are stored in the upper 32 bits, the days in the next 32 bits and the
+/// nanoseconds in the lower 64 bits. The sign bits of the lower components are flipped,
+/// so that the order of the integers is the order of the (months, days, nanoseconds) tuples.
+#[inline]
+pub fn interval_to_i128(v: months_days_ns) -> i128 {
+    ((v.months() as i128) << 96)
+        | ((((v.days() as u32) ^ DAYS_SIGN) as i128) << 64)
+        | (((v.ns() as u64) ^ NS_SIGN) as i128)
+}
+
+/// Unpack the physical `i128` representation of an interval.
+#[inline]
+pub fn interval_from_i128(v: i128) -> months_days_ns {
+    months_days_ns::new(
+        (v >> 96) as i32,
+        (((v >> 64) as u32) ^ DAYS_SIGN) as i32,
+        ((v as u64) ^ NS_SIGN) as i64,
+    )
+}
+
+pub(crate) fn interval_array_to_physical(arr: &dyn Array) -> ArrayRef {
+    let arr = arr
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap();
+    let values = arr
+        .values()
+        .iter()
+        .map(|v| interval_to_i128(*v))
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(
+        Int128Type::get_dtype().to_arrow(CompatLevel::newest()),
+        values.into(),
+        arr.validity().cloned(),
+    )
+    .boxed()
+}
+
+impl Int128Chunked {
+    pub fn into_interval(self) -> IntervalChunked {
+        let mut dt = IntervalChunked::new_logical(self);
+        dt.2 = Some(DataType::Interval);
+        dt
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.0.get_unchecked(i) {
+            Some(v) => AnyValue::Interval(interval_from_i128(v)),
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        use DataType::*;
+        match dtype {
+            Interval => Ok(self.clone().into_series()),
+            Decimal(precision, Some(0)) => Ok(self
+                .0
+                .clone()
+                .into_decimal_unchecked(*precision, 0)
+                .into_series()),
+            Duration(tu) => {
+                // Only intervals without a month component have a fixed length.
+                let out: Int64Chunked = unary_elementwise(&self.0, |opt_v| {
+                    opt_v.and_then(|v| {
+                        let v = interval_from_i128(v);
+                        if v.months() != 0 {
+                            return None;
+                        }
+                        let ns = (v.days() as i64)
+                            .checked_mul(NS_IN_DAY)?
+                            .checked_add(v.ns())?;
+                        Some(match tu {
+                            TimeUnit::Nanoseconds => ns,
+                            TimeUnit::Microseconds => ns / 1_000,
+                            TimeUnit::Milliseconds => ns / 1_000_000,
+                        })
+                    })
+                });
+                if cast_options.strict() && out.null_count() != self.null_count() {
+                    polars_bail!(
+                        InvalidOperation:
+                        "cannot cast intervals with a month component to {}", dtype
+                    );
+                }
+                Ok(out.into_duration(*tu).into_series())
+            },
+            String => {
+                let out = self.0.apply_into_string_amortized(|v, buf| {
+                    write!(buf, "{}", AnyValue::Interval(interval_from_i128(v))).unwrap()
+                });
+                Ok(out.into_series())
+            },
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        }
+    }
+}
+
+impl IntervalChunked {
+    /// Create an [`IntervalChunked`] from optional intervals.
+    pub fn from_intervals<I>(name: PlSmallStr, iter: I) -> Self
+    where
+        I: IntoIterator<Item = Option<months_days_ns>>,
+    {
+        Int128Chunked::from_iter_options(name, iter.into_iter().map(|v| v.map(interval_to_i128)))
+            .into_interval()
+    }
+
+    /// Iterate over the intervals.
+    pub fn iter_intervals(&self) -> impl Iterator<Item = Option<months_days_ns>> + '_ {
+        self.0.iter().map(|v| v.map(interval_from_i128))
+    }
+
+    /// Convert a chunk to an Arrow array of the MonthDayNano interval type.
+    pub(crate) fn chunk_to_arrow(&self, chunk_idx: usize) -> ArrayRef {
+        let arr = self.0.downcast_get(chunk_idx).unwrap();
+        let values = arr
+            .values()
+            .iter()
+            .map(|v| interval_from_i128(*v))
+            .collect::<Vec<_>>();
+        PrimitiveArray::new(
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano),
+            values.into(),
+            arr.validity().cloned(),
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interval_physical_order() {
+        let intervals = [
+            months_days_ns::new(-1, 40, 0),
+            months_days_ns::new(0, -1, i64::MAX),
+            months_days_ns::new(0, 0, -1),
+            months_days_ns::new(0, 0, 0),
+            months_days_ns::new(0, 1, -5),
+            months_days_ns::new(1, i32::MIN, i64::MIN),
+        ];
+        for w in intervals.windows(2) {
+            assert!(interval_to_i128(w[0]) < interval_to_i128(w[1]));
+        }
+        for v in intervals {
+            assert_eq!(interval_from_i128(interval_to_i128(v)), v);
+        }
+    }
+
+    #[test]
+    fn test_interval_cast() {
+        let ca = IntervalChunked::from_intervals(
+            "a".into(),
+            [
+                Some(months_days_ns::new(0, 1, 1_000)),
+                Some(months_days_ns::new(1, 0, 0)),
+                None,
+            ],
+        );
+        let out = ca
+            .cast_with_options(
+                &DataType::Duration(TimeUnit::Microseconds),
+                CastOptions::NonStrict,
+            )
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.duration().unwrap().physical()),
+            &[Some(86_400_000_001), None, None]
+        );
+        assert!(ca
+            .cast_with_options(
+                &DataType::Duration(TimeUnit::Microseconds),
+                CastOptions::Strict
+            )
+            .is_err());
+
+        let out = ca.cast(&DataType::String).unwrap();
+        assert_eq!(
+            Vec::from(out.str().unwrap()),
+            &[Some("1d 1µs"), Some("1mo"), None]
+        );
+    }
+
+    #[test]
+    fn test_interval_arithmetic_and_arrow_roundtrip() {
+        let a =
+            IntervalChunked::from_intervals("a".into(), [Some(months_days_ns::new(1, 2, 3)), None])
+                .into_series();
+        let b = IntervalChunked::from_intervals(
+            "b".into(),
+            [
+                Some(months_days_ns::new(-2, 1, 1)),
+                Some(months_days_ns::new(0, 0, 1)),
+            ],
+        )
+        .into_series();
+        let out = (&a - &b).unwrap();
+        assert_eq!(out.dtype(), &DataType::Interval);
+        assert_eq!(
+            out.get(0).unwrap(),
+            AnyValue::Interval(months_days_ns::new(3, 1, 2))
+        );
+        assert_eq!(out.get(1).unwrap(), AnyValue::Null);
+
+        let arr = out.to_arrow(0, CompatLevel::newest());
+        assert_eq!(
+            arr.dtype(),
+            &ArrowDataType::Interval(IntervalUnit::MonthDayNano)
+        );
+        let back = Series::from_arrow("a".into(), arr).unwrap();
+        assert!(back.equals_missing(&out));
+    }
+}
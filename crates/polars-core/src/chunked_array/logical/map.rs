@@ -0,0 +1,227 @@
+use arrow::offset::OffsetsBuffer;
+
+use super::*;
+use crate::prelude::*;
+
+pub type MapChunked = Logical<MapType, ListType>;
+
+/// The dtype of the entries of a map: a struct with a `key` and a `value` field.
+pub fn map_entries_dtype(key: &DataType, value: &DataType) -> DataType {
+    DataType::Struct(vec![
+        Field::new(PlSmallStr::from_static("key"), key.clone()),
+        Field::new(PlSmallStr::from_static("value"), value.clone()),
+    ])
+}
+
+impl ListChunked {
+    /// Interpret a list of structs with two fields as a map. The first field holds the keys
+    /// and the second field the values; they are renamed to `key` and `value`.
+    pub fn into_map(self) -> PolarsResult<MapChunked> {
+        let DataType::Struct(fields) = self.inner_dtype() else {
+            polars_bail!(
+                SchemaMismatch: "expected a list of structs to create a map, got {}", self.dtype()
+            );
+        };
+        polars_ensure!(
+            fields.len() == 2,
+            SchemaMismatch: "expected a list of structs with a key and a value field to create a map, got {} fields",
+            fields.len()
+        );
+        if fields[0].name() == "key" && fields[1].name() == "value" {
+            return Ok(unsafe { self.into_map_unchecked() });
+        }
+
+        let out = self.apply_to_inner(&|s| {
+            let ca = s.struct_()?;
+            let mut entries = ca.fields_as_series();
+            entries[0].rename(PlSmallStr::from_static("key"));
+            entries[1].rename(PlSmallStr::from_static("value"));
+            let mut out = StructChunked::from_series(ca.name().clone(), ca.len(), entries.iter())?;
+            if ca.null_count() > 0 {
+                out.zip_outer_validity(ca);
+            }
+            Ok(out.into_series())
+        })?;
+        Ok(unsafe { out.into_map_unchecked() })
+    }
+
+    /// # Safety
+    /// The inner dtype must be a struct with a `key` and a `value` field, in that order.
+    pub unsafe fn into_map_unchecked(self) -> MapChunked {
+        let DataType::Struct(fields) = self.inner_dtype() else {
+            unreachable!()
+        };
+        let dtype = DataType::Map(
+            Box::new(fields[0].dtype().clone()),
+            Box::new(fields[1].dtype().clone()),
+        );
+        let mut ca = MapChunked::new_logical::<MapType>(self);
+        ca.2 = Some(dtype);
+        ca
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
+    }
+
+    /// A map value is returned as a list of `{key, value}` structs.
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.0.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        use DataType::*;
+        match dtype {
+            Map(key, value) => {
+                let entries = List(Box::new(map_entries_dtype(key, value)));
+                let out = self.0.cast_with_options(&entries, cast_options)?;
+                Ok(unsafe { out.list()?.clone().into_map_unchecked() }.into_series())
+            },
+            List(_) => self.0.cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation: "casting from {:?} to {:?} not supported", self.dtype(), dt
+            ),
+        }
+    }
+}
+
+impl MapChunked {
+    /// Get the dtype of the keys.
+    pub fn key_dtype(&self) -> &DataType {
+        match self.dtype() {
+            DataType::Map(key, _) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the dtype of the values.
+    pub fn value_dtype(&self) -> &DataType {
+        match self.dtype() {
+            DataType::Map(_, value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Create a [`MapChunked`] from Arrow [`MapArray`]s.
+    pub(crate) fn from_map_arrays(name: PlSmallStr, chunks: &[ArrayRef]) -> PolarsResult<Self> {
+        let chunks = chunks
+            .iter()
+            .map(|arr| {
+                let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
+                let entries = arr.field().as_any().downcast_ref::<StructArray>().unwrap();
+                // The names of the key and value fields differ between producers.
+                let ArrowDataType::Struct(fields) = entries.dtype() else {
+                    unreachable!()
+                };
+                let fields = fields
+                    .iter()
+                    .zip(["key", "value"])
+                    .map(|(fld, name)| {
+                        ArrowField::new(PlSmallStr::from_static(name), fld.dtype().clone(), true)
+                    })
+                    .collect();
+                let entries = StructArray::new(
+                    ArrowDataType::Struct(fields),
+                    entries.len(),
+                    entries.values().to_vec(),
+                    entries.validity().cloned(),
+                )
+                .boxed();
+
+                let dtype = ListArray::<i64>::default_datatype(entries.dtype().clone());
+                ListArray::<i64>::new(
+                    dtype,
+                    arr.offsets().into(),
+                    entries,
+                    arr.validity().cloned(),
+                )
+                .boxed()
+            })
+            .collect::<Vec<_>>();
+        let s = Series::try_from((name, chunks))?;
+        s.list()?.clone().into_map()
+    }
+
+    /// Convert a chunk to an Arrow [`MapArray`].
+    pub(crate) fn chunk_to_arrow(&self, chunk_idx: usize, compat_level: CompatLevel) -> ArrayRef {
+        let dtype = self.dtype().to_arrow(compat_level);
+        let list = self
+            .0
+            .clone()
+            .into_series()
+            .to_arrow(chunk_idx, compat_level);
+        let list = list.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+        let entries = list
+            .values()
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let ArrowDataType::Map(entries_field, _) = &dtype else {
+            unreachable!()
+        };
+        let entries = StructArray::new(
+            entries_field.dtype().clone(),
+            entries.len(),
+            entries.values().to_vec(),
+            entries.validity().cloned(),
+        )
+        .boxed();
+        let offsets = OffsetsBuffer::<i32>::try_from(list.offsets())
+            .expect("map has more entries than fit in 32-bit offsets");
+        MapArray::new(dtype, offsets, entries, list.validity().cloned()).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_map_arrow_roundtrip() {
+        let keys = Series::new("k".into(), ["a", "b", "a"]);
+        let values = Series::new("v".into(), [1i32, 2, 3]);
+        let entries = StructChunked::from_series("".into(), 3, [keys, values].iter())
+            .unwrap()
+            .into_series();
+        let offsets = OffsetsBuffer::<i64>::try_from(vec![0i64, 2, 2, 3]).unwrap();
+        let arr = ListArray::<i64>::new(
+            ListArray::<i64>::default_datatype(entries.dtype().to_arrow(CompatLevel::newest())),
+            offsets,
+            entries.to_arrow(0, CompatLevel::newest()),
+            None,
+        );
+        let list = Series::from_arrow("m".into(), arr.boxed()).unwrap();
+        let map = list.list().unwrap().clone().into_map().unwrap();
+        assert_eq!(
+            map.dtype(),
+            &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32))
+        );
+
+        let s = map.into_series();
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert!(matches!(arr.dtype(), ArrowDataType::Map(_, _)));
+        let back = Series::from_arrow("m".into(), arr).unwrap();
+        assert_eq!(back.dtype(), s.dtype());
+        assert!(back.equals_missing(&s));
+    }
+    #[test]
+    fn test_map_without_key_value_entries() {
+        // entries that are not a struct of a key and a value are read as a list
+        let entries = ArrowField::new("entries".into(), ArrowDataType::Int32, true);
+        let dtype = ArrowDataType::Map(Box::new(entries), false);
+        assert_eq!(
+            DataType::from_arrow(&dtype, true),
+            DataType::List(Box::new(DataType::Int32))
+        );
+    }
+}
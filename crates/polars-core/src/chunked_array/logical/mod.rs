@@ -10,10 +10,30 @@ pub use datetime::*;
 mod decimal;
 #[cfg(feature = "dtype-decimal")]
 pub use decimal::*;
+#[cfg(feature = "dtype-decimal")]
+mod decimal256;
+#[cfg(feature = "dtype-decimal")]
+pub use decimal256::*;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-f16")]
+mod float16;
+#[cfg(feature = "dtype-interval")]
+mod interval;
 #[cfg(feature = "dtype-duration")]
 pub use duration::*;
+#[cfg(feature = "dtype-f16")]
+pub use float16::*;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
+#[cfg(feature = "dtype-map")]
+mod map;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
+#[cfg(feature = "dtype-union")]
+mod union;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical;
 #[cfg(feature = "dtype-categorical")]
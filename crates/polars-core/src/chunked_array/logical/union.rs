@@ -0,0 +1,378 @@
+use arrow::array::{new_empty_array, UnionArray};
+use polars_utils::format_pl_smallstr;
+
+use super::*;
+use crate::chunked_array::ops::any_value::arr_to_any_value;
+use crate::prelude::*;
+use crate::utils::index_to_chunked_index;
+
+pub type UnionChunked = Logical<UnionType, StructType>;
+
+/// The dtype of the struct backing a union with the given variants.
+///
+/// The first field holds the `tag`: the index of the variant a row belongs to. It is followed
+/// by a field per variant, named by its index. A variant only holds values in the rows tagged
+/// with it; all other rows are null.
+pub fn union_physical_dtype(variants: &[Field]) -> DataType {
+    let tag = Field::new(PlSmallStr::from_static("tag"), DataType::Int8);
+    let variants = variants
+        .iter()
+        .enumerate()
+        .map(|(i, fld)| Field::new(format_pl_smallstr!("{i}"), fld.dtype().clone()));
+    DataType::Struct(std::iter::once(tag).chain(variants).collect())
+}
+
+impl StructChunked {
+    /// # Safety
+    /// The struct must have the layout described in [`union_physical_dtype`].
+    pub unsafe fn into_union_unchecked(
+        self,
+        variants: Vec<Field>,
+        mode: UnionMode,
+    ) -> UnionChunked {
+        let mut ca = UnionChunked::new_logical::<UnionType>(self);
+        ca.2 = Some(DataType::Union(variants, mode));
+        ca
+    }
+}
+
+impl UnionChunked {
+    /// Create a union from the `tags` of the rows and a column per variant.
+    ///
+    /// The tags are the indices of the variants, the variants are named after their columns.
+    /// Values of a variant in rows that are tagged with another variant are ignored.
+    pub fn from_tags_and_variants(
+        name: PlSmallStr,
+        tags: &Int8Chunked,
+        variants: &[Series],
+        mode: UnionMode,
+    ) -> PolarsResult<Self> {
+        let len = tags.len();
+        polars_ensure!(
+            variants.len() <= i8::MAX as usize,
+            ComputeError: "a union can have at most {} variants, got {}", i8::MAX, variants.len()
+        );
+        polars_ensure!(
+            tags.into_iter()
+                .flatten()
+                .all(|tag| tag >= 0 && (tag as usize) < variants.len()),
+            ComputeError: "union tags must be smaller than the number of variants ({})", variants.len()
+        );
+
+        let mut validity = vec![false; len];
+        let mut physical = Vec::with_capacity(variants.len() + 1);
+        physical.push(
+            tags.clone()
+                .with_name(PlSmallStr::from_static("tag"))
+                .into_series(),
+        );
+        for (k, variant) in variants.iter().enumerate() {
+            polars_ensure!(
+                variant.len() == len,
+                ShapeMismatch: "union variant '{}' has length {} while there are {} tags",
+                variant.name(), variant.len(), len
+            );
+            let idx: IdxCa = tags
+                .into_iter()
+                .enumerate()
+                .map(|(i, tag)| (tag == Some(k as i8)).then_some(i as IdxSize))
+                .collect();
+            let mut values = variant.take(&idx)?;
+            for (i, (tag, valid)) in tags
+                .into_iter()
+                .zip(values.is_not_null().into_iter())
+                .enumerate()
+            {
+                validity[i] |= tag == Some(k as i8) && valid == Some(true);
+            }
+            values.rename(format_pl_smallstr!("{k}"));
+            physical.push(values);
+        }
+
+        let validity = BooleanChunked::from_slice(PlSmallStr::EMPTY, &validity);
+        let ca = StructChunked::from_series(name, len, physical.iter())?
+            .with_outer_validity_chunked(validity);
+        let variants = variants
+            .iter()
+            .map(|s| Field::new(s.name().clone(), s.dtype().clone()))
+            .collect();
+        // SAFETY: we just created the physical layout.
+        Ok(unsafe { ca.into_union_unchecked(variants, mode) })
+    }
+
+    /// Get the variants of the union.
+    pub fn variants(&self) -> &[Field] {
+        match self.dtype() {
+            DataType::Union(variants, _) => variants,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the [`UnionMode`] used when converting to Arrow.
+    pub fn mode(&self) -> UnionMode {
+        match self.dtype() {
+            DataType::Union(_, mode) => *mode,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get the tags of the rows: the index of the variant every row belongs to.
+    /// Null values have a null tag.
+    pub fn tags(&self) -> Int8Chunked {
+        let tags = self.0.fields_as_series().swap_remove(0);
+        tags.i8().unwrap().clone().with_name(self.name().clone())
+    }
+
+    /// Get the values of the variant at index `i`. Rows tagged with another variant are null.
+    pub fn variant(&self, i: usize) -> PolarsResult<Series> {
+        let variants = self.variants();
+        polars_ensure!(
+            i < variants.len(),
+            OutOfBounds: "union variant index {} is out of bounds for a union with {} variants",
+            i, variants.len()
+        );
+        let mut out = self.0.fields_as_series().swap_remove(i + 1);
+        out.rename(variants[i].name().clone());
+        Ok(out)
+    }
+
+    /// Create a [`UnionChunked`] from Arrow [`UnionArray`]s.
+    pub(crate) fn from_union_arrays(
+        name: PlSmallStr,
+        chunks: &[ArrayRef],
+        dtype: &ArrowDataType,
+    ) -> PolarsResult<Self> {
+        let mut out: Option<UnionChunked> = None;
+        for arr in chunks {
+            let arr = arr.as_any().downcast_ref::<UnionArray>().unwrap();
+            let ArrowDataType::Union(fields, _, mode) = arr.dtype() else {
+                unreachable!()
+            };
+            // Resolve the type ids and dense offsets to variant indices and slots.
+            let (tags, slots): (Vec<i8>, Vec<IdxSize>) = (0..arr.len())
+                .map(|i| {
+                    let (variant, slot) = arr.index(i);
+                    (variant as i8, slot as IdxSize)
+                })
+                .unzip();
+            let variants = fields
+                .iter()
+                .zip(arr.fields())
+                .enumerate()
+                .map(|(k, (fld, values))| {
+                    let s = Series::from_arrow(fld.name.clone(), values.clone())?;
+                    let idx: IdxCa = tags
+                        .iter()
+                        .zip(&slots)
+                        .map(|(&tag, &slot)| (tag as usize == k).then_some(slot))
+                        .collect();
+                    s.take(&idx)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let tags = Int8Chunked::from_vec(PlSmallStr::from_static("tag"), tags);
+            let ca = Self::from_tags_and_variants(name.clone(), &tags, &variants, *mode)?;
+            match out.as_mut() {
+                Some(out) => out.0.append(&ca.0)?,
+                None => out = Some(ca),
+            }
+        }
+
+        match out {
+            Some(out) => Ok(out),
+            None => {
+                let s = Series::full_null(name, 0, &DataType::from_arrow(dtype, true));
+                Ok(s.union()?.clone())
+            },
+        }
+    }
+
+    /// Convert a chunk to an Arrow [`UnionArray`].
+    pub(crate) fn chunk_to_arrow(&self, chunk_idx: usize, compat_level: CompatLevel) -> ArrayRef {
+        fn to_arrow(s: &Series, compat_level: CompatLevel) -> ArrayRef {
+            let s = s.rechunk();
+            if s.chunks().is_empty() {
+                new_empty_array(s.dtype().to_arrow(compat_level))
+            } else {
+                s.to_arrow(0, compat_level)
+            }
+        }
+
+        let chunk = &self.0.chunks()[chunk_idx];
+        let physical = unsafe {
+            Series::from_chunks_and_dtype_unchecked(
+                self.name().clone(),
+                vec![chunk.clone()],
+                self.0.dtype(),
+            )
+        };
+        let physical = physical.struct_().unwrap().fields_as_series();
+        let tags = physical[0]
+            .i8()
+            .unwrap()
+            .into_iter()
+            .map(|tag| tag.unwrap_or(0))
+            .collect::<Vec<_>>();
+        let variants = &physical[1..];
+
+        let dtype = self.dtype().to_arrow(compat_level);
+        match self.mode() {
+            UnionMode::Sparse => {
+                let fields = variants.iter().map(|s| to_arrow(s, compat_level)).collect();
+                UnionArray::new(dtype, tags.into(), fields, None).boxed()
+            },
+            UnionMode::Dense => {
+                let mut lengths = vec![0i32; variants.len()];
+                let offsets = tags
+                    .iter()
+                    .map(|&tag| {
+                        let offset = lengths[tag as usize];
+                        lengths[tag as usize] += 1;
+                        offset
+                    })
+                    .collect::<Vec<_>>();
+                let tags_ca = Int8Chunked::from_slice(PlSmallStr::EMPTY, &tags);
+                let fields = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(k, s)| {
+                        let mask = tags_ca.equal(k as i8);
+                        to_arrow(&s.filter(&mask).unwrap(), compat_level)
+                    })
+                    .collect();
+                UnionArray::new(dtype, tags.into(), fields, Some(offsets.into())).boxed()
+            },
+        }
+    }
+}
+
+impl Series {
+    /// Cast a non-union [`Series`] to the variant of the union with the same dtype.
+    pub(crate) fn cast_to_union(
+        &self,
+        variants: &[Field],
+        mode: UnionMode,
+    ) -> PolarsResult<Series> {
+        let dtype = DataType::Union(variants.to_vec(), mode);
+        if self.dtype().is_null() {
+            return Ok(Series::full_null(self.name().clone(), self.len(), &dtype));
+        }
+        let Some(k) = variants.iter().position(|fld| fld.dtype() == self.dtype()) else {
+            polars_bail!(
+                InvalidOperation: "cannot cast {} to {}: no variant has this dtype",
+                self.dtype(), dtype
+            );
+        };
+        let len = self.len();
+        let tags = Int8Chunked::full(PlSmallStr::from_static("tag"), k as i8, len);
+        let variants = variants
+            .iter()
+            .enumerate()
+            .map(|(i, fld)| {
+                if i == k {
+                    self.clone().with_name(fld.name().clone())
+                } else {
+                    Series::full_null(fld.name().clone(), len, fld.dtype())
+                }
+            })
+            .collect::<Vec<_>>();
+        let ca = UnionChunked::from_tags_and_variants(self.name().clone(), &tags, &variants, mode)?;
+        Ok(ca.into_series())
+    }
+}
+
+impl LogicalType for UnionChunked {
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
+    }
+
+    /// A union value is returned as the value of its variant.
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        let (chunk_idx, idx) = index_to_chunked_index(self.chunks.iter().map(|c| c.len()), i);
+        let arr = &**self.chunks.get_unchecked(chunk_idx);
+        arr_to_any_value(arr, idx, self.dtype())
+    }
+
+    /// Cast every variant to the given dtype and pick the value of the variant of every row.
+    /// Casting to a union with the same number of variants casts the variants pairwise.
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        let physical = self.0.fields_as_series();
+        let tags = physical[0].i8()?;
+        let variants = &physical[1..];
+        match dtype {
+            DataType::Union(fields, mode) => {
+                polars_ensure!(
+                    fields.len() == variants.len(),
+                    InvalidOperation: "cannot cast {} to {}: the number of variants differs",
+                    self.dtype(), dtype
+                );
+                let variants = variants
+                    .iter()
+                    .zip(fields)
+                    .map(|(s, fld)| {
+                        let mut s = s.cast_with_options(fld.dtype(), cast_options)?;
+                        s.rename(fld.name().clone());
+                        Ok(s)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let ca = Self::from_tags_and_variants(self.name().clone(), tags, &variants, *mode)?;
+                Ok(ca.into_series())
+            },
+            dt => {
+                let len = self.len();
+                let mut values = Series::new_empty(self.name().clone(), dt);
+                for s in variants {
+                    values.append(&s.cast_with_options(dt, cast_options)?)?;
+                }
+                let idx: IdxCa = tags
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, tag)| tag.map(|tag| (tag as usize * len + i) as IdxSize))
+                    .collect();
+                values.take(&idx)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_union_arrow_roundtrip() -> PolarsResult<()> {
+        let tags = Int8Chunked::new("".into(), [Some(0), Some(1), Some(1), Some(0)]);
+        let variants = [
+            Series::new("int".into(), [Some(1i32), None, None, Some(4)]),
+            Series::new("str".into(), [None, Some("b"), None, Some("d")]),
+        ];
+        for mode in [UnionMode::Sparse, UnionMode::Dense] {
+            let ca = UnionChunked::from_tags_and_variants("u".into(), &tags, &variants, mode)?;
+            assert_eq!(ca.null_count(), 1);
+            assert_eq!(ca.get_any_value(1)?, AnyValue::String("b"));
+            assert_eq!(ca.get_any_value(3)?, AnyValue::Int32(4));
+
+            let s = ca.into_series();
+            let arr = s.to_arrow(0, CompatLevel::newest());
+            assert!(matches!(arr.dtype(), ArrowDataType::Union(_, _, m) if *m == mode));
+            let back = Series::from_arrow("u".into(), arr)?;
+            assert_eq!(back.dtype(), s.dtype());
+            assert!(back.equals_missing(&s));
+
+            let out = s.cast(&DataType::String)?;
+            assert_eq!(
+                Vec::from(out.str()?),
+                &[Some("1"), Some("b"), None, Some("4")]
+            );
+        }
+        Ok(())
+    }
+}
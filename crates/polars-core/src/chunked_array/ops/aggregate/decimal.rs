@@ -0,0 +1,205 @@
+use ethnum::{I256, U256};
+
+use super::*;
+use crate::chunked_array::logical::DECIMAL_MAX_ABS;
+
+/// Running state for the exact `mean`, `var` and `std` of a decimal, computed on the physical
+/// values. The sums are kept in 256 bits, sums that overflow even those are tracked so the
+/// finalizers can report them.
+#[derive(Clone, Copy, Debug)]
+pub struct DecimalMoments {
+    count: i128,
+    sum: Option<I256>,
+    sum_sq: Option<I256>,
+}
+
+impl Default for DecimalMoments {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: Some(I256::ZERO),
+            sum_sq: Some(I256::ZERO),
+        }
+    }
+}
+
+fn overflow(op: &str) -> PolarsError {
+    polars_err!(ComputeError: "decimal overflow in {}: the result doesn't fit in precision 38", op)
+}
+
+fn pow10(exp: usize) -> I256 {
+    I256::new(10).pow(exp as u32)
+}
+
+/// Divide by a positive `d`, rounding half away from zero.
+fn div_round(a: I256, d: I256) -> I256 {
+    let (q, r) = (a / d, a % d);
+    if r.unsigned_abs() * 2 >= d.unsigned_abs() {
+        q + r.signum()
+    } else {
+        q
+    }
+}
+
+/// The integer square root, rounded down.
+fn isqrt(n: U256) -> U256 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method converges from above, starting at a power of two above the root.
+    let mut x = U256::ONE << ((256 - n.leading_zeros() + 1) / 2);
+    loop {
+        let y = (x + n / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Narrow a result back to the physical `i128` of a decimal with precision 38.
+fn to_decimal(v: I256, op: &str) -> PolarsResult<i128> {
+    i128::try_from(v)
+        .ok()
+        .filter(|v| v.unsigned_abs() < DECIMAL_MAX_ABS)
+        .ok_or_else(|| overflow(op))
+}
+
+impl DecimalMoments {
+    #[inline]
+    pub fn insert(&mut self, v: i128) {
+        let v = I256::new(v);
+        self.count += 1;
+        self.sum = self.sum.and_then(|s| s.checked_add(v));
+        self.sum_sq = self
+            .sum_sq
+            .and_then(|s| v.checked_mul(v).and_then(|sq| s.checked_add(sq)));
+    }
+
+    pub fn combine(&mut self, other: &Self) {
+        self.count += other.count;
+        self.sum = self.sum.zip(other.sum).and_then(|(a, b)| a.checked_add(b));
+        self.sum_sq = self
+            .sum_sq
+            .zip(other.sum_sq)
+            .and_then(|(a, b)| a.checked_add(b));
+    }
+
+    /// The mean of values with the given scale, at the scale given by [`decimal_div_scale`] and
+    /// rounded half away from zero.
+    pub fn mean(&self, scale: usize) -> PolarsResult<Option<i128>> {
+        if self.count == 0 {
+            return Ok(None);
+        }
+        let mean = self
+            .sum
+            .and_then(|sum| sum.checked_mul(pow10(decimal_div_scale(scale) - scale)))
+            .map(|sum| div_round(sum, I256::new(self.count)))
+            .ok_or_else(|| overflow("mean"))?;
+        to_decimal(mean, "mean").map(Some)
+    }
+
+    /// `n * sum(x^2) - sum(x)^2` and `n * (n - ddof)`, the variance is their ratio.
+    fn var_parts(&self, ddof: u8, op: &str) -> PolarsResult<Option<(I256, I256)>> {
+        if self.count <= ddof as i128 {
+            return Ok(None);
+        }
+        let n = I256::new(self.count);
+        let num = self
+            .sum
+            .zip(self.sum_sq)
+            .and_then(|(sum, sum_sq)| n.checked_mul(sum_sq)?.checked_sub(sum.checked_mul(sum)?))
+            .ok_or_else(|| overflow(op))?;
+        let den = n
+            .checked_mul(n - I256::from(ddof))
+            .ok_or_else(|| overflow(op))?;
+        Ok(Some((num, den)))
+    }
+
+    /// The variance of values with the given scale, at the scale given by
+    /// [`decimal_div_scale`] and truncated.
+    pub fn var(&self, scale: usize, ddof: u8) -> PolarsResult<Option<i128>> {
+        let Some((num, den)) = self.var_parts(ddof, "var")? else {
+            return Ok(None);
+        };
+        // The parts have twice the input scale.
+        let out_scale = decimal_div_scale(scale);
+        let var = if out_scale >= 2 * scale {
+            num.checked_mul(pow10(out_scale - 2 * scale))
+                .ok_or_else(|| overflow("var"))?
+                / den
+        } else {
+            num / den / pow10(2 * scale - out_scale)
+        };
+        to_decimal(var, "var").map(Some)
+    }
+
+    /// The standard deviation of values with the given scale, at the scale given by
+    /// [`decimal_div_scale`] and truncated.
+    pub fn std(&self, scale: usize, ddof: u8) -> PolarsResult<Option<i128>> {
+        let Some((num, den)) = self.var_parts(ddof, "std")? else {
+            return Ok(None);
+        };
+        // The square root halves the scale, so compute the variance at twice the output scale.
+        let var = num
+            .checked_mul(pow10(2 * (decimal_div_scale(scale) - scale)))
+            .ok_or_else(|| overflow("std"))?
+            / den;
+        to_decimal(isqrt(var.unsigned_abs()).as_i256(), "std").map(Some)
+    }
+}
+
+impl DecimalChunked {
+    /// Gather the [`DecimalMoments`] of the non-null values.
+    pub fn moments(&self) -> DecimalMoments {
+        let mut out = DecimalMoments::default();
+        for arr in self.downcast_iter() {
+            arr.non_null_values_iter().for_each(|v| out.insert(v));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decimal_moments() {
+        // 0.10, 10.10, 100.01, 9000.12
+        let ca = Int128Chunked::from_slice(PlSmallStr::EMPTY, &[10, 1010, 10001, 900012])
+            .into_decimal_unchecked(None, 2);
+        let m = ca.moments();
+        assert_eq!(m.mean(2).unwrap(), Some(2277582500));
+        assert_eq!(m.var(2, 1).unwrap(), Some(20087578619091));
+        assert_eq!(m.std(2, 1).unwrap(), Some(4481916846));
+
+        let m = DecimalMoments::default();
+        assert_eq!(m.mean(2).unwrap(), None);
+        assert_eq!(m.var(2, 1).unwrap(), None);
+
+        let mut m = DecimalMoments::default();
+        m.insert(-1);
+        m.insert(-2);
+        m.insert(-2);
+        // -0.16666... is rounded at scale 1 + 4.
+        assert_eq!(m.mean(1).unwrap(), Some(-16667));
+        m.insert(10i128.pow(37));
+        assert!(m.mean(1).is_err());
+
+        // +-0.03125 is rounded half away from zero at scale 0 + 4.
+        for (v, mean) in [(1, 313), (-1, -313)] {
+            let mut m = DecimalMoments::default();
+            m.insert(v);
+            (0..31).for_each(|_| m.insert(0));
+            assert_eq!(m.mean(0).unwrap(), Some(mean));
+        }
+
+        // the squares don't fit in an i128, but the variance does
+        let mut m = DecimalMoments::default();
+        m.insert(10i128.pow(30));
+        m.insert(10i128.pow(30) + 2);
+        assert_eq!(m.var(0, 1).unwrap(), Some(20000));
+        assert_eq!(m.std(0, 1).unwrap(), Some(14142));
+    }
+}
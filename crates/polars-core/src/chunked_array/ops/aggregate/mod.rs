@@ -1,4 +1,6 @@
 //! Implementations of the ChunkAgg trait.
+#[cfg(feature = "dtype-decimal")]
+mod decimal;
 mod quantile;
 mod var;
 
@@ -12,6 +14,8 @@ use polars_compute::float_sum;
 use polars_compute::min_max::MinMaxKernel;
 use polars_utils::min_max::MinMax;
 use polars_utils::sync::SyncPtr;
+#[cfg(feature = "dtype-decimal")]
+pub use decimal::*;
 pub use quantile::*;
 pub use var::*;
 
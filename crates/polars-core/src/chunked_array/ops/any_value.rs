@@ -62,6 +62,20 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
                 AnyValue::List(s)
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            // Map values are represented as a list of `{key, value}` structs.
+            let v: ArrayRef = downcast!(LargeListArray);
+            let entries = map_entries_dtype(key, value);
+            let s = Series::from_chunks_and_dtype_unchecked(
+                PlSmallStr::EMPTY,
+                vec![v],
+                &entries.to_physical(),
+            )
+            .cast_unchecked(&entries)
+            .unwrap();
+            AnyValue::List(s)
+        },
         #[cfg(feature = "dtype-array")]
         DataType::Array(dt, width) => {
             let v: ArrayRef = downcast!(FixedSizeListArray);
@@ -96,6 +110,14 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let arr = &*(arr as *const dyn Array as *const StructArray);
             AnyValue::Struct(idx, arr, flds)
         },
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(variants, _) => {
+            // A union value is the value of its variant.
+            let arr = &*(arr as *const dyn Array as *const StructArray);
+            let tags = &*(&*arr.values()[0] as *const dyn Array as *const Int8Array);
+            let tag = tags.value_unchecked(idx) as usize;
+            arr_to_any_value(&*arr.values()[tag + 1], idx, variants[tag].dtype())
+        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Datetime(tu, tz) => {
             let arr = &*(arr as *const dyn Array as *const Int64Array);
@@ -126,6 +148,27 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Decimal(v, scale.unwrap_or_else(|| unreachable!()))
         },
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal256(_, scale) => {
+            let arr = &*(arr as *const dyn Array as *const BinaryViewArray);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Decimal256(
+                i256_from_physical(v),
+                scale.unwrap_or_else(|| unreachable!()),
+            )
+        },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let arr = &*(arr as *const dyn Array as *const Float32Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Float16(f16::from_f32(v))
+        },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(interval_from_i128(v))
+        },
         #[cfg(feature = "object")]
         DataType::Object(_, _) => {
             // We should almost never hit this. The only known exception is when we put objects in
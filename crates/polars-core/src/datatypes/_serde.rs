@@ -87,6 +87,8 @@ enum SerializableDataType {
     Int64,
     Float32,
     Float64,
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     String,
     Binary,
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
@@ -97,11 +99,19 @@ enum SerializableDataType {
     Datetime(TimeUnit, Option<TimeZone>),
     // 64-bit integer representing difference between times in milli|micro|nano seconds
     Duration(TimeUnit),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     /// A 64-bit time representing elapsed time since midnight in the given TimeUnit.
     Time,
     List(Box<SerializableDataType>),
     #[cfg(feature = "dtype-array")]
     Array(Box<SerializableDataType>, usize),
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>),
+    /// The variants of the union and whether it is sparse.
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>, bool),
     Null,
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
@@ -111,6 +121,8 @@ enum SerializableDataType {
     Categorical(Option<Wrap<Utf8ViewArray>>, CategoricalOrdering),
     #[cfg(feature = "dtype-decimal")]
     Decimal(Option<usize>, Option<usize>),
+    #[cfg(feature = "dtype-decimal")]
+    Decimal256(Option<usize>, Option<usize>),
     #[cfg(feature = "dtype-categorical")]
     Enum(Option<Wrap<Utf8ViewArray>>, CategoricalOrdering),
     #[cfg(feature = "object")]
@@ -132,15 +144,26 @@ impl From<&DataType> for SerializableDataType {
             Int64 => Self::Int64,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
             String => Self::String,
             Binary => Self::Binary,
             Date => Self::Date,
             Datetime(tu, tz) => Self::Datetime(*tu, tz.clone()),
             Duration(tu) => Self::Duration(*tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
             Time => Self::Time,
             List(dt) => Self::List(Box::new(dt.as_ref().into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new(dt.as_ref().into()), *width),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+            ),
+            #[cfg(feature = "dtype-union")]
+            Union(flds, mode) => Self::Union(flds.clone(), mode.is_sparse()),
             Null => Self::Null,
             Unknown(kind) => Self::Unknown(*kind),
             #[cfg(feature = "dtype-struct")]
@@ -155,6 +178,8 @@ impl From<&DataType> for SerializableDataType {
             Enum(None, ordering) => Self::Enum(None, *ordering),
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(precision, scale) => Self::Decimal256(*precision, *scale),
             #[cfg(feature = "object")]
             Object(name, _) => Self::Object(name.to_string()),
             dt => panic!("{dt:?} not supported"),
@@ -176,15 +201,23 @@ impl From<SerializableDataType> for DataType {
             Int64 => Self::Int64,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
             String => Self::String,
             Binary => Self::Binary,
             Date => Self::Date,
             Datetime(tu, tz) => Self::Datetime(tu, tz),
             Duration(tu) => Self::Duration(tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
             Time => Self::Time,
             List(dt) => Self::List(Box::new((*dt).into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new((*dt).into()), width),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
+            #[cfg(feature = "dtype-union")]
+            Union(flds, is_sparse) => Self::Union(flds, UnionMode::sparse(is_sparse)),
             Null => Self::Null,
             Unknown(kind) => Self::Unknown(kind),
             #[cfg(feature = "dtype-struct")]
@@ -197,6 +230,8 @@ impl From<SerializableDataType> for DataType {
             Enum(None, ordering) => Self::Enum(None, ordering),
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(precision, scale),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(precision, scale) => Self::Decimal256(precision, scale),
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown", None),
         }
@@ -103,6 +103,15 @@ pub enum AnyValue<'a> {
     /// A 128-bit fixed point decimal number with a scale.
     #[cfg(feature = "dtype-decimal")]
     Decimal(i128, usize),
+    /// A 256-bit fixed point decimal number with a scale.
+    #[cfg(feature = "dtype-decimal")]
+    Decimal256(i256, usize),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
+    /// A 16-bit IEEE 754 half-precision float.
+    #[cfg(feature = "dtype-f16")]
+    Float16(f16),
 }
 
 #[cfg(feature = "serde")]
@@ -364,6 +373,14 @@ impl AnyValue<'static> {
             DataType::Decimal(_p, s) => {
                 AnyValue::Decimal(0, s.expect("unknown scale during execution"))
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal256(_p, s) => {
+                AnyValue::Decimal256(i256::default(), s.expect("unknown scale during execution"))
+            },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => AnyValue::Interval(months_days_ns::default()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => AnyValue::Float16(f16::default()),
             _ => AnyValue::Null,
         }
     }
@@ -423,6 +440,12 @@ impl<'a> AnyValue<'a> {
             StructOwned(payload) => DataType::Struct(payload.1.clone()),
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, scale) => DataType::Decimal(None, Some(*scale)),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(_, scale) => DataType::Decimal256(None, Some(*scale)),
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
+            #[cfg(feature = "dtype-f16")]
+            Float16(_) => DataType::Float16,
             #[cfg(feature = "object")]
             Object(o) => DataType::Object(o.type_name(), None),
             #[cfg(feature = "object")]
@@ -446,6 +469,8 @@ impl<'a> AnyValue<'a> {
             UInt64(v) => NumCast::from(*v),
             Float32(v) => NumCast::from(*v),
             Float64(v) => NumCast::from(*v),
+            #[cfg(feature = "dtype-f16")]
+            Float16(v) => NumCast::from(v.to_f32()),
             #[cfg(feature = "dtype-date")]
             Date(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-datetime")]
@@ -463,6 +488,14 @@ impl<'a> AnyValue<'a> {
                     NumCast::from(f? / 10f64.powi(*scale as _))
                 }
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(v, scale) => {
+                if *scale == 0 {
+                    NumCast::from(i128::try_from(v.0).ok()?)
+                } else {
+                    NumCast::from(v.0.as_f64() / 10f64.powi(*scale as _))
+                }
+            },
             Boolean(v) => NumCast::from(if *v { 1 } else { 0 }),
             String(v) => {
                 if let Ok(val) = (*v).parse::<i128>() {
@@ -520,6 +553,8 @@ impl<'a> AnyValue<'a> {
         match self {
             AnyValue::Float32(f) => f.is_nan(),
             AnyValue::Float64(f) => f.is_nan(),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(f) => f.to_f32().is_nan(),
             _ => false,
         }
     }
@@ -555,6 +590,8 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
+            #[cfg(feature = "dtype-f16")]
+            (av, DataType::Float16) => AnyValue::Float16(f16::from_f32(av.extract::<f32>()?)),
 
             // to boolean
             (AnyValue::UInt8(v), DataType::Boolean) => AnyValue::Boolean(*v != u8::default()),
@@ -853,6 +890,15 @@ impl AnyValue<'_> {
                 v.hash(state);
                 k.hash(state);
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(v, k) => {
+                v.hash(state);
+                k.hash(state);
+            },
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
+            #[cfg(feature = "dtype-f16")]
+            Float16(v) => v.to_f32().to_ne_bytes().hash(state),
             Null => {},
         }
     }
@@ -934,6 +980,8 @@ impl<'a> AnyValue<'a> {
         match self {
             AnyValue::Float32(v) => Some((*v).into()),
             AnyValue::Float64(v) => Some(*v),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(v) => Some(v.to_f32().into()),
             _ => None,
         }
     }
@@ -950,6 +998,8 @@ impl<'a> AnyValue<'a> {
             (UInt64(l), UInt64(r)) => UInt64(l + r),
             (Float32(l), Float32(r)) => Float32(l + r),
             (Float64(l), Float64(r)) => Float64(l + r),
+            #[cfg(feature = "dtype-f16")]
+            (Float16(l), Float16(r)) => Float16(f16::from_f32(l.to_f32() + r.to_f32())),
             #[cfg(feature = "dtype-duration")]
             (Duration(l, lu), Duration(r, ru)) => {
                 if lu != ru {
@@ -966,6 +1016,22 @@ impl<'a> AnyValue<'a> {
 
                 Decimal(l + r, *ls)
             },
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal256(l, ls), Decimal256(r, rs)) => {
+                if ls != rs {
+                    unimplemented!("adding decimals with different scales is not supported here");
+                }
+
+                Decimal256(i256(l.0 + r.0), *ls)
+            },
+            // the components saturate rather than overflow (each is added separately,
+            // so there is no carry between them)
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => Interval(months_days_ns::new(
+                l.months().saturating_add(r.months()),
+                l.days().saturating_add(r.days()),
+                l.ns().saturating_add(r.ns()),
+            )),
             _ => unimplemented!(),
         }
     }
@@ -1045,6 +1111,12 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(val, scale) => Decimal(val, scale),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(val, scale) => Decimal256(val, scale),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
+            #[cfg(feature = "dtype-f16")]
+            Float16(v) => Float16(v),
             #[cfg(feature = "dtype-categorical")]
             Categorical(v, rev, arr) => CategoricalOwned(v, Arc::new(rev.clone()), arr),
             #[cfg(feature = "dtype-categorical")]
@@ -1211,6 +1283,14 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l == r,
+            #[cfg(feature = "dtype-f16")]
+            (Float16(l), Float16(r)) => l.to_f32().to_total_ord() == r.to_f32().to_total_ord(),
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal256(l_v, l_s), Decimal256(r_v, r_s)) => {
+                decimal256_cmp(*l_v, *l_s, *r_v, *r_s).is_eq()
+            },
 
             #[cfg(feature = "dtype-struct")]
             (StructOwned(l), StructOwned(r)) => struct_eq_missing(
@@ -1379,6 +1459,14 @@ impl PartialOrd for AnyValue<'_> {
 
                 lt.partial_cmp(rt)
             },
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => Some(l.tot_cmp(r)),
+            #[cfg(feature = "dtype-f16")]
+            (Float16(l), Float16(r)) => Some(l.to_f32().tot_cmp(&r.to_f32())),
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal256(l_v, l_s), Decimal256(r_v, r_s)) => {
+                Some(decimal256_cmp(*l_v, *l_s, *r_v, *r_s))
+            },
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-categorical")]
@@ -1446,6 +1534,25 @@ impl PartialOrd for AnyValue<'_> {
     }
 }
 
+/// Compare two 256-bit decimals with possibly different scales.
+#[cfg(feature = "dtype-decimal")]
+fn decimal256_cmp(l_v: i256, l_s: usize, r_v: i256, r_s: usize) -> Ordering {
+    // Bring both values to the larger scale, a value that overflows is larger in magnitude.
+    let rescale = |v: i256, s: usize, to: usize| {
+        ethnum::I256::new(10)
+            .checked_pow((to - s) as u32)
+            .and_then(|f| v.0.checked_mul(f))
+    };
+    let scale = l_s.max(r_s);
+    match (rescale(l_v, l_s, scale), rescale(r_v, r_s, scale)) {
+        (Some(l), Some(r)) => l.cmp(&r),
+        (None, _) if l_v.0.is_negative() => Ordering::Less,
+        (None, _) => Ordering::Greater,
+        (_, None) if r_v.0.is_negative() => Ordering::Greater,
+        (_, None) => Ordering::Less,
+    }
+}
+
 impl TotalEq for AnyValue<'_> {
     #[inline]
     fn tot_eq(&self, other: &Self) -> bool {
@@ -1669,9 +1776,23 @@ impl From<bool> for AnyValue<'static> {
     }
 }
 
+#[cfg(feature = "dtype-interval")]
+impl From<months_days_ns> for AnyValue<'static> {
+    fn from(value: months_days_ns) -> Self {
+        AnyValue::Interval(value)
+    }
+}
+
+#[cfg(feature = "dtype-f16")]
+impl From<f16> for AnyValue<'static> {
+    fn from(value: f16) -> Self {
+        AnyValue::Float16(value)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "dtype-categorical")]
+    #[cfg(any(feature = "dtype-categorical", feature = "dtype-interval"))]
     use super::*;
 
     #[test]
@@ -1784,4 +1905,15 @@ mod test {
             assert_eq!(dt_p, dt);
         }
     }
+
+    #[test]
+    #[cfg(feature = "dtype-interval")]
+    fn test_add_intervals_saturates() {
+        let l = AnyValue::Interval(months_days_ns::new(i32::MAX, 1, i64::MIN));
+        let r = AnyValue::Interval(months_days_ns::new(1, 2, -1));
+        assert_eq!(
+            l.add(&r),
+            AnyValue::Interval(months_days_ns::new(i32::MAX, 3, i64::MIN))
+        );
+    }
 }
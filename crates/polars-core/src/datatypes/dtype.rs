@@ -54,11 +54,21 @@ pub enum DataType {
     Int64,
     Float32,
     Float64,
+    /// A half precision (16-bit) floating point number. The values are held as `f32` in memory,
+    /// so a Float16 column takes as much memory as a Float32 one; only when exported to Arrow,
+    /// Parquet or IPC are they stored as `f16`. Half precision columns that were not written by
+    /// Polars as Float16 are read as Float32.
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     /// Fixed point decimal type optional precision and non-negative scale.
     /// This is backed by a signed 128-bit integer which allows for up to 38 significant digits.
     /// Meaning max precision is 38.
     #[cfg(feature = "dtype-decimal")]
     Decimal(Option<usize>, Option<usize>), // precision/scale; scale being None means "infer"
+    /// Fixed point decimal type backed by a signed 256-bit integer, which allows for up to 76
+    /// significant digits. Decimals with a precision above 38 are promoted to this type.
+    #[cfg(feature = "dtype-decimal")]
+    Decimal256(Option<usize>, Option<usize>),
     /// String data
     String,
     Binary,
@@ -71,6 +81,11 @@ pub enum DataType {
     Datetime(TimeUnit, Option<TimeZone>),
     // 64-bit integer representing difference between times in milliseconds or nanoseconds
     Duration(TimeUnit),
+    /// A calendar interval of months, days and nanoseconds (like Arrow's MonthDayNano).
+    /// Unlike [`DataType::Duration`] the length of an interval depends on the date it is
+    /// applied to. This is backed by a signed 128-bit integer.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
     /// A nested list with a fixed size in each row
@@ -78,6 +93,15 @@ pub enum DataType {
     Array(Box<DataType>, usize),
     /// A nested list with a variable size in each row
     List(Box<DataType>),
+    /// A map from keys to values (key dtype, value dtype).
+    /// This is backed by a list of `{key, value}` structs, like Arrow's `Map` type.
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
+    /// A value that is one of several variants, each with its own name and dtype.
+    /// This is backed by a struct holding the `tag` of the variant followed by a field per
+    /// variant, like a sparse Arrow `Union`.
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>, UnionMode),
     /// A generic type that can be used in a `Series`
     /// &'static str can be used to determine/set inner type
     #[cfg(feature = "object")]
@@ -129,6 +153,12 @@ impl PartialEq for DataType {
                 },
                 (Datetime(tu_l, tz_l), Datetime(tu_r, tz_r)) => tu_l == tu_r && tz_l == tz_r,
                 (List(left_inner), List(right_inner)) => left_inner == right_inner,
+                #[cfg(feature = "dtype-map")]
+                (Map(left_key, left_value), Map(right_key, right_value)) => {
+                    left_key == right_key && left_value == right_value
+                },
+                #[cfg(feature = "dtype-union")]
+                (Union(lhs, mode_l), Union(rhs, mode_r)) => mode_l == mode_r && lhs == rhs,
                 #[cfg(feature = "dtype-duration")]
                 (Duration(tu_l), Duration(tu_r)) => tu_l == tu_r,
                 #[cfg(feature = "object")]
@@ -187,6 +217,10 @@ impl DataType {
             DataType::List(inner) => inner.is_known(),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, _) => inner.is_known(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => fields.iter().all(|fld| fld.dtype.is_known()),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
             DataType::Unknown(_) => false,
@@ -202,6 +236,24 @@ impl DataType {
                 .materialize()
                 .ok_or_else(|| polars_err!(SchemaMismatch: "failed to materialize unknown type")),
             DataType::List(inner) => Ok(DataType::List(Box::new(inner.materialize_unknown()?))),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown()?),
+                Box::new(value.materialize_unknown()?),
+            )),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, mode) => Ok(DataType::Union(
+                fields
+                    .iter()
+                    .map(|f| {
+                        PolarsResult::Ok(Field::new(
+                            f.name().clone(),
+                            f.dtype().materialize_unknown()?,
+                        ))
+                    })
+                    .try_collect_vec()?,
+                *mode,
+            )),
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => Ok(DataType::Array(
                 Box::new(inner.materialize_unknown()?),
@@ -300,11 +352,24 @@ impl DataType {
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
             Time => Int64,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Float32,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Decimal(None, Some(0)),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(_, _) => Binary,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => UInt32,
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Array(Box::new(dt.to_physical()), *width),
             List(dt) => List(Box::new(dt.to_physical())),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => List(Box::new(Struct(vec![
+                Field::new(PlSmallStr::from_static("key"), key.to_physical()),
+                Field::new(PlSmallStr::from_static("value"), value.to_physical()),
+            ]))),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => union_physical_dtype(fields).to_physical(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
                 let new_fields = fields
@@ -365,8 +430,32 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a map.
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(_, _))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is a union.
+    pub fn is_union(&self) -> bool {
+        #[cfg(feature = "dtype-union")]
+        {
+            matches!(self, DataType::Union(_, _))
+        }
+        #[cfg(not(feature = "dtype-union"))]
+        {
+            false
+        }
+    }
+
     pub fn is_nested(&self) -> bool {
-        self.is_list() || self.is_struct() || self.is_array()
+        self.is_list() || self.is_struct() || self.is_array() || self.is_map() || self.is_union()
     }
 
     /// Check if this [`DataType`] is a struct
@@ -389,6 +478,30 @@ impl DataType {
         matches!(self, DataType::Date)
     }
 
+    /// Check if this [`DataType`] is a half precision float.
+    pub fn is_float16(&self) -> bool {
+        #[cfg(feature = "dtype-f16")]
+        {
+            matches!(self, DataType::Float16)
+        }
+        #[cfg(not(feature = "dtype-f16"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is a calendar interval.
+    pub fn is_interval(&self) -> bool {
+        #[cfg(feature = "dtype-interval")]
+        {
+            matches!(self, DataType::Interval)
+        }
+        #[cfg(not(feature = "dtype-interval"))]
+        {
+            false
+        }
+    }
+
     pub fn is_object(&self) -> bool {
         #[cfg(feature = "object")]
         {
@@ -415,6 +528,10 @@ impl DataType {
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields.iter().any(|field| field.dtype.contains_views()),
             _ => false,
         }
     }
@@ -431,6 +548,12 @@ impl DataType {
             Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_categoricals()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_categoricals() || value.contains_categoricals(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields
+                .iter()
+                .any(|field| field.dtype.contains_categoricals()),
             _ => false,
         }
     }
@@ -445,6 +568,10 @@ impl DataType {
             Array(inner, _) => inner.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_objects() || value.contains_objects(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields, _) => fields.iter().any(|field| field.dtype.contains_objects()),
             _ => false,
         }
     }
@@ -475,6 +602,27 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a 256-bit Decimal type (of any scale/precision).
+    pub fn is_decimal256(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal256(_, _) => true,
+            _ => false,
+        }
+    }
+
+    /// Decimals with a precision above 38 don't fit in 128 bits, these return the
+    /// [`DataType::Decimal256`] they are promoted to.
+    pub fn promote_decimal(&self) -> Option<DataType> {
+        match self {
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(Some(precision), scale) if *precision > 38 => {
+                Some(DataType::Decimal256(Some(*precision), *scale))
+            },
+            _ => None,
+        }
+    }
+
     /// Check if this [`DataType`] is a basic floating point type (excludes Decimal).
     pub fn is_float(&self) -> bool {
         matches!(
@@ -556,6 +704,11 @@ impl DataType {
                 DTYPE_ENUM_KEY.into(),
                 DTYPE_ENUM_VALUE.into(),
             )])),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Some(BTreeMap::from([(
+                PlSmallStr::from_static("pl"),
+                PlSmallStr::from_static("maintain_type"),
+            )])),
             DataType::BinaryOffset => Some(BTreeMap::from([(
                 PlSmallStr::from_static("pl"),
                 PlSmallStr::from_static("maintain_type"),
@@ -592,6 +745,8 @@ impl DataType {
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
             Float32 => Ok(ArrowDataType::Float32),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(ArrowDataType::Float16),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => {
@@ -603,6 +758,13 @@ impl DataType {
                     scale.unwrap_or(0), // and what else can we do here?
                 ))
             },
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(precision, scale) => {
+                let precision = (*precision).unwrap_or(76);
+                polars_ensure!(precision <= 76 && precision > 0, InvalidOperation: "decimal precision should be <= 76 & >= 1");
+
+                Ok(ArrowDataType::Decimal256(precision, scale.unwrap_or(0)))
+            },
             String => {
                 let dt = if compat_level.0 >= 1 {
                     ArrowDataType::Utf8View
@@ -622,6 +784,8 @@ impl DataType {
             Date => Ok(ArrowDataType::Date32),
             Datetime(unit, tz) => Ok(ArrowDataType::Timestamp(unit.to_arrow(), tz.clone())),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(ArrowDataType::Interval(IntervalUnit::MonthDayNano)),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
             #[cfg(feature = "dtype-array")]
             Array(dt, size) => Ok(dt
@@ -630,6 +794,26 @@ impl DataType {
             List(dt) => Ok(ArrowDataType::LargeList(Box::new(
                 dt.to_arrow_field(PlSmallStr::from_static("item"), compat_level),
             ))),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                // Arrow requires the keys of a map to be non-nullable.
+                let entries = ArrowDataType::Struct(vec![
+                    ArrowField::new(
+                        PlSmallStr::from_static("key"),
+                        key.try_to_arrow(compat_level)?,
+                        false,
+                    ),
+                    value.to_arrow_field(PlSmallStr::from_static("value"), compat_level),
+                ]);
+                Ok(ArrowDataType::Map(
+                    Box::new(ArrowField::new(
+                        PlSmallStr::from_static("entries"),
+                        entries,
+                        false,
+                    )),
+                    false,
+                ))
+            },
             Null => Ok(ArrowDataType::Null),
             #[cfg(feature = "object")]
             Object(_, Some(reg)) => Ok(reg.physical_dtype.clone()),
@@ -661,6 +845,14 @@ impl DataType {
                     .collect();
                 Ok(ArrowDataType::Struct(fields))
             },
+            #[cfg(feature = "dtype-union")]
+            Union(fields, mode) => {
+                let fields = fields
+                    .iter()
+                    .map(|fld| fld.to_arrow(compat_level))
+                    .collect();
+                Ok(ArrowDataType::Union(fields, None, *mode))
+            },
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
             Unknown(kind) => {
                 let dt = match kind {
@@ -698,6 +890,10 @@ impl DataType {
     pub fn matches_schema_type(&self, schema_type: &DataType) -> PolarsResult<bool> {
         match (self, schema_type) {
             (DataType::List(l), DataType::List(r)) => l.matches_schema_type(r),
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv), DataType::Map(rk, rv)) => {
+                Ok(lk.matches_schema_type(rk)? | lv.matches_schema_type(rv)?)
+            },
             #[cfg(feature = "dtype-array")]
             (DataType::Array(l, sl), DataType::Array(r, sr)) => {
                 Ok(l.matches_schema_type(r)? && sl == sr)
@@ -710,9 +906,19 @@ impl DataType {
                 }
                 Ok(must_cast)
             },
+            #[cfg(feature = "dtype-union")]
+            (DataType::Union(l, lm), DataType::Union(r, rm)) if lm == rm && l.len() == r.len() => {
+                let mut must_cast = false;
+                for (l, r) in l.iter().zip(r.iter()) {
+                    must_cast |= l.dtype.matches_schema_type(&r.dtype)?;
+                }
+                Ok(must_cast)
+            },
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(_, s1), DataType::Decimal(_, s2)) => Ok(s1 != s2),
+            #[cfg(feature = "dtype-decimal")]
+            (DataType::Decimal256(_, s1), DataType::Decimal256(_, s2)) => Ok(s1 != s2),
             // We don't allow the other way around, only if our current type is
             // null and the schema isn't we allow it.
             (DataType::Null, _) => Ok(true),
@@ -745,6 +951,8 @@ impl Display for DataType {
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
             DataType::Float32 => "f32",
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => "f16",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => {
@@ -756,6 +964,16 @@ impl Display for DataType {
                     _ => f.write_str("decimal[?]"), // shouldn't happen
                 };
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal256(precision, scale) => {
+                return match (precision, scale) {
+                    (Some(precision), Some(scale)) => {
+                        f.write_str(&format!("decimal256[{precision},{scale}]"))
+                    },
+                    (None, Some(scale)) => f.write_str(&format!("decimal256[*,{scale}]")),
+                    _ => f.write_str("decimal256[?]"),
+                };
+            },
             DataType::String => "str",
             DataType::Binary => "binary",
             DataType::Date => "date",
@@ -767,6 +985,8 @@ impl Display for DataType {
                 return f.write_str(&s);
             },
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => "interval",
             DataType::Time => "time",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
@@ -781,6 +1001,16 @@ impl Display for DataType {
                 return write!(f, "array[{tp}, {}]", shape);
             },
             DataType::List(tp) => return write!(f, "list[{tp}]"),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields, _) => {
+                let variants = fields
+                    .iter()
+                    .map(|fld| fld.dtype().to_string())
+                    .collect::<Vec<_>>();
+                return write!(f, "union[{}]", variants.join(", "));
+            },
             #[cfg(feature = "object")]
             DataType::Object(s, _) => s,
             #[cfg(feature = "dtype-categorical")]
@@ -831,6 +1061,22 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
             let merged = merge_dtypes(inner_l, inner_r)?;
             List(Box::new(merged))
         },
+        #[cfg(feature = "dtype-map")]
+        (Map(key_l, value_l), Map(key_r, value_r)) => {
+            let key = merge_dtypes(key_l, key_r)?;
+            let value = merge_dtypes(value_l, value_r)?;
+            Map(Box::new(key), Box::new(value))
+        },
+        #[cfg(feature = "dtype-union")]
+        (Union(inner_l, mode_l), Union(inner_r, mode_r)) => {
+            polars_ensure!(inner_l.len() == inner_r.len() && mode_l == mode_r, ComputeError: "cannot combine unions with different variants");
+            let fields = inner_l.iter().zip(inner_r.iter()).map(|(l, r)| {
+                polars_ensure!(l.name() == r.name(), ComputeError: "cannot combine unions with different variants ({} != {})", l.name(), r.name());
+                let merged = merge_dtypes(l.dtype(), r.dtype())?;
+                Ok(Field::new(l.name().clone(), merged))
+            }).collect::<PolarsResult<Vec<_>>>()?;
+            Union(fields, *mode_l)
+        },
         #[cfg(feature = "dtype-struct")]
         (Struct(inner_l), Struct(inner_r)) => {
             polars_ensure!(inner_l.len() == inner_r.len(), ComputeError: "cannot combine structs with differing amounts of fields ({} != {})", inner_l.len(), inner_r.len());
@@ -130,6 +130,20 @@ impl DataType {
         Box::new(self)
     }
 
+    /// Convert the data type of an Arrow field. A half precision float field is read as
+    /// Float32, unless it was written by Polars as Float16.
+    pub fn from_arrow_field(field: &ArrowField) -> DataType {
+        match field.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16
+                if field.metadata.get("pl").map(|s| s.as_str()) != Some("maintain_type") =>
+            {
+                DataType::Float32
+            },
+            dt => DataType::from_arrow(dt, true),
+        }
+    }
+
     pub fn from_arrow(dt: &ArrowDataType, bin_to_view: bool) -> DataType {
         match dt {
             ArrowDataType::Null => DataType::Null,
@@ -142,14 +156,33 @@ impl DataType {
             ArrowDataType::Int32 => DataType::Int32,
             ArrowDataType::Int64 => DataType::Int64,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            // Without the `dtype-f16` feature half precision floats are read as `f32`.
+            #[cfg(not(feature = "dtype-f16"))]
+            ArrowDataType::Float16 => DataType::Float32,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
             #[cfg(feature = "dtype-array")]
             ArrowDataType::FixedSizeList(f, size) => DataType::Array(DataType::from_arrow(f.dtype(), bin_to_view).boxed(), *size),
             ArrowDataType::LargeList(f) | ArrowDataType::List(f) => DataType::List(DataType::from_arrow(f.dtype(), bin_to_view).boxed()),
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(f, _) => match f.dtype() {
+                ArrowDataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    DataType::from_arrow(fields[0].dtype(), bin_to_view).boxed(),
+                    DataType::from_arrow(fields[1].dtype(), bin_to_view).boxed(),
+                ),
+                // Maps with other entries are read as a list of those entries.
+                dt => DataType::List(DataType::from_arrow(dt, bin_to_view).boxed()),
+            },
+            // Without the `dtype-map` feature maps are read as a list of structs.
+            #[cfg(not(feature = "dtype-map"))]
+            ArrowDataType::Map(f, _) => DataType::List(DataType::from_arrow(f.dtype(), bin_to_view).boxed()),
             ArrowDataType::Date32 => DataType::Date,
             ArrowDataType::Timestamp(tu, tz) => DataType::Datetime(tu.into(), DataType::canonical_timezone(tz)),
             ArrowDataType::Duration(tu) => DataType::Duration(tu.into()),
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => DataType::Interval,
             ArrowDataType::Date64 => DataType::Datetime(TimeUnit::Milliseconds, None),
             ArrowDataType::Time64(_) | ArrowDataType::Time32(_) => DataType::Time,
             #[cfg(feature = "dtype-categorical")]
@@ -158,6 +191,10 @@ impl DataType {
             ArrowDataType::Struct(fields) => {
                 DataType::Struct(fields.iter().map(|fld| fld.into()).collect())
             }
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(fields, _, mode) => {
+                DataType::Union(fields.iter().map(|fld| fld.into()).collect(), *mode)
+            }
             #[cfg(not(feature = "dtype-struct"))]
             ArrowDataType::Struct(_) => {
                 panic!("activate the 'dtype-struct' feature to handle struct data types")
@@ -174,6 +211,8 @@ impl DataType {
             }
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => DataType::Decimal(Some(*precision), Some(*scale)),
+            #[cfg(feature = "dtype-decimal")]
+            ArrowDataType::Decimal256(precision, scale) => DataType::Decimal256(Some(*precision), Some(*scale)),
             ArrowDataType::Utf8View |ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => DataType::String,
             ArrowDataType::BinaryView => DataType::Binary,
             ArrowDataType::LargeBinary | ArrowDataType::Binary => {
@@ -198,6 +237,6 @@ impl From<&ArrowDataType> for DataType {
 
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
-        Field::new(f.name.clone(), f.dtype().into())
+        Field::new(f.name.clone(), DataType::from_arrow_field(f))
     }
 }
@@ -28,7 +28,17 @@ pub use arrow::array::{ArrayCollectIterExt, ArrayFromIter, ArrayFromIterDtype, S
 pub use arrow::datatypes::reshape::*;
 #[cfg(feature = "dtype-categorical")]
 use arrow::datatypes::IntegerType;
+#[cfg(feature = "dtype-interval")]
+use arrow::datatypes::IntervalUnit;
 pub use arrow::datatypes::{ArrowDataType, TimeUnit as ArrowTimeUnit};
+#[cfg(feature = "dtype-union")]
+pub use arrow::datatypes::UnionMode;
+#[cfg(feature = "dtype-f16")]
+pub use arrow::types::f16;
+#[cfg(feature = "dtype-decimal")]
+pub use arrow::types::i256;
+#[cfg(feature = "dtype-interval")]
+pub use arrow::types::months_days_ns;
 use arrow::types::simd::Simd;
 use arrow::types::NativeType;
 use bytemuck::Zeroable;
@@ -194,6 +204,8 @@ impl_polars_num_datatype!(PolarsFloatType, Float32Type, Float32, f32, f32);
 impl_polars_num_datatype!(PolarsFloatType, Float64Type, Float64, f64, f64);
 impl_polars_datatype!(DateType, Date, PrimitiveArray<i32>, 'a, i32, i32, i32);
 impl_polars_datatype!(TimeType, Time, PrimitiveArray<i64>, 'a, i64, i64, i64);
+#[cfg(feature = "dtype-f16")]
+impl_polars_datatype!(Float16Type, Float16, PrimitiveArray<f32>, 'a, f32, f32, f32);
 impl_polars_binview_datatype!(StringType, String, Utf8ViewArray, 'a, &'a str, Option<&'a str>, String);
 impl_polars_binview_datatype!(BinaryType, Binary, BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>);
 impl_polars_datatype!(BinaryOffsetType, BinaryOffset, BinaryArray<i64>, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>);
@@ -201,6 +213,10 @@ impl_polars_datatype!(BooleanType, Boolean, BooleanArray, 'a, bool, bool, bool);
 
 #[cfg(feature = "dtype-decimal")]
 impl_polars_datatype_pass_dtype!(DecimalType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
+#[cfg(feature = "dtype-decimal")]
+impl_polars_datatype_pass_dtype!(Decimal256Type, DataType::Unknown(UnknownKind::Any), BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>, TrueT);
+#[cfg(feature = "dtype-interval")]
+impl_polars_datatype_pass_dtype!(IntervalType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
 impl_polars_datatype_pass_dtype!(DatetimeType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype_pass_dtype!(DurationType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype_pass_dtype!(CategoricalType, DataType::Unknown(UnknownKind::Any), PrimitiveArray<u32>, 'a, u32, u32, u32, FalseT);
@@ -223,6 +239,25 @@ unsafe impl PolarsDataType for ListType {
     }
 }
 
+#[cfg(feature = "dtype-map")]
+pub struct MapType {}
+#[cfg(feature = "dtype-map")]
+unsafe impl PolarsDataType for MapType {
+    type Physical<'a> = Box<dyn Array>;
+    type OwnedPhysical = Box<dyn Array>;
+    type ZeroablePhysical<'a> = Option<Box<dyn Array>>;
+    type Array = ListArray<i64>;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = FalseT;
+    type IsObject = FalseT;
+
+    fn get_dtype() -> DataType {
+        // Null as we cannot know anything without self.
+        DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null))
+    }
+}
+
 #[cfg(feature = "dtype-struct")]
 pub struct StructType {}
 #[cfg(feature = "dtype-struct")]
@@ -249,6 +284,24 @@ unsafe impl PolarsDataType for StructType {
     }
 }
 
+#[cfg(feature = "dtype-union")]
+pub struct UnionType {}
+#[cfg(feature = "dtype-union")]
+unsafe impl PolarsDataType for UnionType {
+    type Physical<'a> = ();
+    type OwnedPhysical = ();
+    type ZeroablePhysical<'a> = ();
+    type Array = StructArray;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = TrueT;
+    type IsObject = FalseT;
+
+    fn get_dtype() -> DataType {
+        DataType::Union(vec![], UnionMode::Sparse)
+    }
+}
+
 #[cfg(feature = "dtype-array")]
 pub struct FixedSizeListType {}
 #[cfg(feature = "dtype-array")]
@@ -380,6 +380,23 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.decimal().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal256(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.decimal256().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                format_array!(f, self.float16().unwrap(), "f16", self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => format_array!(
+                f,
+                self.interval().unwrap(),
+                "interval",
+                self.name(),
+                "Series"
+            ),
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -389,6 +406,16 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.list().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.union().unwrap(), &dt, self.name(), "Series")
+            },
             #[cfg(feature = "object")]
             DataType::Object(_, _) => format_object_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
@@ -1022,6 +1049,24 @@ fn fmt_duration_ms(f: &mut Formatter<'_>, v: i64) -> fmt::Result {
 }
 
 #[cfg(feature = "dtype-duration")]
+#[cfg(feature = "dtype-interval")]
+fn fmt_interval(f: &mut Formatter<'_>, v: months_days_ns) -> fmt::Result {
+    let (years, months) = (v.months() / 12, v.months() % 12);
+    let mut sep = "";
+    for (value, name) in [(years, "y"), (months, "mo"), (v.days(), "d")] {
+        if value != 0 {
+            write!(f, "{sep}{value}{name}")?;
+            sep = " ";
+        }
+    }
+    // the time part is also written if all other parts are zero
+    if v.ns() != 0 || sep.is_empty() {
+        write!(f, "{sep}")?;
+        fmt_duration_ns(f, v.ns())?;
+    }
+    Ok(())
+}
+
 fn format_duration(f: &mut Formatter, v: i64, sizes: &[i64], names: &[&str]) -> fmt::Result {
     for i in 0..4 {
         let whole_num = if i == 0 {
@@ -1122,6 +1167,12 @@ impl Display for AnyValue<'_> {
             AnyValue::StructOwned(payload) => fmt_struct(f, &payload.0),
             #[cfg(feature = "dtype-decimal")]
             AnyValue::Decimal(v, scale) => fmt_decimal(f, *v, *scale),
+            #[cfg(feature = "dtype-decimal")]
+            AnyValue::Decimal256(v, scale) => fmt_decimal256(f, *v, *scale),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval(f, *v),
+            #[cfg(feature = "dtype-f16")]
+            AnyValue::Float16(v) => fmt_float(f, width, f16_shortest_repr(v.to_f32())),
         }
     }
 }
@@ -1229,6 +1280,15 @@ pub fn fmt_decimal(f: &mut Formatter<'_>, v: i128, scale: usize) -> fmt::Result
     f.write_str(fmt_float_string(repr.as_str()).as_str())
 }
 
+#[cfg(feature = "dtype-decimal")]
+pub fn fmt_decimal256(f: &mut Formatter<'_>, v: i256, scale: usize) -> fmt::Result {
+    use arrow::compute::decimal::format_decimal256;
+
+    let trim_zeros = get_trim_decimal_zeros();
+    let repr = format_decimal256(v, scale, trim_zeros);
+    f.write_str(fmt_float_string(repr.as_str()).as_str())
+}
+
 #[cfg(all(
     test,
     feature = "temporal",
@@ -339,6 +339,10 @@ impl Column {
     pub fn struct_(&self) -> PolarsResult<&StructChunked> {
         self.as_materialized_series().struct_()
     }
+    #[cfg(feature = "dtype-f16")]
+    pub fn float16(&self) -> PolarsResult<&Float16Chunked> {
+        self.as_materialized_series().float16()
+    }
     #[cfg(feature = "dtype-decimal")]
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
         self.as_materialized_series().decimal()
@@ -347,6 +351,14 @@ impl Column {
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
         self.as_materialized_series().array()
     }
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.as_materialized_series().map()
+    }
+    #[cfg(feature = "dtype-union")]
+    pub fn union(&self) -> PolarsResult<&UnionChunked> {
+        self.as_materialized_series().union()
+    }
     #[cfg(feature = "dtype-categorical")]
     pub fn categorical(&self) -> PolarsResult<&CategoricalChunked> {
         self.as_materialized_series().categorical()
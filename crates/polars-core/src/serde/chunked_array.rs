@@ -136,6 +136,10 @@ impl_serialize!(ListChunked);
 impl_serialize!(BinaryChunked);
 #[cfg(feature = "dtype-array")]
 impl_serialize!(ArrayChunked);
+#[cfg(feature = "dtype-map")]
+impl_serialize!(MapChunked);
+#[cfg(feature = "dtype-decimal")]
+impl_serialize!(Decimal256Chunked);
 
 #[cfg(feature = "dtype-categorical")]
 impl Serialize for CategoricalChunked {
@@ -183,6 +187,29 @@ impl Serialize for StructChunked {
     }
 }
 
+#[cfg(feature = "dtype-union")]
+impl Serialize for UnionChunked {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        // The tags are serialized first, followed by the values of the variants.
+        let mut values = vec![self.tags().into_series()];
+        for i in 0..self.variants().len() {
+            values.push(self.variant(i).map_err(S::Error::custom)?);
+        }
+
+        let mut state = serializer.serialize_map(Some(3))?;
+        state.serialize_entry("name", self.name())?;
+        state.serialize_entry("datatype", self.dtype())?;
+        state.serialize_entry("values", &values)?;
+        state.end()
+    }
+}
+
 impl Serialize for NullChunked {
     fn serialize<S>(
         &self,
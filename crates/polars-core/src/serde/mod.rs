@@ -148,6 +148,27 @@ mod test {
         let out = serde_json::from_str::<DataFrame>(&df_str).unwrap();
         assert!(df.equals_missing(&out));
     }
+
+    #[test]
+    #[cfg(feature = "dtype-union")]
+    fn test_serde_union_series_json() {
+        let dtype = DataType::Union(
+            vec![
+                Field::new("int".into(), DataType::Int64),
+                Field::new("str".into(), DataType::String),
+            ],
+            UnionMode::Dense,
+        );
+        let values = [AnyValue::String("a"), AnyValue::Null, AnyValue::Int64(2)];
+        let s = Series::from_any_values_and_dtype("item".into(), &values, &dtype, true).unwrap();
+        assert_eq!(s.dtype(), &dtype);
+
+        let json = serde_json::to_string(&s).unwrap();
+        let out = serde_json::from_str::<Series>(&json).unwrap();
+        assert_eq!(out.dtype(), &dtype);
+        assert!(s.equals_missing(&out));
+    }
+
     /// test using the `DeserializedOwned` trait
     #[test]
     fn test_serde_df_owned_bincode() {
@@ -29,6 +29,16 @@ impl Serialize for Series {
                 let ca = self.list().unwrap();
                 ca.serialize(serializer)
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let ca = self.map().unwrap();
+                ca.serialize(serializer)
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_, _) => {
+                let ca = self.union().unwrap();
+                ca.serialize(serializer)
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let ca = self.array().unwrap();
@@ -77,6 +87,21 @@ impl Serialize for Series {
                 let ca = self.decimal().unwrap();
                 ca.serialize(serializer)
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal256(_, _) => {
+                let ca = self.decimal256().unwrap();
+                ca.serialize(serializer)
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let ca = self.float16().unwrap();
+                ca.serialize(serializer)
+            },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let ca = self.interval().unwrap();
+                ca.serialize(serializer)
+            },
             DataType::Null => {
                 let ca = self.null().unwrap();
                 ca.serialize(serializer)
@@ -220,6 +245,27 @@ impl<'de> Deserialize<'de> for Series {
                             .into_decimal_unchecked(precision, scale)
                             .into_series())
                     },
+                    #[cfg(feature = "dtype-decimal")]
+                    DataType::Decimal256(precision, Some(scale)) => {
+                        let values: Vec<Option<Cow<[u8]>>> = map.next_value()?;
+                        Ok(BinaryChunked::from_iter_options(name, values.into_iter())
+                            .into_decimal256_unchecked(precision, scale)
+                            .into_series())
+                    },
+                    #[cfg(feature = "dtype-f16")]
+                    DataType::Float16 => {
+                        let values: Vec<Option<f32>> = map.next_value()?;
+                        Ok(Float32Chunked::from_slice_options(name, &values)
+                            .into_float16()
+                            .into_series())
+                    },
+                    #[cfg(feature = "dtype-interval")]
+                    DataType::Interval => {
+                        let values: Vec<Option<i128>> = map.next_value()?;
+                        Ok(ChunkedArray::from_slice_options(name, &values)
+                            .into_interval()
+                            .into_series())
+                    },
                     DataType::Boolean => {
                         let values: Vec<Option<bool>> = map.next_value()?;
                         Ok(Series::new(name, values))
@@ -246,6 +292,22 @@ impl<'de> Deserialize<'de> for Series {
                         }
                         Ok(lb.finish().into_series())
                     },
+                    #[cfg(feature = "dtype-map")]
+                    DataType::Map(key, value) => {
+                        let values: Vec<Option<Series>> = map.next_value()?;
+                        let mut lb = AnonymousListBuilder::new(
+                            name,
+                            values.len(),
+                            Some(map_entries_dtype(&key, &value)),
+                        );
+                        for value in &values {
+                            lb.append_opt_series(value.as_ref()).map_err(|e| {
+                                de::Error::custom(format!("could not append series to map: {e}"))
+                            })?;
+                        }
+                        let ca = lb.finish().into_map().map_err(de::Error::custom)?;
+                        Ok(ca.into_series())
+                    },
                     #[cfg(feature = "dtype-array")]
                     DataType::Array(inner, width) => {
                         let values: Vec<Option<Series>> = map.next_value()?;
@@ -305,6 +367,21 @@ impl<'de> Deserialize<'de> for Series {
                         s.rename(name);
                         Ok(s)
                     },
+                    #[cfg(feature = "dtype-union")]
+                    DataType::Union(variants, mode) => {
+                        let values: Vec<Series> = map.next_value()?;
+                        if values.len() != variants.len() + 1 {
+                            let expected =
+                                format!("tags followed by {} union variants", variants.len());
+                            let expected = expected.as_str();
+                            return Err(de::Error::invalid_length(values.len(), &expected));
+                        }
+                        let tags = values[0].i8().map_err(de::Error::custom)?;
+                        let ca =
+                            UnionChunked::from_tags_and_variants(name, tags, &values[1..], mode)
+                                .map_err(de::Error::custom)?;
+                        Ok(ca.into_series())
+                    },
                     #[cfg(feature = "dtype-categorical")]
                     dt @ (DataType::Categorical(_, _) | DataType::Enum(_, _)) => {
                         let values: Vec<Option<Cow<str>>> = map.next_value()?;
@@ -75,6 +75,11 @@ impl Series {
             let dtype = DataType::Decimal(None, None);
             return Self::from_any_values_and_dtype(name, values, &dtype, strict);
         }
+        #[cfg(feature = "dtype-decimal")]
+        if dtype.is_decimal256() {
+            let dtype = DataType::Decimal256(None, None);
+            return Self::from_any_values_and_dtype(name, values, &dtype, strict);
+        }
 
         Self::from_any_values_and_dtype(name, values, &dtype, strict)
     }
@@ -144,6 +149,10 @@ impl Series {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(tu) => any_values_to_duration(values, *tu, strict)?.into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_f16(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
             #[cfg(feature = "dtype-categorical")]
             dt @ DataType::Categorical(_, _) => any_values_to_categorical(values, dt, strict)?,
             #[cfg(feature = "dtype-categorical")]
@@ -152,13 +161,28 @@ impl Series {
             DataType::Decimal(precision, scale) => {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal256(precision, scale) => {
+                any_values_to_decimal256(values, *precision, *scale, strict)?.into_series()
+            },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = map_entries_dtype(key, value);
+                any_values_to_list(values, &entries, strict)?
+                    .into_map()?
+                    .into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
                 .into_series()
                 .cast(&DataType::Array(inner.clone(), *size))?,
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => any_values_to_struct(values, fields, strict)?,
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(variants, mode) => {
+                any_values_to_union(values, variants, *mode, strict)?
+            },
             #[cfg(feature = "object")]
             DataType::Object(_, registry) => any_values_to_object(values, registry)?,
             DataType::Null => Series::new_null(PlSmallStr::EMPTY, values.len()),
@@ -220,6 +244,8 @@ fn any_values_to_f32(values: &[AnyValue], strict: bool) -> PolarsResult<Float32C
         for av in values {
             match av {
                 AnyValue::Float32(i) => builder.append_value(*i),
+                #[cfg(feature = "dtype-f16")]
+                AnyValue::Float16(i) => builder.append_value(i.to_f32()),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float32, av)),
             }
@@ -240,6 +266,8 @@ fn any_values_to_f64(values: &[AnyValue], strict: bool) -> PolarsResult<Float64C
             match av {
                 AnyValue::Float64(i) => builder.append_value(*i),
                 AnyValue::Float32(i) => builder.append_value(*i as f64),
+                #[cfg(feature = "dtype-f16")]
+                AnyValue::Float16(i) => builder.append_value(i.to_f32() as f64),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float64, av)),
             }
@@ -440,6 +468,42 @@ fn any_values_to_duration(
     Ok(builder.finish().into_duration(time_unit))
 }
 
+#[cfg(feature = "dtype-f16")]
+fn any_values_to_f16(values: &[AnyValue], strict: bool) -> PolarsResult<Float16Chunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Float32Type>::new(PlSmallStr::EMPTY, values.len());
+    for av in values {
+        match av {
+            AnyValue::Float16(v) => builder.append_value(v.to_f32()),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Float16, av));
+                }
+                builder.append_option(av.extract::<f32>().map(round_to_f16))
+            },
+        }
+    }
+    Ok(builder.finish().into_float16())
+}
+
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> PolarsResult<IntervalChunked> {
+    let mut out = Vec::with_capacity(values.len());
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => out.push(Some(*v)),
+            AnyValue::Null => out.push(None),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&DataType::Interval, av));
+                }
+                out.push(None)
+            },
+        }
+    }
+    Ok(IntervalChunked::from_intervals(PlSmallStr::EMPTY, out))
+}
+
 #[cfg(feature = "dtype-categorical")]
 fn any_values_to_categorical(
     values: &[AnyValue],
@@ -601,6 +665,67 @@ fn any_values_to_decimal(
     builder.finish().into_decimal(precision, scale)
 }
 
+#[cfg(feature = "dtype-decimal")]
+fn any_values_to_decimal256(
+    values: &[AnyValue],
+    precision: Option<usize>,
+    scale: Option<usize>, // If None, we're inferring the scale.
+    strict: bool,
+) -> PolarsResult<Decimal256Chunked> {
+    use ethnum::I256;
+
+    let scale = scale.unwrap_or_else(|| {
+        values
+            .iter()
+            .filter_map(|av| match av {
+                AnyValue::Decimal(_, s) | AnyValue::Decimal256(_, s) => Some(*s),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    });
+    let target_dtype = DataType::Decimal256(precision, Some(scale));
+    let max_precision = precision.unwrap_or(DECIMAL256_MAX_PRECISION);
+
+    let mut out = Vec::with_capacity(values.len());
+    for av in values {
+        // Allow equal or less scale. We do want to support different scales even in 'strict' mode.
+        let v = match av {
+            AnyValue::Decimal256(v, s) if *s <= scale => rescale_256(v.0, *s, scale),
+            AnyValue::Decimal(v, s) if *s <= scale => rescale_256(I256::new(*v), *s, scale),
+            AnyValue::Null => {
+                out.push(None);
+                continue;
+            },
+            av if av.is_integer() => av
+                .extract::<i128>()
+                .and_then(|v| rescale_256(I256::new(v), 0, scale)),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&target_dtype, av));
+                }
+                None
+            },
+        };
+        match v {
+            Some(v) if fits_precision_256(v, max_precision) => out.push(Some(i256(v))),
+            _ => {
+                polars_ensure!(
+                    !strict,
+                    ComputeError: "decimal precision {} can't fit value {}", max_precision, av
+                );
+                out.push(None)
+            },
+        }
+    }
+    Ok(Decimal256Chunked::from_i256s(
+        PlSmallStr::EMPTY,
+        out,
+        precision,
+        scale,
+    ))
+}
+
 fn any_values_to_list(
     avs: &[AnyValue],
     inner_type: &DataType,
@@ -715,6 +840,13 @@ fn any_values_to_array(
     }
 
     let target_dtype = DataType::Array(Box::new(inner_type.clone()), width);
+    // Float16 values are stored as f32, so we collect into the physical type and
+    // restore the logical type afterwards.
+    let collect_dtype = if inner_type.is_float16() {
+        target_dtype.to_physical()
+    } else {
+        target_dtype.clone()
+    };
 
     // This is handled downstream. The builder will choose the first non null type.
     let mut valid = true;
@@ -752,7 +884,7 @@ fn any_values_to_array(
                     None
                 },
             })
-            .collect_ca_with_dtype(PlSmallStr::EMPTY, target_dtype.clone())
+            .collect_ca_with_dtype(PlSmallStr::EMPTY, collect_dtype)
     };
 
     if strict && !valid {
@@ -770,6 +902,11 @@ fn any_values_to_array(
             out.set_dtype(target_dtype.clone());
         };
     }
+    if inner_type.is_float16() {
+        unsafe {
+            out.set_dtype(target_dtype);
+        };
+    }
 
     Ok(out)
 }
@@ -817,6 +954,50 @@ fn _any_values_to_struct<'a>(
     }
 }
 
+/// Every value is assigned to the first variant with the same dtype.
+#[cfg(feature = "dtype-union")]
+fn any_values_to_union(
+    values: &[AnyValue],
+    variants: &[Field],
+    mode: UnionMode,
+    strict: bool,
+) -> PolarsResult<Series> {
+    let tags = values
+        .iter()
+        .map(|av| {
+            if av.is_null() {
+                return Ok(None);
+            }
+            let dtype = av.dtype();
+            match variants.iter().position(|fld| fld.dtype() == &dtype) {
+                Some(tag) => Ok(Some(tag as i8)),
+                None => polars_bail!(
+                    SchemaMismatch: "no variant of {} has the dtype {} of value {}",
+                    DataType::Union(variants.to_vec(), mode), dtype, av
+                ),
+            }
+        })
+        .collect::<PolarsResult<Int8Chunked>>()?;
+
+    let variants = variants
+        .iter()
+        .enumerate()
+        .map(|(k, fld)| {
+            let values = values
+                .iter()
+                .zip(tags.iter())
+                .map(|(av, tag)| match tag {
+                    Some(tag) if tag as usize == k => av.clone(),
+                    _ => AnyValue::Null,
+                })
+                .collect::<Vec<_>>();
+            Series::from_any_values_and_dtype(fld.name().clone(), &values, fld.dtype(), strict)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let ca = UnionChunked::from_tags_and_variants(PlSmallStr::EMPTY, &tags, &variants, mode)?;
+    Ok(ca.into_series())
+}
+
 #[cfg(feature = "dtype-struct")]
 fn any_values_to_struct(
     values: &[AnyValue],
@@ -1,4 +1,6 @@
 use arrow::compute::cast::cast_unchecked as cast;
+#[cfg(feature = "dtype-interval")]
+use arrow::datatypes::IntervalUnit;
 use arrow::datatypes::Metadata;
 #[cfg(feature = "dtype-categorical")]
 use arrow::legacy::kernels::concatenate::concatenate_owned_unchecked;
@@ -73,6 +75,21 @@ impl Series {
                     scale.unwrap_or_else(|| unreachable!("scale should be set")),
                 )
                 .into_series(),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(precision, scale) => BinaryChunked::from_chunks(name, chunks)
+                .into_decimal256_unchecked(
+                    *precision,
+                    scale.unwrap_or_else(|| unreachable!("scale should be set")),
+                )
+                .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Float32Chunked::from_chunks(name, chunks)
+                .into_float16()
+                .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-array")]
             Array(_, _) => {
                 ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
@@ -80,6 +97,22 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => ListChunked::from_chunks_and_dtype_unchecked(
+                name,
+                chunks,
+                List(Box::new(map_entries_dtype(key, value))),
+            )
+            .into_map_unchecked()
+            .into_series(),
+            #[cfg(feature = "dtype-union")]
+            Union(variants, mode) => StructChunked::from_chunks_and_dtype_unchecked(
+                name,
+                chunks,
+                union_physical_dtype(variants),
+            )
+            .into_union_unchecked(variants.clone(), *mode)
+            .into_series(),
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -211,6 +244,22 @@ impl Series {
             ArrowDataType::Int16 => Ok(Int16Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int32 => Ok(Int32Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::Int64 => Ok(Int64Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16
+                if md.map_or(true, |md| {
+                    md.get("pl").map(|s| s.as_str()) == Some("maintain_type")
+                }) =>
+            {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| float16_array_to_physical(arr.as_ref()))
+                    .collect();
+                Ok(Float32Chunked::from_chunks(name, chunks)
+                    .into_float16()
+                    .into_series())
+            },
+            // Half precision floats that were not written by Polars as Float16 are read as
+            // `f32` (which they are held as either way).
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -415,8 +464,7 @@ impl Series {
                 let chunks = cast_chunks(&chunks, &DataType::Binary, CastOptions::NonStrict)?;
                 Ok(BinaryChunked::from_chunks(name, chunks).into_series())
             },
-            ArrowDataType::Decimal(precision, scale)
-            | ArrowDataType::Decimal256(precision, scale) => {
+            ArrowDataType::Decimal(precision, scale) => {
                 #[cfg(not(feature = "dtype-decimal"))]
                 {
                     panic!("activate 'dtype-decimal'")
@@ -454,12 +502,42 @@ impl Series {
                     }
                 }
             },
+            #[cfg(feature = "dtype-decimal")]
+            ArrowDataType::Decimal256(precision, scale) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| decimal256_array_to_physical(arr.as_ref()))
+                    .collect();
+                Ok(BinaryChunked::from_chunks(name, chunks)
+                    .into_decimal256_unchecked(Some(*precision), *scale)
+                    .into_series())
+            },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| interval_array_to_physical(arr.as_ref()))
+                    .collect();
+                Ok(Int128Chunked::from_chunks(name, chunks)
+                    .into_interval()
+                    .into_series())
+            },
             ArrowDataType::Map(_, _) => map_arrays_to_series(name, chunks),
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(_, _, _) => {
+                Ok(UnionChunked::from_union_arrays(name, &chunks, dtype)?.into_series())
+            },
             dt => polars_bail!(ComputeError: "cannot create series from {:?}", dt),
         }
     }
 }
 
+#[cfg(feature = "dtype-map")]
+fn map_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult<Series> {
+    Ok(MapChunked::from_map_arrays(name, &chunks)?.into_series())
+}
+
+#[cfg(not(feature = "dtype-map"))]
 fn map_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult<Series> {
     let chunks = chunks
         .iter()
@@ -625,6 +703,20 @@ unsafe fn to_physical_and_dtype(
                 (arrays, DataType::Struct(pl_fields.unwrap()))
             })
         },
+        #[cfg(feature = "dtype-map")]
+        dt @ ArrowDataType::Map(_, _) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-union")]
+        dt @ ArrowDataType::Union(_, _, _) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
         // Use Series architecture to convert nested logical types to physical.
         dt @ (ArrowDataType::Duration(_)
         | ArrowDataType::Time32(_)
@@ -632,6 +724,9 @@ unsafe fn to_physical_and_dtype(
         | ArrowDataType::Timestamp(_, _)
         | ArrowDataType::Date32
         | ArrowDataType::Decimal(_, _)
+        | ArrowDataType::Decimal256(_, _)
+        | ArrowDataType::Interval(_)
+        | ArrowDataType::Float16
         | ArrowDataType::Date64) => {
             let dt = dt.clone();
             let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
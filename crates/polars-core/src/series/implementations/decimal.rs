@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::aggregations::{_agg_helper_idx, _agg_helper_slice};
 use crate::prelude::*;
 
 unsafe impl IntoSeries for DecimalChunked {
@@ -81,6 +83,66 @@ impl SeriesWrap<DecimalChunked> {
             _ => unreachable!(),
         }
     }
+
+    /// Aggregate the [`DecimalMoments`] of every group, groups that overflow become null.
+    #[cfg(feature = "algorithm_group_by")]
+    fn agg_moments<F>(&self, groups: &GroupsProxy, f: F) -> Series
+    where
+        F: Fn(&DecimalMoments) -> PolarsResult<Option<i128>> + Send + Sync,
+    {
+        let ca = self.0.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = match groups {
+            GroupsProxy::Idx(groups) => _agg_helper_idx::<Int128Type, _>(groups, |(_, idx)| {
+                let mut moments = DecimalMoments::default();
+                for &i in idx.iter() {
+                    // SAFETY: group tuples are in bounds.
+                    if let Some(v) = unsafe { arr.get_unchecked(i as usize) } {
+                        moments.insert(v)
+                    }
+                }
+                f(&moments).ok().flatten()
+            }),
+            GroupsProxy::Slice { groups, .. } => {
+                _agg_helper_slice::<Int128Type, _>(groups, |[first, len]| {
+                    let mut moments = DecimalMoments::default();
+                    for i in first..first + len {
+                        // SAFETY: group slices are in bounds.
+                        if let Some(v) = unsafe { arr.get_unchecked(i as usize) } {
+                            moments.insert(v)
+                        }
+                    }
+                    f(&moments).ok().flatten()
+                })
+            },
+        };
+        let scale = decimal_div_scale(self.0.scale());
+        out.decimal()
+            .unwrap()
+            .0
+            .clone()
+            .with_name(self.0.name().clone())
+            .into_decimal_unchecked(None, scale)
+            .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    pub(crate) unsafe fn agg_mean(&self, groups: &GroupsProxy) -> Series {
+        let scale = self.0.scale();
+        self.agg_moments(groups, |m| m.mean(scale))
+    }
+
+    fn moments_reduce<F>(&self, f: F) -> PolarsResult<Scalar>
+    where
+        F: Fn(&DecimalMoments) -> PolarsResult<Option<i128>>,
+    {
+        let scale = decimal_div_scale(self.0.scale());
+        let av = match f(&self.0.moments())? {
+            Some(v) => AnyValue::Decimal(v, scale),
+            None => AnyValue::Null,
+        };
+        Ok(Scalar::new(DataType::Decimal(None, Some(scale)), av))
+    }
 }
 
 unsafe impl IntoSeries for Int128Chunked {
@@ -90,7 +152,7 @@ unsafe impl IntoSeries for Int128Chunked {
     {
         // this is incorrect as it ignores the datatype
         // the caller must correct this.
-        let mut ca = DecimalChunked::new_logical(self);
+        let mut ca = DecimalChunked::new_logical::<DecimalType>(self);
         ca.2 = Some(DataType::Decimal(None, None));
         ca.into_series()
     }
@@ -159,6 +221,18 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
         self.agg_helper(|ca| ca.agg_max(groups))
     }
 
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        let scale = self.0.scale();
+        self.agg_moments(groups, |m| m.var(scale, ddof))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        let scale = self.0.scale();
+        self.agg_moments(groups, |m| m.std(scale, ddof))
+    }
+
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
         self.agg_helper(|ca| ca.agg_list(groups))
@@ -344,14 +418,21 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
     }
 
     fn sum_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(self.apply_physical(|ca| {
-            let sum = ca.sum();
-            let DataType::Decimal(_, Some(scale)) = self.dtype() else {
-                unreachable!()
-            };
-            let av = AnyValue::Decimal(sum.unwrap(), *scale);
-            Scalar::new(self.dtype().clone(), av)
-        }))
+        self.apply_physical(|ca| {
+            let sum = ca.downcast_iter().try_fold(0i128, |acc, arr| {
+                arr.non_null_values_iter()
+                    .try_fold(acc, |acc, v| acc.checked_add(v))
+            });
+            let sum = sum
+                .filter(|v| v.unsigned_abs() < 10u128.pow(38))
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "decimal overflow in sum: the result doesn't fit in precision 38"
+                    )
+                })?;
+            let av = AnyValue::Decimal(sum, self.0.scale());
+            Ok(Scalar::new(self.dtype().clone(), av))
+        })
     }
     fn min_reduce(&self) -> PolarsResult<Scalar> {
         Ok(self.apply_physical(|ca| {
@@ -401,7 +482,17 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
         self.0.std(ddof).map(|v| v / self.scale_factor() as f64)
     }
     fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
-        Ok(self.apply_scale(self.0.std_reduce(ddof)))
+        let scale = self.0.scale();
+        self.moments_reduce(|m| m.std(scale, ddof))
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        let scale_factor = self.scale_factor() as f64;
+        self.0.var(ddof).map(|v| v / (scale_factor * scale_factor))
+    }
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        let scale = self.0.scale();
+        self.moments_reduce(|m| m.var(scale, ddof))
     }
 
     fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
@@ -0,0 +1,390 @@
+use ethnum::I256;
+
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for Decimal256Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Decimal256Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+/// The exact sum of the values at the given indices, or `None` if it overflows.
+///
+/// # Safety
+/// The indices must be in bounds.
+#[cfg(feature = "algorithm_group_by")]
+unsafe fn sum_group(arr: &BinaryViewArray, mut idx: impl Iterator<Item = usize>) -> Option<i256> {
+    idx.try_fold(I256::ZERO, |acc, i| match arr.get_unchecked(i) {
+        Some(v) => acc.checked_add(i256_from_physical(v).0),
+        None => Some(acc),
+    })
+    .filter(|v| fits_precision_256(*v, DECIMAL256_MAX_PRECISION))
+    .map(i256)
+}
+
+impl SeriesWrap<Decimal256Chunked> {
+    fn apply_physical_to_s<F: Fn(&BinaryChunked) -> BinaryChunked>(&self, f: F) -> Series {
+        f(&self.0)
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn with_physical(&self, ca: BinaryChunked) -> Series {
+        ca.into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    /// The decimals as floats, to compute the statistics that aren't exact.
+    fn to_f64(&self) -> Series {
+        self.0.cast(&DataType::Float64).unwrap()
+    }
+
+    fn to_scalar(&self, v: Option<i256>) -> Scalar {
+        let av = match v {
+            Some(v) => AnyValue::Decimal256(v, self.0.scale()),
+            None => AnyValue::Null,
+        };
+        Scalar::new(self.dtype().clone(), av)
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Decimal256Chunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> MetadataFlags {
+        self.0.get_flags()
+    }
+    fn _set_flags(&mut self, flags: MetadataFlags) {
+        self.0.set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0.equal_element(idx_self, idx_other, other)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.decimal256()?;
+        Ok(self.with_physical(self.0.zip_with(mask, &other.0)?))
+    }
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        (&self.0).into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        (&self.0).into_total_ord_inner()
+    }
+
+    fn vec_hash(&self, random_state: PlRandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
+        self.0.vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlRandomState,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    /// The exact sum of every group, groups that overflow become null.
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsProxy) -> Series {
+        let ca = self.0.rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out: Vec<_> = match groups {
+            GroupsProxy::Idx(groups) => groups
+                .all()
+                .iter()
+                .map(|idx| sum_group(arr, idx.iter().map(|i| *i as usize)))
+                .collect(),
+            GroupsProxy::Slice { groups, .. } => groups
+                .iter()
+                .map(|&[first, len]| sum_group(arr, first as usize..(first + len) as usize))
+                .collect(),
+        };
+        Decimal256Chunked::from_i256s(
+            self.0.name().clone(),
+            out,
+            self.0.precision(),
+            self.0.scale(),
+        )
+        .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsProxy) -> Series {
+        let out = self.0.agg_min(groups);
+        self.with_physical(out.binary().unwrap().clone())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsProxy) -> Series {
+        let out = self.0.agg_max(groups);
+        self.with_physical(out.binary().unwrap().clone())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.to_f64().agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsProxy, ddof: u8) -> Series {
+        self.to_f64().agg_var(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsProxy) -> Series {
+        // The list values keep the physical binary representation.
+        let out = self.0.agg_list(groups);
+        let ca = out.list().unwrap();
+        ListChunked::from_chunks_and_dtype_unchecked(
+            ca.name().clone(),
+            ca.chunks().clone(),
+            DataType::List(Box::new(self.dtype().clone())),
+        )
+        .into_series()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) - rhs).map(|ca| ca.into_series())
+    }
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) + rhs).map(|ca| ca.into_series())
+    }
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) * rhs).map(|ca| ca.into_series())
+    }
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) / rhs).map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
+        self.0.group_tuples(multithreaded, sorted)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Decimal256Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name)
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.chunk_lengths()
+    }
+
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.apply_physical_to_s(|ca| ca.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (self.with_physical(a), self.with_physical(b))
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.decimal256()?;
+        self.0.append(&other.0)?;
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.decimal256()?;
+        self.0.extend(&other.0)?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        Ok(self.with_physical(self.0.filter(filter)?))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.with_physical(self.0.take(indices)?))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.with_physical(self.0.take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.with_physical(self.0.take(indices)?))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.with_physical(self.0.take_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.with_physical(self.0.rechunk())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.with_physical(self.0.new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    fn get(&self, index: usize) -> PolarsResult<AnyValue> {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        Ok(self.with_physical(self.0.sort_with(options)))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        Ok(self.with_physical(self.0.unique()?))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_physical_to_s(|ca| ca.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_physical_to_s(|ca| ca.shift(periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        let sum = self
+            .0
+            .iter_i256()
+            .flatten()
+            .try_fold(I256::ZERO, |acc, v| acc.checked_add(v.0))
+            .filter(|v| fits_precision_256(*v, DECIMAL256_MAX_PRECISION))
+            .ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "decimal overflow in sum: the result doesn't fit in precision 76"
+                )
+            })?;
+        Ok(self.to_scalar(Some(i256(sum))))
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.to_scalar(self.0.min_binary().map(i256_from_physical)))
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.to_scalar(self.0.max_binary().map(i256_from_physical)))
+    }
+
+    fn _sum_as_f64(&self) -> f64 {
+        self.to_f64()._sum_as_f64()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.to_f64().mean()
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.to_f64().median()
+    }
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.to_f64().median_reduce()
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.to_f64().std(ddof)
+    }
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_f64().std_reduce(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.to_f64().var(ddof)
+    }
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_f64().var_reduce(ddof)
+    }
+
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        self.to_f64().quantile_reduce(quantile, method)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+}
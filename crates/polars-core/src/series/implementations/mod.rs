@@ -11,10 +11,18 @@ mod date;
 mod datetime;
 #[cfg(feature = "dtype-decimal")]
 mod decimal;
+#[cfg(feature = "dtype-decimal")]
+mod decimal256;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-f16")]
+mod float16;
 mod floats;
+#[cfg(feature = "dtype-interval")]
+mod interval;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 pub(crate) mod null;
 #[cfg(feature = "object")]
 mod object;
@@ -23,6 +31,8 @@ mod string;
 mod struct_;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "dtype-union")]
+mod union;
 
 use std::any::Any;
 use std::borrow::Cow;
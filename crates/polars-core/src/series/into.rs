@@ -156,6 +156,19 @@ impl Series {
                 &DataType::Time.to_arrow(compat_level),
             )
             .unwrap(),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal256(_, _) => self.decimal256().unwrap().chunk_to_arrow(chunk_idx),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => self.float16().unwrap().chunk_to_arrow(chunk_idx),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => self.interval().unwrap().chunk_to_arrow(chunk_idx),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => self.map().unwrap().chunk_to_arrow(chunk_idx, compat_level),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_, _) => self
+                .union()
+                .unwrap()
+                .chunk_to_arrow(chunk_idx, compat_level),
             #[cfg(feature = "object")]
             DataType::Object(_, None) => {
                 use crate::chunked_array::object::builder::object_series_to_arrow_array;
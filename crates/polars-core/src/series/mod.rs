@@ -429,6 +429,8 @@ impl Series {
 
                     Some(D::Struct(new_fields))
                 },
+                #[cfg(feature = "dtype-decimal")]
+                D::Decimal(_, _) => dtype.promote_decimal(),
                 _ => None,
             }
         }
@@ -451,7 +453,24 @@ impl Series {
             opt => opt,
         };
 
-        let ret = self.0.cast(dtype, new_options);
+        #[cfg(feature = "dtype-union")]
+        if let D::Union(variants, mode) = dtype {
+            if !self.dtype().is_union() {
+                return self.cast_to_union(variants, *mode);
+            }
+        }
+
+        let ret = match dtype {
+            #[cfg(feature = "dtype-decimal")]
+            D::Decimal256(precision, scale) if !self.dtype().is_decimal256() => {
+                cast_to_decimal256(self, *precision, *scale).map(|ca| ca.into_series())
+            },
+            #[cfg(feature = "dtype-f16")]
+            D::Float16 if !self.dtype().is_float16() => {
+                cast_to_float16(self, new_options).map(|ca| ca.into_series())
+            },
+            _ => self.0.cast(dtype, new_options),
+        };
 
         match options {
             CastOptions::NonStrict | CastOptions::Overflowing => ret,
@@ -537,6 +556,8 @@ impl Series {
             DataType::List(_) => self.list().unwrap().explode(),
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => self.array().unwrap().explode(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => self.map().unwrap().physical().explode(),
             _ => Ok(self.clone()),
         }
     }
@@ -546,6 +567,8 @@ impl Series {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_nan()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.float16().unwrap().physical().is_nan()),
             dt if dt.is_numeric() => {
                 Ok(BooleanChunked::full(self.name().clone(), false, self.len()))
             },
@@ -558,6 +581,8 @@ impl Series {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_not_nan()),
             DataType::Float64 => Ok(self.f64().unwrap().is_not_nan()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.float16().unwrap().physical().is_not_nan()),
             dt if dt.is_numeric() => {
                 Ok(BooleanChunked::full(self.name().clone(), true, self.len()))
             },
@@ -570,6 +595,8 @@ impl Series {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_finite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_finite()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.float16().unwrap().physical().is_finite()),
             dt if dt.is_numeric() => {
                 Ok(BooleanChunked::full(self.name().clone(), true, self.len()))
             },
@@ -582,6 +609,8 @@ impl Series {
         match self.dtype() {
             DataType::Float32 => Ok(self.f32().unwrap().is_infinite()),
             DataType::Float64 => Ok(self.f64().unwrap().is_infinite()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Ok(self.float16().unwrap().physical().is_infinite()),
             dt if dt.is_numeric() => {
                 Ok(BooleanChunked::full(self.name().clone(), false, self.len()))
             },
@@ -622,12 +651,45 @@ impl Series {
             Duration(_) => Cow::Owned(self.duration().unwrap().0.clone().into_series()),
             #[cfg(feature = "dtype-time")]
             Time => Cow::Owned(self.time().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(_, _) => Cow::Owned(self.decimal256().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Cow::Owned(self.float16().unwrap().0.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(
+                self.interval()
+                    .unwrap()
+                    .0
+                    .clone()
+                    .into_decimal_unchecked(None, 0)
+                    .into_series(),
+            ),
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 let ca = self.categorical().unwrap();
                 Cow::Owned(ca.physical().clone().into_series())
             },
             List(inner) => Cow::Owned(self.cast(&List(Box::new(inner.to_physical()))).unwrap()),
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => Cow::Owned(
+                self.map()
+                    .unwrap()
+                    .0
+                    .clone()
+                    .into_series()
+                    .to_physical_repr()
+                    .into_owned(),
+            ),
+            #[cfg(feature = "dtype-union")]
+            Union(_, _) => Cow::Owned(
+                self.union()
+                    .unwrap()
+                    .0
+                    .clone()
+                    .into_series()
+                    .to_physical_repr()
+                    .into_owned(),
+            ),
             #[cfg(feature = "dtype-array")]
             Array(inner, size) => Cow::Owned(
                 self.cast(&Array(Box::new(inner.to_physical()), *size))
@@ -683,6 +745,21 @@ impl Series {
             Duration(u) => Ok(self.i64()?.clone().into_duration(*u).into_series()),
             #[cfg(feature = "dtype-time")]
             Time => Ok(self.i64()?.clone().into_time().into_series()),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal256(precision, scale) => Ok(self
+                .binary()?
+                .clone()
+                .into_decimal256_unchecked(*precision, scale.unwrap_or(0))
+                .into_series()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(self.f32()?.clone().into_float16().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(self
+                .decimal()?
+                .physical()
+                .clone()
+                .into_interval()
+                .into_series()),
             #[cfg(feature = "dtype-categorical")]
             Categorical { .. } | Enum { .. } => {
                 Ok(CategoricalChunked::from_cats_and_dtype_unchecked(
@@ -699,6 +776,20 @@ impl Series {
                 }
                 err()
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                let entries = List(Box::new(map_entries_dtype(key, value)));
+                let out = self.to_logical_repr_unchecked(&entries)?;
+                Ok(out.list()?.clone().into_map_unchecked().into_series())
+            },
+            #[cfg(feature = "dtype-union")]
+            Union(variants, mode) => {
+                let out = self.to_logical_repr_unchecked(&union_physical_dtype(variants))?;
+                let ca = out.struct_()?.clone();
+                Ok(ca
+                    .into_union_unchecked(variants.clone(), *mode)
+                    .into_series())
+            },
             #[cfg(feature = "dtype-struct")]
             Struct(target_fields) => {
                 let ca = self.struct_().unwrap();
@@ -895,6 +986,16 @@ impl Series {
     }
 
     pub fn mean_reduce(&self) -> Scalar {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.decimal() {
+            // The decimal mean is exact, an overflow results in null.
+            let scale = decimal_div_scale(ca.scale());
+            let av = match ca.moments().mean(ca.scale()) {
+                Ok(Some(v)) => AnyValue::Decimal(v, scale),
+                _ => AnyValue::Null,
+            };
+            return Scalar::new(DataType::Decimal(None, Some(scale)), av);
+        }
         crate::scalar::reduce::mean_reduce(self.mean(), self.dtype().clone())
     }
 
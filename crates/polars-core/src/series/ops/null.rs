@@ -13,6 +13,18 @@ impl Series {
             DataType::List(inner_dtype) => {
                 ListChunked::full_null_with_dtype(name, size, inner_dtype).into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = map_entries_dtype(key, value);
+                let ca = ListChunked::full_null_with_dtype(name, size, &entries);
+                unsafe { ca.into_map_unchecked() }.into_series()
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(variants, mode) => {
+                let s = Series::full_null(name, size, &union_physical_dtype(variants));
+                let ca = s.struct_().unwrap().clone();
+                unsafe { ca.into_union_unchecked(variants.clone(), *mode) }.into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner_dtype, width) => {
                 ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
@@ -51,6 +63,18 @@ impl Series {
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, scale.unwrap_or(0))
                 .into_series(),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal256(precision, scale) => BinaryChunked::full_null(name, size)
+                .into_decimal256_unchecked(*precision, scale.unwrap_or(0))
+                .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Float32Chunked::full_null(name, size)
+                .into_float16()
+                .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Int128Chunked::full_null(name, size)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => {
                 let fields = fields
@@ -244,6 +244,14 @@ pub fn get_supertype_with_options(
             #[cfg(feature = "dtype-time")]
             (Time, Float64) => Some(Float64),
 
+            // Half precision floats are computed in f32, literals keep the column type.
+            #[cfg(feature = "dtype-f16")]
+            (Float16, Unknown(UnknownKind::Float | UnknownKind::Int(_))) => Some(Float16),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, f @ (Float32 | Float64)) => Some(f.clone()),
+            #[cfg(feature = "dtype-f16")]
+            (Float16, dt) if dt.is_integer() || dt.is_bool() => get_supertype(&Float32, dt),
+
             // Every known type can be cast to a string except binary
             (dt, String) if !matches!(dt, Unknown(UnknownKind::Any)) && dt != &Binary && options.allow_primitive_to_string() || !dt.to_physical().is_primitive() => Some(String),
             (String, Binary) => Some(Binary),
@@ -381,6 +389,28 @@ pub fn get_supertype_with_options(
             (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
             #[cfg(feature = "dtype-decimal")]
             (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal256(p1, s1), Decimal256(p2, s2) | Decimal(p2, s2)) => {
+                Some(Decimal256((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
+            }
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal256(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
+            #[cfg(feature = "dtype-decimal")]
+            (d @ Decimal256(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
+            #[cfg(feature = "dtype-union")]
+            (Union(fields_a, mode), Union(fields_b, _)) if fields_a.len() == fields_b.len() => {
+                let mut new_fields = Vec::with_capacity(fields_a.len());
+                for (a, b) in fields_a.iter().zip(fields_b) {
+                    let st = get_supertype(&a.dtype, &b.dtype)?;
+                    new_fields.push(Field::new(a.name.clone(), st))
+                }
+                Some(Union(new_fields, *mode))
+            }
+            // A union is cast to a non-union type by casting all its variants.
+            #[cfg(feature = "dtype-union")]
+            (Union(fields, _), rhs) if !rhs.is_union() => {
+                fields.iter().try_fold(rhs.clone(), |st, fld| get_supertype(&st, &fld.dtype))
+            }
             _ => None,
         }
     }
@@ -40,8 +40,35 @@ impl BinaryExpr {
     }
 }
 
+/// Offset dates and datetimes by intervals, returning `None` for other operands.
+/// This is done here rather than in polars-core, as the calendar logic lives in
+/// polars-time.
+#[cfg(feature = "dtype-interval")]
+fn apply_interval_operator(
+    left: &Series,
+    right: &Series,
+    op: Operator,
+) -> Option<PolarsResult<Series>> {
+    use DataType::*;
+    let out = match (left.dtype(), right.dtype(), op) {
+        (Date | Datetime(_, _), Interval, Operator::Plus | Operator::Minus) => {
+            polars_time::impl_add_interval(left, right, op == Operator::Minus)
+        },
+        (Interval, Date | Datetime(_, _), Operator::Plus) => {
+            polars_time::impl_add_interval(right, left, false)
+                .map(|s| s.with_name(left.name().clone()))
+        },
+        _ => return None,
+    };
+    Some(out)
+}
+
 /// Can partially do operations in place.
 fn apply_operator_owned(left: Series, right: Series, op: Operator) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-interval")]
+    if let Some(out) = apply_interval_operator(&left, &right, op) {
+        return out;
+    }
     match op {
         Operator::Plus => left.try_add_owned(right),
         Operator::Minus => left.try_sub_owned(right),
@@ -54,6 +81,10 @@ fn apply_operator_owned(left: Series, right: Series, op: Operator) -> PolarsResu
 
 pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResult<Series> {
     use DataType::*;
+    #[cfg(feature = "dtype-interval")]
+    if let Some(out) = apply_interval_operator(left, right, op) {
+        return out;
+    }
     match op {
         Operator::Gt => ChunkCompareIneq::gt(left, right).map(|ca| ca.into_series()),
         Operator::GtEq => ChunkCompareIneq::gt_eq(left, right).map(|ca| ca.into_series()),
@@ -275,7 +306,7 @@ impl PhysicalExpr for BinaryExpr {
 
 #[cfg(feature = "parquet")]
 mod stats {
-    use polars_io::predicates::{BatchStats, StatsEvaluator};
+    use polars_io::predicates::{BatchStats, ColumnStats, StatsEvaluator};
 
     use super::*;
 
@@ -414,28 +445,35 @@ mod stats {
             let dummy = DataFrame::empty();
             let state = ExecutionState::new();
 
+            // col == lit: the bloom filter shows if the literal can be in the column.
+            let may_contain = |st: &ColumnStats, lit_s: &Series| {
+                !matches!(self.op, Eq | EqValidity) || st.may_contain_any(lit_s)
+            };
+
             let out = match (self.left.is_literal(), self.right.is_literal()) {
                 (false, true) => {
                     let l = stats.get_stats(fld_l.name())?;
+                    let lit_s = self.right.evaluate(&dummy, &state).unwrap();
                     match l.to_min_max() {
-                        None => Ok(true),
+                        None => Ok(may_contain(l, &lit_s)),
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.right.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
+                            Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op)
+                                && may_contain(l, &lit_s))
                         },
                     }
                 },
                 (true, false) => {
                     let r = stats.get_stats(fld_r.name())?;
+                    let lit_s = self.left.evaluate(&dummy, &state).unwrap();
                     match r.to_min_max() {
-                        None => Ok(true),
+                        None => Ok(may_contain(r, &lit_s)),
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.left.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
+                            Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op)
+                                && may_contain(r, &lit_s))
                         },
                     }
                 },
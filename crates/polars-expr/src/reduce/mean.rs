@@ -16,7 +16,12 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumMeanReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(
+            dtype,
+            DecimalMomentsReducer {
+                stat: DecimalStat::Mean,
+            },
+        )),
         _ => unimplemented!(),
     }
 }
@@ -37,15 +42,6 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .collect_ca(PlSmallStr::EMPTY);
             ca.into_series()
         },
-        #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_prec, scale) => {
-            let inv_scale_factor = 1.0 / 10u128.pow(scale.unwrap() as u32) as f64;
-            let ca: Float64Chunked = values
-                .into_iter()
-                .map(|(s, c)| (c != 0).then(|| s / c as f64 * inv_scale_factor))
-                .collect_ca(PlSmallStr::EMPTY);
-            ca.into_series()
-        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Date => {
             const MS_IN_DAY: i64 = 86_400_000;
@@ -118,6 +114,78 @@ where
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone, Copy)]
+pub(super) enum DecimalStat {
+    Mean,
+    Var(u8),
+    Std(u8),
+}
+
+/// Computes the mean, variance or standard deviation of decimals exactly on their physical
+/// values.
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+pub(super) struct DecimalMomentsReducer {
+    pub(super) stat: DecimalStat,
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalMomentsReducer {
+    type Dtype = Int128Type;
+    type Value = DecimalMoments;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        DecimalMoments::default()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b)
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>) {
+        if let Some(x) = b {
+            a.insert(x);
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>) {
+        for arr in ca.downcast_iter() {
+            arr.non_null_values_iter().for_each(|x| v.insert(x));
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(_, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let ca: Int128Chunked = v
+            .into_iter()
+            .map(|m| match self.stat {
+                DecimalStat::Mean => m.mean(*scale),
+                DecimalStat::Var(ddof) => m.var(*scale, ddof),
+                DecimalStat::Std(ddof) => m.std(*scale, ddof),
+            })
+            .try_collect_ca(PlSmallStr::EMPTY)?;
+        Ok(ca
+            .into_decimal_unchecked(None, decimal_div_scale(*scale))
+            .into_series())
+    }
+}
+
 #[derive(Clone)]
 struct BoolMeanReducer;
 
@@ -41,7 +41,10 @@ pub use utils::materialize_empty_df;
 
 pub mod _internal {
     pub use super::mmap::to_deserializer;
-    pub use super::predicates::read_this_row_group;
+    pub use super::predicates::{
+        bloom_filter_byte_ranges, compute_page_mask, page_index_byte_ranges, read_this_row_group,
+        read_this_row_group_bloom,
+    };
     pub use super::read_impl::{calc_prefilter_cost, PrefilterMaskSetting};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
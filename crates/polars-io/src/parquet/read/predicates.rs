@@ -1,9 +1,19 @@
+use std::ops::Range;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_core::config;
 use polars_core::prelude::*;
-use polars_parquet::read::statistics::{deserialize, Statistics};
-use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::arrow::bloom_filter;
+use polars_parquet::read::indexes::{
+    compute_page_row_intervals, deserialize_column_index, deserialize_offset_index,
+};
+use polars_parquet::read::statistics::{self, deserialize, Statistics};
+use polars_parquet::read::{ColumnChunkMetadata, PhysicalType, RowGroupMetadata};
+use polars_utils::mmap::MemSlice;
 
-use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::predicates::{
+    BatchStats, ColumnBloomFilter, ColumnStats, PhysicalIoExpr, StatsEvaluator,
+};
 
 impl ColumnStats {
     fn from_arrow_stats(stats: Statistics, field: &ArrowField) -> Self {
@@ -86,3 +96,285 @@ pub fn read_this_row_group(
 
     Ok(should_read)
 }
+
+fn to_usize_range(range: Range<u64>) -> Range<usize> {
+    range.start as usize..range.end as usize
+}
+
+/// Returns the flat (non-nested) columns of the row group `md` that are used in `predicate`.
+fn flat_predicate_columns<'a>(
+    predicate: &dyn PhysicalIoExpr,
+    md: &'a RowGroupMetadata,
+    schema: &'a ArrowSchema,
+) -> Vec<(&'a ArrowField, &'a ColumnChunkMetadata)> {
+    if std::env::var("POLARS_NO_PARQUET_STATISTICS").is_ok()
+        || predicate.as_stats_evaluator().is_none()
+    {
+        return Vec::new();
+    }
+
+    let Some(mut live_variables) = predicate.live_variables() else {
+        return Vec::new();
+    };
+    live_variables.sort_unstable();
+    live_variables.dedup();
+
+    live_variables
+        .iter()
+        .filter_map(|name| {
+            let field = schema.get(name)?;
+            if field.dtype.is_nested() {
+                return None;
+            }
+
+            let mut iter = md.columns_under_root_iter(name)?;
+            let column_md = iter.next()?;
+            if iter.next().is_some() {
+                return None;
+            }
+            Some((field, column_md))
+        })
+        .collect()
+}
+
+/// A predicate column of a row group for which both the column index and the offset index were
+/// written.
+struct PageIndexedColumn<'a> {
+    field: &'a ArrowField,
+    md: &'a ColumnChunkMetadata,
+    column_index: Range<usize>,
+    offset_index: Range<usize>,
+}
+
+fn page_indexed_columns<'a>(
+    predicate: &dyn PhysicalIoExpr,
+    md: &'a RowGroupMetadata,
+    schema: &'a ArrowSchema,
+) -> Vec<PageIndexedColumn<'a>> {
+    // Page statistics only map onto rows for flat columns.
+    flat_predicate_columns(predicate, md, schema)
+        .into_iter()
+        .filter_map(|(field, column_md)| {
+            Some(PageIndexedColumn {
+                field,
+                md: column_md,
+                column_index: to_usize_range(column_md.column_index_byte_range()?),
+                offset_index: to_usize_range(column_md.offset_index_byte_range()?),
+            })
+        })
+        .collect()
+}
+
+/// Returns the byte ranges of the page indexes that [`compute_page_mask`] needs for the row group
+/// `md`.
+pub fn page_index_byte_ranges(
+    predicate: &dyn PhysicalIoExpr,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+) -> Vec<Range<usize>> {
+    page_indexed_columns(predicate, md, schema)
+        .into_iter()
+        .flat_map(|c| [c.column_index, c.offset_index])
+        .collect()
+}
+
+/// Evaluate `predicate` against the page statistics of a single column and returns which rows of
+/// the row group may contain matches.
+fn column_page_mask(
+    predicate: &dyn StatsEvaluator,
+    column: &PageIndexedColumn,
+    num_rows: usize,
+    get_bytes: &impl Fn(Range<usize>) -> PolarsResult<MemSlice>,
+) -> PolarsResult<Option<MutableBitmap>> {
+    let column_index = deserialize_column_index(&get_bytes(column.column_index.clone())?)?;
+    let offset_index = deserialize_offset_index(&get_bytes(column.offset_index.clone())?)?;
+
+    let intervals = compute_page_row_intervals(&offset_index.page_locations, num_rows)?;
+    if intervals.len() != column_index.null_pages.len() {
+        return Ok(None);
+    }
+
+    let stats = statistics::deserialize_column_index(column.field, column.md, &column_index)?;
+    let stats = ColumnStats::from_arrow_stats(stats, column.field);
+    let field = Field::from(column.field);
+    let schema = Arc::new(Schema::from_iter([field.clone()]));
+
+    let mut mask = MutableBitmap::with_capacity(num_rows);
+    for (i, interval) in intervals.into_iter().enumerate() {
+        let page_stats = ColumnStats::new(
+            field.clone(),
+            stats.get_null_count_state().map(|s| s.slice(i as i64, 1)),
+            stats.get_min_state().map(|s| s.slice(i as i64, 1)),
+            stats.get_max_state().map(|s| s.slice(i as i64, 1)),
+        );
+        let page_stats = BatchStats::new(schema.clone(), vec![page_stats], Some(interval.len()));
+
+        // An error means that the statistics cannot be used, so the page has to be read.
+        let should_read = predicate.should_read(&page_stats).unwrap_or(true);
+        mask.extend_constant(interval.len(), should_read);
+    }
+
+    Ok(Some(mask))
+}
+
+/// Use the page indexes of the predicate columns to compute which rows of the row group `md` may
+/// contain matches for `predicate`. Pages are pruned per column and the selections of all
+/// columns are combined.
+///
+/// `get_bytes` should return the bytes of the file in the given range.
+///
+/// Returns `None` if all rows have to be read.
+pub fn compute_page_mask(
+    predicate: &dyn PhysicalIoExpr,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    get_bytes: impl Fn(Range<usize>) -> PolarsResult<MemSlice>,
+) -> PolarsResult<Option<Bitmap>> {
+    let columns = page_indexed_columns(predicate, md, schema);
+    let Some(stats_evaluator) = predicate.as_stats_evaluator() else {
+        return Ok(None);
+    };
+
+    let mut mask: Option<MutableBitmap> = None;
+    for column in &columns {
+        let Some(column_mask) =
+            column_page_mask(stats_evaluator, column, md.num_rows(), &get_bytes)?
+        else {
+            continue;
+        };
+
+        mask = Some(match mask {
+            None => column_mask,
+            Some(mask) => mask & &column_mask.freeze(),
+        });
+    }
+
+    let Some(mask) = mask.map(MutableBitmap::freeze) else {
+        return Ok(None);
+    };
+
+    if config::verbose() {
+        eprintln!(
+            "parquet page index selected {} of {} rows in row group",
+            mask.set_bits(),
+            mask.len()
+        );
+    }
+
+    Ok((mask.unset_bits() > 0).then_some(mask))
+}
+
+/// The bloom filter of a column chunk.
+#[derive(Debug)]
+struct ParquetBloomFilter {
+    bitset: Vec<u8>,
+    dtype: DataType,
+    physical_type: PhysicalType,
+}
+
+impl ColumnBloomFilter for ParquetBloomFilter {
+    fn may_contain_any(&self, values: &Series) -> bool {
+        if values.has_nulls() {
+            return true;
+        }
+
+        // Integer literals are not always of the type of the column.
+        let values = if values.dtype() == &self.dtype {
+            values.rechunk()
+        } else if values.dtype().is_integer() && self.dtype.is_integer() {
+            match values.strict_cast(&self.dtype) {
+                Ok(values) => values.rechunk(),
+                Err(_) => return true,
+            }
+        } else {
+            return true;
+        };
+        let array = values.to_arrow(0, CompatLevel::newest());
+        let mut found = false;
+        let supported = bloom_filter::hash_array(array.as_ref(), self.physical_type, |hash| {
+            found |= bloom_filter::is_in_set(&self.bitset, hash)
+        });
+        !supported || found
+    }
+}
+
+/// Returns the predicate columns of the row group `md` that have a bloom filter, along with the
+/// byte ranges of their bloom filters.
+fn bloom_filter_columns<'a>(
+    predicate: &dyn PhysicalIoExpr,
+    md: &'a RowGroupMetadata,
+    schema: &'a ArrowSchema,
+) -> Vec<(&'a ArrowField, &'a ColumnChunkMetadata, Range<usize>)> {
+    flat_predicate_columns(predicate, md, schema)
+        .into_iter()
+        .filter_map(|(field, column_md)| {
+            let range = to_usize_range(column_md.bloom_filter_byte_range()?);
+            Some((field, column_md, range))
+        })
+        .collect()
+}
+
+/// Returns the byte ranges of the bloom filters that [`read_this_row_group_bloom`] needs for the
+/// row group `md`.
+pub fn bloom_filter_byte_ranges(
+    predicate: &dyn PhysicalIoExpr,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+) -> Vec<Range<usize>> {
+    bloom_filter_columns(predicate, md, schema)
+        .into_iter()
+        .map(|(_, _, range)| range)
+        .collect()
+}
+
+/// Use the bloom filters of the predicate columns to determine if the row group `md` may contain
+/// matches for the equality and `is_in` conditions of `predicate`.
+///
+/// `get_bytes` should return the bytes of the file in the given range.
+pub fn read_this_row_group_bloom(
+    predicate: &dyn PhysicalIoExpr,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    get_bytes: impl Fn(Range<usize>) -> PolarsResult<MemSlice>,
+) -> PolarsResult<bool> {
+    let columns = bloom_filter_columns(predicate, md, schema);
+    let Some(stats_evaluator) = predicate.as_stats_evaluator() else {
+        return Ok(true);
+    };
+    if columns.is_empty() {
+        return Ok(true);
+    }
+
+    let mut fields = Vec::with_capacity(columns.len());
+    let mut stats = Vec::with_capacity(columns.len());
+    for (field, column_md, range) in columns {
+        let field = Field::from(field);
+        let mut column_stats = ColumnStats::from_field(field.clone());
+
+        let bytes = get_bytes(range)?;
+        if let Some(bitset) = bloom_filter::deserialize(&bytes)? {
+            column_stats = column_stats.with_bloom_filter(Arc::new(ParquetBloomFilter {
+                bitset: bitset.to_vec(),
+                dtype: field.dtype().clone(),
+                physical_type: column_md.physical_type(),
+            }));
+        }
+
+        fields.push(field);
+        stats.push(column_stats);
+    }
+
+    let stats = BatchStats::new(
+        Arc::new(Schema::from_iter(fields)),
+        stats,
+        Some(md.num_rows()),
+    );
+    // An error means that the bloom filters cannot be used, so the row group has to be read.
+    let should_read = stats_evaluator.should_read(&stats).unwrap_or(true);
+
+    if config::verbose() && !should_read {
+        eprintln!("parquet row group can be skipped, the bloom filters were sufficient to apply the predicate.");
+    }
+
+    Ok(should_read)
+}
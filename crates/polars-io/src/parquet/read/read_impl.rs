@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use std::ops::{Deref, Range};
 
 use arrow::array::BooleanArray;
-use arrow::bitmap::MutableBitmap;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::chunked_array::builder::NullChunkedBuilder;
 use polars_core::prelude::*;
@@ -20,7 +20,7 @@ use rayon::prelude::*;
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
-use super::predicates::read_this_row_group;
+use super::predicates::{compute_page_mask, read_this_row_group, read_this_row_group_bloom};
 use super::to_metadata::ToMetadata;
 use super::utils::materialize_empty_df;
 use super::{mmap, ParallelStrategy};
@@ -43,20 +43,14 @@ fn assert_dtypes(dtype: &ArrowDataType) {
         // These should all be casted to the BinaryView / Utf8View variants
         D::Utf8 | D::Binary | D::LargeUtf8 | D::LargeBinary => unreachable!(),
 
-        // These should be casted to Float32
-        D::Float16 => unreachable!(),
-
         // This should have been converted to a LargeList
         D::List(_) => unreachable!(),
 
-        // This should have been converted to a LargeList(Struct(_))
-        D::Map(_, _) => unreachable!(),
-
         // Recursive checks
         D::Dictionary(_, dtype, _) => assert_dtypes(dtype),
         D::Extension(_, dtype, _) => assert_dtypes(dtype),
         D::LargeList(inner) => assert_dtypes(&inner.dtype),
-        D::FixedSizeList(inner, _) => assert_dtypes(&inner.dtype),
+        D::FixedSizeList(inner, _) | D::Map(inner, _) => assert_dtypes(&inner.dtype),
         D::Struct(fields) => fields.iter().for_each(|f| assert_dtypes(f.dtype())),
 
         _ => {},
@@ -196,6 +190,88 @@ fn column_idx_to_series(
     Ok(series)
 }
 
+/// Use the page indexes to compute which rows of the row group `md` can match `predicate`.
+///
+/// This is only done for local files, as the page indexes are not prefetched for cloud files.
+fn local_page_mask(
+    store: &mmap::ColumnStore,
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    use_statistics: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    let (true, Some(predicate), mmap::ColumnStore::Local(mem_slice)) =
+        (use_statistics, predicate, store)
+    else {
+        return Ok(None);
+    };
+
+    compute_page_mask(predicate, md, schema, |range| {
+        polars_ensure!(
+            range.end <= mem_slice.len(),
+            ComputeError: "parquet page index is out of bounds"
+        );
+        Ok(mem_slice.slice(range))
+    })
+}
+
+/// Use the bloom filters of a row group of a local file to determine if it has to be read.
+fn local_read_this_row_group_bloom(
+    store: &mmap::ColumnStore,
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    use_statistics: bool,
+) -> PolarsResult<bool> {
+    let (true, Some(predicate), mmap::ColumnStore::Local(mem_slice)) =
+        (use_statistics, predicate, store)
+    else {
+        return Ok(true);
+    };
+
+    read_this_row_group_bloom(predicate, md, schema, |range| {
+        polars_ensure!(
+            range.end <= mem_slice.len(),
+            ComputeError: "parquet bloom filter is out of bounds"
+        );
+        Ok(mem_slice.slice(range))
+    })
+}
+
+/// Restrict `page_mask` to the rows `start..start + len` of the row group.
+fn slice_page_mask(page_mask: &Bitmap, start: usize, len: usize) -> Bitmap {
+    let mut mask = MutableBitmap::with_capacity(page_mask.len());
+    mask.extend_constant(start, false);
+    mask.extend_from_bitmap(&page_mask.clone().sliced(start, len));
+    mask.extend_constant(page_mask.len() - start - len, false);
+    mask.freeze()
+}
+
+/// Create a row index column for the rows selected by `mask`, where the row at `start` gets index
+/// `offset`.
+fn masked_row_index(name: PlSmallStr, offset: IdxSize, start: usize, mask: &Bitmap) -> Column {
+    let mut ca = IdxCa::from_vec(
+        name,
+        mask.true_idx_iter()
+            .map(|i| offset + (i - start) as IdxSize)
+            .collect(),
+    );
+    ca.set_sorted_flag(IsSorted::Ascending);
+    ca.into_column()
+}
+
+/// Spread `mask`, which is defined over the rows selected by `page_mask`, out over all the rows
+/// of the row group.
+fn expand_mask(page_mask: &Bitmap, mask: &Bitmap) -> Bitmap {
+    debug_assert_eq!(page_mask.set_bits(), mask.len());
+
+    let mut values = mask.iter();
+    page_mask
+        .iter()
+        .map(|selected| selected && values.next().unwrap())
+        .collect()
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -388,6 +464,34 @@ fn rg_to_dfs_prefiltered(
                         Err(e) => return Err(e),
                     }
                 }
+                if !local_read_this_row_group_bloom(
+                    store,
+                    Some(predicate),
+                    md,
+                    schema,
+                    use_statistics,
+                )? {
+                    return Ok(None);
+                }
+
+                let page_mask =
+                    local_page_mask(store, Some(predicate), md, schema, use_statistics)?;
+                if page_mask.as_ref().is_some_and(|m| m.set_bits() == 0) {
+                    if config::verbose() {
+                        eprintln!("parquet page index found that row group can be skipped");
+                    }
+
+                    return Ok(None);
+                }
+                // test we don't read the parquet file if this env var is set
+                #[cfg(debug_assertions)]
+                {
+                    assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
+                }
+
+                // The rows of the row group that were not pruned by the page index.
+                let live_filter = page_mask.clone().map(Filter::new_masked);
+                let live_height = page_mask.as_ref().map_or(md.num_rows(), |m| m.set_bits());
 
                 let sorting_map = create_sorting_map(md);
 
@@ -402,15 +506,20 @@ fn rg_to_dfs_prefiltered(
                         let Some(iter) = md.columns_under_root_iter(name) else {
                             return Ok(Column::full_null(
                                 name.clone(),
-                                md.num_rows(),
+                                live_height,
                                 &DataType::from_arrow(&field.dtype, true),
                             ));
                         };
 
                         let part = iter.collect::<Vec<_>>();
 
-                        let mut series =
-                            column_idx_to_series(col_idx, part.as_slice(), None, schema, store)?;
+                        let mut series = column_idx_to_series(
+                            col_idx,
+                            part.as_slice(),
+                            live_filter.clone(),
+                            schema,
+                            store,
+                        )?;
 
                         try_set_sorted_flag(&mut series, col_idx, &sorting_map);
 
@@ -420,19 +529,30 @@ fn rg_to_dfs_prefiltered(
 
                 // Apply the predicate to the live columns and save the dataframe and the bitmask
                 let md = &file_metadata.row_groups[rg_idx];
-                let mut df = unsafe { DataFrame::new_no_checks(md.num_rows(), live_columns) };
+                let mut df = unsafe { DataFrame::new_no_checks(live_height, live_columns) };
 
                 materialize_hive_partitions(
                     &mut df,
                     schema.as_ref(),
                     hive_partition_columns,
-                    md.num_rows(),
+                    live_height,
                 );
                 let s = predicate.evaluate_io(&df)?;
                 let mask = s.bool().expect("filter predicates was not of type boolean");
 
                 if let Some(rc) = &row_index {
-                    df.with_row_index_mut(rc.name.clone(), Some(rg_offsets[rg_idx] + rc.offset));
+                    let offset = rg_offsets[rg_idx] + rc.offset;
+                    match &page_mask {
+                        None => {
+                            df.with_row_index_mut(rc.name.clone(), Some(offset));
+                        },
+                        Some(page_mask) => {
+                            df.insert_column(
+                                0,
+                                masked_row_index(rc.name.clone(), offset, 0, page_mask),
+                            )?;
+                        },
+                    }
                 }
                 df = df.filter(mask)?;
 
@@ -450,9 +570,15 @@ fn rg_to_dfs_prefiltered(
 
                 let filter_mask = filter_mask.freeze();
 
-                debug_assert_eq!(md.num_rows(), filter_mask.len());
+                debug_assert_eq!(live_height, filter_mask.len());
                 debug_assert_eq!(df.height(), filter_mask.set_bits());
 
+                // The filter mask over all the rows of the row group.
+                let rg_filter_mask = match &page_mask {
+                    None => filter_mask.clone(),
+                    Some(page_mask) => expand_mask(page_mask, &filter_mask),
+                };
+
                 if filter_mask.set_bits() == 0 {
                     if config::verbose() {
                         eprintln!("parquet filter mask found that row group can be skipped");
@@ -467,13 +593,12 @@ fn rg_to_dfs_prefiltered(
                 }
 
                 let prefilter_cost = matches!(mask_setting, PrefilterMaskSetting::Auto)
-                    .then(|| calc_prefilter_cost(&filter_mask))
+                    .then(|| calc_prefilter_cost(&rg_filter_mask))
                     .unwrap_or_default();
 
                 #[cfg(debug_assertions)]
                 {
-                    let md = &file_metadata.row_groups[rg_idx];
-                    debug_assert_eq!(md.num_rows(), mask.len());
+                    debug_assert_eq!(live_height, mask.len());
                 }
 
                 let n_rows_in_result = filter_mask.set_bits();
@@ -499,7 +624,7 @@ fn rg_to_dfs_prefiltered(
                             column_idx_to_series(
                                 col_idx,
                                 field_md.as_slice(),
-                                Some(Filter::new_masked(filter_mask.clone())),
+                                Some(Filter::new_masked(rg_filter_mask.clone())),
                                 schema,
                                 store,
                             )
@@ -508,7 +633,7 @@ fn rg_to_dfs_prefiltered(
                             let array = column_idx_to_series(
                                 col_idx,
                                 field_md.as_slice(),
-                                None,
+                                live_filter.clone(),
                                 schema,
                                 store,
                             )?;
@@ -639,7 +764,8 @@ fn rg_to_dfs_optionally_par_over_columns(
         let current_row_count = md.num_rows() as IdxSize;
 
         if use_statistics
-            && !read_this_row_group(predicate, &file_metadata.row_groups[rg_idx], schema)?
+            && (!read_this_row_group(predicate, md, schema)?
+                || !local_read_this_row_group_bloom(store, predicate, md, schema, use_statistics)?)
         {
             *previous_row_count += rg_slice.1 as IdxSize;
             continue;
@@ -650,6 +776,14 @@ fn rg_to_dfs_optionally_par_over_columns(
             assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
         }
 
+        let page_mask = local_page_mask(store, predicate, md, schema, use_statistics)?
+            .map(|page_mask| slice_page_mask(&page_mask, rg_slice.0, rg_slice.1));
+        let filter = match &page_mask {
+            None => Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1),
+            Some(page_mask) => Filter::new_masked(page_mask.clone()),
+        };
+        let height = filter.num_rows();
+
         let sorting_map = create_sorting_map(md);
 
         let columns = if let ParallelStrategy::Columns = parallel {
@@ -662,7 +796,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                         let Some(iter) = md.columns_under_root_iter(name) else {
                             return Ok(Column::full_null(
                                 name.clone(),
-                                rg_slice.1,
+                                height,
                                 &DataType::from_arrow(&field.dtype, true),
                             ));
                         };
@@ -672,7 +806,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                         let mut series = column_idx_to_series(
                             *column_i,
                             part.as_slice(),
-                            Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
+                            Some(filter.clone()),
                             schema,
                             store,
                         )?;
@@ -692,7 +826,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                     let Some(iter) = md.columns_under_root_iter(name) else {
                         return Ok(Column::full_null(
                             name.clone(),
-                            rg_slice.1,
+                            height,
                             &DataType::from_arrow(&field.dtype, true),
                         ));
                     };
@@ -702,7 +836,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                     let mut series = column_idx_to_series(
                         *column_i,
                         part.as_slice(),
-                        Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
+                        Some(filter.clone()),
                         schema,
                         store,
                     )?;
@@ -714,12 +848,23 @@ fn rg_to_dfs_optionally_par_over_columns(
                 .collect::<PolarsResult<Vec<_>>>()?
         };
 
-        let mut df = unsafe { DataFrame::new_no_checks(rg_slice.1, columns) };
+        let mut df = unsafe { DataFrame::new_no_checks(height, columns) };
         if let Some(rc) = &row_index {
-            df.with_row_index_mut(rc.name.clone(), Some(*previous_row_count + rc.offset));
+            let offset = *previous_row_count + rc.offset;
+            match &page_mask {
+                None => {
+                    df.with_row_index_mut(rc.name.clone(), Some(offset));
+                },
+                Some(page_mask) => {
+                    df.insert_column(
+                        0,
+                        masked_row_index(rc.name.clone(), offset, rg_slice.0, page_mask),
+                    )?;
+                },
+            }
         }
 
-        materialize_hive_partitions(&mut df, schema.as_ref(), hive_partition_columns, rg_slice.1);
+        materialize_hive_partitions(&mut df, schema.as_ref(), hive_partition_columns, height);
         apply_predicate(&mut df, predicate, true)?;
 
         *previous_row_count = previous_row_count.checked_add(current_row_count).ok_or_else(||
@@ -785,7 +930,17 @@ fn rg_to_dfs_par_over_rg(
         row_groups
             .into_par_iter()
             .map(|(md, slice, row_count_start)| {
-                if slice.1 == 0 || use_statistics && !read_this_row_group(predicate, md, schema)? {
+                if slice.1 == 0
+                    || use_statistics
+                        && (!read_this_row_group(predicate, md, schema)?
+                            || !local_read_this_row_group_bloom(
+                                store,
+                                predicate,
+                                md,
+                                schema,
+                                use_statistics,
+                            )?)
+                {
                     return Ok(None);
                 }
                 // test we don't read the parquet file if this env var is set
@@ -794,6 +949,14 @@ fn rg_to_dfs_par_over_rg(
                     assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
                 }
 
+                let page_mask = local_page_mask(store, predicate, md, schema, use_statistics)?
+                    .map(|page_mask| slice_page_mask(&page_mask, slice.0, slice.1));
+                let filter = match &page_mask {
+                    None => Filter::new_ranged(slice.0, slice.0 + slice.1),
+                    Some(page_mask) => Filter::new_masked(page_mask.clone()),
+                };
+                let height = filter.num_rows();
+
                 let sorting_map = create_sorting_map(md);
 
                 let columns = projection
@@ -804,7 +967,7 @@ fn rg_to_dfs_par_over_rg(
                         let Some(iter) = md.columns_under_root_iter(name) else {
                             return Ok(Column::full_null(
                                 name.clone(),
-                                md.num_rows(),
+                                height,
                                 &DataType::from_arrow(&field.dtype, true),
                             ));
                         };
@@ -814,7 +977,7 @@ fn rg_to_dfs_par_over_rg(
                         let mut series = column_idx_to_series(
                             *column_i,
                             part.as_slice(),
-                            Some(Filter::new_ranged(slice.0, slice.0 + slice.1)),
+                            Some(filter.clone()),
                             schema,
                             store,
                         )?;
@@ -825,20 +988,28 @@ fn rg_to_dfs_par_over_rg(
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
-                let mut df = unsafe { DataFrame::new_no_checks(slice.1, columns) };
+                let mut df = unsafe { DataFrame::new_no_checks(height, columns) };
 
                 if let Some(rc) = &row_index {
-                    df.with_row_index_mut(
-                        rc.name.clone(),
-                        Some(row_count_start as IdxSize + rc.offset),
-                    );
+                    let offset = row_count_start as IdxSize + rc.offset;
+                    match &page_mask {
+                        None => {
+                            df.with_row_index_mut(rc.name.clone(), Some(offset));
+                        },
+                        Some(page_mask) => {
+                            df.insert_column(
+                                0,
+                                masked_row_index(rc.name.clone(), offset, slice.0, page_mask),
+                            )?;
+                        },
+                    }
                 }
 
                 materialize_hive_partitions(
                     &mut df,
                     schema.as_ref(),
                     hive_partition_columns,
-                    slice.1,
+                    height,
                 );
                 apply_predicate(&mut df, predicate, false)?;
 
@@ -4,11 +4,12 @@ use std::sync::Mutex;
 use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
 use polars_core::POOL;
+use polars_parquet::arrow::bloom_filter::{hash_array, insert, optimal_num_of_bytes};
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
     array_to_columns, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
-    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions,
+    FallibleStreamingIterator, FileWriter, Page, ParquetPhysicalType, ParquetType,
+    RowGroupIterColumns, SchemaDescriptor, WriteOptions,
 };
 use rayon::prelude::*;
 
@@ -18,25 +19,49 @@ pub struct BatchedWriter<W: Write> {
     pub(super) writer: Mutex<FileWriter<W>>,
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Vec<Vec<Encoding>>,
+    pub(super) bloom_filters: Vec<BloomFilterColumn>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
 }
 
+/// A column for which a bloom filter is written.
+pub(super) struct BloomFilterColumn {
+    /// Index of the column in the schema.
+    pub(super) column_idx: usize,
+    /// Index of the column in the leaves of the parquet schema.
+    pub(super) leaf_idx: usize,
+    pub(super) physical_type: ParquetPhysicalType,
+    pub(super) fpp: f64,
+    pub(super) ndv: Option<usize>,
+}
+
+/// An encoded and compressed row group, along with the bloom filters of its leaf columns.
+pub struct EncodedRowGroup {
+    columns: RowGroupIterColumns<'static, PolarsError>,
+    bloom_filters: Vec<Option<Vec<u8>>>,
+}
+
 impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
             _ => {
+                let bloom_filters =
+                    build_bloom_filters(&batch, &self.bloom_filters, &self.parquet_schema);
                 let row_group = create_eager_serializer(
                     batch,
                     self.parquet_schema.fields(),
                     self.encodings.as_ref(),
                     self.options,
-                );
+                )
+                .map(|columns| EncodedRowGroup {
+                    columns,
+                    bloom_filters,
+                });
 
                 Some(row_group)
             },
@@ -52,13 +77,15 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
+            &self.bloom_filters,
             self.options,
             self.parallel,
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let group = group?;
+            writer.write_with_bloom_filters(group.columns, &group.bloom_filters)?;
         }
         Ok(())
     }
@@ -67,14 +94,11 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
-    pub fn write_row_groups(
-        &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
-    ) -> PolarsResult<()> {
+    pub fn write_row_groups(&self, rgs: Vec<EncodedRowGroup>) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
         for group in rgs {
-            writer.write(group)?;
+            writer.write_with_bloom_filters(group.columns, &group.bloom_filters)?;
         }
         Ok(())
     }
@@ -92,21 +116,58 @@ fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
+    bloom_filters: &'a [BloomFilterColumn],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
+            let bloom_filters = build_bloom_filters(&batch, bloom_filters, parquet_schema);
             let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel)
+                    .map(|columns| EncodedRowGroup {
+                        columns,
+                        bloom_filters,
+                    });
 
             Some(row_group)
         },
     })
 }
 
+/// Builds the bloom filters of a row group, indexed by leaf column.
+fn build_bloom_filters(
+    batch: &RecordBatch,
+    columns: &[BloomFilterColumn],
+    parquet_schema: &SchemaDescriptor,
+) -> Vec<Option<Vec<u8>>> {
+    if columns.is_empty() {
+        return Vec::new();
+    }
+
+    let mut bloom_filters = vec![None; parquet_schema.columns().len()];
+    for column in columns {
+        let array = batch.columns()[column.column_idx].as_ref();
+
+        let mut hashes = Vec::with_capacity(array.len() - array.null_count());
+        let supported = hash_array(array, column.physical_type, |hash| hashes.push(hash));
+        // This is checked when the writer is created.
+        debug_assert!(supported);
+        hashes.sort_unstable();
+        hashes.dedup();
+
+        let ndv = column.ndv.unwrap_or(hashes.len());
+        let mut bitset = vec![0; optimal_num_of_bytes(ndv, column.fpp)];
+        for hash in hashes {
+            insert(&mut bitset, hash);
+        }
+        bloom_filters[column.leaf_idx] = Some(bitset);
+    }
+    bloom_filters
+}
+
 fn pages_iter_to_compressor(
     encoded_columns: Vec<DynIter<'static, PolarsResult<Page>>>,
     options: WriteOptions,
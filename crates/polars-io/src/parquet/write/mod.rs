@@ -4,7 +4,10 @@ mod batched_writer;
 mod options;
 mod writer;
 
-pub use batched_writer::BatchedWriter;
-pub use options::{BrotliLevel, GzipLevel, ParquetCompression, ParquetWriteOptions, ZstdLevel};
+pub use batched_writer::{BatchedWriter, EncodedRowGroup};
+pub use options::{
+    BrotliLevel, GzipLevel, ParquetBloomFilterOptions, ParquetCompression, ParquetWriteOptions,
+    ZstdLevel,
+};
 pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
 pub use writer::ParquetWriter;
@@ -3,10 +3,11 @@ use polars_parquet::write::{
     BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
     StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
+use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -19,6 +20,47 @@ pub struct ParquetWriteOptions {
     pub data_page_size: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Columns to write a bloom filter for.
+    pub bloom_filters: Vec<ParquetBloomFilterOptions>,
+}
+
+/// Options of the bloom filter of a column.
+///
+/// A split-block bloom filter is written for every row group of the column, which allows readers
+/// to skip row groups that don't contain the values of an equality or `is_in` predicate.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetBloomFilterOptions {
+    /// Name of the column.
+    pub column: PlSmallStr,
+    /// Bit pattern of the target false positive probability, stored as bits so that the options
+    /// can be hashed. See [`ParquetBloomFilterOptions::fpp`].
+    fpp_bits: u64,
+    /// Expected number of distinct values in a row group. If `None`, the number of distinct values
+    /// of each row group is used.
+    pub ndv: Option<usize>,
+}
+
+impl ParquetBloomFilterOptions {
+    /// Bloom filter of `column` with a false positive probability of 1%.
+    pub fn new(column: impl Into<PlSmallStr>) -> Self {
+        Self {
+            column: column.into(),
+            fpp_bits: 0.01f64.to_bits(),
+            ndv: None,
+        }
+    }
+
+    /// Set the target false positive probability, in the range `(0, 1)`.
+    pub fn with_fpp(mut self, fpp: f64) -> Self {
+        self.fpp_bits = fpp.to_bits();
+        self
+    }
+
+    /// The target false positive probability.
+    pub fn fpp(&self) -> f64 {
+        f64::from_bits(self.fpp_bits)
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
@@ -1,15 +1,17 @@
 use std::io::Write;
 use std::sync::Mutex;
 
+use arrow::array::new_empty_array;
 use arrow::datatypes::PhysicalType;
 use polars_core::prelude::*;
+use polars_parquet::arrow::bloom_filter::hash_array;
 use polars_parquet::write::{
-    to_parquet_schema, transverse, CompressionOptions, Encoding, FileWriter, StatisticsOptions,
-    Version, WriteOptions,
+    to_parquet_leaves, to_parquet_schema, transverse, CompressionOptions, Encoding, FileWriter,
+    ParquetType, SchemaDescriptor, StatisticsOptions, Version, WriteOptions,
 };
 
-use super::batched_writer::BatchedWriter;
-use super::options::ParquetCompression;
+use super::batched_writer::{BatchedWriter, BloomFilterColumn};
+use super::options::{ParquetBloomFilterOptions, ParquetCompression};
 use super::ParquetWriteOptions;
 use crate::prelude::chunk_df_for_writing;
 use crate::shared::schema_to_arrow_checked;
@@ -24,6 +26,7 @@ impl ParquetWriteOptions {
             .with_statistics(self.statistics)
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
+            .with_bloom_filters(self.bloom_filters.clone())
     }
 }
 
@@ -39,6 +42,8 @@ pub struct ParquetWriter<W> {
     row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
     data_page_size: Option<usize>,
+    /// Columns to write a bloom filter for.
+    bloom_filters: Vec<ParquetBloomFilterOptions>,
     /// Serialize columns in parallel
     parallel: bool,
 }
@@ -58,6 +63,7 @@ where
             statistics: StatisticsOptions::default(),
             row_group_size: None,
             data_page_size: None,
+            bloom_filters: Vec::new(),
             parallel: true,
         }
     }
@@ -90,6 +96,12 @@ where
         self
     }
 
+    /// Write a bloom filter for each row group of the given columns.
+    pub fn with_bloom_filters(mut self, bloom_filters: Vec<ParquetBloomFilterOptions>) -> Self {
+        self.bloom_filters = bloom_filters;
+        self
+    }
+
     /// Serialize columns in parallel
     pub fn set_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
@@ -100,6 +112,7 @@ where
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
         let parquet_schema = to_parquet_schema(&schema)?;
         let encodings = get_encodings(&schema);
+        let bloom_filters = resolve_bloom_filters(&self.bloom_filters, &schema, &parquet_schema)?;
         let options = self.materialize_options();
         let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
 
@@ -107,6 +120,7 @@ where
             writer,
             parquet_schema,
             encodings,
+            bloom_filters,
             options,
             parallel: self.parallel,
         })
@@ -130,6 +144,56 @@ where
     }
 }
 
+/// Resolve the bloom filter options to the leaf columns of the parquet schema.
+fn resolve_bloom_filters(
+    bloom_filters: &[ParquetBloomFilterOptions],
+    schema: &ArrowSchema,
+    parquet_schema: &SchemaDescriptor,
+) -> PolarsResult<Vec<BloomFilterColumn>> {
+    bloom_filters
+        .iter()
+        .map(|options| {
+            polars_ensure!(
+                options.fpp() > 0.0 && options.fpp() < 1.0,
+                InvalidOperation: "bloom filter false positive probability must be in the range (0, 1), got {}",
+                options.fpp()
+            );
+
+            let (column_idx, _, field) = schema.try_get_full(&options.column)?;
+            let leaf_idx = parquet_schema.fields()[..column_idx]
+                .iter()
+                .map(|type_| to_parquet_leaves(type_.clone()).len())
+                .sum();
+
+            let supported = match &parquet_schema.fields()[column_idx] {
+                ParquetType::PrimitiveType(primitive_type) => {
+                    hash_array(
+                        new_empty_array(field.dtype.clone()).as_ref(),
+                        primitive_type.physical_type,
+                        |_| {},
+                    )
+                    .then_some(primitive_type.physical_type)
+                },
+                ParquetType::GroupType { .. } => None,
+            };
+            let Some(physical_type) = supported else {
+                polars_bail!(
+                    InvalidOperation: "bloom filters are not supported for column '{}' of type {:?}",
+                    options.column, field.dtype
+                );
+            };
+
+            Ok(BloomFilterColumn {
+                column_idx,
+                leaf_idx,
+                physical_type,
+                fpp: options.fpp(),
+                ndv: options.ndv,
+            })
+        })
+        .collect()
+}
+
 fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     schema
         .iter_values()
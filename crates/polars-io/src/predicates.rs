@@ -23,6 +23,15 @@ pub trait StatsEvaluator {
     fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool>;
 }
 
+/// A probabilistic set of the values of a column, such as a bloom filter.
+pub trait ColumnBloomFilter: std::fmt::Debug + Send + Sync {
+    /// Returns `false` if none of `values` can be in the column.
+    ///
+    /// This may return `true` for values that are not in the column. It returns `true` if the
+    /// values contain nulls or cannot be cast to the type of the column.
+    fn may_contain_any(&self, values: &Series) -> bool;
+}
+
 #[cfg(any(feature = "parquet", feature = "ipc"))]
 pub fn apply_predicate(
     df: &mut DataFrame,
@@ -48,6 +57,8 @@ pub fn apply_predicate(
 /// - Null count
 /// - Minimum value
 /// - Maximum value
+///
+/// A [`ColumnBloomFilter`] can be attached for a single row group.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColumnStats {
@@ -56,6 +67,8 @@ pub struct ColumnStats {
     null_count: Option<Series>,
     min_value: Option<Series>,
     max_value: Option<Series>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    bloom_filter: Option<Arc<dyn ColumnBloomFilter>>,
 }
 
 impl ColumnStats {
@@ -71,6 +84,7 @@ impl ColumnStats {
             null_count,
             min_value,
             max_value,
+            bloom_filter: None,
         }
     }
 
@@ -81,6 +95,7 @@ impl ColumnStats {
             null_count: None,
             min_value: None,
             max_value: None,
+            bloom_filter: None,
         }
     }
 
@@ -92,9 +107,16 @@ impl ColumnStats {
             null_count: None,
             min_value: Some(s.clone()),
             max_value: Some(s),
+            bloom_filter: None,
         }
     }
 
+    /// Attaches a [`ColumnBloomFilter`] of the values of the column.
+    pub fn with_bloom_filter(mut self, bloom_filter: Arc<dyn ColumnBloomFilter>) -> Self {
+        self.bloom_filter = Some(bloom_filter);
+        self
+    }
+
     pub fn field_name(&self) -> &PlSmallStr {
         self.field.name()
     }
@@ -119,6 +141,16 @@ impl ColumnStats {
         self.max_value.as_ref()
     }
 
+    /// Returns `false` if the bloom filter of the column shows that none of `values` are in the
+    /// column.
+    ///
+    /// Returns `true` if no bloom filter is available.
+    pub fn may_contain_any(&self, values: &Series) -> bool {
+        self.bloom_filter
+            .as_ref()
+            .map_or(true, |bloom_filter| bloom_filter.may_contain_any(values))
+    }
+
     /// Returns the null count of the column.
     pub fn null_count(&self) -> Option<usize> {
         match self.dtype() {
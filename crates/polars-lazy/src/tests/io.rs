@@ -355,6 +355,147 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_page_index() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_parquet_page_index.parquet");
+    let n = 10_000i64;
+    let mut df = df![
+        "a" => (0..n).collect::<Vec<_>>(),
+        "b" => (0..n).map(|i| (i % 7 != 0).then_some(i)).collect::<Vec<_>>(),
+    ]?;
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(4_000))
+        .with_data_page_size(Some(1024))
+        .with_statistics(StatisticsOptions::full())
+        .finish(&mut df)?;
+
+    for parallel in [
+        ParallelStrategy::None,
+        ParallelStrategy::Columns,
+        ParallelStrategy::RowGroups,
+        ParallelStrategy::Prefiltered,
+    ] {
+        let args = ScanArgsParquet {
+            parallel,
+            row_index: Some(RowIndex {
+                name: PlSmallStr::from_static("index"),
+                offset: 0,
+            }),
+            ..Default::default()
+        };
+        let lf = LazyFrame::scan_parquet(&path, args)?;
+
+        let out = lf.clone().filter(col("a").eq(lit(4_321i64))).collect()?;
+        assert_eq!(out.column("index")?.idx()?.get(0), Some(4_321));
+        assert_eq!(out.column("b")?.get(0)?, AnyValue::Int64(4_321));
+        assert_eq!(out.height(), 1);
+
+        let expected = df
+            .clone()
+            .lazy()
+            .with_row_index("index", None)
+            .filter(col("a").gt_eq(lit(3_990i64)).and(col("b").is_null()))
+            .collect()?;
+        let out = lf
+            .filter(col("a").gt_eq(lit(3_990i64)).and(col("b").is_null()))
+            .collect()?;
+        assert!(out.equals_missing(&expected));
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "is_in"))]
+fn test_parquet_bloom_filter() -> PolarsResult<()> {
+    use polars_io::parquet::write::ParquetBloomFilterOptions;
+
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_test_parquet_bloom_filter.parquet");
+    let n = 10_000i64;
+    // Shuffled even ids, so that the min/max statistics of every row group cover all ids, but odd
+    // ids are in none of them.
+    let ids = (0..n).map(|i| (i * 7_919) % n * 2).collect::<Vec<_>>();
+    let mut df = df![
+        "id" => &ids,
+        "name" => ids.iter().map(|i| format!("user{i}")).collect::<Vec<_>>(),
+        "value" => ids.iter().map(|&i| i as f64).collect::<Vec<_>>(),
+    ]?;
+
+    let writer = || -> PolarsResult<_> {
+        Ok(ParquetWriter::new(std::fs::File::create(&path)?).with_row_group_size(Some(1_000)))
+    };
+    assert!(writer()?
+        .with_bloom_filters(vec![ParquetBloomFilterOptions::new("value")])
+        .finish(&mut df.clone())
+        .is_err());
+    writer()?
+        .with_bloom_filters(vec![
+            ParquetBloomFilterOptions::new("id"),
+            ParquetBloomFilterOptions::new("name"),
+        ])
+        .finish(&mut df)?;
+
+    let mut reader = ParquetReader::new(std::fs::File::open(&path)?);
+    let metadata = reader.get_metadata()?;
+    assert_eq!(metadata.row_groups.len(), 10);
+    for md in &metadata.row_groups {
+        for name in ["id", "name"] {
+            let column = md.columns_under_root_iter(name).unwrap().next().unwrap();
+            assert!(column.bloom_filter_byte_range().is_some());
+        }
+        let column = md.columns_under_root_iter("value").unwrap().next().unwrap();
+        assert!(column.bloom_filter_byte_range().is_none());
+    }
+
+    let predicates = [
+        col("id").eq(typed_lit(4_320i64)),
+        col("id").eq(typed_lit(-1i64)),
+        col("name").eq(lit("user1234")),
+        col("id").is_in(lit(Series::new("".into(), [6i64, 6_000, -3]))),
+        col("name").is_in(lit(Series::new("".into(), ["nobody", "user42"]))),
+    ];
+    // None of these values occur in the file, but all of them are within the min/max statistics
+    // of every row group, so only the bloom filters can skip the row groups.
+    let pruned_predicates = [
+        col("id").eq(typed_lit(4_321i64)),
+        col("name").eq(lit("user4321")),
+        col("id").is_in(lit(Series::new("".into(), [5i64, 6_001]))),
+        col("name").is_in(lit(Series::new("".into(), ["user1", "user9997"]))),
+    ];
+    for parallel in [
+        ParallelStrategy::None,
+        ParallelStrategy::Columns,
+        ParallelStrategy::RowGroups,
+        ParallelStrategy::Prefiltered,
+    ] {
+        let args = ScanArgsParquet {
+            parallel,
+            ..Default::default()
+        };
+        for predicate in &predicates {
+            let expected = df.clone().lazy().filter(predicate.clone()).collect()?;
+            let out = LazyFrame::scan_parquet(&path, args.clone())?
+                .filter(predicate.clone())
+                .collect()?;
+            assert!(out.equals(&expected));
+        }
+
+        // Panic if any row group is read.
+        std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+        for predicate in &pruned_predicates {
+            let out = LazyFrame::scan_parquet(&path, args.clone())?
+                .filter(predicate.clone())
+                .collect()?;
+            assert_eq!(out.height(), 0);
+        }
+        std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+    }
+
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -5,6 +5,8 @@ mod cse;
 #[cfg(feature = "parquet")]
 mod io;
 mod logical;
+#[cfg(feature = "new_streaming")]
+mod new_streaming;
 mod optimization_checks;
 #[cfg(all(feature = "strings", feature = "cse"))]
 mod pdsh;
@@ -0,0 +1,232 @@
+use super::*;
+
+/// Runs the query on both the new streaming engine and the in-memory engine
+/// and checks that they agree. Unless `check_order` is set the rows are sorted
+/// first, as the new engine doesn't guarantee the same row order.
+fn assert_new_streaming_with_default(q: LazyFrame, check_order: bool) -> PolarsResult<()> {
+    let mut out = q.clone().with_new_streaming(true).collect()?;
+    let mut expected = q.collect()?;
+    if !check_order {
+        let by = expected.get_column_names_owned();
+        out = out.sort(by.clone(), SortMultipleOptions::default())?;
+        expected = expected.sort(by, SortMultipleOptions::default())?;
+    }
+    assert!(
+        out.equals_missing(&expected),
+        "new streaming:\n{out}\nin-memory:\n{expected}"
+    );
+    Ok(())
+}
+
+fn join_frames() -> PolarsResult<(LazyFrame, LazyFrame)> {
+    let left = df![
+        "a" => [Some(1), Some(2), None, Some(2), Some(4), None],
+        "b" => [Some(1.0), Some(2.0), Some(3.0), None, Some(-0.0), Some(6.0)],
+        "c" => ["l0", "l1", "l2", "l3", "l4", "l5"],
+    ]?;
+    let right = df![
+        "a" => [Some(2), None, Some(1), Some(2), Some(5)],
+        "b" => [Some(2.0), Some(3.0), Some(1.0), None, Some(0.0)],
+        "c" => ["r0", "r1", "r2", "r3", "r4"],
+    ]?;
+    Ok((left.lazy(), right.lazy()))
+}
+
+#[test]
+fn test_new_streaming_join_null_keys() -> PolarsResult<()> {
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Full] {
+        for join_nulls in [false, true] {
+            for keys in [&["a"][..], &["a", "b"], &["b"]] {
+                let (left, right) = join_frames()?;
+                let on = keys.iter().map(|k| col(*k)).collect::<Vec<_>>();
+                let args = JoinArgs {
+                    join_nulls,
+                    ..JoinArgs::new(how.clone())
+                };
+                let q = left.join(right, on.clone(), on, args);
+                assert_new_streaming_with_default(q, false)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_new_streaming_semi_anti_join_null_keys() -> PolarsResult<()> {
+    for how in [JoinType::Semi, JoinType::Anti] {
+        for join_nulls in [false, true] {
+            for keys in [&["a"][..], &["a", "b"], &["b"]] {
+                let (left, right) = join_frames()?;
+                let on = keys.iter().map(|k| col(*k)).collect::<Vec<_>>();
+                let args = JoinArgs {
+                    join_nulls,
+                    ..JoinArgs::new(how.clone())
+                };
+                let q = left.join(right, on.clone(), on, args);
+                assert_new_streaming_with_default(q, false)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_new_streaming_join_empty_side() -> PolarsResult<()> {
+    for how in [JoinType::Inner, JoinType::Left, JoinType::Full] {
+        let (left, right) = join_frames()?;
+        let q = left.clone().join(
+            right.clone().filter(lit(false)),
+            [col("a")],
+            [col("a")],
+            JoinArgs::new(how.clone()),
+        );
+        assert_new_streaming_with_default(q, false)?;
+
+        let q =
+            left.filter(lit(false))
+                .join(right, [col("a")], [col("a")], JoinArgs::new(how.clone()));
+        assert_new_streaming_with_default(q, false)?;
+    }
+    Ok(())
+}
+
+fn distinct_frame() -> PolarsResult<LazyFrame> {
+    let n = 10_000;
+    let key = (0..n)
+        .map(|i| (i % 7 != 0).then_some(i % 13))
+        .collect::<Vec<_>>();
+    let df = df![
+        "key" => key,
+        "value" => (0..n).map(|i| i % 5).collect::<Vec<_>>(),
+        "idx" => (0..n).collect::<Vec<_>>(),
+    ]?;
+    Ok(df.lazy())
+}
+
+#[test]
+fn test_new_streaming_distinct() -> PolarsResult<()> {
+    for keep in [
+        UniqueKeepStrategy::First,
+        UniqueKeepStrategy::Last,
+        UniqueKeepStrategy::None,
+    ] {
+        for maintain_order in [false, true] {
+            let lf = distinct_frame()?;
+            let subset = Some(vec!["key".into(), "value".into()]);
+            let q = if maintain_order {
+                lf.unique_stable(subset, keep)
+            } else {
+                lf.unique(
+                    subset.map(|s| s.iter().map(|c| c.to_string()).collect()),
+                    keep,
+                )
+            };
+            assert_new_streaming_with_default(q, maintain_order)?;
+        }
+    }
+
+    // Which row is kept is unspecified for `Any`, so only compare the keys.
+    for maintain_order in [false, true] {
+        let lf = distinct_frame()?;
+        let q = if maintain_order {
+            lf.unique_stable(Some(vec!["key".into()]), UniqueKeepStrategy::Any)
+        } else {
+            lf.unique(Some(vec!["key".into()]), UniqueKeepStrategy::Any)
+        };
+        assert_new_streaming_with_default(q.select([col("key")]), maintain_order)?;
+    }
+
+    // Without a subset all columns are compared.
+    let q = distinct_frame()?
+        .select([col("key"), col("value")])
+        .unique_stable(None, UniqueKeepStrategy::First);
+    assert_new_streaming_with_default(q, true)
+}
+
+fn sink_frame() -> PolarsResult<DataFrame> {
+    let n = 2_500;
+    df![
+        "a" => (0..n).map(|i| (i % 3 != 0).then_some(i)).collect::<Vec<_>>(),
+        "b" => (0..n).map(|i| format!("s{}", i % 11)).collect::<Vec<_>>(),
+        "c" => (0..n).map(|i| i as f64 / 4.0).collect::<Vec<_>>(),
+    ]
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_new_streaming_sink_parquet() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_new_streaming_sink.parquet");
+    let df = sink_frame()?;
+    let options = ParquetWriteOptions {
+        row_group_size: Some(1_000),
+        ..Default::default()
+    };
+    df.clone()
+        .lazy()
+        .with_new_streaming(true)
+        .sink_parquet(&path, options)?;
+
+    // The morsels are buffered into row groups of the requested size.
+    let mut reader = ParquetReader::new(std::fs::File::open(&path)?);
+    let row_groups = reader
+        .get_metadata()?
+        .row_groups
+        .iter()
+        .map(|rg| rg.num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(row_groups, [1_000, 1_000, 500]);
+
+    let out = LazyFrame::scan_parquet(&path, Default::default())?.collect()?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_new_streaming_sink_ipc() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_new_streaming_sink.ipc");
+    let df = sink_frame()?;
+    df.clone()
+        .lazy()
+        .with_new_streaming(true)
+        .sink_ipc(&path, Default::default())?;
+
+    let out = LazyFrame::scan_ipc(&path, Default::default())?.collect()?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_new_streaming_sink_csv() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_new_streaming_sink.csv");
+    let df = sink_frame()?;
+    df.clone()
+        .lazy()
+        .with_new_streaming(true)
+        .sink_csv(&path, Default::default())?;
+
+    let out = LazyCsvReader::new(&path).finish()?.collect()?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "cloud_write", feature = "parquet"))]
+fn test_new_streaming_sink_parquet_cloud() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_new_streaming_sink_cloud.parquet");
+    let df = sink_frame()?;
+    df.clone()
+        .lazy()
+        .with_new_streaming(true)
+        .sink_parquet_cloud(
+            format!("file://{}", path.display()),
+            None,
+            Default::default(),
+        )?;
+
+    let out = LazyFrame::scan_parquet(&path, Default::default())?.collect()?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
@@ -0,0 +1,14 @@
+mod namespace;
+
+pub use namespace::MapNameSpace;
+use polars_core::prelude::*;
+
+pub trait AsMap {
+    fn as_map(&self) -> &MapChunked;
+}
+
+impl AsMap for MapChunked {
+    fn as_map(&self) -> &MapChunked {
+        self
+    }
+}
@@ -0,0 +1,143 @@
+use arrow::array::Array;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::*;
+
+/// Get the key and value columns of the flattened entries of a map.
+fn map_entries(ca: &MapChunked) -> PolarsResult<(ListChunked, Series, Series)> {
+    let list = ca.physical().rechunk_and_trim_to_normalized_offsets();
+    let entries = list.get_inner();
+    let fields = entries.struct_()?.fields_as_series();
+    Ok((list, fields[0].clone(), fields[1].clone()))
+}
+
+/// Find the index of the entry with the given key in every map.
+///
+/// `key` must either have length one or the length of the map column. If a map contains the
+/// key more than once, the first entry is returned.
+fn map_find_key(ca: &MapChunked, key: &Series) -> PolarsResult<IdxCa> {
+    polars_ensure!(
+        key.len() == 1 || key.len() == ca.len(),
+        ShapeMismatch: "`map.get` got a key of length {} while the map has {} elements",
+        key.len(), ca.len()
+    );
+    let key = key.strict_cast(ca.key_dtype())?;
+    let (list, keys, _) = map_entries(ca)?;
+    let arr = list.downcast_iter().next().unwrap();
+    let offsets = arr.offsets();
+
+    let found = if key.len() == 1 {
+        keys.equal(&key)?
+    } else {
+        let rows = offsets
+            .lengths()
+            .enumerate()
+            .flat_map(|(row, len)| std::iter::repeat(row as IdxSize).take(len))
+            .collect::<Vec<_>>();
+        // SAFETY: every row is in bounds of the key.
+        keys.equal(&unsafe { key.take_slice_unchecked(&rows) })?
+    };
+    let found = found.rechunk();
+    let found = found.downcast_iter().next().unwrap();
+
+    let out: IdxCa = offsets
+        .buffer()
+        .windows(2)
+        .enumerate()
+        .map(|(row, w)| {
+            if !arr.is_valid(row) {
+                return None;
+            }
+            (w[0] as usize..w[1] as usize)
+                .find(|&i| found.get(i) == Some(true))
+                .map(|i| i as IdxSize)
+        })
+        .collect();
+    Ok(out)
+}
+
+pub trait MapNameSpace: AsMap {
+    /// Get the keys of every map as a list.
+    fn map_keys(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_map();
+        ca.physical()
+            .apply_to_inner(&|s| Ok(s.struct_()?.fields_as_series()[0].clone()))
+    }
+
+    /// Get the values of every map as a list.
+    fn map_values(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_map();
+        ca.physical()
+            .apply_to_inner(&|s| Ok(s.struct_()?.fields_as_series()[1].clone()))
+    }
+
+    /// Get the value stored under `key` in every map, or null if the key is missing.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        let idx = map_find_key(ca, key)?;
+        let (_, _, values) = map_entries(ca)?;
+        let mut out = values.take(&idx)?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    /// Check whether every map contains `key`.
+    fn map_contains_key(&self, key: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_map();
+        let idx = map_find_key(ca, key)?;
+        let validity = ca.physical().is_not_null();
+        let out: BooleanChunked = idx
+            .iter()
+            .zip(validity.iter())
+            .map(|(idx, valid)| valid.unwrap_or(false).then_some(idx.is_some()))
+            .collect();
+        Ok(out.with_name(ca.name().clone()))
+    }
+
+    /// Create a struct with a field for every given key, holding the value stored under that
+    /// key.
+    fn map_to_struct(&self, fields: &[PlSmallStr]) -> PolarsResult<StructChunked> {
+        let ca = self.as_map();
+        let fields = fields
+            .iter()
+            .map(|name| {
+                let key = Series::new(name.clone(), [name.as_str()]);
+                let mut out = self.map_get(&key)?;
+                out.rename(name.clone());
+                Ok(out)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())
+    }
+}
+
+impl MapNameSpace for MapChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_map_get() -> PolarsResult<()> {
+        let keys = Series::new("key".into(), ["a", "b", "b", "a"]);
+        let values = Series::new("value".into(), [1i32, 2, 3, 4]);
+        let entries =
+            StructChunked::from_series("".into(), 4, [keys, values].iter())?.into_series();
+        let ca: ListChunked = [Some(entries.slice(0, 2)), Some(entries.slice(2, 1)), None]
+            .into_iter()
+            .collect();
+        let ca = ca.into_map()?;
+
+        let out = ca.map_get(&Series::new("".into(), ["a"]))?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), None, None]);
+
+        let key = Series::new("".into(), ["b", "b", "b"]);
+        let out = ca.map_contains_key(&key)?;
+        assert_eq!(Vec::from(&out), &[Some(true), Some(true), None]);
+
+        let out = ca.map_to_struct(&["a".into(), "b".into()])?;
+        let fields = out.fields_as_series();
+        assert_eq!(Vec::from(fields[1].i32()?), &[Some(2), Some(3), None]);
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@ mod binary;
 #[cfg(feature = "timezones")]
 pub mod datetime;
 pub mod list;
+#[cfg(feature = "dtype-map")]
+pub mod map;
 #[cfg(feature = "propagate_nans")]
 pub mod nan_propagating_aggregate;
 #[cfg(feature = "peaks")]
@@ -13,6 +15,8 @@ pub mod strings;
 mod sum;
 #[cfg(feature = "top_k")]
 mod top_k;
+#[cfg(feature = "dtype-union")]
+pub mod union;
 
 #[cfg(feature = "mode")]
 pub mod mode;
@@ -35,6 +39,8 @@ pub use gather::*;
 #[cfg(feature = "hist")]
 pub use hist::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[allow(unused_imports)]
 use polars_core::prelude::*;
 #[cfg(feature = "repeat_by")]
@@ -43,6 +49,8 @@ pub use scatter::ChunkedSet;
 pub use strings::*;
 #[cfg(feature = "top_k")]
 pub use top_k::*;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
 
 #[allow(unused_imports)]
 use crate::prelude::*;
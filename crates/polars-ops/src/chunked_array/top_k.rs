@@ -208,7 +208,7 @@ pub fn top_k(s: &[Column], descending: bool) -> PolarsResult<Column> {
         DataType::Decimal(_, _) => {
             let src = src.decimal().unwrap();
             let ca = top_k_num_impl(src, k, descending);
-            let mut lca = DecimalChunked::new_logical(ca);
+            let mut lca = DecimalChunked::new_logical::<DecimalType>(ca);
             lca.2 = Some(DataType::Decimal(src.precision(), Some(src.scale())));
             Ok(lca.into_column())
         },
@@ -0,0 +1,46 @@
+use super::*;
+
+pub trait UnionNameSpace: AsUnion {
+    /// Get the index of the variant every value belongs to. Null values have a null tag.
+    fn union_tag(&self) -> Int8Chunked {
+        self.as_union().tags()
+    }
+
+    /// Get the values of the variant at index `i`, or null where a value belongs to another
+    /// variant.
+    fn union_field(&self, i: usize) -> PolarsResult<Series> {
+        let ca = self.as_union();
+        let mut out = ca.variant(i)?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+}
+
+impl UnionNameSpace for UnionChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_union_field() -> PolarsResult<()> {
+        let tags = Int8Chunked::new("".into(), [Some(1), Some(0), None]);
+        let variants = [
+            Series::new("a".into(), [Some(1i64), Some(2), None]),
+            Series::new("b".into(), [Some(true), Some(false), Some(true)]),
+        ];
+        let ca =
+            UnionChunked::from_tags_and_variants("u".into(), &tags, &variants, UnionMode::Dense)?;
+
+        let out = ca.union_tag();
+        assert_eq!(Vec::from(&out), &[Some(1), Some(0), None]);
+
+        let out = ca.union_field(0)?;
+        assert_eq!(out.name().as_str(), "u");
+        assert_eq!(Vec::from(out.i64()?), &[None, Some(2), None]);
+        let out = ca.union_field(1)?;
+        assert_eq!(Vec::from(out.bool()?), &[Some(true), None, None]);
+        assert!(ca.union_field(2).is_err());
+        Ok(())
+    }
+}
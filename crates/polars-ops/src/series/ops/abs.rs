@@ -12,6 +12,11 @@ pub fn abs(s: &Series) -> PolarsResult<Series> {
         Int64 => s.i64().unwrap().wrapping_abs().into_series(),
         Float32 => s.f32().unwrap().wrapping_abs().into_series(),
         Float64 => s.f64().unwrap().wrapping_abs().into_series(),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => {
+            let ca = s.float16().unwrap();
+            ca.physical().wrapping_abs().into_float16().into_series()
+        },
         #[cfg(feature = "dtype-decimal")]
         Decimal(_, _) => {
             let ca = s.decimal().unwrap();
@@ -88,7 +88,7 @@ pub fn business_day_count(
 
 /// Ported from:
 /// https://github.com/numpy/numpy/blob/e59c074842e3f73483afa5ddef031e856b9fd313/numpy/_core/src/multiarray/datetime_busday.c#L355-L433
-fn business_day_count_impl(
+pub(super) fn business_day_count_impl(
     mut start_date: i32,
     mut end_date: i32,
     week_mask: &[bool; 7],
@@ -354,7 +354,7 @@ fn roll_start_date(
 }
 
 /// Sort and deduplicate holidays and remove holidays that are not business days.
-fn normalise_holidays(holidays: &[i32], week_mask: &[bool; 7]) -> Vec<i32> {
+pub(super) fn normalise_holidays(holidays: &[i32], week_mask: &[bool; 7]) -> Vec<i32> {
     let mut holidays: Vec<i32> = holidays.to_vec();
     holidays.sort_unstable();
     let mut previous_holiday: Option<i32> = None;
@@ -370,7 +370,7 @@ fn normalise_holidays(holidays: &[i32], week_mask: &[bool; 7]) -> Vec<i32> {
     holidays
 }
 
-fn get_day_of_week(x: i32) -> usize {
+pub(super) fn get_day_of_week(x: i32) -> usize {
     // the first modulo might return a negative number, so we add 7 and take
     // the modulo again so we're sure we have something between 0 (Monday)
     // and 6 (Sunday)
@@ -0,0 +1,621 @@
+use std::sync::{LazyLock, RwLock};
+
+use chrono::{Datelike, NaiveDate};
+#[cfg(feature = "timezones")]
+use polars_core::chunked_array::temporal::validate_time_zone;
+use polars_core::prelude::arity::binary_elementwise_values;
+use polars_core::prelude::*;
+use polars_core::utils::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::business::{business_day_count_impl, get_day_of_week, normalise_holidays};
+use super::{add_business_days, business_day_count, Roll};
+#[cfg(feature = "timezones")]
+use crate::prelude::replace_time_zone;
+
+const NANOSECONDS_IN_DAY: i64 = 86_400_000_000_000;
+
+static BUSINESS_CALENDARS: LazyLock<RwLock<PlHashMap<PlSmallStr, BusinessCalendar>>> =
+    LazyLock::new(Default::default);
+
+/// A rule that generates (at most) one holiday per year.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HolidayRule {
+    /// The same day every year, e.g. `Fixed { month: 12, day: 25 }` for Christmas Day.
+    /// Years in which the day doesn't exist (29 February) are skipped.
+    Fixed { month: u8, day: u8 },
+    /// The `n`-th `weekday` (0 is Monday) of `month`. A negative `n` counts from the end
+    /// of the month, e.g. `NthWeekdayOfMonth { month: 5, weekday: 0, n: -1 }` is the last
+    /// Monday of May. Years in which the month has no such day are skipped.
+    NthWeekdayOfMonth { month: u8, weekday: u8, n: i8 },
+}
+
+impl HolidayRule {
+    fn validate(&self) -> PolarsResult<()> {
+        match *self {
+            HolidayRule::Fixed { month, day } => {
+                // 2000 is a leap year, so 29 February is accepted.
+                polars_ensure!(
+                    NaiveDate::from_ymd_opt(2000, month as u32, day as u32).is_some(),
+                    ComputeError: "invalid holiday rule: month {} has no day {}", month, day
+                );
+            },
+            HolidayRule::NthWeekdayOfMonth { month, weekday, n } => {
+                polars_ensure!(
+                    (1..=12).contains(&month),
+                    ComputeError: "invalid holiday rule: `month` must be between 1 and 12, got {}", month
+                );
+                polars_ensure!(
+                    weekday < 7,
+                    ComputeError: "invalid holiday rule: `weekday` must be between 0 and 6, got {}", weekday
+                );
+                polars_ensure!(
+                    n != 0 && (-5..=5).contains(&n),
+                    ComputeError: "invalid holiday rule: `n` must be between -5 and 5 and non-zero, got {}", n
+                );
+            },
+        }
+        Ok(())
+    }
+
+    /// The holiday in `year`, as the number of days since the UNIX epoch.
+    fn date_in_year(&self, year: i32) -> Option<i32> {
+        let date = match *self {
+            HolidayRule::Fixed { month, day } => {
+                NaiveDate::from_ymd_opt(year, month as u32, day as u32)?
+            },
+            HolidayRule::NthWeekdayOfMonth { month, weekday, n } => {
+                let (month, weekday) = (month as u32, weekday as u32);
+                if n > 0 {
+                    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                    let offset = (weekday + 7 - first.weekday().num_days_from_monday()) % 7;
+                    NaiveDate::from_ymd_opt(year, month, 1 + offset + 7 * (n as u32 - 1))?
+                } else {
+                    let (next_year, next_month) = if month == 12 {
+                        (year + 1, 1)
+                    } else {
+                        (year, month + 1)
+                    };
+                    let last = NaiveDate::from_ymd_opt(next_year, next_month, 1)?.pred_opt()?;
+                    let offset = (last.weekday().num_days_from_monday() + 7 - weekday) % 7;
+                    let day = last.day().checked_sub(offset + 7 * (-n as u32 - 1))?;
+                    NaiveDate::from_ymd_opt(year, month, day)?
+                }
+            },
+        };
+        Some(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+    }
+}
+
+/// Daily business hours of a [`BusinessCalendar`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BusinessHours {
+    /// Opening time, in nanoseconds since midnight.
+    pub start: i64,
+    /// Closing time, in nanoseconds since midnight.
+    pub end: i64,
+    /// Time zone the business hours are expressed in. Time zone aware datetimes are
+    /// converted to this time zone before computing business durations.
+    pub time_zone: Option<TimeZone>,
+}
+
+/// A reusable set of business days, built from a week mask, explicit holidays and
+/// holiday rules, and optionally daily business hours.
+///
+/// Calendars can be registered under a name with [`register_business_calendar`] and
+/// looked up again with [`get_business_calendar`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BusinessCalendar {
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+    rules: Vec<HolidayRule>,
+    business_hours: Option<BusinessHours>,
+}
+
+impl Default for BusinessCalendar {
+    /// Monday to Friday, without holidays or business hours.
+    fn default() -> Self {
+        Self {
+            week_mask: [true, true, true, true, true, false, false],
+            holidays: vec![],
+            rules: vec![],
+            business_hours: None,
+        }
+    }
+}
+
+impl BusinessCalendar {
+    /// Create a calendar without holidays, where `week_mask` indicates which days of
+    /// the week (starting on Monday) are business days.
+    pub fn new(week_mask: [bool; 7]) -> PolarsResult<Self> {
+        if !week_mask.iter().any(|&x| x) {
+            polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+        }
+        Ok(Self {
+            week_mask,
+            ..Default::default()
+        })
+    }
+
+    /// Add holidays, given as the number of days since the UNIX epoch.
+    pub fn with_holidays(mut self, holidays: &[i32]) -> Self {
+        self.holidays.extend_from_slice(holidays);
+        self.holidays = normalise_holidays(&self.holidays, &self.week_mask);
+        self
+    }
+
+    /// Add rules that generate a holiday every year.
+    pub fn with_rules(
+        mut self,
+        rules: impl IntoIterator<Item = HolidayRule>,
+    ) -> PolarsResult<Self> {
+        for rule in rules {
+            rule.validate()?;
+            self.rules.push(rule);
+        }
+        Ok(self)
+    }
+
+    /// Set the daily business hours, in nanoseconds since midnight in `time_zone`.
+    pub fn with_business_hours(
+        mut self,
+        start: i64,
+        end: i64,
+        time_zone: Option<TimeZone>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            0 <= start && start < end && end <= NANOSECONDS_IN_DAY,
+            ComputeError: "business hours must start before they end, within a single day"
+        );
+        if let Some(time_zone) = &time_zone {
+            #[cfg(feature = "timezones")]
+            validate_time_zone(time_zone)?;
+            #[cfg(not(feature = "timezones"))]
+            polars_bail!(
+                ComputeError: "business hours in time zone {} require the `timezones` feature", time_zone
+            );
+        }
+        self.business_hours = Some(BusinessHours {
+            start,
+            end,
+            time_zone,
+        });
+        Ok(self)
+    }
+
+    pub fn week_mask(&self) -> [bool; 7] {
+        self.week_mask
+    }
+
+    pub fn rules(&self) -> &[HolidayRule] {
+        &self.rules
+    }
+
+    pub fn business_hours(&self) -> Option<&BusinessHours> {
+        self.business_hours.as_ref()
+    }
+
+    /// All holidays between `start` and `end` (inclusive), sorted and without
+    /// duplicates. Both explicit holidays and the dates generated by the holiday
+    /// rules are included.
+    pub fn holidays(&self, start: i32, end: i32) -> Vec<i32> {
+        let year = |date: i32| {
+            NaiveDate::from_num_days_from_ce_opt(date.saturating_add(EPOCH_DAYS_FROM_CE))
+                .unwrap_or(if date < 0 {
+                    NaiveDate::MIN
+                } else {
+                    NaiveDate::MAX
+                })
+                .year()
+        };
+        let mut holidays: Vec<i32> = self
+            .holidays
+            .iter()
+            .copied()
+            .filter(|date| (start..=end).contains(date))
+            .collect();
+        for year in year(start)..=year(end) {
+            holidays.extend(
+                self.rules
+                    .iter()
+                    .filter_map(|rule| rule.date_in_year(year))
+                    .filter(|date| (start..=end).contains(date)),
+            );
+        }
+        normalise_holidays(&holidays, &self.week_mask)
+    }
+
+    fn n_business_days_in_week_mask(&self) -> i32 {
+        self.week_mask.iter().filter(|&x| *x).count() as i32
+    }
+}
+
+/// Register `calendar` under `name`, replacing (and returning) any calendar that was
+/// previously registered under that name.
+pub fn register_business_calendar(
+    name: PlSmallStr,
+    calendar: BusinessCalendar,
+) -> Option<BusinessCalendar> {
+    BUSINESS_CALENDARS.write().unwrap().insert(name, calendar)
+}
+
+/// Get the calendar that was registered under `name`.
+pub fn get_business_calendar(name: &str) -> PolarsResult<BusinessCalendar> {
+    BUSINESS_CALENDARS
+        .read()
+        .unwrap()
+        .get(name)
+        .cloned()
+        .ok_or_else(|| polars_err!(ComputeError: "no business calendar registered as '{}'", name))
+}
+
+/// Remove (and return) the calendar that was registered under `name`.
+pub fn unregister_business_calendar(name: &str) -> Option<BusinessCalendar> {
+    BUSINESS_CALENDARS.write().unwrap().remove(name)
+}
+
+/// The names of all registered calendars, sorted.
+pub fn business_calendar_names() -> Vec<PlSmallStr> {
+    let mut names: Vec<_> = BUSINESS_CALENDARS.read().unwrap().keys().cloned().collect();
+    names.sort_unstable();
+    names
+}
+
+/// The smallest and largest date in `series`, widened by `padding` days.
+fn date_bounds(series: &[&Series], padding: i32) -> PolarsResult<Option<(i32, i32)>> {
+    let mut bounds: Option<(i32, i32)> = None;
+    for s in series {
+        let dates = s.cast(&DataType::Date)?;
+        let dates = dates.date()?.physical();
+        if let (Some(min), Some(max)) = (dates.min(), dates.max()) {
+            bounds = Some(match bounds {
+                Some((lower, upper)) => (lower.min(min), upper.max(max)),
+                None => (min, max),
+            });
+        }
+    }
+    Ok(bounds.map(|(lower, upper)| (lower.saturating_sub(padding), upper.saturating_add(padding))))
+}
+
+/// Count the number of business days of `calendar` between `start` and `end`, excluding `end`.
+///
+/// See [`business_day_count`].
+pub fn business_day_count_with_calendar(
+    start: &Series,
+    end: &Series,
+    calendar: &BusinessCalendar,
+) -> PolarsResult<Series> {
+    let holidays = match date_bounds(&[start, end], 0)? {
+        Some((lower, upper)) => calendar.holidays(lower, upper),
+        None => vec![],
+    };
+    business_day_count(start, end, calendar.week_mask, &holidays)
+}
+
+/// Add a given number of business days of `calendar`.
+///
+/// See [`add_business_days`].
+pub fn add_business_days_with_calendar(
+    start: &Series,
+    n: &Series,
+    calendar: &BusinessCalendar,
+    roll: Roll,
+) -> PolarsResult<Series> {
+    let n_abs_max = {
+        let n = n.cast(&DataType::Int64)?;
+        let n = n.i64()?;
+        n.min()
+            .unwrap_or(0)
+            .unsigned_abs()
+            .max(n.max().unwrap_or(0).unsigned_abs())
+    };
+    // Generate the holidays for every date we might land on. The extra year leaves
+    // room for the days skipped because of holidays (and for rolling).
+    let n_weeks = n_abs_max / calendar.n_business_days_in_week_mask() as u64 + 1;
+    let padding = i32::try_from(n_weeks * 7 + 366).unwrap_or(i32::MAX);
+    let holidays = match date_bounds(&[start], padding)? {
+        Some((lower, upper)) => calendar.holidays(lower, upper),
+        None => vec![],
+    };
+    add_business_days(start, n, calendar.week_mask, &holidays, roll)
+}
+
+/// Compute the business time between `start` and `end`.
+///
+/// Only time on business days of `calendar` counts and, if the calendar has business
+/// hours, only time within those hours. Time zone aware datetimes are converted to the
+/// time zone of the business hours first, whereas naive datetimes are taken to be in
+/// local time already. The result is negative if `end` is before `start`, and has the
+/// time unit of `start`.
+///
+/// Business hours are applied to wall-clock time, so a daylight saving time transition
+/// within business hours is not accounted for.
+pub fn business_duration(
+    start: &Series,
+    end: &Series,
+    calendar: &BusinessCalendar,
+) -> PolarsResult<Series> {
+    let time_unit = match start.dtype() {
+        DataType::Datetime(time_unit, _) => *time_unit,
+        dt => polars_bail!(InvalidOperation: "expected datetime, got {}", dt),
+    };
+    let time_zone = calendar
+        .business_hours
+        .as_ref()
+        .and_then(|hours| hours.time_zone.as_ref());
+    let name = start.name().clone();
+    let start = to_local_naive(start, time_unit, time_zone)?;
+    let end = to_local_naive(end, time_unit, time_zone)?;
+
+    let units_per_day = match time_unit {
+        TimeUnit::Nanoseconds => NANOSECONDS_IN_DAY,
+        TimeUnit::Microseconds => NANOSECONDS_IN_DAY / 1_000,
+        TimeUnit::Milliseconds => NANOSECONDS_IN_DAY / 1_000_000,
+    };
+    let (open, close) = match &calendar.business_hours {
+        Some(hours) => {
+            let nanoseconds_per_unit = NANOSECONDS_IN_DAY / units_per_day;
+            (
+                hours.start / nanoseconds_per_unit,
+                hours.end / nanoseconds_per_unit,
+            )
+        },
+        None => (0, units_per_day),
+    };
+
+    let day_bounds = [&start, &end]
+        .iter()
+        .filter_map(|ca| Some((ca.min()?, ca.max()?)))
+        .map(|(min, max)| (min.div_euclid(units_per_day), max.div_euclid(units_per_day)))
+        .reduce(|(a_min, a_max), (b_min, b_max)| (a_min.min(b_min), a_max.max(b_max)));
+    let holidays = match day_bounds {
+        Some((lower, upper)) => calendar.holidays(lower as i32, upper as i32),
+        None => vec![],
+    };
+    let week_mask = calendar.week_mask;
+    let n_business_days_in_week_mask = calendar.n_business_days_in_week_mask();
+
+    let business_time = |day: i32, from: i64, to: i64| -> i64 {
+        // SAFETY: week_mask is length 7, get_day_of_week result is between 0 and 6
+        let is_business_day = unsafe { *week_mask.get_unchecked(get_day_of_week(day)) }
+            && holidays.binary_search(&day).is_err();
+        if is_business_day {
+            (to.min(close) - from.max(open)).max(0)
+        } else {
+            0
+        }
+    };
+    let business_duration_impl = |start: i64, end: i64| -> i64 {
+        let (start, end, sign) = if start <= end {
+            (start, end, 1)
+        } else {
+            (end, start, -1)
+        };
+        let start_day = start.div_euclid(units_per_day) as i32;
+        let end_day = end.div_euclid(units_per_day) as i32;
+        let start_time = start.rem_euclid(units_per_day);
+        let end_time = end.rem_euclid(units_per_day);
+        let duration = if start_day == end_day {
+            business_time(start_day, start_time, end_time)
+        } else {
+            let n_full_days = business_day_count_impl(
+                start_day + 1,
+                end_day,
+                &week_mask,
+                n_business_days_in_week_mask,
+                &holidays,
+            );
+            business_time(start_day, start_time, units_per_day)
+                + n_full_days as i64 * (close - open)
+                + business_time(end_day, 0, end_time)
+        };
+        sign * duration
+    };
+
+    let out = match (start.len(), end.len()) {
+        (_, 1) => match end.get(0) {
+            Some(end) => start.apply_values(|start| business_duration_impl(start, end)),
+            None => Int64Chunked::full_null(name.clone(), start.len()),
+        },
+        (1, _) => match start.get(0) {
+            Some(start) => end.apply_values(|end| business_duration_impl(start, end)),
+            None => Int64Chunked::full_null(name.clone(), end.len()),
+        },
+        _ => binary_elementwise_values(&start, &end, business_duration_impl),
+    };
+    Ok(out.with_name(name).into_duration(time_unit).into_series())
+}
+
+/// The physical values of `s` in `time_unit`, as local (naive) datetimes in `time_zone`.
+#[cfg_attr(not(feature = "timezones"), allow(unused_variables))]
+fn to_local_naive(
+    s: &Series,
+    time_unit: TimeUnit,
+    time_zone: Option<&TimeZone>,
+) -> PolarsResult<Int64Chunked> {
+    match s.dtype() {
+        DataType::Datetime(_, None) => {
+            let s = s.cast(&DataType::Datetime(time_unit, None))?;
+            Ok(s.datetime()?.physical().clone())
+        },
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(own_time_zone)) => {
+            let time_zone = time_zone.unwrap_or(own_time_zone);
+            let s = s.cast(&DataType::Datetime(time_unit, Some(time_zone.clone())))?;
+            let local = replace_time_zone(
+                s.datetime()?,
+                None,
+                &StringChunked::from_iter(std::iter::once("raise")),
+                NonExistent::Raise,
+            )?;
+            Ok(local.physical().clone())
+        },
+        dt => polars_bail!(InvalidOperation: "expected datetime, got {}", dt),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> i32 {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .num_days_from_ce()
+            - EPOCH_DAYS_FROM_CE
+    }
+
+    fn datetime_ms(values: &[(i32, u32, u32, i64)], time_zone: Option<TimeZone>) -> Series {
+        let values: Vec<i64> = values
+            .iter()
+            .map(|&(year, month, day, hour)| {
+                date(year, month, day) as i64 * 86_400_000 + hour * 3_600_000
+            })
+            .collect();
+        Int64Chunked::from_vec(PlSmallStr::EMPTY, values)
+            .into_datetime(TimeUnit::Milliseconds, time_zone)
+            .into_series()
+    }
+
+    fn hours(s: &Series) -> Vec<Option<i64>> {
+        s.duration()
+            .unwrap()
+            .physical()
+            .into_iter()
+            .map(|v| v.map(|v| v / 3_600_000))
+            .collect()
+    }
+
+    const MEMORIAL_DAY: HolidayRule = HolidayRule::NthWeekdayOfMonth {
+        month: 5,
+        weekday: 0,
+        n: -1,
+    };
+
+    #[test]
+    fn test_holiday_rules() {
+        let thanksgiving = HolidayRule::NthWeekdayOfMonth {
+            month: 11,
+            weekday: 3,
+            n: 4,
+        };
+        assert_eq!(thanksgiving.date_in_year(2024), Some(date(2024, 11, 28)));
+        assert_eq!(MEMORIAL_DAY.date_in_year(2024), Some(date(2024, 5, 27)));
+        // there are only four Mondays in June 2024
+        let fifth_monday = HolidayRule::NthWeekdayOfMonth {
+            month: 6,
+            weekday: 0,
+            n: 5,
+        };
+        assert_eq!(fifth_monday.date_in_year(2024), None);
+        let leap_day = HolidayRule::Fixed { month: 2, day: 29 };
+        assert_eq!(leap_day.date_in_year(2024), Some(date(2024, 2, 29)));
+        assert_eq!(leap_day.date_in_year(2023), None);
+
+        assert!(HolidayRule::Fixed { month: 2, day: 30 }.validate().is_err());
+        assert!(HolidayRule::NthWeekdayOfMonth {
+            month: 1,
+            weekday: 0,
+            n: 0
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_calendar_holidays() -> PolarsResult<()> {
+        let calendar = BusinessCalendar::default()
+            // 2024-12-28 is a Saturday, so it isn't kept
+            .with_holidays(&[date(2024, 7, 4), date(2024, 12, 28)])
+            .with_rules([MEMORIAL_DAY, HolidayRule::Fixed { month: 12, day: 25 }])?;
+        assert_eq!(
+            calendar.holidays(date(2024, 1, 1), date(2025, 6, 1)),
+            &[
+                date(2024, 5, 27),
+                date(2024, 7, 4),
+                date(2024, 12, 25),
+                date(2025, 5, 26)
+            ]
+        );
+
+        let start = Int32Chunked::from_slice(PlSmallStr::EMPTY, &[date(2024, 5, 20)])
+            .into_date()
+            .into_series();
+        let end = Int32Chunked::from_slice(PlSmallStr::EMPTY, &[date(2024, 6, 3)])
+            .into_date()
+            .into_series();
+        let out = business_day_count_with_calendar(&start, &end, &calendar)?;
+        assert_eq!(out.i32()?.get(0), Some(9));
+
+        let n = Series::new(PlSmallStr::EMPTY, &[5]);
+        let out = add_business_days_with_calendar(&start, &n, &calendar, Roll::Raise)?;
+        assert_eq!(out.date()?.physical().get(0), Some(date(2024, 5, 28)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_duration() -> PolarsResult<()> {
+        let calendar = BusinessCalendar::default().with_business_hours(
+            9 * 3_600_000_000_000,
+            17 * 3_600_000_000_000,
+            None,
+        )?;
+        // Friday 16:00 to Tuesday 10:00, then the same day before opening time,
+        // then backwards from Wednesday 12:00 to Tuesday 12:00
+        let start = datetime_ms(
+            &[(2024, 5, 24, 16), (2024, 5, 24, 7), (2024, 5, 29, 12)],
+            None,
+        );
+        let end = datetime_ms(
+            &[(2024, 5, 28, 10), (2024, 5, 24, 8), (2024, 5, 28, 12)],
+            None,
+        );
+
+        let out = business_duration(&start, &end, &calendar)?;
+        assert_eq!(hours(&out), &[Some(10), Some(0), Some(-8)]);
+
+        let calendar = calendar.with_rules([MEMORIAL_DAY])?;
+        let out = business_duration(&start, &end, &calendar)?;
+        assert_eq!(hours(&out), &[Some(2), Some(0), Some(-8)]);
+
+        // without business hours, whole business days count
+        let calendar = BusinessCalendar::default();
+        let out = business_duration(&start, &end, &calendar)?;
+        assert_eq!(hours(&out), &[Some(42), Some(1), Some(-24)]);
+        Ok(())
+    }
+
+    #[cfg(feature = "timezones")]
+    #[test]
+    fn test_business_duration_time_zone() -> PolarsResult<()> {
+        let calendar = BusinessCalendar::default().with_business_hours(
+            9 * 3_600_000_000_000,
+            17 * 3_600_000_000_000,
+            Some("America/New_York".into()),
+        )?;
+        // 12:00 to 23:00 UTC is 08:00 to 19:00 in New York
+        let start = datetime_ms(&[(2024, 5, 24, 12)], Some("UTC".into()));
+        let end = datetime_ms(&[(2024, 5, 24, 23)], Some("UTC".into()));
+        let out = business_duration(&start, &end, &calendar)?;
+        assert_eq!(hours(&out), &[Some(8)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_calendar_registry() -> PolarsResult<()> {
+        let calendar = BusinessCalendar::new([true, true, true, true, false, false, true])?;
+        assert!(register_business_calendar("test_registry".into(), calendar.clone()).is_none());
+        assert_eq!(get_business_calendar("test_registry")?, calendar);
+        assert!(business_calendar_names().contains(&"test_registry".into()));
+        assert_eq!(
+            unregister_business_calendar("test_registry"),
+            Some(calendar)
+        );
+        assert!(get_business_calendar("test_registry").is_err());
+        Ok(())
+    }
+}
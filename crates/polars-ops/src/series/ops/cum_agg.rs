@@ -194,6 +194,34 @@ pub fn cum_prod(s: &Series, reverse: bool) -> PolarsResult<Series> {
     Ok(out)
 }
 
+/// The cumulative sum of a decimal, computed exactly on the physical values. The sums must fit
+/// the precision of the input.
+#[cfg(feature = "dtype-decimal")]
+fn cum_sum_decimal(ca: &DecimalChunked, reverse: bool) -> PolarsResult<DecimalChunked> {
+    let mut state = 0i128;
+    let step = |v: Option<i128>| -> PolarsResult<Option<i128>> {
+        match v {
+            Some(v) => {
+                state = state.checked_add(v).ok_or_else(
+                    || polars_err!(ComputeError: "decimal overflow in cumulative sum"),
+                )?;
+                Ok(Some(state))
+            },
+            None => Ok(None),
+        }
+    };
+    let out: Vec<_> = match reverse {
+        false => ca.iter().map(step).collect::<PolarsResult<_>>()?,
+        true => {
+            let mut out: Vec<_> = ca.iter().rev().map(step).collect::<PolarsResult<_>>()?;
+            out.reverse();
+            out
+        },
+    };
+    let out: Int128Chunked = out.into_iter().collect_ca(ca.name().clone());
+    out.into_decimal(ca.precision(), ca.scale())
+}
+
 /// Get an array with the cumulative sum computed at every element
 ///
 /// If the [`DataType`] is one of `{Int8, UInt8, Int16, UInt16}` the `Series` is
@@ -221,6 +249,8 @@ pub fn cum_sum(s: &Series, reverse: bool) -> PolarsResult<Series> {
             let ca = s.i64()?;
             cum_sum_numeric(ca, reverse).cast(&Duration(*tu))?
         },
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => cum_sum_decimal(s.decimal()?, reverse)?.into_series(),
         dt => polars_bail!(opq = cum_sum, dt),
     };
     Ok(out)
@@ -0,0 +1,105 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An arithmetic operation on decimals, see [`decimal_arithmetic`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DecimalArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl DecimalArithmeticOp {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+            Self::Div => "div",
+        }
+    }
+}
+
+/// The scale of a decimal operand; integers are decimals with a scale of zero.
+fn operand_scale(dtype: &DataType, op: DecimalArithmeticOp) -> PolarsResult<Option<usize>> {
+    match dtype {
+        DataType::Decimal(_, scale) => Ok(*scale),
+        dt if dt.is_integer() => Ok(Some(0)),
+        dt => polars_bail!(
+            InvalidOperation: "decimal {} expects decimal or integer operands; found {}",
+            op.name(), dt
+        ),
+    }
+}
+
+/// The data type of the result of [`decimal_arithmetic`]: adding and subtracting keep the
+/// largest of both scales, multiplying sums them, and dividing follows [`decimal_div_scale`].
+pub fn decimal_arithmetic_dtype(
+    lhs: &DataType,
+    rhs: &DataType,
+    op: DecimalArithmeticOp,
+) -> PolarsResult<DataType> {
+    polars_ensure!(
+        lhs.is_decimal() || rhs.is_decimal(),
+        InvalidOperation: "decimal {} expects at least one decimal operand; found {} and {}",
+        op.name(), lhs, rhs
+    );
+    let scales = operand_scale(lhs, op)?.zip(operand_scale(rhs, op)?);
+    let scale = scales.map(|(l, r)| match op {
+        DecimalArithmeticOp::Add | DecimalArithmeticOp::Sub => l.max(r),
+        DecimalArithmeticOp::Mul => l + r,
+        DecimalArithmeticOp::Div => decimal_div_scale(l),
+    });
+    Ok(DataType::Decimal(None, scale))
+}
+
+/// Add, subtract, multiply or divide two decimal Series (an integer operand is cast to a
+/// decimal with a scale of zero). If `strict`, a result that doesn't fit in a decimal raises
+/// an error, otherwise it becomes null.
+pub fn decimal_arithmetic(
+    lhs: &Series,
+    rhs: &Series,
+    op: DecimalArithmeticOp,
+    strict: bool,
+) -> PolarsResult<Series> {
+    decimal_arithmetic_dtype(lhs.dtype(), rhs.dtype(), op)?;
+    let as_decimal = |s: &Series| match s.dtype() {
+        DataType::Decimal(_, _) => Ok(s.clone()),
+        _ => s.cast(&DataType::Decimal(None, Some(0))),
+    };
+    let (lhs, rhs) = (as_decimal(lhs)?, as_decimal(rhs)?);
+    let (l, r) = (lhs.decimal()?, rhs.decimal()?);
+    let mut out = match op {
+        DecimalArithmeticOp::Add => l.checked_add(r, strict),
+        DecimalArithmeticOp::Sub => l.checked_sub(r, strict),
+        DecimalArithmeticOp::Mul => l.checked_mul(r, strict),
+        DecimalArithmeticOp::Div => l.checked_div(r, strict),
+    }?;
+    out.rename(lhs.name().clone());
+    Ok(out.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decimal_arithmetic_overflow() {
+        let big = 10i128.pow(37);
+        let lhs = Int128Chunked::from_slice("a".into(), &[big, 1])
+            .into_decimal_unchecked(None, 0)
+            .into_series();
+        let rhs = Series::new("b".into(), [100i64, 2]);
+
+        let out = decimal_arithmetic(&lhs, &rhs, DecimalArithmeticOp::Mul, false).unwrap();
+        assert_eq!(out.dtype(), &DataType::Decimal(None, Some(0)));
+        assert_eq!(Vec::from(&out.decimal().unwrap().0), &[None, Some(2)]);
+        assert!(decimal_arithmetic(&lhs, &rhs, DecimalArithmeticOp::Mul, true).is_err());
+
+        let rhs = Series::new("b".into(), [1.0f64, 2.0]);
+        assert!(decimal_arithmetic(&lhs, &rhs, DecimalArithmeticOp::Add, false).is_err());
+    }
+}
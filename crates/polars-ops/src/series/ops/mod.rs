@@ -4,11 +4,15 @@ mod arg_min_max;
 mod bitwise;
 #[cfg(feature = "business")]
 mod business;
+#[cfg(feature = "business")]
+mod business_calendar;
 mod clip;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
 #[cfg(feature = "cutqcut")]
 mod cut;
+#[cfg(feature = "dtype-decimal")]
+mod decimal_arithmetic;
 #[cfg(feature = "diff")]
 mod diff;
 #[cfg(feature = "ewma")]
@@ -67,11 +71,15 @@ pub use arg_min_max::ArgAgg;
 pub use bitwise::*;
 #[cfg(feature = "business")]
 pub use business::*;
+#[cfg(feature = "business")]
+pub use business_calendar::*;
 pub use clip::*;
 #[cfg(feature = "cum_agg")]
 pub use cum_agg::*;
 #[cfg(feature = "cutqcut")]
 pub use cut::*;
+#[cfg(feature = "dtype-decimal")]
+pub use decimal_arithmetic::*;
 #[cfg(feature = "diff")]
 pub use diff::*;
 #[cfg(feature = "ewma")]
@@ -35,6 +35,25 @@ pub trait RoundSeries: SeriesSealed {
                 Ok(s)
             };
         }
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = s.decimal() {
+            let scale = ca.scale() as u32;
+            if decimals >= scale {
+                return Ok(s.clone());
+            }
+            // Round half away from zero, like floats.
+            let multiplier = 10i128.pow(scale - decimals);
+            let out = ca.apply_values(|v| {
+                let rem = v % multiplier;
+                let v = v - rem;
+                if rem.abs() >= multiplier / 2 {
+                    v + rem.signum() * multiplier
+                } else {
+                    v
+                }
+            });
+            return Ok(out.into_decimal(ca.precision(), ca.scale())?.into_series());
+        }
 
         polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "round can only be used on numeric types" );
         Ok(s.clone())
@@ -70,6 +89,12 @@ pub trait RoundSeries: SeriesSealed {
             let s = ca.apply_values(|val| val.floor()).into_series();
             return Ok(s);
         }
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = s.decimal() {
+            let multiplier = 10i128.pow(ca.scale() as u32);
+            let out = ca.apply_values(|v| v.div_euclid(multiplier) * multiplier);
+            return Ok(out.into_decimal(ca.precision(), ca.scale())?.into_series());
+        }
 
         polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "floor can only be used on numeric types" );
         Ok(s.clone())
@@ -87,6 +112,12 @@ pub trait RoundSeries: SeriesSealed {
             let s = ca.apply_values(|val| val.ceil()).into_series();
             return Ok(s);
         }
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = s.decimal() {
+            let multiplier = 10i128.pow(ca.scale() as u32);
+            let out = ca.apply_values(|v| -(-v).div_euclid(multiplier) * multiplier);
+            return Ok(out.into_decimal(ca.precision(), ca.scale())?.into_series());
+        }
 
         polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "ceil can only be used on numeric types" );
         Ok(s.clone())
@@ -106,4 +137,40 @@ mod test {
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
     }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_round_decimal() {
+        let series = Int128Chunked::from_slice("a".into(), &[1250, -1250, 1249, 1999])
+            .into_decimal_unchecked(Some(5), 3)
+            .into_series();
+
+        let out = series.round(1).unwrap();
+        assert_eq!(out.dtype(), series.dtype());
+        let out = out.decimal().unwrap();
+        assert_eq!(
+            Vec::from(&out.0),
+            &[Some(1300), Some(-1300), Some(1200), Some(2000)]
+        );
+
+        let out = series.floor().unwrap();
+        let out = out.decimal().unwrap();
+        assert_eq!(
+            Vec::from(&out.0),
+            &[Some(1000), Some(-2000), Some(1000), Some(1000)]
+        );
+
+        let out = series.ceil().unwrap();
+        let out = out.decimal().unwrap();
+        assert_eq!(
+            Vec::from(&out.0),
+            &[Some(2000), Some(-1000), Some(2000), Some(2000)]
+        );
+
+        // Rounding up beyond the precision raises.
+        let series = Int128Chunked::from_slice("a".into(), &[99999])
+            .into_decimal_unchecked(Some(5), 3)
+            .into_series();
+        assert!(series.round(0).is_err());
+    }
 }
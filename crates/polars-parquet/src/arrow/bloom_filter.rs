@@ -0,0 +1,80 @@
+//! API to read, write and use bloom filters of arrow arrays
+use arrow::array::{Array, BinaryArray, BinaryViewArray, PrimitiveArray, Utf8Array, Utf8ViewArray};
+use arrow::datatypes::ArrowDataType;
+use arrow::types::NativeType;
+
+pub use crate::parquet::bloom_filter::*;
+use crate::parquet::schema::types::PhysicalType;
+
+fn hash_primitive<T, P>(array: &dyn Array, cast: impl Fn(T) -> P, f: &mut impl FnMut(u64))
+where
+    T: NativeType,
+    P: crate::parquet::types::NativeType,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .non_null_values_iter()
+        .for_each(|value| f(hash_native(cast(value))));
+}
+
+fn hash_bytes<'a>(values: impl Iterator<Item = &'a [u8]>, f: &mut impl FnMut(u64)) {
+    values.for_each(|value| f(hash_byte(value)));
+}
+
+/// Calls `f` with the bloom filter hash of every non-null value of `array`, for a column of the
+/// given parquet [`PhysicalType`].
+///
+/// The values are hashed the way they are encoded when written, so that the hashes match the
+/// bloom filters written by any implementation.
+///
+/// Returns `false` if bloom filters are not supported for arrays of this type, or if this type is
+/// not written with `physical_type`. Floating point types are not supported, as equal values can
+/// have different representations (e.g. `-0.0` and `0.0`).
+pub fn hash_array(array: &dyn Array, physical_type: PhysicalType, mut f: impl FnMut(u64)) -> bool {
+    use ArrowDataType as D;
+
+    let f = &mut f;
+    let any = array.as_any();
+    // The casts below MUST match the casts done when writing the pages.
+    match (array.dtype().to_logical_type(), physical_type) {
+        (D::Int8, PhysicalType::Int32) => hash_primitive(array, |x: i8| x as i32, f),
+        (D::Int16, PhysicalType::Int32) => hash_primitive(array, |x: i16| x as i32, f),
+        (D::Int32 | D::Date32 | D::Time32(_), PhysicalType::Int32) => {
+            hash_primitive(array, |x: i32| x, f)
+        },
+        (D::UInt8, PhysicalType::Int32) => hash_primitive(array, |x: u8| x as i32, f),
+        (D::UInt16, PhysicalType::Int32) => hash_primitive(array, |x: u16| x as i32, f),
+        (D::UInt32, PhysicalType::Int32) => hash_primitive(array, |x: u32| x as i32, f),
+        (
+            D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_),
+            PhysicalType::Int64,
+        ) => hash_primitive(array, |x: i64| x, f),
+        (D::UInt64, PhysicalType::Int64) => hash_primitive(array, |x: u64| x as i64, f),
+        (D::Utf8View, PhysicalType::ByteArray) => {
+            let array = any.downcast_ref::<Utf8ViewArray>().unwrap();
+            hash_bytes(array.non_null_values_iter().map(str::as_bytes), f)
+        },
+        (D::BinaryView, PhysicalType::ByteArray) => {
+            let array = any.downcast_ref::<BinaryViewArray>().unwrap();
+            hash_bytes(array.non_null_values_iter(), f)
+        },
+        (D::Utf8, PhysicalType::ByteArray) => {
+            let array = any.downcast_ref::<Utf8Array<i32>>().unwrap();
+            hash_bytes(array.non_null_values_iter().map(str::as_bytes), f)
+        },
+        (D::LargeUtf8, PhysicalType::ByteArray) => {
+            let array = any.downcast_ref::<Utf8Array<i64>>().unwrap();
+            hash_bytes(array.non_null_values_iter().map(str::as_bytes), f)
+        },
+        (D::Binary, PhysicalType::ByteArray) => {
+            let array = any.downcast_ref::<BinaryArray<i32>>().unwrap();
+            hash_bytes(array.non_null_values_iter(), f)
+        },
+        (D::LargeBinary, PhysicalType::ByteArray) => {
+            let array = any.downcast_ref::<BinaryArray<i64>>().unwrap();
+            hash_bytes(array.non_null_values_iter(), f)
+        },
+        _ => return false,
+    }
+    true
+}
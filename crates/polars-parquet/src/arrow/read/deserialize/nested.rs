@@ -1,5 +1,6 @@
 use arrow::array::{DictionaryArray, PrimitiveArray, StructArray};
 use arrow::match_integer_type;
+use arrow::types::f16;
 use ethnum::I256;
 use polars_error::polars_bail;
 
@@ -180,6 +181,32 @@ pub fn columns_to_iter_recursive(
             .collect_n(filter)
             .map(|(s, a)| (s, Box::new(a) as Box<_>))?
         },
+        Primitive(Float16) => {
+            init.push(InitNested::Primitive(field.is_nullable));
+            types.pop();
+            let (nested, array) = PageNestedDecoder::new(
+                columns.pop().unwrap(),
+                ArrowDataType::FixedSizeBinary(2),
+                fixed_size_binary::BinaryDecoder { size: 2 },
+                init,
+            )?
+            .collect_n(filter)?;
+
+            let values = array
+                .values()
+                .chunks_exact(2)
+                .map(|v| f16::from_bits(u16::from_le_bytes([v[0], v[1]])))
+                .collect::<Vec<_>>();
+            let validity = array.validity().cloned();
+
+            let array: Box<dyn Array> = Box::new(PrimitiveArray::<f16>::try_new(
+                field.dtype.clone(),
+                values.into(),
+                validity,
+            )?);
+
+            (nested, array)
+        },
         BinaryView | Utf8View => {
             init.push(InitNested::Primitive(field.is_nullable));
             types.pop();
@@ -1,7 +1,7 @@
 use arrow::array::{Array, DictionaryArray, DictionaryKey, FixedSizeBinaryArray, PrimitiveArray};
 use arrow::datatypes::{ArrowDataType, IntervalUnit, TimeUnit};
 use arrow::match_integer_type;
-use arrow::types::{days_ms, i256, NativeType};
+use arrow::types::{days_ms, i256, months_days_ns, NativeType};
 use ethnum::I256;
 use polars_error::{polars_bail, PolarsResult};
 
@@ -142,6 +142,30 @@ pub fn page_iter_to_array(
                 validity,
             )?)
         },
+        (PhysicalType::FixedLenByteArray(16), Interval(IntervalUnit::MonthDayNano)) => {
+            // @TODO: Make a separate decoder for this
+
+            let n = 16;
+            let array = PageDecoder::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
+            )?
+            .collect_n(filter)?;
+
+            let values = array
+                .values()
+                .chunks_exact(n)
+                .map(super::super::convert_months_days_ns)
+                .collect::<Vec<_>>();
+            let validity = array.validity().cloned();
+
+            Box::new(PrimitiveArray::<months_days_ns>::try_new(
+                dtype.clone(),
+                values.into(),
+                validity,
+            )?)
+        },
         (PhysicalType::Int32, Decimal(_, _)) => Box::new(PageDecoder::new(
             pages,
             dtype,
@@ -302,6 +326,25 @@ pub fn page_iter_to_array(
 
             Box::new(array)
         },
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
+            let mut fsb_array = PageDecoder::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(2),
+                fixed_size_binary::BinaryDecoder { size: 2 },
+            )?
+            .collect_n(filter)?;
+
+            let validity = fsb_array.take_validity();
+            let values = fsb_array
+                .values()
+                .chunks_exact(2)
+                .map(|v| arrow::types::f16::from_le_bytes([v[0], v[1]]))
+                .collect();
+
+            Box::new(PrimitiveArray::<arrow::types::f16>::new(
+                dtype, values, validity,
+            ))
+        },
 
         (PhysicalType::Float, Float32) => Box::new(PageDecoder::new(
             pages,
@@ -27,8 +27,8 @@ pub use crate::parquet::{
     metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata},
     page::{CompressedDataPage, DataPageHeader, Page},
     read::{
-        decompress, get_column_iterator, read_metadata as _read_metadata, BasicDecompressor,
-        MutStreamingIterator, PageReader, ReadColumnIterator, State,
+        decompress, get_column_iterator, indexes, read_metadata as _read_metadata,
+        BasicDecompressor, MutStreamingIterator, PageReader, ReadColumnIterator, State,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -73,6 +73,14 @@ fn convert_days_ms(value: &[u8]) -> arrow::types::days_ms {
     )
 }
 
+fn convert_months_days_ns(value: &[u8]) -> arrow::types::months_days_ns {
+    arrow::types::months_days_ns::new(
+        i32::from_le_bytes(value[0..4].try_into().unwrap()),
+        i32::from_le_bytes(value[4..8].try_into().unwrap()),
+        i64::from_le_bytes(value[8..16].try_into().unwrap()),
+    )
+}
+
 fn convert_i128(value: &[u8], n: usize) -> i128 {
     // Copy the fixed-size byte value to the start of a 16 byte stack
     // allocated buffer, then use an arithmetic right shift to fill in
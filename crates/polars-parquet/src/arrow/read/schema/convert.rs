@@ -170,12 +170,20 @@ fn from_fixed_len_byte_array(
     converted_type: Option<PrimitiveConvertedType>,
 ) -> ArrowDataType {
     match (logical_type, converted_type) {
+        // Decimals that don't fit in 128 bits are read as 256-bit decimals.
+        (Some(PrimitiveLogicalType::Decimal(precision, scale)), _)
+        | (None, Some(PrimitiveConvertedType::Decimal(precision, scale)))
+            if precision > 38 =>
+        {
+            ArrowDataType::Decimal256(precision, scale)
+        },
         (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) => {
             ArrowDataType::Decimal(precision, scale)
         },
         (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
             ArrowDataType::Decimal(precision, scale)
         },
+        (Some(PrimitiveLogicalType::Float16), _) if length == 2 => ArrowDataType::Float16,
         (None, Some(PrimitiveConvertedType::Interval)) => {
             // There is currently no reliable way of determining which IntervalUnit
             // to return. Thus without the original Arrow schema, the results
@@ -244,7 +252,7 @@ fn non_repeated_group(
     match (logical_type, converted_type) {
         (Some(GroupLogicalType::List), _) => to_list(fields, parent_name, options),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name, options),
-        (Some(GroupLogicalType::Map), _) => to_list(fields, parent_name, options),
+        (Some(GroupLogicalType::Map), _) => to_map(fields, options),
         (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
             to_map(fields, options)
         },
@@ -266,10 +274,17 @@ fn to_struct(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option
     }
 }
 
-/// Converts a parquet group type to an arrow [`ArrowDataType::Struct`].
+/// Converts a parquet group type to an arrow [`ArrowDataType::Map`].
 /// Returns [`None`] if all its fields are empty
 fn to_map(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option<ArrowDataType> {
-    let inner = to_field(&fields[0], options)?;
+    // The single child is the repeated `key_value` group holding the entries.
+    let ParquetType::GroupType {
+        field_info, fields, ..
+    } = &fields[0]
+    else {
+        return to_struct(fields, options);
+    };
+    let inner = Field::new(field_info.name.clone(), to_struct(fields, options)?, false);
     Some(ArrowDataType::Map(Box::new(inner), false))
 }
 
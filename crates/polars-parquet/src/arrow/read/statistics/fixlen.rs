@@ -1,9 +1,9 @@
 use arrow::array::*;
-use arrow::types::{days_ms, i256};
+use arrow::types::{days_ms, f16, i256, months_days_ns};
 use ethnum::I256;
 use polars_error::PolarsResult;
 
-use super::super::{convert_days_ms, convert_i128};
+use super::super::{convert_days_ms, convert_i128, convert_months_days_ns};
 use crate::arrow::read::convert_i256;
 use crate::parquet::statistics::FixedLenStatistics;
 
@@ -140,3 +140,44 @@ pub(super) fn push_days_ms(
 
     Ok(())
 }
+
+pub(super) fn push_months_days_ns(
+    from: Option<&FixedLenStatistics>,
+    min: &mut dyn MutableArray,
+    max: &mut dyn MutableArray,
+) -> PolarsResult<()> {
+    let min = min
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<months_days_ns>>()
+        .unwrap();
+    let max = max
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<months_days_ns>>()
+        .unwrap();
+
+    min.push(from.and_then(|s| s.min_value.as_deref().map(convert_months_days_ns)));
+    max.push(from.and_then(|s| s.max_value.as_deref().map(convert_months_days_ns)));
+
+    Ok(())
+}
+
+pub(super) fn push_f16(
+    from: Option<&FixedLenStatistics>,
+    min: &mut dyn MutableArray,
+    max: &mut dyn MutableArray,
+) -> PolarsResult<()> {
+    let min = min
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<f16>>()
+        .unwrap();
+    let max = max
+        .as_mut_any()
+        .downcast_mut::<MutablePrimitiveArray<f16>>()
+        .unwrap();
+
+    let convert = |v: &[u8]| f16::from_bits(u16::from_le_bytes([v[0], v[1]]));
+    min.push(from.and_then(|s| s.min_value.as_deref().map(convert)));
+    max.push(from.and_then(|s| s.max_value.as_deref().map(convert)));
+
+    Ok(())
+}
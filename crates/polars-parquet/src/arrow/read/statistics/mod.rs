@@ -7,7 +7,9 @@ use arrow::types::{f16, i256, NativeType};
 use arrow::with_match_primitive_type_full;
 use ethnum::I256;
 use polars_error::{polars_bail, PolarsResult};
+use polars_parquet_format::Statistics as ThriftStatistics;
 
+use crate::parquet::read::indexes::ColumnIndex;
 use crate::parquet::schema::types::{
     PhysicalType as ParquetPhysicalType, PrimitiveType as ParquetPrimitiveType,
 };
@@ -425,6 +427,9 @@ fn push(
         Interval(IntervalUnit::DayTime) => {
             fixlen::push_days_ms(rmap!(from, expect_as_fixedlen), min, max)
         },
+        Interval(IntervalUnit::MonthDayNano) => {
+            fixlen::push_months_days_ns(rmap!(from, expect_as_fixedlen), min, max)
+        },
         UInt8 => primitive::push(rmap!(from, expect_as_int32), min, max, |x: i32| Ok(x as u8)),
         UInt16 => primitive::push(
             rmap!(from, expect_as_int32),
@@ -502,6 +507,7 @@ fn push(
                 })
             }
         },
+        Float16 => fixlen::push_f16(rmap!(from, expect_as_fixedlen), min, max),
         Float32 => primitive::push::<f32, f32, _>(rmap!(from, expect_as_float), min, max, Ok),
         Float64 => primitive::push::<f64, f64, _>(rmap!(from, expect_as_double), min, max, Ok),
         Decimal(_, _) => match physical_type {
@@ -614,3 +620,77 @@ pub fn deserialize<'a>(
 
     Ok(statistics.into())
 }
+
+/// Deserializes the per-page statistics in the [`ColumnIndex`] of a single non-nested column
+/// chunk into [`Statistics`] with one entry per data page.
+///
+/// Pages that only contain nulls have no min or max value.
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8) or if
+/// `field` is nested.
+///
+/// [`ColumnIndex`]: crate::parquet::read::indexes::ColumnIndex
+pub fn deserialize_column_index(
+    field: &Field,
+    column: &ColumnChunkMetadata,
+    column_index: &ColumnIndex,
+) -> PolarsResult<Statistics> {
+    if matches!(
+        field.dtype.to_physical_type(),
+        PhysicalType::List
+            | PhysicalType::LargeList
+            | PhysicalType::FixedSizeList
+            | PhysicalType::Struct
+            | PhysicalType::Map
+    ) {
+        polars_bail!(ComputeError: "page statistics are only supported for non-nested columns")
+    }
+
+    let num_pages = column_index.null_pages.len();
+    if column_index.min_values.len() != num_pages
+        || column_index.max_values.len() != num_pages
+        || column_index
+            .null_counts
+            .as_ref()
+            .is_some_and(|c| c.len() != num_pages)
+    {
+        polars_bail!(ComputeError: "the column index of '{}' is out of spec", field.name)
+    }
+
+    let mut statistics = MutableStatistics::try_new(field)?;
+    let primitive_type = &column.descriptor().descriptor.primitive_type;
+
+    let mut stats = (0..num_pages)
+        .map(|i| {
+            let is_null_page = column_index.null_pages[i];
+            let thrift_stats = ThriftStatistics {
+                max: None,
+                min: None,
+                null_count: column_index.null_counts.as_ref().map(|c| c[i]),
+                distinct_count: None,
+                max_value: (!is_null_page).then(|| column_index.max_values[i].clone()),
+                min_value: (!is_null_page).then(|| column_index.min_values[i].clone()),
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            };
+            let stats = ParquetStatistics::deserialize(&thrift_stats, primitive_type.clone())?;
+            Ok((
+                Some(cast_statistics(stats, primitive_type, &field.dtype)),
+                primitive_type.clone(),
+            ))
+        })
+        .collect::<PolarsResult<VecDeque<(Option<ParquetStatistics>, ParquetPrimitiveType)>>>()?;
+
+    for _ in 0..num_pages {
+        push(
+            &mut stats,
+            statistics.min_value.as_mut(),
+            statistics.max_value.as_mut(),
+            statistics.distinct_count.as_mut(),
+            statistics.null_count.as_mut(),
+        )?;
+    }
+
+    Ok(statistics.into())
+}
@@ -200,6 +200,11 @@ pub(crate) fn encode_as_dictionary_optional(
     type_: PrimitiveType,
     options: WriteOptions,
 ) -> Option<PolarsResult<DynIter<'static, PolarsResult<Page>>>> {
+    // Intervals are written as fixed-size binary and are never dictionary encoded.
+    if matches!(array.dtype(), ArrowDataType::Interval(_)) {
+        return None;
+    }
+
     if array.is_empty() {
         let array = DictionaryArray::<u32>::new_empty(ArrowDataType::Dictionary(
             IntegerType::UInt32,
@@ -227,6 +232,8 @@ pub(crate) fn encode_as_dictionary_optional(
             PT::UInt16 => min_max_integer_encode_as_dictionary_optional::<_, u16>(array),
             PT::UInt32 => min_max_integer_encode_as_dictionary_optional::<_, u32>(array),
             PT::UInt64 => min_max_integer_encode_as_dictionary_optional::<_, u64>(array),
+            // 256-bit decimals cannot be dictionary encoded.
+            PT::Int256 => DictionaryDecision::NotWorth,
             _ => DictionaryDecision::TryAgain,
         },
         _ => DictionaryDecision::TryAgain,
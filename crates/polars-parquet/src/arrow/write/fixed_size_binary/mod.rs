@@ -2,7 +2,7 @@ mod basic;
 mod nested;
 
 use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
-use arrow::types::i256;
+use arrow::types::{f16, i256, NativeType};
 pub use basic::array_to_page;
 pub use nested::array_to_page as nested_array_to_page;
 
@@ -60,6 +60,35 @@ pub(super) fn build_statistics(
     }
 }
 
+/// Float16 statistics are ordered by value (ignoring NaNs), not by their bytes.
+pub(super) fn build_statistics_f16(
+    array: &PrimitiveArray<f16>,
+    primitive_type: PrimitiveType,
+    options: &StatisticsOptions,
+) -> FixedLenStatistics {
+    let values = || {
+        array
+            .iter()
+            .flatten()
+            .map(|x| x.to_f32())
+            .filter(|x| !x.is_nan())
+    };
+    let to_bytes = |x: f32| f16::from_f32(x).to_le_bytes().to_vec();
+    FixedLenStatistics {
+        primitive_type,
+        null_count: options.null_count.then_some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: options
+            .max_value
+            .then(|| values().max_by(f32::total_cmp).map(to_bytes))
+            .flatten(),
+        min_value: options
+            .min_value
+            .then(|| values().min_by(f32::total_cmp).map(to_bytes))
+            .flatten(),
+    }
+}
+
 pub(super) fn build_statistics_decimal(
     array: &PrimitiveArray<i128>,
     primitive_type: PrimitiveType,
@@ -29,7 +29,7 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{days_ms, i256, NativeType};
+use arrow::types::{days_ms, f16, i256, months_days_ns, NativeType};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -565,6 +565,62 @@ pub fn array_to_page_simple(
             };
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Float16 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_f16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let mut values = Vec::<u8>::with_capacity(2 * array.len());
+            array.values().iter().for_each(|x| {
+                values.extend_from_slice(&x.to_le_bytes());
+            });
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(2),
+                values.into(),
+                array.validity().cloned(),
+            );
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            let mut values = Vec::<u8>::with_capacity(16 * array.len());
+            array.values().iter().for_each(|x| {
+                values.extend_from_slice(&x.to_le_bytes());
+            });
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(16),
+                values.into(),
+                array.validity().cloned(),
+            );
+            // the byte order of the components is not a meaningful order, so only the
+            // null count is written
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics(
+                    &array,
+                    type_.clone(),
+                    &StatisticsOptions {
+                        min_value: false,
+                        max_value: false,
+                        ..options.statistics
+                    },
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -816,6 +872,31 @@ fn array_to_page_nested(
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<f32, f32>(array, options, type_, nested)
         },
+        Float16 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_f16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let mut values = Vec::<u8>::with_capacity(2 * array.len());
+            array.values().iter().for_each(|x| {
+                values.extend_from_slice(&x.to_le_bytes());
+            });
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(2),
+                values.into(),
+                array.validity().cloned(),
+            );
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
         Float64 => {
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<f64, f64>(array, options, type_, nested)
@@ -1,4 +1,4 @@
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, TimeUnit};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::engine::general_purpose;
 use base64::Engine as _;
@@ -137,6 +137,14 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
             None,
             None,
         )?),
+        ArrowDataType::Float16 => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(2),
+            repetition,
+            None,
+            Some(PrimitiveLogicalType::Float16),
+            None,
+        )?),
         ArrowDataType::Float64 => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::Double,
@@ -374,12 +382,22 @@ pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
                     name,
                     PhysicalType::FixedLenByteArray(32),
                     repetition,
-                    None,
-                    None,
+                    Some(PrimitiveConvertedType::Decimal(precision, scale)),
+                    logical_type,
                     None,
                 )?)
             }
         },
+        // The parquet INTERVAL type has millisecond precision, so month-day-nano intervals
+        // are stored as plain bytes and recovered from the arrow schema in the metadata.
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(16),
+            repetition,
+            None,
+            None,
+            None,
+        )?),
         ArrowDataType::Interval(_) => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::FixedLenByteArray(12),
@@ -1,11 +1,13 @@
-//! API to read and use bloom filters
+//! API to read, write and use bloom filters
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
+pub use read::{deserialize, read};
 pub use split_block::{insert, is_in_set};
+pub use write::{optimal_num_of_bytes, write};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +70,26 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn write_and_deserialize() {
+        assert_eq!(optimal_num_of_bytes(0, 0.01), 32);
+        assert_eq!(optimal_num_of_bytes(1_000, 0.01), 2048);
+        assert_eq!(optimal_num_of_bytes(usize::MAX, 0.01), 128 * 1024 * 1024);
+
+        let mut bitset = vec![0; optimal_num_of_bytes(100, 0.01)];
+        for a in 0..100i64 {
+            insert(&mut bitset, hash_native(a));
+        }
+
+        let mut buffer = vec![];
+        let length = write(&mut buffer, &bitset).unwrap();
+        assert_eq!(length as usize, buffer.len());
+
+        let deserialized = deserialize(&buffer).unwrap().unwrap();
+        assert_eq!(deserialized, bitset);
+        assert!((0..100i64).all(|a| is_in_set(deserialized, hash_native(a))));
+
+        assert!(deserialize(&buffer[..buffer.len() - 1]).is_err());
+    }
 }
@@ -6,7 +6,7 @@ use polars_parquet_format::{
     Uncompressed,
 };
 
-use crate::parquet::error::ParquetResult;
+use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnChunkMetadata;
 
 /// Reads the bloom filter associated to [`ColumnChunkMetadata`] into `bitset`.
@@ -49,3 +49,24 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Deserializes a bloom filter, as found at [`ColumnChunkMetadata::bloom_filter_byte_range`], and
+/// returns its bitset.
+/// Returns `None` if the algorithm or compression is not supported.
+/// # Error
+/// Errors if the filter can't be deserialized.
+pub fn deserialize(mut data: &[u8]) -> ParquetResult<Option<&[u8]>> {
+    let mut prot = TCompactInputProtocol::new(&mut data, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {})
+        || header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {})
+    {
+        return Ok(None);
+    }
+
+    let length: usize = header.num_bytes.try_into()?;
+    data.get(..length)
+        .map(Some)
+        .ok_or_else(|| ParquetError::oos("The bloom filter is larger than its byte range"))
+}
@@ -0,0 +1,44 @@
+use std::io::Write;
+
+use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::error::ParquetResult;
+
+/// The size of a single block of a split-block bloom filter.
+const MIN_NUM_BYTES: usize = 32;
+/// The maximum size of a bloom filter, as also used by parquet-mr.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the size in bytes of a split-block bloom filter that holds `ndv` distinct values with a
+/// false positive probability of at most `fpp`.
+///
+/// The size is a power of two between 32 bytes and 128 MiB.
+pub fn optimal_num_of_bytes(ndv: usize, fpp: f64) -> usize {
+    // See the "Sizing an SBBF" section of the parquet-format specification.
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+}
+
+/// Writes the split-block bloom filter `bitset`, preceded by its header, to `writer`.
+/// Returns the number of bytes written.
+pub fn write<W: Write>(writer: &mut W, bitset: &[u8]) -> ParquetResult<u64> {
+    let header = BloomFilterHeader::new(
+        bitset.len().try_into()?,
+        BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        BloomFilterHash::XXHASH(XxHash {}),
+        BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    );
+
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    let header_len = header.write_to_out_protocol(&mut protocol)? as u64;
+    writer.write_all(bitset)?;
+
+    Ok(header_len + bitset.len() as u64)
+}
@@ -172,6 +172,33 @@ impl ColumnChunkMetadata {
         column_metadata_byte_range(self.metadata())
     }
 
+    /// Returns the offset and length in bytes of the column index of this column chunk, if it
+    /// was written.
+    pub fn column_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.column_index_offset,
+            self.column_chunk.column_index_length,
+        )
+    }
+
+    /// Returns the offset and length in bytes of the offset index of this column chunk, if it
+    /// was written.
+    pub fn offset_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.offset_index_offset,
+            self.column_chunk.offset_index_length,
+        )
+    }
+
+    /// Returns the offset and length in bytes of the bloom filter of this column chunk, if it was
+    /// written along with its length.
+    pub fn bloom_filter_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.metadata().bloom_filter_offset,
+            self.metadata().bloom_filter_length,
+        )
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
@@ -214,3 +241,9 @@ pub(super) fn column_metadata_byte_range(
     let len = column_metadata.total_compressed_size as u64;
     offset..offset.checked_add(len).unwrap()
 }
+
+fn index_byte_range(offset: Option<i64>, length: Option<i32>) -> Option<core::ops::Range<u64>> {
+    let offset: u64 = offset?.try_into().ok()?;
+    let length: u64 = length?.try_into().ok()?;
+    Some(offset..offset.checked_add(length)?)
+}
@@ -0,0 +1,54 @@
+use std::ops::Range;
+
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+// Every list item is accounted for as a `usize`, while a boolean or a small integer is encoded in a
+// single byte.
+fn max_size(data: &[u8]) -> usize {
+    data.len() * size_of::<usize>() + 1024
+}
+
+/// Deserializes a thrift-encoded [`ColumnIndex`].
+pub fn deserialize_column_index(mut data: &[u8]) -> ParquetResult<ColumnIndex> {
+    let max_size = max_size(data);
+    let mut prot = TCompactInputProtocol::new(&mut data, max_size);
+    Ok(ColumnIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes a thrift-encoded [`OffsetIndex`].
+pub fn deserialize_offset_index(mut data: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = max_size(data);
+    let mut prot = TCompactInputProtocol::new(&mut data, max_size);
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Returns the range of rows covered by each of the data pages in `locations`, for a column chunk
+/// of `num_rows` rows.
+///
+/// # Error
+/// Errors if the locations are not sorted by row or exceed `num_rows`.
+pub fn compute_page_row_intervals(
+    locations: &[PageLocation],
+    num_rows: usize,
+) -> ParquetResult<Vec<Range<usize>>> {
+    let starts = locations
+        .iter()
+        .map(|location| usize::try_from(location.first_row_index))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut intervals = Vec::with_capacity(starts.len());
+    for (i, &start) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).copied().unwrap_or(num_rows);
+        if start > end || end > num_rows {
+            return Err(ParquetError::oos(
+                "The page locations of an offset index must be sorted and within the row group",
+            ));
+        }
+        intervals.push(start..end);
+    }
+
+    Ok(intervals)
+}
@@ -173,6 +173,38 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Writes a row group to the file, followed by the bloom filter bitsets of its columns.
+    ///
+    /// `bloom_filters` holds an optional bitset for each leaf column of the schema; columns
+    /// beyond its length get no bloom filter.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: &[Option<Vec<u8>>],
+    ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write(row_group)?;
+
+        let group = self.row_groups.last_mut().unwrap();
+        for (column, bitset) in group.columns.iter_mut().zip(bloom_filters) {
+            let Some(bitset) = bitset else {
+                continue;
+            };
+
+            let offset = self.offset;
+            self.offset += crate::parquet::bloom_filter::write(&mut self.writer, bitset)?;
+
+            let metadata = column.meta_data.as_mut().unwrap();
+            metadata.bloom_filter_offset = Some(offset as i64);
+            metadata.bloom_filter_length = Some((self.offset - offset) as i32);
+        }
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file and the
     /// underlying writer.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> ParquetResult<u64> {
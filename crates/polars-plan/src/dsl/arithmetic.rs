@@ -57,6 +57,18 @@ impl Expr {
         binary_expr(self, Operator::FloorDivide, rhs)
     }
 
+    /// Add, subtract, multiply or divide decimals. If `strict`, a result that doesn't fit in a
+    /// decimal raises an error, otherwise it becomes null.
+    #[cfg(feature = "dtype-decimal")]
+    pub fn decimal_arithmetic(self, rhs: Self, op: DecimalArithmeticOp, strict: bool) -> Self {
+        self.map_many_private(
+            FunctionExpr::DecimalArithmetic { op, strict },
+            &[rhs],
+            false,
+            None,
+        )
+    }
+
     /// Raise expression to the power `exponent`
     pub fn pow<E: Into<Expr>>(self, exponent: E) -> Self {
         self.map_many_private(
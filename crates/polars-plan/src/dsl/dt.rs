@@ -25,6 +25,35 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Add a given number of business days of `calendar`.
+    #[cfg(feature = "business")]
+    pub fn add_business_days_with_calendar(
+        self,
+        n: Expr,
+        calendar: BusinessCalendar,
+        roll: Roll,
+    ) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::CalendarAddBusinessDays { calendar, roll }),
+            &[n],
+            false,
+            None,
+        )
+    }
+
+    /// Compute the business time of `calendar` between these datetimes and `end`.
+    ///
+    /// See [`polars_ops::prelude::business_duration`].
+    #[cfg(feature = "business")]
+    pub fn business_duration(self, end: Expr, calendar: BusinessCalendar) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::Business(BusinessFunction::BusinessDuration { calendar }),
+            &[end],
+            false,
+            None,
+        )
+    }
+
     /// Convert from Date/Time/Datetime into String with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
     pub fn to_string(self, format: &str) -> Expr {
@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter};
 
 use polars_core::prelude::*;
-use polars_ops::prelude::Roll;
+use polars_ops::prelude::{BusinessCalendar, Roll};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -23,6 +23,15 @@ pub enum BusinessFunction {
         holidays: Vec<i32>,
         roll: Roll,
     },
+    #[cfg(feature = "business")]
+    CalendarBusinessDayCount { calendar: BusinessCalendar },
+    #[cfg(feature = "business")]
+    CalendarAddBusinessDays {
+        calendar: BusinessCalendar,
+        roll: Roll,
+    },
+    #[cfg(feature = "business")]
+    BusinessDuration { calendar: BusinessCalendar },
 }
 
 impl Display for BusinessFunction {
@@ -33,6 +42,12 @@ impl Display for BusinessFunction {
             &BusinessDayCount { .. } => "business_day_count",
             #[cfg(feature = "business")]
             &AddBusinessDay { .. } => "add_business_days",
+            #[cfg(feature = "business")]
+            &CalendarBusinessDayCount { .. } => "business_day_count",
+            #[cfg(feature = "business")]
+            &CalendarAddBusinessDays { .. } => "add_business_days",
+            #[cfg(feature = "business")]
+            &BusinessDuration { .. } => "business_duration",
         };
         write!(f, "{s}")
     }
@@ -56,6 +71,18 @@ impl From<BusinessFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             } => {
                 map_as_slice!(add_business_days, week_mask, &holidays, roll)
             },
+            #[cfg(feature = "business")]
+            CalendarBusinessDayCount { calendar } => {
+                map_as_slice!(business_day_count_with_calendar, &calendar)
+            },
+            #[cfg(feature = "business")]
+            CalendarAddBusinessDays { calendar, roll } => {
+                map_as_slice!(add_business_days_with_calendar, &calendar, roll)
+            },
+            #[cfg(feature = "business")]
+            BusinessDuration { calendar } => {
+                map_as_slice!(business_duration, &calendar)
+            },
         }
     }
 }
@@ -95,3 +122,47 @@ pub(super) fn add_business_days(
     )
     .map(Column::from)
 }
+
+#[cfg(feature = "business")]
+pub(super) fn business_day_count_with_calendar(
+    s: &[Column],
+    calendar: &BusinessCalendar,
+) -> PolarsResult<Column> {
+    let start = &s[0];
+    let end = &s[1];
+    polars_ops::prelude::business_day_count_with_calendar(
+        start.as_materialized_series(),
+        end.as_materialized_series(),
+        calendar,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn add_business_days_with_calendar(
+    s: &[Column],
+    calendar: &BusinessCalendar,
+    roll: Roll,
+) -> PolarsResult<Column> {
+    let start = &s[0];
+    let n = &s[1];
+    polars_ops::prelude::add_business_days_with_calendar(
+        start.as_materialized_series(),
+        n.as_materialized_series(),
+        calendar,
+        roll,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn business_duration(s: &[Column], calendar: &BusinessCalendar) -> PolarsResult<Column> {
+    let start = &s[0];
+    let end = &s[1];
+    polars_ops::prelude::business_duration(
+        start.as_materialized_series(),
+        end.as_materialized_series(),
+        calendar,
+    )
+    .map(Column::from)
+}
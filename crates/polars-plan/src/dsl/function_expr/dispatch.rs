@@ -33,6 +33,21 @@ pub(super) fn interpolate_by(s: &[Column]) -> PolarsResult<Column> {
     polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
 }
 
+#[cfg(feature = "dtype-decimal")]
+pub(super) fn decimal_arithmetic(
+    s: &[Column],
+    op: DecimalArithmeticOp,
+    strict: bool,
+) -> PolarsResult<Column> {
+    polars_ops::prelude::decimal_arithmetic(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        op,
+        strict,
+    )
+    .map(Column::from)
+}
+
 pub(super) fn to_physical(s: &Column) -> PolarsResult<Column> {
     Ok(s.to_physical_repr())
 }
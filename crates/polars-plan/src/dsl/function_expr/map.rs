@@ -0,0 +1,97 @@
+use polars_ops::chunked_array::map::*;
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MapFunction {
+    Get,
+    Keys,
+    Values,
+    ContainsKey,
+    ToStruct(Arc<[PlSmallStr]>),
+}
+
+impl MapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use MapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| map_dtype(dt).map(|(_, value)| value.clone())),
+            Keys => mapper.try_map_dtype(|dt| {
+                map_dtype(dt).map(|(key, _)| DataType::List(Box::new(key.clone())))
+            }),
+            Values => mapper.try_map_dtype(|dt| {
+                map_dtype(dt).map(|(_, value)| DataType::List(Box::new(value.clone())))
+            }),
+            ContainsKey => mapper.with_dtype(DataType::Boolean),
+            ToStruct(names) => mapper.try_map_dtype(|dt| {
+                let (_, value) = map_dtype(dt)?;
+                Ok(DataType::Struct(
+                    names
+                        .iter()
+                        .map(|name| Field::new(name.clone(), value.clone()))
+                        .collect(),
+                ))
+            }),
+        }
+    }
+}
+
+fn map_dtype(dt: &DataType) -> PolarsResult<(&DataType, &DataType)> {
+    match dt {
+        DataType::Map(key, value) => Ok((key, value)),
+        dt => polars_bail!(InvalidOperation: "expected Map type, got: {}", dt),
+    }
+}
+
+impl Display for MapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use MapFunction::*;
+        let name = match self {
+            Get => "get",
+            Keys => "keys",
+            Values => "values",
+            ContainsKey => "contains_key",
+            ToStruct(_) => "to_struct",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<MapFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: MapFunction) -> Self {
+        use MapFunction::*;
+        match func {
+            Get => map_as_slice!(get),
+            Keys => map!(keys),
+            Values => map!(values),
+            ContainsKey => map_as_slice!(contains_key),
+            ToStruct(names) => map!(to_struct, &names),
+        }
+    }
+}
+
+pub(super) fn get(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_get(key).map(Column::from)
+}
+
+pub(super) fn keys(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_keys().map(|ca| ca.into_column())
+}
+
+pub(super) fn values(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_values().map(|ca| ca.into_column())
+}
+
+pub(super) fn contains_key(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_contains_key(key).map(|ca| ca.into_column())
+}
+
+pub(super) fn to_struct(s: &Column, names: &[PlSmallStr]) -> PolarsResult<Column> {
+    s.map()?.map_to_struct(names).map(|ca| ca.into_column())
+}
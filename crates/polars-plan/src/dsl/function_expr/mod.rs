@@ -37,6 +37,8 @@ mod fused;
 mod list;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod nan;
 #[cfg(feature = "peaks")]
 mod peaks;
@@ -71,6 +73,8 @@ mod struct_;
 mod temporal;
 #[cfg(feature = "trigonometry")]
 pub mod trigonometry;
+#[cfg(feature = "dtype-union")]
+mod union;
 mod unique;
 
 use std::fmt::{Display, Formatter};
@@ -83,6 +87,8 @@ pub(crate) use correlation::CorrelationMethod;
 #[cfg(feature = "fused")]
 pub(crate) use fused::FusedOperator;
 pub(crate) use list::ListFunction;
+#[cfg(feature = "dtype-map")]
+pub(crate) use map::MapFunction;
 use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 #[cfg(feature = "random")]
@@ -114,6 +120,8 @@ pub use self::strings::StringFunction;
 pub use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub(super) use self::trigonometry::TrigonometricFunction;
+#[cfg(feature = "dtype-union")]
+pub use self::union::UnionFunction;
 use super::*;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -126,12 +134,16 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
     ListExpr(ListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(MapFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     #[cfg(feature = "dtype-struct")]
     StructExpr(StructFunction),
     #[cfg(feature = "temporal")]
     TemporalExpr(TemporalFunction),
+    #[cfg(feature = "dtype-union")]
+    UnionExpr(UnionFunction),
     #[cfg(feature = "bitwise")]
     Bitwise(BitwiseFunction),
 
@@ -278,6 +290,11 @@ pub enum FunctionExpr {
     LowerBound,
     #[cfg(feature = "fused")]
     Fused(fused::FusedOperator),
+    #[cfg(feature = "dtype-decimal")]
+    DecimalArithmetic {
+        op: DecimalArithmeticOp,
+        strict: bool,
+    },
     ConcatExpr(bool),
     #[cfg(feature = "cov")]
     Correlation {
@@ -378,12 +395,16 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
             StructExpr(f) => f.hash(state),
             #[cfg(feature = "temporal")]
             TemporalExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(f) => f.hash(state),
             #[cfg(feature = "bitwise")]
             Bitwise(f) => f.hash(state),
 
@@ -404,6 +425,11 @@ impl Hash for FunctionExpr {
             Trigonometry(f) => f.hash(state),
             #[cfg(feature = "fused")]
             Fused(f) => f.hash(state),
+            #[cfg(feature = "dtype-decimal")]
+            DecimalArithmetic { op, strict } => {
+                op.hash(state);
+                strict.hash(state);
+            },
             #[cfg(feature = "diff")]
             Diff(_, null_behavior) => null_behavior.hash(state),
             #[cfg(feature = "interpolate")]
@@ -607,12 +633,16 @@ impl Display for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
             StructExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "temporal")]
             TemporalExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "bitwise")]
             Bitwise(func) => return write!(f, "bitwise_{func}"),
 
@@ -731,6 +761,8 @@ impl Display for FunctionExpr {
             LowerBound => "lower_bound",
             #[cfg(feature = "fused")]
             Fused(fused) => return Display::fmt(fused, f),
+            #[cfg(feature = "dtype-decimal")]
+            DecimalArithmetic { op, .. } => return write!(f, "decimal_{}", op.name()),
             ConcatExpr(_) => "concat_expr",
             #[cfg(feature = "cov")]
             Correlation { method, .. } => return Display::fmt(method, f),
@@ -879,12 +911,16 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
             ListExpr(func) => func.into(),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.into(),
             #[cfg(feature = "strings")]
             StringExpr(func) => func.into(),
             #[cfg(feature = "dtype-struct")]
             StructExpr(func) => func.into(),
             #[cfg(feature = "temporal")]
             TemporalExpr(func) => func.into(),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => func.into(),
             #[cfg(feature = "bitwise")]
             Bitwise(func) => func.into(),
 
@@ -952,6 +988,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     Quantile(options) => map!(rolling::rolling_quantile, options.clone()),
                     Var(options) => map!(rolling::rolling_var, options.clone()),
                     Std(options) => map!(rolling::rolling_std, options.clone()),
+                    Product(options) => map!(rolling::rolling_product, options.clone()),
+                    Kurtosis(options) => map!(rolling::rolling_kurtosis, options.clone()),
+                    Rank(options) => map!(rolling::rolling_rank, options.clone()),
+                    NUnique(options) => map!(rolling::rolling_n_unique, options.clone()),
+                    ArgMin(options) => map!(rolling::rolling_arg_min, options.clone()),
+                    ArgMax(options) => map!(rolling::rolling_arg_max, options.clone()),
                     #[cfg(feature = "moment")]
                     Skew(window_size, bias) => map!(rolling::rolling_skew, window_size, bias),
                     #[cfg(feature = "cov")]
@@ -982,6 +1024,24 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     },
                     VarBy(options) => map_as_slice!(rolling_by::rolling_var_by, options.clone()),
                     StdBy(options) => map_as_slice!(rolling_by::rolling_std_by, options.clone()),
+                    ProductBy(options) => {
+                        map_as_slice!(rolling_by::rolling_product_by, options.clone())
+                    },
+                    KurtosisBy(options) => {
+                        map_as_slice!(rolling_by::rolling_kurtosis_by, options.clone())
+                    },
+                    RankBy(options) => {
+                        map_as_slice!(rolling_by::rolling_rank_by, options.clone())
+                    },
+                    NUniqueBy(options) => {
+                        map_as_slice!(rolling_by::rolling_n_unique_by, options.clone())
+                    },
+                    ArgMinBy(options) => {
+                        map_as_slice!(rolling_by::rolling_arg_min_by, options.clone())
+                    },
+                    ArgMaxBy(options) => {
+                        map_as_slice!(rolling_by::rolling_arg_max_by, options.clone())
+                    },
                 }
             },
             #[cfg(feature = "hist")]
@@ -1090,6 +1150,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             LowerBound => map!(bounds::lower_bound),
             #[cfg(feature = "fused")]
             Fused(op) => map_as_slice!(fused::fused, op),
+            #[cfg(feature = "dtype-decimal")]
+            DecimalArithmetic { op, strict } => {
+                map_as_slice!(dispatch::decimal_arithmetic, op, strict)
+            },
             ConcatExpr(rechunk) => map_as_slice!(concat::concat_expr, rechunk),
             #[cfg(feature = "cov")]
             Correlation { method, ddof } => map_as_slice!(correlation::corr, ddof, method),
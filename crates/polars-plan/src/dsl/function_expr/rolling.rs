@@ -18,6 +18,12 @@ pub enum RollingFunction {
     Quantile(RollingOptionsFixedWindow),
     Var(RollingOptionsFixedWindow),
     Std(RollingOptionsFixedWindow),
+    Product(RollingOptionsFixedWindow),
+    Kurtosis(RollingOptionsFixedWindow),
+    Rank(RollingOptionsFixedWindow),
+    NUnique(RollingOptionsFixedWindow),
+    ArgMin(RollingOptionsFixedWindow),
+    ArgMax(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
     Skew(usize, bool),
     #[cfg(feature = "cov")]
@@ -41,6 +47,12 @@ impl Display for RollingFunction {
             Quantile(_) => "rolling_quantile",
             Var(_) => "rolling_var",
             Std(_) => "rolling_std",
+            Product(_) => "rolling_product",
+            Kurtosis(_) => "rolling_kurtosis",
+            Rank(_) => "rolling_rank",
+            NUnique(_) => "rolling_n_unique",
+            ArgMin(_) => "rolling_arg_min",
+            ArgMax(_) => "rolling_arg_max",
             #[cfg(feature = "moment")]
             Skew(..) => "rolling_skew",
             #[cfg(feature = "cov")]
@@ -129,6 +141,63 @@ pub(super) fn rolling_std(s: &Column, options: RollingOptionsFixedWindow) -> Pol
         .map(Column::from)
 }
 
+pub(super) fn rolling_product(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_product(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_kurtosis(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_kurtosis(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_rank(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_rank(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_n_unique(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_n_unique(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_min(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_arg_min(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_max(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_arg_max(options)
+        .map(Column::from)
+}
+
 #[cfg(feature = "moment")]
 pub(super) fn rolling_skew(s: &Column, window_size: usize, bias: bool) -> PolarsResult<Column> {
     // @scalar-opt
@@ -12,6 +12,12 @@ pub enum RollingFunctionBy {
     QuantileBy(RollingOptionsDynamicWindow),
     VarBy(RollingOptionsDynamicWindow),
     StdBy(RollingOptionsDynamicWindow),
+    ProductBy(RollingOptionsDynamicWindow),
+    KurtosisBy(RollingOptionsDynamicWindow),
+    RankBy(RollingOptionsDynamicWindow),
+    NUniqueBy(RollingOptionsDynamicWindow),
+    ArgMinBy(RollingOptionsDynamicWindow),
+    ArgMaxBy(RollingOptionsDynamicWindow),
 }
 
 impl Display for RollingFunctionBy {
@@ -26,6 +32,12 @@ impl Display for RollingFunctionBy {
             QuantileBy(_) => "rolling_quantile_by",
             VarBy(_) => "rolling_var_by",
             StdBy(_) => "rolling_std_by",
+            ProductBy(_) => "rolling_product_by",
+            KurtosisBy(_) => "rolling_kurtosis_by",
+            RankBy(_) => "rolling_rank_by",
+            NUniqueBy(_) => "rolling_n_unique_by",
+            ArgMinBy(_) => "rolling_arg_min_by",
+            ArgMaxBy(_) => "rolling_arg_max_by",
         };
 
         write!(f, "{name}")
@@ -107,3 +119,63 @@ pub(super) fn rolling_std_by(
         .rolling_std_by(s[1].as_materialized_series(), options)
         .map(Column::from)
 }
+
+pub(super) fn rolling_product_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_product_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_kurtosis_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_kurtosis_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_rank_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_rank_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_n_unique_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_n_unique_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_min_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_arg_min_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_max_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_arg_max_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
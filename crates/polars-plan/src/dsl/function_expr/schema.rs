@@ -20,12 +20,16 @@ impl FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
             StructExpr(s) => s.get_field(mapper),
             #[cfg(feature = "temporal")]
             TemporalExpr(fun) => fun.get_field(mapper),
+        #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => func.get_field(mapper),
             #[cfg(feature = "bitwise")]
             Bitwise(fun) => fun.get_field(mapper),
 
@@ -35,6 +39,14 @@ impl FunctionExpr {
             Business(func) => match func {
                 BusinessFunction::BusinessDayCount { .. } => mapper.with_dtype(DataType::Int32),
                 BusinessFunction::AddBusinessDay { .. } => mapper.with_same_dtype(),
+                BusinessFunction::CalendarBusinessDayCount { .. } => {
+                    mapper.with_dtype(DataType::Int32)
+                },
+                BusinessFunction::CalendarAddBusinessDays { .. } => mapper.with_same_dtype(),
+                BusinessFunction::BusinessDuration { .. } => mapper.try_map_dtype(|dt| match dt {
+                    DataType::Datetime(tu, _) => Ok(DataType::Duration(*tu)),
+                    dt => polars_bail!(InvalidOperation: "expected datetime, got {}", dt),
+                }),
             },
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
@@ -65,7 +77,12 @@ impl FunctionExpr {
                 use RollingFunction::*;
                 match rolling_func {
                     Min(_) | Max(_) | Sum(_) => mapper.with_same_dtype(),
-                    Mean(_) | Quantile(_) | Var(_) | Std(_) => mapper.map_to_float_dtype(),
+                    Mean(_) | Quantile(_) | Var(_) | Std(_) | Kurtosis(_) => {
+                        mapper.map_to_float_dtype()
+                    },
+                    Product(_) => mapper.map_dtype(rolling_product_dtype),
+                    Rank(_) => mapper.with_dtype(DataType::Float64),
+                    NUnique(_) | ArgMin(_) | ArgMax(_) => mapper.with_dtype(IDX_DTYPE),
                     #[cfg(feature = "cov")]
                     CorrCov {..} => mapper.map_to_float_dtype(),
                     #[cfg(feature = "moment")]
@@ -77,7 +94,12 @@ impl FunctionExpr {
                 use RollingFunctionBy::*;
                 match rolling_func {
                     MinBy(_) | MaxBy(_) | SumBy(_) => mapper.with_same_dtype(),
-                    MeanBy(_) | QuantileBy(_) | VarBy(_) | StdBy(_) => mapper.map_to_float_dtype(),
+                    MeanBy(_) | QuantileBy(_) | VarBy(_) | StdBy(_) | KurtosisBy(_) => {
+                        mapper.map_to_float_dtype()
+                    },
+                    ProductBy(_) => mapper.map_dtype(rolling_product_dtype),
+                    RankBy(_) => mapper.with_dtype(DataType::Float64),
+                    NUniqueBy(_) | ArgMinBy(_) | ArgMaxBy(_) => mapper.with_dtype(IDX_DTYPE),
                 }
             },
             ShiftAndFill => mapper.with_same_dtype(),
@@ -221,6 +243,10 @@ impl FunctionExpr {
             UpperBound | LowerBound => mapper.with_same_dtype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
+            #[cfg(feature = "dtype-decimal")]
+            DecimalArithmetic { op, .. } => {
+                mapper.try_map_dtypes(|dts| decimal_arithmetic_dtype(dts[0], dts[1], *op))
+            },
             ConcatExpr(_) => mapper.map_to_supertype(),
             #[cfg(feature = "cov")]
             Correlation { .. } => mapper.map_to_float_dtype(),
@@ -623,3 +649,12 @@ pub(crate) fn args_to_supertype<D: AsRef<DataType>>(dtypes: &[D]) -> PolarsResul
 
     Ok(st)
 }
+
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn rolling_product_dtype(dtype: &DataType) -> DataType {
+    use DataType::*;
+    match dtype {
+        Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => Int64,
+        dt => dt.clone(),
+    }
+}
@@ -0,0 +1,60 @@
+use polars_ops::chunked_array::union::*;
+
+use super::*;
+use crate::map;
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnionFunction {
+    Tag,
+    Field(usize),
+}
+
+impl UnionFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use UnionFunction::*;
+        match self {
+            Tag => mapper.with_dtype(DataType::Int8),
+            Field(i) => mapper.try_map_dtype(|dt| match dt {
+                DataType::Union(variants, _) => variants
+                    .get(*i)
+                    .map(|fld| fld.dtype().clone())
+                    .ok_or_else(|| {
+                        polars_err!(
+                            OutOfBounds: "union variant index {} is out of bounds for a union with {} variants",
+                            i, variants.len()
+                        )
+                    }),
+                dt => polars_bail!(InvalidOperation: "expected Union type, got: {}", dt),
+            }),
+        }
+    }
+}
+
+impl Display for UnionFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use UnionFunction::*;
+        match self {
+            Tag => write!(f, "union.tag"),
+            Field(i) => write!(f, "union.field({i})"),
+        }
+    }
+}
+
+impl From<UnionFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: UnionFunction) -> Self {
+        use UnionFunction::*;
+        match func {
+            Tag => map!(tag),
+            Field(i) => map!(field, i),
+        }
+    }
+}
+
+pub(super) fn tag(s: &Column) -> PolarsResult<Column> {
+    Ok(s.union()?.union_tag().into_column())
+}
+
+pub(super) fn field(s: &Column, i: usize) -> PolarsResult<Column> {
+    s.union()?.union_field(i).map(Column::from)
+}
@@ -21,3 +21,37 @@ pub fn business_day_count(
         },
     }
 }
+
+/// Count the number of business days of `calendar` between `start` and `end`, excluding `end`.
+#[cfg(feature = "dtype-date")]
+pub fn business_day_count_with_calendar(
+    start: Expr,
+    end: Expr,
+    calendar: BusinessCalendar,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::CalendarBusinessDayCount { calendar }),
+        options: FunctionOptions {
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
+
+/// Compute the business time of `calendar` between the datetimes `start` and `end`.
+#[cfg(feature = "dtype-date")]
+pub fn business_duration(start: Expr, end: Expr, calendar: BusinessCalendar) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Business(BusinessFunction::BusinessDuration { calendar }),
+        options: FunctionOptions {
+            flags: FunctionFlags::default() | FunctionFlags::ALLOW_RENAME,
+            ..Default::default()
+        },
+    }
+}
@@ -0,0 +1,48 @@
+use polars_core::prelude::*;
+
+use crate::dsl::function_expr::MapFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Map`].
+pub struct MapNameSpace(pub Expr);
+
+impl MapNameSpace {
+    /// Get the value stored under `key` in every map. Missing keys result in a null.
+    pub fn get(self, key: Expr) -> Expr {
+        self.0
+            .map_many_private(FunctionExpr::MapExpr(MapFunction::Get), &[key], false, None)
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_private(FunctionExpr::MapExpr(MapFunction::Keys))
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::MapExpr(MapFunction::Values))
+    }
+
+    /// Check whether every map contains `key`.
+    pub fn contains_key(self, key: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::MapExpr(MapFunction::ContainsKey),
+            &[key],
+            false,
+            None,
+        )
+    }
+
+    /// Convert every map to a struct with a field per given key.
+    pub fn to_struct<I, S>(self, fields: I) -> Expr
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        self.0
+            .map_private(FunctionExpr::MapExpr(MapFunction::ToStruct(
+                fields.into_iter().map(|x| x.into()).collect(),
+            )))
+    }
+}
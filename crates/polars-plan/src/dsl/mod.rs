@@ -23,6 +23,8 @@ mod from;
 pub mod function_expr;
 pub mod functions;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 #[cfg(feature = "meta")]
 mod meta;
 mod name;
@@ -38,6 +40,8 @@ pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
 pub mod udf;
+#[cfg(feature = "dtype-union")]
+mod union;
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -51,6 +55,8 @@ pub use function_expr::schema::FieldsMapper;
 pub use function_expr::*;
 pub use functions::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
 pub use name::*;
@@ -68,6 +74,8 @@ pub use selector::Selector;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 pub use udf::UserDefinedFunction;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
 
 use crate::constants::MAP_LIST_NAME;
 pub use crate::plans::lit;
@@ -1382,6 +1390,54 @@ impl Expr {
         self.finish_rolling_by(by, options, RollingFunctionBy::StdBy)
     }
 
+    /// Apply a rolling kurtosis based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_kurtosis_by(
+        self,
+        by: Expr,
+        fisher: bool,
+        bias: bool,
+        mut options: RollingOptionsDynamicWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Kurtosis(RollingKurtosisParams {
+            fisher,
+            bias,
+        }));
+
+        self.finish_rolling_by(by, options, RollingFunctionBy::KurtosisBy)
+    }
+
+    /// Apply a rolling product based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_product_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ProductBy)
+    }
+
+    /// Apply a rolling rank based on another column. The rank of each value within
+    /// its window is expressed as a fraction of the number of values in the window.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_rank_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::RankBy)
+    }
+
+    /// Count the unique values in a rolling window based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_n_unique_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::NUniqueBy)
+    }
+
+    /// Get the index of the minimum within a rolling window based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_arg_min_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ArgMinBy)
+    }
+
+    /// Get the index of the maximum within a rolling window based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_arg_max_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ArgMaxBy)
+    }
+
     /// Apply a rolling median based on another column.
     #[cfg(feature = "rolling_window_by")]
     pub fn rolling_median_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
@@ -1458,6 +1514,56 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Std)
     }
 
+    /// Apply a rolling kurtosis.
+    ///
+    /// With `fisher`, the excess kurtosis (normal ==> 0.0) is returned. Without `bias`,
+    /// the result is corrected for statistical bias.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_kurtosis(
+        self,
+        fisher: bool,
+        bias: bool,
+        mut options: RollingOptionsFixedWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Kurtosis(RollingKurtosisParams {
+            fisher,
+            bias,
+        }));
+
+        self.finish_rolling(options, RollingFunction::Kurtosis)
+    }
+
+    /// Apply a rolling product.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_product(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::Product)
+    }
+
+    /// Apply a rolling rank. The rank of each value within its window is expressed
+    /// as a fraction of the number of values in the window.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_rank(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::Rank)
+    }
+
+    /// Count the unique values in a rolling window.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_n_unique(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::NUnique)
+    }
+
+    /// Get the index of the minimum within a rolling window.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_arg_min(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::ArgMin)
+    }
+
+    /// Get the index of the maximum within a rolling window.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_arg_max(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::ArgMax)
+    }
+
     /// Apply a rolling skew.
     #[cfg(feature = "rolling_window")]
     #[cfg(feature = "moment")]
@@ -1952,6 +2058,18 @@ impl Expr {
         cat::CategoricalNameSpace(self)
     }
 
+    /// Get the [`map::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map::MapNameSpace {
+        map::MapNameSpace(self)
+    }
+
+    /// Get the [`union::UnionNameSpace`].
+    #[cfg(feature = "dtype-union")]
+    pub fn union(self) -> union::UnionNameSpace {
+        union::UnionNameSpace(self)
+    }
+
     /// Get the [`struct_::StructNameSpace`].
     #[cfg(feature = "dtype-struct")]
     pub fn struct_(self) -> struct_::StructNameSpace {
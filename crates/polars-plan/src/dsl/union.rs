@@ -0,0 +1,19 @@
+use crate::dsl::function_expr::UnionFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Union`].
+pub struct UnionNameSpace(pub Expr);
+
+impl UnionNameSpace {
+    /// Get the index of the variant every value belongs to.
+    pub fn tag(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::UnionExpr(UnionFunction::Tag))
+    }
+
+    /// Get the values of the variant at index `i`. Values of other variants result in a null.
+    pub fn field(self, i: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::UnionExpr(UnionFunction::Field(i)))
+    }
+}
@@ -5,8 +5,13 @@ use super::*;
 fn float_type(field: &mut Field) {
     let should_coerce = match &field.dtype {
         DataType::Float32 => false,
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            field.coerce(DataType::Float32);
+            return;
+        },
         #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(..) => true,
+        DataType::Decimal(..) | DataType::Decimal256(..) => true,
         DataType::Boolean => true,
         dt => dt.is_numeric(),
     };
@@ -15,6 +20,17 @@ fn float_type(field: &mut Field) {
     }
 }
 
+/// The output type of `mean`, `std` and `var`, these are exact for decimals.
+fn moment_type(field: &mut Field) {
+    match field.dtype {
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, scale) => {
+            field.coerce(DataType::Decimal(None, scale.map(decimal_div_scale)))
+        },
+        _ => float_type(field),
+    }
+}
+
 impl AExpr {
     pub fn to_dtype(
         &self,
@@ -74,10 +90,14 @@ impl AExpr {
                 let field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
                 *nested = nested.saturating_sub(1);
 
-                if let List(inner) = field.dtype() {
-                    Ok(Field::new(field.name().clone(), *inner.clone()))
-                } else {
-                    Ok(field)
+                match field.dtype() {
+                    List(inner) => Ok(Field::new(field.name().clone(), *inner.clone())),
+                    #[cfg(feature = "dtype-map")]
+                    Map(key, value) => Ok(Field::new(
+                        field.name().clone(),
+                        map_entries_dtype(key, value),
+                    )),
+                    _ => Ok(field),
                 }
             },
             Alias(expr, name) => Ok(Field::new(
@@ -152,6 +172,8 @@ impl AExpr {
                         let dt = match field.dtype() {
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-f16")]
+                            Float16 => Some(Float32),
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -173,7 +195,7 @@ impl AExpr {
                         let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Milliseconds, None)),
-                            _ => float_type(&mut field),
+                            _ => moment_type(&mut field),
                         }
                         Ok(field)
                     },
@@ -185,13 +207,13 @@ impl AExpr {
                     Std(expr, _) => {
                         *nested = nested.saturating_sub(1);
                         let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
-                        float_type(&mut field);
+                        moment_type(&mut field);
                         Ok(field)
                     },
                     Var(expr, _) => {
                         *nested = nested.saturating_sub(1);
                         let mut field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
-                        float_type(&mut field);
+                        moment_type(&mut field);
                         Ok(field)
                     },
                     NUnique(expr) => {
@@ -229,7 +251,8 @@ impl AExpr {
             },
             Cast { expr, dtype, .. } => {
                 let field = arena.get(*expr).to_field_impl(schema, arena, nested)?;
-                Ok(Field::new(field.name().clone(), dtype.clone()))
+                let dtype = dtype.promote_decimal().unwrap_or_else(|| dtype.clone());
+                Ok(Field::new(field.name().clone(), dtype))
             },
             Ternary { truthy, falsy, .. } => {
                 let mut nested_truthy = *nested;
@@ -347,6 +370,10 @@ fn get_arithmetic_field(
                 (Struct(_), Struct(_)) => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date | Interval, Interval) => {
+                    return Ok(left_field);
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -398,6 +425,12 @@ fn get_arithmetic_field(
         Operator::Plus => {
             let right_type = right_ae.to_field_impl(schema, arena, nested)?.dtype;
             match (&left_field.dtype, &right_type) {
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date | Interval, Interval) => {
+                    return Ok(left_field);
+                },
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Datetime(_, _) | Date) => right_type,
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
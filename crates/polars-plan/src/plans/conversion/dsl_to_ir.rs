@@ -752,6 +752,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                             |dt| {
                                 dt.is_numeric()
                                     || dt.is_decimal()
+                                    || dt.is_decimal256()
                                     || matches!(dt, DataType::Boolean | DataType::Duration(_))
                             },
                             |name| col(name.clone()).sum(),
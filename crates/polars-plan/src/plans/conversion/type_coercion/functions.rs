@@ -68,6 +68,14 @@ fn check_namespace(function: &FunctionExpr, first_dtype: &DataType) -> PolarsRes
         FunctionExpr::ArrayExpr(_) => {
             polars_ensure!(matches!(first_dtype, DataType::Array(_, _)), InvalidOperation: "expected Array type, got: {}", first_dtype)
         },
+        #[cfg(feature = "dtype-map")]
+        FunctionExpr::MapExpr(_) => {
+            polars_ensure!(matches!(first_dtype, DataType::Map(_, _)), InvalidOperation: "expected Map type, got: {}", first_dtype)
+        },
+        #[cfg(feature = "dtype-union")]
+        FunctionExpr::UnionExpr(_) => {
+            polars_ensure!(matches!(first_dtype, DataType::Union(_, _)), InvalidOperation: "expected Union type, got: {}", first_dtype)
+        },
         #[cfg(feature = "dtype-struct")]
         FunctionExpr::StructExpr(_) => {
             polars_ensure!(matches!(first_dtype, DataType::Struct(_)), InvalidOperation: "expected Struct type, got: {}", first_dtype)
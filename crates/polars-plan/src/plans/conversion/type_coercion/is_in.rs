@@ -35,13 +35,16 @@ pub(super) fn resolve_is_in(
         #[cfg(feature = "dtype-categorical")]
         (DataType::String, DataType::Categorical(_, _) | DataType::Enum(_, _)) => return Ok(None),
         #[cfg(feature = "dtype-decimal")]
-        (DataType::Decimal(_, _), dt) if dt.is_numeric() => AExpr::Cast {
-            expr: other_e.node(),
-            dtype: type_left,
-            options: CastOptions::NonStrict,
+        (DataType::Decimal(_, _) | DataType::Decimal256(_, _), dt) if dt.is_numeric() => {
+            AExpr::Cast {
+                expr: other_e.node(),
+                dtype: type_left,
+                options: CastOptions::NonStrict,
+            }
         },
         #[cfg(feature = "dtype-decimal")]
-        (DataType::Decimal(_, _), _) | (_, DataType::Decimal(_, _)) => {
+        (DataType::Decimal(_, _) | DataType::Decimal256(_, _), _)
+        | (_, DataType::Decimal(_, _) | DataType::Decimal256(_, _)) => {
             polars_bail!(InvalidOperation: "'is_in' cannot check for {:?} values in {:?} data", &type_other, &type_left)
         },
         // can't check for more granular time_unit in less-granular time_unit data,
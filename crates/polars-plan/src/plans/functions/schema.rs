@@ -132,9 +132,17 @@ fn explode_schema<'a>(
 
     // columns to string
     columns.iter().try_for_each(|name| {
-        if let DataType::List(inner) = schema.try_get(name)? {
-            let inner = *inner.clone();
-            schema.with_column(name.clone(), inner);
+        match schema.try_get(name)? {
+            DataType::List(inner) => {
+                let inner = *inner.clone();
+                schema.with_column(name.clone(), inner);
+            },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = map_entries_dtype(key, value);
+                schema.with_column(name.clone(), entries);
+            },
+            _ => {},
         };
         PolarsResult::Ok(())
     })?;
@@ -4,7 +4,7 @@ use std::borrow::Cow;
 use polars::chunked_array::object::PolarsObjectSafe;
 #[cfg(feature = "object")]
 use polars::datatypes::OwnedObject;
-use polars::datatypes::{DataType, Field, PlHashMap, TimeUnit};
+use polars::datatypes::{months_days_ns, DataType, Field, PlHashMap, TimeUnit};
 use polars::prelude::{AnyValue, PlSmallStr, Series, TimeZone};
 use polars_core::export::chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
 use polars_core::utils::any_values_to_supertype_and_n_dtypes;
@@ -17,7 +17,7 @@ use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PySequence, P
 use super::datetime::{
     elapsed_offset_to_timedelta, nanos_since_midnight_to_naivetime, timestamp_to_naive_datetime,
 };
-use super::{decimal_to_digits, struct_dict, ObjectValue, Wrap};
+use super::{decimal256_to_digits, decimal_to_digits, struct_dict, ObjectValue, Wrap};
 use crate::error::PyPolarsErr;
 use crate::py_modules::{SERIES, UTILS};
 use crate::series::PySeries;
@@ -51,6 +51,7 @@ pub(crate) fn any_value_into_py_object(av: AnyValue, py: Python) -> PyObject {
         AnyValue::Int16(v) => v.into_py(py),
         AnyValue::Int32(v) => v.into_py(py),
         AnyValue::Int64(v) => v.into_py(py),
+        AnyValue::Float16(v) => v.to_f32().into_py(py),
         AnyValue::Float32(v) => v.into_py(py),
         AnyValue::Float64(v) => v.into_py(py),
         AnyValue::Null => py.None(),
@@ -92,6 +93,7 @@ pub(crate) fn any_value_into_py_object(av: AnyValue, py: Python) -> PyObject {
             time_delta.into_py(py)
         },
         AnyValue::Time(v) => nanos_since_midnight_to_naivetime(v).into_py(py),
+        AnyValue::Interval(v) => interval_to_py_tuple(v).into_py(py),
         AnyValue::Array(v, _) | AnyValue::List(v) => PySeries::new(v).to_list(),
         ref av @ AnyValue::Struct(_, _, flds) => struct_dict(py, av._iter_struct_av(), flds),
         AnyValue::StructOwned(payload) => struct_dict(py, payload.0.into_iter(), &payload.1),
@@ -124,9 +126,25 @@ pub(crate) fn any_value_into_py_object(av: AnyValue, py: Python) -> PyObject {
                 .unwrap()
                 .into_py(py)
         },
+        AnyValue::Decimal256(v, scale) => {
+            let convert = utils.getattr(intern!(py, "to_py_decimal")).unwrap();
+            let digits = decimal256_to_digits(v);
+            let n_digits = digits.len();
+            let digits = PyTuple::new_bound(py, digits);
+            convert
+                .call1((v.0.is_negative() as u8, digits, n_digits, -(scale as i32)))
+                .unwrap()
+                .into_py(py)
+        },
     }
 }
 
+/// Intervals have no Python equivalent, so they are represented as a
+/// `(months, days, nanoseconds)` tuple.
+pub(crate) fn interval_to_py_tuple(v: months_days_ns) -> (i32, i32, i64) {
+    (v.months(), v.days(), v.ns())
+}
+
 fn datetime_to_py_object(
     py: Python,
     utils: &Bound<PyAny>,
@@ -282,21 +300,46 @@ pub(crate) fn py_object_to_any_value<'py>(
             (v <= MAX_ABS_DEC).then_some((v, scale))
         }
 
+        fn abs_decimal256_from_digits(digits: &[u8], exp: i32) -> Option<(i256, usize)> {
+            let mut value = digits
+                .iter()
+                .skip_while(|d| **d == 0)
+                .map(|d| char::from(b'0' + d))
+                .collect::<String>();
+            let scale = if exp > 0 {
+                value.extend(std::iter::repeat('0').take(exp as usize));
+                0
+            } else {
+                (-exp) as usize
+            };
+            if value.is_empty() {
+                value.push('0');
+            }
+            (value.len() <= DECIMAL256_MAX_PRECISION)
+                .then(|| value.parse().ok().map(|v| (i256(v), scale)))
+                .flatten()
+        }
+
         // Note: Using Vec<u8> is not the most efficient thing here (input is a tuple)
         let (sign, digits, exp): (i8, Vec<u8>, i32) = ob
             .call_method0(intern!(ob.py(), "as_tuple"))
             .unwrap()
             .extract()
             .unwrap();
-        let (mut v, scale) = abs_decimal_from_digits(digits, exp).ok_or_else(|| {
+        if let Some((mut v, scale)) = abs_decimal_from_digits(digits.iter().copied(), exp) {
+            if sign > 0 {
+                v = -v; // Won't overflow since -i128::MAX > i128::MIN
+            }
+            return Ok(AnyValue::Decimal(v, scale));
+        }
+        // Decimals that don't fit in 128 bits fall back to `Decimal256`.
+        let (v, scale) = abs_decimal256_from_digits(&digits, exp).ok_or_else(|| {
             PyErr::from(PyPolarsErr::Other(
-                "Decimal is too large to fit in Decimal128".into(),
+                "Decimal is too large to fit in Decimal256".into(),
             ))
         })?;
-        if sign > 0 {
-            v = -v; // Won't overflow since -i128::MAX > i128::MIN
-        }
-        Ok(AnyValue::Decimal(v, scale))
+        let v = if sign > 0 { i256(-v.0) } else { v };
+        Ok(AnyValue::Decimal256(v, scale))
     }
 
     fn get_list(ob: &Bound<'_, PyAny>, strict: bool) -> PyResult<AnyValue<'static>> {
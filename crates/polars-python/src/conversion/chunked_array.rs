@@ -7,7 +7,7 @@ use pyo3::types::{PyBytes, PyList, PyNone, PyTuple};
 use super::datetime::{
     elapsed_offset_to_timedelta, nanos_since_midnight_to_naivetime, timestamp_to_naive_datetime,
 };
-use super::{decimal_to_digits, struct_dict};
+use super::{decimal256_to_digits, decimal_to_digits, struct_dict};
 use crate::prelude::*;
 use crate::py_modules::UTILS;
 
@@ -137,3 +137,31 @@ pub(crate) fn decimal_to_pyobject_iter<'a>(
         })
     })
 }
+
+impl ToPyObject for Wrap<&Decimal256Chunked> {
+    fn to_object(&self, py: Python) -> PyObject {
+        let values = decimal256_to_pyobject_iter(py, self.0).collect::<Vec<_>>();
+        PyList::new_bound(py, values).into_py(py)
+    }
+}
+
+pub(crate) fn decimal256_to_pyobject_iter<'a>(
+    py: Python<'a>,
+    ca: &'a Decimal256Chunked,
+) -> impl Iterator<Item = Option<Bound<'a, PyAny>>> {
+    let utils = UTILS.bind(py);
+    let convert = utils.getattr(intern!(py, "to_py_decimal")).unwrap();
+    let py_scale = (-(ca.scale() as i32)).to_object(py);
+    let py_precision = ca
+        .precision()
+        .unwrap_or(DECIMAL256_MAX_PRECISION)
+        .to_object(py);
+    ca.iter_i256().map(move |opt_v| {
+        opt_v.map(|v| {
+            let digits = PyTuple::new_bound(py, decimal256_to_digits(v));
+            convert
+                .call1((v.0.is_negative() as u8, digits, &py_precision, &py_scale))
+                .unwrap()
+        })
+    })
+}
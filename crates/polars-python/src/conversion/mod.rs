@@ -179,6 +179,12 @@ fn decimal_to_digits(v: i128, buf: &mut [u128; 3]) -> usize {
     len
 }
 
+/// The digits of the absolute value of a 256-bit decimal.
+fn decimal256_to_digits(v: i256) -> Vec<u8> {
+    let value = v.0.unsigned_abs().to_string();
+    value.bytes().map(|b| b - b'0').collect()
+}
+
 impl ToPyObject for Wrap<DataType> {
     fn to_object(&self, py: Python) -> PyObject {
         let pl = POLARS.bind(py);
@@ -216,6 +222,10 @@ impl ToPyObject for Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "UInt64")).unwrap();
                 class.call0().unwrap().into()
             },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16")).unwrap();
+                class.call0().unwrap().into()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32")).unwrap();
                 class.call0().unwrap().into()
@@ -229,6 +239,11 @@ impl ToPyObject for Wrap<DataType> {
                 let args = (*precision, *scale);
                 class.call1(args).unwrap().into()
             },
+            DataType::Decimal256(precision, scale) => {
+                let class = pl.getattr(intern!(py, "Decimal")).unwrap();
+                let args = (precision.unwrap_or(DECIMAL256_MAX_PRECISION), *scale);
+                class.call1(args).unwrap().into()
+            },
             DataType::Boolean => {
                 let class = pl.getattr(intern!(py, "Boolean")).unwrap();
                 class.call0().unwrap().into()
@@ -252,6 +267,12 @@ impl ToPyObject for Wrap<DataType> {
                 let inner = Wrap(*inner.clone()).to_object(py);
                 class.call1((inner,)).unwrap().into()
             },
+            DataType::Map(key, value) => {
+                let class = pl.getattr(intern!(py, "Map")).unwrap();
+                let key = Wrap(*key.clone()).to_object(py);
+                let value = Wrap(*value.clone()).to_object(py);
+                class.call1((key, value)).unwrap().into()
+            },
             DataType::Date => {
                 let class = pl.getattr(intern!(py, "Date")).unwrap();
                 class.call0().unwrap().into()
@@ -290,6 +311,7 @@ impl ToPyObject for Wrap<DataType> {
                 class.call1((series,)).unwrap().into()
             },
             DataType::Time => pl.getattr(intern!(py, "Time")).unwrap().into(),
+            DataType::Interval => pl.getattr(intern!(py, "Interval")).unwrap().into(),
             DataType::Struct(fields) => {
                 let field_class = pl.getattr(intern!(py, "Field")).unwrap();
                 let iter = fields.iter().map(|fld| {
@@ -354,6 +376,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
@@ -365,9 +388,11 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "Time" => DataType::Time,
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Duration" => DataType::Duration(TimeUnit::Microseconds),
+                    "Interval" => DataType::Interval,
                     "Decimal" => DataType::Decimal(None, None), // "none" scale => "infer"
                     "List" => DataType::List(Box::new(DataType::Null)),
                     "Array" => DataType::Array(Box::new(DataType::Null), 0),
+                    "Map" => DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null)),
                     "Struct" => DataType::Struct(vec![]),
                     "Null" => DataType::Null,
                     #[cfg(feature = "object")]
@@ -388,6 +413,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
@@ -419,10 +445,13 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let time_unit = time_unit.extract::<Wrap<TimeUnit>>()?.0;
                 DataType::Duration(time_unit)
             },
+            "Interval" => DataType::Interval,
             "Decimal" => {
                 let precision = ob.getattr(intern!(py, "precision"))?.extract()?;
                 let scale = ob.getattr(intern!(py, "scale"))?.extract()?;
-                DataType::Decimal(precision, Some(scale))
+                let dtype = DataType::Decimal(precision, Some(scale));
+                // Decimals that don't fit in 128 bits are stored as `Decimal256`.
+                dtype.promote_decimal().unwrap_or(dtype)
             },
             "List" => {
                 let inner = ob.getattr(intern!(py, "inner")).unwrap();
@@ -436,6 +465,13 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let size = size.extract::<usize>()?;
                 DataType::Array(Box::new(inner.0), size)
             },
+            "Map" => {
+                let key = ob.getattr(intern!(py, "key")).unwrap();
+                let value = ob.getattr(intern!(py, "value")).unwrap();
+                let key = key.extract::<Wrap<DataType>>()?;
+                let value = value.extract::<Wrap<DataType>>()?;
+                DataType::Map(Box::new(key.0), Box::new(value.0))
+            },
             "Struct" => {
                 let fields = ob.getattr(intern!(py, "fields"))?;
                 let fields = fields
@@ -743,6 +779,23 @@ impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<DecimalArithmeticOp> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*(ob.extract::<PyBackedStr>()?) {
+            "add" => DecimalArithmeticOp::Add,
+            "sub" => DecimalArithmeticOp::Sub,
+            "mul" => DecimalArithmeticOp::Mul,
+            "div" => DecimalArithmeticOp::Div,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "decimal `op` must be one of {{'add', 'sub', 'mul', 'div'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "avro")]
 impl<'py> FromPyObject<'py> for Wrap<Option<AvroCompression>> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -409,6 +409,7 @@ impl PyDataFrame {
                     row_group_size,
                     data_page_size,
                     maintain_order: true,
+                    bloom_filters: Vec::new(),
                 };
                 write_partitioned_dataset(
                     &mut self.df,
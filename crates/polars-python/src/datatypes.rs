@@ -34,6 +34,9 @@ pub(crate) enum PyDataType {
     Decimal(Option<usize>, usize),
     Array(usize),
     Enum(Utf8ViewArray),
+    Interval,
+    Map,
+    Float16,
 }
 
 impl From<&DataType> for PyDataType {
@@ -48,17 +51,24 @@ impl From<&DataType> for PyDataType {
             DataType::UInt16 => UInt16,
             DataType::UInt32 => UInt32,
             DataType::UInt64 => UInt64,
+            DataType::Float16 => Float16,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::Decimal(p, s) => Decimal(*p, s.expect("unexpected null decimal scale")),
+            DataType::Decimal256(p, s) => Decimal(
+                Some(p.unwrap_or(DECIMAL256_MAX_PRECISION)),
+                s.expect("unexpected null decimal scale"),
+            ),
             DataType::Boolean => Bool,
             DataType::String => String,
             DataType::Binary => Binary,
             DataType::Array(_, width) => Array(*width),
             DataType::List(_) => List,
+            DataType::Map(_, _) => Map,
             DataType::Date => Date,
             DataType::Datetime(tu, tz) => Datetime(*tu, tz.clone()),
             DataType::Duration(tu) => Duration(*tu),
+            DataType::Interval => Interval,
             DataType::Time => Time,
             #[cfg(feature = "object")]
             DataType::Object(_, _) => Object,
@@ -90,6 +100,7 @@ impl From<PyDataType> for DataType {
             PyDataType::UInt16 => UInt16,
             PyDataType::UInt32 => UInt32,
             PyDataType::UInt64 => UInt64,
+            PyDataType::Float16 => Float16,
             PyDataType::Float32 => Float32,
             PyDataType::Float64 => Float64,
             PyDataType::Bool => Boolean,
@@ -99,14 +110,19 @@ impl From<PyDataType> for DataType {
             PyDataType::Date => Date,
             PyDataType::Datetime(tu, tz) => Datetime(tu, tz),
             PyDataType::Duration(tu) => Duration(tu),
+            PyDataType::Interval => Interval,
             PyDataType::Time => Time,
             #[cfg(feature = "object")]
             PyDataType::Object => Object(OBJECT_NAME, None),
             PyDataType::Categorical => Categorical(None, Default::default()),
             PyDataType::Enum(categories) => create_enum_dtype(categories),
             PyDataType::Struct => Struct(vec![]),
-            PyDataType::Decimal(p, s) => Decimal(p, Some(s)),
+            PyDataType::Decimal(p, s) => {
+                let dtype = Decimal(p, Some(s));
+                dtype.promote_decimal().unwrap_or(dtype)
+            },
             PyDataType::Array(width) => Array(DataType::Null.into(), width),
+            PyDataType::Map => Map(DataType::Null.into(), DataType::Null.into()),
         }
     }
 }
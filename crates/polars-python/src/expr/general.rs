@@ -648,6 +648,13 @@ impl PyExpr {
         self.inner.clone().repeat_by(by.inner).into()
     }
 
+    fn decimal_arithmetic(&self, other: Self, op: Wrap<DecimalArithmeticOp>, strict: bool) -> Self {
+        self.inner
+            .clone()
+            .decimal_arithmetic(other.inner, op.0, strict)
+            .into()
+    }
+
     fn pow(&self, exponent: Self) -> Self {
         self.inner.clone().pow(exponent.inner).into()
     }
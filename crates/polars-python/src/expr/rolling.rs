@@ -323,6 +323,232 @@ impl PyExpr {
             .into())
     }
 
+    #[pyo3(signature = (window_size, min_periods, center, fisher, bias))]
+    fn rolling_kurtosis(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+        fisher: bool,
+        bias: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner
+            .clone()
+            .rolling_kurtosis(fisher, bias, options)
+            .into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed, fisher, bias))]
+    fn rolling_kurtosis_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+        fisher: bool,
+        bias: bool,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_kurtosis_by(by.inner, fisher, bias, options)
+            .into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_product(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_product(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_product_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_product_by(by.inner, options)
+            .into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_rank(&self, window_size: usize, min_periods: Option<usize>, center: bool) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_rank(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_rank_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self.inner.clone().rolling_rank_by(by.inner, options).into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_n_unique(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_n_unique(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_n_unique_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_n_unique_by(by.inner, options)
+            .into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_arg_min(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_arg_min(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_arg_min_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_arg_min_by(by.inner, options)
+            .into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_arg_max(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_arg_max(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_arg_max_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_arg_max_by(by.inner, options)
+            .into())
+    }
+
     fn rolling_skew(&self, window_size: usize, bias: bool) -> Self {
         self.inner.clone().rolling_skew(window_size, bias).into()
     }
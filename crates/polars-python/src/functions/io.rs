@@ -4,7 +4,7 @@ use std::io::BufReader;
 use polars::prelude::ArrowSchema;
 use polars_core::datatypes::create_enum_dtype;
 use polars_core::export::arrow::array::Utf8ViewArray;
-use polars_core::prelude::{DTYPE_ENUM_KEY, DTYPE_ENUM_VALUE};
+use polars_core::prelude::{DataType, DTYPE_ENUM_KEY, DTYPE_ENUM_VALUE};
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
@@ -55,7 +55,7 @@ fn fields_to_pydict(schema: &ArrowSchema, dict: &Bound<'_, PyDict>, py: Python)
                 ArrowDataType::LargeUtf8,
             )))
         } else {
-            Wrap((&field.dtype).into())
+            Wrap(DataType::from_arrow_field(field))
         };
         dict.set_item(field.name.as_str(), dt.to_object(py))?;
     }
@@ -13,7 +13,10 @@ use super::utils::{
     create_borrowed_np_array, dtype_supports_view, polars_dtype_to_np_temporal_dtype,
     reshape_numpy_array, series_contains_null,
 };
-use crate::conversion::chunked_array::{decimal_to_pyobject_iter, time_to_pyobject_iter};
+use crate::conversion::any_value::interval_to_py_tuple;
+use crate::conversion::chunked_array::{
+    decimal256_to_pyobject_iter, decimal_to_pyobject_iter, time_to_pyobject_iter,
+};
 use crate::conversion::ObjectValue;
 use crate::series::PySeries;
 
@@ -193,6 +196,12 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
         UInt16 => numeric_series_to_numpy::<UInt16Type, f32>(py, s),
         UInt32 => numeric_series_to_numpy::<UInt32Type, f64>(py, s),
         UInt64 => numeric_series_to_numpy::<UInt64Type, f64>(py, s),
+        Float16 => {
+            // Widen to f32 and let NumPy narrow to its native `float16`.
+            let arr = numeric_series_to_numpy::<Float32Type, f32>(py, &s.to_physical_repr());
+            arr.call_method1(py, intern!(py, "astype"), ("float16",))
+                .unwrap()
+        },
         Float32 => numeric_series_to_numpy::<Float32Type, f32>(py, s),
         Float64 => numeric_series_to_numpy::<Float64Type, f64>(py, s),
         Boolean => boolean_series_to_numpy(py, s),
@@ -225,6 +234,13 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
                 },
             }
         },
+        Interval => {
+            let ca = s.interval().unwrap();
+            let values = ca
+                .iter_intervals()
+                .map(|v| v.map(interval_to_py_tuple).into_py(py));
+            PyArray1::from_iter_bound(py, values).into_py(py)
+        },
         Time => {
             let ca = s.time().unwrap();
             let values = time_to_pyobject_iter(ca).map(|v| v.into_py(py));
@@ -250,7 +266,16 @@ fn series_to_numpy_with_copy(py: Python, s: &Series, writable: bool) -> PyObject
             let values = decimal_to_pyobject_iter(py, ca).map(|v| v.into_py(py));
             PyArray1::from_iter_bound(py, values).into_py(py)
         },
+        Decimal256(_, _) => {
+            let ca = s.decimal256().unwrap();
+            let values = decimal256_to_pyobject_iter(py, ca).map(|v| v.into_py(py));
+            PyArray1::from_iter_bound(py, values).into_py(py)
+        },
         List(_) => list_series_to_numpy(py, s, writable),
+        Map(_, _) => {
+            let s = s.map().unwrap().physical().clone().into_series();
+            list_series_to_numpy(py, &s, writable)
+        },
         Array(_, _) => array_series_to_numpy(py, s, writable),
         Struct(_) => {
             let ca = s.struct_().unwrap();
@@ -697,6 +697,7 @@ impl PyLazyFrame {
             row_group_size,
             data_page_size,
             maintain_order,
+            bloom_filters: Vec::new(),
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -720,6 +721,7 @@ impl PyLazyFrame {
         let options = IpcWriterOptions {
             compression: compression.map(|c| c.0),
             maintain_order,
+            bloom_filters: Vec::new(),
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
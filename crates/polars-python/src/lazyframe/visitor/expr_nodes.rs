@@ -1184,6 +1184,24 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunction::Std(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std"))
                     },
+                    RollingFunction::Product(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling product"))
+                    },
+                    RollingFunction::Kurtosis(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling kurtosis"))
+                    },
+                    RollingFunction::Rank(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling rank"))
+                    },
+                    RollingFunction::NUnique(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling n_unique"))
+                    },
+                    RollingFunction::ArgMin(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling arg_min"))
+                    },
+                    RollingFunction::ArgMax(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling arg_max"))
+                    },
                     RollingFunction::Skew(_, _) => {
                         return Err(PyNotImplementedError::new_err("rolling skew"))
                     },
@@ -1213,6 +1231,24 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     RollingFunctionBy::StdBy(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std by"))
                     },
+                    RollingFunctionBy::ProductBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling product by"))
+                    },
+                    RollingFunctionBy::KurtosisBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling kurtosis by"))
+                    },
+                    RollingFunctionBy::RankBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling rank by"))
+                    },
+                    RollingFunctionBy::NUniqueBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling n_unique by"))
+                    },
+                    RollingFunctionBy::ArgMinBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling arg_min by"))
+                    },
+                    RollingFunctionBy::ArgMaxBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling arg_max by"))
+                    },
                 },
                 FunctionExpr::ShiftAndFill => ("shift_and_fill",).to_object(py),
                 FunctionExpr::Shift => ("shift",).to_object(py),
@@ -1285,6 +1321,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::UpperBound => ("upper_bound",).to_object(py),
                 FunctionExpr::LowerBound => ("lower_bound",).to_object(py),
                 FunctionExpr::Fused(_) => return Err(PyNotImplementedError::new_err("fused")),
+                FunctionExpr::DecimalArithmetic { op, strict } => {
+                    ("decimal_arithmetic", op.name(), strict).to_object(py)
+                },
                 FunctionExpr::ConcatExpr(_) => {
                     return Err(PyNotImplementedError::new_err("concat expr"))
                 },
@@ -55,7 +55,7 @@ impl PySeries {
             )
             .into_py(py)),
             // For non-numeric output types we require mean_reduce.
-            dt if dt.is_temporal() => {
+            dt if dt.is_temporal() || dt.is_decimal() => {
                 Ok(Wrap(self.series.mean_reduce().as_any_value()).into_py(py))
             },
             _ => Ok(self.series.mean().into_py(py)),
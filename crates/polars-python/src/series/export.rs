@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyCapsule, PyList};
 
 use super::PySeries;
+use crate::conversion::any_value::interval_to_py_tuple;
 use crate::interop;
 use crate::interop::arrow::to_py::series_to_stream;
 use crate::prelude::*;
@@ -26,6 +27,9 @@ impl PySeries {
                     DataType::Int16 => PyList::new_bound(py, series.i16().unwrap()),
                     DataType::Int32 => PyList::new_bound(py, series.i32().unwrap()),
                     DataType::Int64 => PyList::new_bound(py, series.i64().unwrap()),
+                    DataType::Float16 => {
+                        PyList::new_bound(py, series.float16().unwrap().physical())
+                    },
                     DataType::Float32 => PyList::new_bound(py, series.f32().unwrap()),
                     DataType::Float64 => PyList::new_bound(py, series.f64().unwrap()),
                     DataType::Categorical(_, _) | DataType::Enum(_, _) => {
@@ -91,6 +95,10 @@ impl PySeries {
                         let ca = series.decimal().unwrap();
                         return Wrap(ca).to_object(py);
                     },
+                    DataType::Decimal256(_, _) => {
+                        let ca = series.decimal256().unwrap();
+                        return Wrap(ca).to_object(py);
+                    },
                     DataType::String => {
                         let ca = series.str().unwrap();
                         return Wrap(ca).to_object(py);
@@ -99,10 +107,23 @@ impl PySeries {
                         let ca = series.struct_().unwrap();
                         return Wrap(ca).to_object(py);
                     },
+                    DataType::Map(_, _) => {
+                        // Maps are returned as lists of `{"key": .., "value": ..}` dicts.
+                        let ca = series.map().unwrap();
+                        return to_list_recursive(py, &ca.physical().clone().into_series());
+                    },
                     DataType::Duration(_) => {
                         let ca = series.duration().unwrap();
                         return Wrap(ca).to_object(py);
                     },
+                    DataType::Interval => {
+                        let ca = series.interval().unwrap();
+                        let values = ca
+                            .iter_intervals()
+                            .map(|v| v.map(interval_to_py_tuple).into_py(py))
+                            .collect::<Vec<_>>();
+                        PyList::new_bound(py, values)
+                    },
                     DataType::Binary => {
                         let ca = series.binary().unwrap();
                         return Wrap(ca).to_object(py);
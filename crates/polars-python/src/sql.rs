@@ -20,10 +20,13 @@ pub struct PySQLContext {
 impl PySQLContext {
     #[staticmethod]
     #[allow(clippy::new_without_default)]
-    pub fn new() -> PySQLContext {
-        PySQLContext {
-            context: SQLContext::new(),
+    #[pyo3(signature = (recursion_limit=None))]
+    pub fn new(recursion_limit: Option<usize>) -> PySQLContext {
+        let mut context = SQLContext::new();
+        if let Some(recursion_limit) = recursion_limit {
+            context = context.with_recursion_limit(recursion_limit);
         }
+        PySQLContext { context }
     }
 
     pub fn execute(&mut self, query: &str) -> PyResult<PyLazyFrame> {
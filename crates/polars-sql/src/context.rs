@@ -1,29 +1,53 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::ops::Deref;
 
 use polars_core::export::regex;
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
 use polars_lazy::prelude::*;
 use polars_ops::frame::JoinCoalesce;
+use polars_plan::constants::get_literal_name;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
+#[cfg(any(
+    feature = "csv",
+    feature = "ipc",
+    feature = "json",
+    feature = "parquet"
+))]
+use sqlparser::ast::CopySource;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg,
-    GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset, OrderBy,
-    Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier, Statement,
-    TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    BinaryOperator, CreateTable, Cte, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr,
+    FromTable, FunctionArg, FunctionArgExpr, FunctionArguments, GroupByExpr, GroupByWithModifier,
+    Ident, Insert, Join, JoinConstraint, JoinOperator, NamedWindowDefinition, NamedWindowExpr,
+    ObjectName, ObjectType, Offset, OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr,
+    SetOperator, SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions, WindowSpec, WindowType,
 };
+#[cfg(feature = "pivot")]
+use sqlparser::ast::{ExprWithAlias, PivotValueSource};
 use sqlparser::dialect::GenericDialect;
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::{Parser, ParserOptions};
-
+use sqlparser::tokenizer::{Token, TokenWithLocation, Tokenizer};
+
+#[cfg(any(
+    feature = "csv",
+    feature = "ipc",
+    feature = "json",
+    feature = "parquet"
+))]
+use crate::copy::{parse_copy_to, CopyTo};
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::functions::PolarsSQLFunctions;
 use crate::sql_expr::{
-    parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
+    parse_sql_any_value, parse_sql_array, parse_sql_expr, resolve_compound_identifier,
+    to_sql_interface_err,
 };
 use crate::table_functions::PolarsTableFunctions;
+use crate::types::map_sql_dtype_to_polars;
 
 #[derive(Clone)]
 pub struct TableInfo {
@@ -53,6 +77,30 @@ impl SelectModifiers {
     }
 }
 
+/// The default maximum number of iterations of a recursive CTE.
+const DEFAULT_RECURSION_LIMIT: usize = 1000;
+
+/// A statement registered with `PREPARE`, along with its (DSL) plan for the parameter types
+/// of its most recent execution. The parameters are placeholders in this plan, that are
+/// bound on each execution (see [`bind_params`]); this saves translating the SQL, but the
+/// bound plan is still resolved and optimized anew (resolving prunes the aliases that mark
+/// the placeholders, so a resolved plan cannot be bound). If the plan depends on the parameter
+/// values it is `None`, and the statement is planned on each execution instead.
+#[derive(Clone)]
+struct PreparedStatement {
+    query: Box<Query>,
+    dtypes: Vec<DataType>,
+    plan: Option<(Vec<DataType>, Option<LazyFrame>)>,
+}
+
+/// A view created with `CREATE VIEW`, which is planned anew whenever it is referenced.
+#[derive(Clone)]
+struct View {
+    query: Box<Query>,
+    /// The name of the view, and the names of its columns (if given).
+    alias: TableAlias,
+}
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
@@ -64,6 +112,16 @@ pub struct SQLContext {
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    referenced_ctes: RefCell<PlHashSet<String>>,
+    named_windows: PlHashMap<String, WindowSpec>,
+    decorrelated_subqueries: PlHashMap<String, Expr>,
+    recursion_limit: usize,
+    views: PlHashMap<String, View>,
+    expanding_views: RefCell<PlHashSet<String>>,
+    params: PlHashMap<String, AnyValue<'static>>,
+    param_placeholders: bool,
+    param_values_used: Cell<bool>,
+    prepared: PlHashMap<String, PreparedStatement>,
 }
 
 impl Default for SQLContext {
@@ -74,6 +132,16 @@ impl Default for SQLContext {
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            referenced_ctes: Default::default(),
+            named_windows: Default::default(),
+            decorrelated_subqueries: Default::default(),
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            views: Default::default(),
+            expanding_views: Default::default(),
+            params: Default::default(),
+            param_placeholders: false,
+            param_values_used: Default::default(),
+            prepared: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -116,14 +184,22 @@ impl SQLContext {
     ///```
     pub fn register(&mut self, name: &str, lf: LazyFrame) {
         self.table_map.insert(name.to_owned(), lf);
+        self.views.remove(name);
+        self.clear_prepared_plans();
     }
 
     /// Unregister a [`LazyFrame`] table from the [`SQLContext`].
     pub fn unregister(&mut self, name: &str) {
         self.table_map.remove(&name.to_owned());
+        self.views.remove(name);
+        self.clear_prepared_plans();
     }
 
     /// Execute a SQL query, returning a [`LazyFrame`].
+    ///
+    /// Note that a recursive CTE (`WITH RECURSIVE`) is evaluated eagerly, when the query
+    /// is executed, as each of its iterations depends on the result of the previous one;
+    /// the number of iterations is limited (see [`SQLContext::with_recursion_limit`]).
     /// ```rust
     /// # use polars_sql::SQLContext;
     /// # use polars_core::prelude::*;
@@ -142,33 +218,67 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
-
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
+        self.execute_with_bound_params(query, Default::default())
+    }
 
-        polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
-        let res = self.execute_statement(ast.first().unwrap())?;
+    /// Execute a SQL query, binding the given values to its positional placeholders
+    /// (`$1`, `$2`, ..., or `?`, which are numbered in order of appearance).
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "a" =>  [1, 2, 3],
+    /// }
+    /// .unwrap();
+    ///
+    /// ctx.register("df", df.lazy());
+    /// let sql_df = ctx
+    ///     .execute_with_params("SELECT * FROM df WHERE a > $1", &[AnyValue::Int32(1)])
+    ///     .unwrap()
+    ///     .collect()
+    ///     .unwrap();
+    /// assert_eq!(sql_df.height(), 2);
+    /// # }
+    ///```
+    pub fn execute_with_params(
+        &mut self,
+        query: &str,
+        params: &[AnyValue],
+    ) -> PolarsResult<LazyFrame> {
+        self.execute_with_bound_params(query, positional_params(params))
+    }
 
-        // Ensure the result uses the proper arenas.
-        // This will instantiate new arenas with a new version.
-        let lp_arena = std::mem::take(&mut self.lp_arena);
-        let expr_arena = std::mem::take(&mut self.expr_arena);
-        res.set_cached_arena(lp_arena, expr_arena);
+    /// Execute a SQL query, binding the given values to its named placeholders (`:name`).
+    pub fn execute_with_named_params(
+        &mut self,
+        query: &str,
+        params: &[(&str, AnyValue)],
+    ) -> PolarsResult<LazyFrame> {
+        let params = params
+            .iter()
+            .map(|(name, av)| (name.to_string(), av.clone().into_static()))
+            .collect();
+        self.execute_with_bound_params(query, params)
+    }
 
-        // Every execution should clear the statement-level maps.
-        self.cte_map.borrow_mut().clear();
-        self.table_aliases.borrow_mut().clear();
-        self.joined_aliases.borrow_mut().clear();
+    /// Execute a statement registered with `PREPARE`, binding the given values to its
+    /// positional placeholders. The statement is planned once for the types of the values,
+    /// so executing it again only binds the new values to the cached plan.
+    pub fn execute_prepared(&mut self, name: &str, params: &[AnyValue]) -> PolarsResult<LazyFrame> {
+        let params = params.iter().map(|av| av.clone().into_static()).collect();
+        let res = self.execute_prepared_statement(name, params);
+        self.finish_execution(res)
+    }
 
-        Ok(res)
+    /// Get the names of all prepared statements, in sorted order.
+    pub fn get_prepared_statements(&self) -> Vec<String> {
+        let mut names = Vec::from_iter(self.prepared.keys().cloned());
+        names.sort_unstable();
+        names
     }
 
     /// add a function registry to the SQLContext
@@ -178,6 +288,13 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations of a recursive CTE; queries with a recursive
+    /// CTE that does not reach a fixed point within this limit will raise an error.
+    pub fn with_recursion_limit(mut self, recursion_limit: usize) -> Self {
+        self.recursion_limit = recursion_limit;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -190,18 +307,172 @@ impl SQLContext {
 }
 
 impl SQLContext {
+    fn execute_with_bound_params(
+        &mut self,
+        query: &str,
+        params: PlHashMap<String, AnyValue<'static>>,
+    ) -> PolarsResult<LazyFrame> {
+        let options = ParserOptions {
+            trailing_commas: true,
+            ..Default::default()
+        };
+        let mut tokens = Tokenizer::new(&GenericDialect, query)
+            .with_unescape(options.unescape)
+            .tokenize_with_location()
+            .map_err(to_sql_interface_err)?;
+
+        parse_comma_laterals(&mut tokens, &options)?;
+
+        // Number anonymous `?` placeholders in order of appearance.
+        let mut n_anonymous = 0;
+        for tok in tokens.iter_mut() {
+            if matches!(&tok.token, Token::Placeholder(p) if p == "?") {
+                n_anonymous += 1;
+                tok.token = Token::Placeholder(format!("?{n_anonymous}"));
+            }
+        }
+        let mut parser = Parser::new(&GenericDialect)
+            .with_options(options)
+            .with_tokens_with_locations(tokens);
+
+        // COPY ... TO is parsed separately, as it supports writer-specific options.
+        #[cfg(any(
+            feature = "csv",
+            feature = "ipc",
+            feature = "json",
+            feature = "parquet"
+        ))]
+        if parser.parse_keyword(Keyword::COPY) {
+            let copy = parse_copy_to(&mut parser).map_err(to_sql_interface_err)?;
+            self.params = params;
+            let res = self.execute_copy_to(&copy);
+            return self.finish_execution(res);
+        }
+        let ast = parser.parse_statements().map_err(to_sql_interface_err)?;
+
+        polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
+        self.params = params;
+        let res = self.execute_statement(ast.first().unwrap());
+        self.finish_execution(res)
+    }
+
+    // COPY { (SELECT ...) | tbl [(cols)] } TO 'path' [(options)]
+    #[cfg(any(
+        feature = "csv",
+        feature = "ipc",
+        feature = "json",
+        feature = "parquet"
+    ))]
+    fn execute_copy_to(&mut self, copy: &CopyTo) -> PolarsResult<LazyFrame> {
+        let lf = match &copy.source {
+            CopySource::Query(query) => self.execute_query(query)?,
+            CopySource::Table {
+                table_name,
+                columns,
+            } => {
+                let tbl_name = table_name.to_string();
+                let lf = self.get_table_from_current_scope(&tbl_name).ok_or_else(
+                    || polars_err!(SQLInterface: "relation '{}' was not found", tbl_name),
+                )?;
+                if columns.is_empty() {
+                    lf
+                } else {
+                    lf.select(
+                        columns
+                            .iter()
+                            .map(|c| col(c.value.as_str()))
+                            .collect::<Vec<_>>(),
+                    )
+                }
+            },
+        };
+        copy.execute(lf)
+    }
+
+    fn finish_execution(&mut self, res: PolarsResult<LazyFrame>) -> PolarsResult<LazyFrame> {
+        // Every execution should clear the statement-level maps (also if it failed, so
+        // that they do not leak into the next one).
+        self.cte_map.borrow_mut().clear();
+        self.table_aliases.borrow_mut().clear();
+        self.joined_aliases.borrow_mut().clear();
+        self.referenced_ctes.borrow_mut().clear();
+        self.params.clear();
+        let res = res?;
+
+        // Ensure the result uses the proper arenas.
+        // This will instantiate new arenas with a new version.
+        let lp_arena = std::mem::take(&mut self.lp_arena);
+        let expr_arena = std::mem::take(&mut self.expr_arena);
+        res.set_cached_arena(lp_arena, expr_arena);
+        Ok(res)
+    }
+
+    pub(crate) fn get_param(&self, placeholder: &str) -> PolarsResult<&AnyValue<'static>> {
+        // the plan depends on the value, so it cannot be bound to other values
+        self.param_values_used.set(true);
+        // placeholders are prefixed with one of '$', '?', ':' or '@'
+        let key = placeholder.get(1..).unwrap_or_default();
+        self.params.get(key).ok_or_else(
+            || polars_err!(SQLInterface: "no value bound to placeholder '{}'", placeholder),
+        )
+    }
+
+    /// Get the literal for a placeholder. When planning a prepared statement, this is a
+    /// placeholder expression that [`bind_params`] replaces with the value on execution;
+    /// only values that are not inspected during planning (unlike strings, which may be
+    /// parsed as temporal values, or NULL) get one.
+    pub(crate) fn get_param_expr(&self, placeholder: &str) -> PolarsResult<Expr> {
+        let key = placeholder.get(1..).unwrap_or_default();
+        match self.params.get(key) {
+            Some(av)
+                if self.param_placeholders && {
+                    let dtype = av.dtype();
+                    dtype.is_numeric() || dtype.is_bool() || dtype.is_temporal()
+                } =>
+            {
+                Ok(Expr::Literal(av.clone().into())
+                    .alias(param_placeholder_name(key))
+                    .alias(get_literal_name().clone()))
+            },
+            _ => Ok(Expr::Literal(self.get_param(placeholder)?.clone().into())),
+        }
+    }
+
     pub(crate) fn execute_statement(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         let ast = stmt;
         Ok(match ast {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
+            stmt @ Statement::CreateView { .. } => self.execute_create_view(stmt)?,
             stmt @ Statement::Drop {
-                object_type: ObjectType::Table,
+                object_type: ObjectType::Table | ObjectType::View,
                 ..
             } => self.execute_drop_table(stmt)?,
+            stmt @ Statement::Insert(_) => self.execute_insert(stmt)?,
+            stmt @ Statement::Delete(_) => self.execute_delete(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
+            stmt @ Statement::Prepare { .. } => self.execute_prepare(stmt)?,
+            Statement::Execute {
+                name,
+                parameters,
+                using,
+            } => {
+                let params = parameters
+                    .iter()
+                    .chain(using)
+                    .map(|e| parse_sql_any_value(e, self))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                self.execute_prepared_statement(&name.value, params)?
+            },
+            Statement::Deallocate { name, .. } => {
+                polars_ensure!(
+                    self.prepared.remove(&name.value).is_some(),
+                    SQLInterface: "prepared statement '{}' does not exist", name.value
+                );
+                DataFrame::empty().lazy()
+            },
             _ => polars_bail!(
                 SQLInterface: "statement type {:?} is not supported", ast,
             ),
@@ -225,7 +496,13 @@ impl SQLContext {
     pub(super) fn get_table_from_current_scope(&self, name: &str) -> Option<LazyFrame> {
         let table = self.table_map.get(name).cloned();
         table
-            .or_else(|| self.cte_map.borrow().get(name).cloned())
+            .or_else(|| {
+                let cte = self.cte_map.borrow().get(name).cloned();
+                if cte.is_some() {
+                    self.referenced_ctes.borrow_mut().insert(name.to_owned());
+                }
+                cte
+            })
             .or_else(|| {
                 self.table_aliases
                     .borrow()
@@ -287,6 +564,8 @@ impl SQLContext {
                     })?
                     .clone())
             },
+            // placeholders are values, not ordinals
+            SQLExpr::Value(SQLValue::Placeholder(_)) => parse_sql_expr(e, self, schema),
             SQLExpr::Value(v) => Err(polars_err!(
                 SQLSyntax:
                 "{} requires a valid expression or positive ordinal; found {}", clause, v,
@@ -489,10 +768,21 @@ impl SQLContext {
 
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
-            Statement::Drop { names, .. } => {
-                names.iter().for_each(|name| {
-                    self.table_map.remove(&name.to_string());
-                });
+            Statement::Drop {
+                names, object_type, ..
+            } => {
+                for name in names.iter().map(|name| name.to_string()) {
+                    let is_view = self.views.contains_key(&name);
+                    if *object_type == ObjectType::View && !is_view {
+                        polars_ensure!(
+                            !self.table_map.contains_key(&name),
+                            SQLInterface: "'{}' is not a view (use DROP TABLE)", name
+                        );
+                    } else if *object_type == ObjectType::Table && is_view {
+                        polars_bail!(SQLInterface: "'{}' is a view (use DROP VIEW)", name);
+                    }
+                    self.unregister(&name);
+                }
                 Ok(DataFrame::empty().lazy())
             },
             _ => unreachable!(),
@@ -509,6 +799,10 @@ impl SQLContext {
             match partitions {
                 None => {
                     let tbl = table_name.to_string();
+                    polars_ensure!(
+                        !self.views.contains_key(&tbl),
+                        SQLInterface: "cannot truncate view '{}'", tbl
+                    );
                     if let Some(lf) = self.table_map.get_mut(&tbl) {
                         *lf = DataFrame::empty_with_schema(
                             lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)
@@ -516,7 +810,9 @@ impl SQLContext {
                                 .as_ref(),
                         )
                         .lazy();
-                        Ok(lf.clone())
+                        let lf = lf.clone();
+                        self.clear_prepared_plans();
+                        Ok(lf)
                     } else {
                         polars_bail!(SQLInterface: "table '{}' does not exist", tbl);
                     }
@@ -530,50 +826,327 @@ impl SQLContext {
         }
     }
 
+    fn execute_prepare(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Prepare {
+            name,
+            data_types,
+            statement,
+        } = stmt
+        {
+            let Statement::Query(query) = statement.as_ref() else {
+                polars_bail!(SQLInterface: "only queries can be prepared; found {}", statement)
+            };
+            let dtypes = data_types
+                .iter()
+                .map(map_sql_dtype_to_polars)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            self.prepared.insert(
+                name.value.clone(),
+                PreparedStatement {
+                    query: query.clone(),
+                    dtypes,
+                    plan: None,
+                },
+            );
+            Ok(DataFrame::empty().lazy())
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn execute_prepared_statement(
+        &mut self,
+        name: &str,
+        mut params: Vec<AnyValue<'static>>,
+    ) -> PolarsResult<LazyFrame> {
+        let Some(prepared) = self.prepared.get(name) else {
+            polars_bail!(SQLInterface: "prepared statement '{}' does not exist", name)
+        };
+        if !prepared.dtypes.is_empty() {
+            polars_ensure!(
+                params.len() == prepared.dtypes.len(),
+                SQLInterface: "prepared statement '{}' expects {} parameters; found {}",
+                name, prepared.dtypes.len(), params.len()
+            );
+            params = params
+                .iter()
+                .zip(&prepared.dtypes)
+                .map(|(av, dtype)| match av {
+                    AnyValue::Null => Ok(AnyValue::Null),
+                    _ => av.strict_cast(dtype).map(AnyValue::into_static).ok_or_else(
+                        || polars_err!(SQLInterface: "cannot cast parameter {} to {}", av, dtype),
+                    ),
+                })
+                .collect::<PolarsResult<_>>()?;
+        }
+        let dtypes = params.iter().map(AnyValue::dtype).collect::<Vec<_>>();
+        let params = positional_params(&params);
+        let query = prepared.query.clone();
+        let plan = match &prepared.plan {
+            Some((planned, plan)) if *planned == dtypes => plan.clone(),
+            _ => {
+                // plan the statement with placeholders for the values, unless the plan
+                // turns out to depend on them
+                self.param_placeholders = true;
+                self.param_values_used.set(false);
+                let res = self.execute_prepared_query(&query, params.clone());
+                self.param_placeholders = false;
+                let plan = res.ok().filter(|_| !self.param_values_used.get());
+                if let Some(prepared) = self.prepared.get_mut(name) {
+                    prepared.plan = Some((dtypes, plan.clone()));
+                }
+                plan
+            },
+        };
+        match plan {
+            Some(lf) => {
+                let opt_state = lf.get_current_optimizations();
+                let plan = bind_params(&lf.logical_plan, &params)?;
+                Ok(LazyFrame::from(plan).with_optimizations(opt_state))
+            },
+            None => self.execute_prepared_query(&query, params),
+        }
+    }
+
+    /// Plan the query of a prepared statement, with the given values for its parameters.
+    fn execute_prepared_query(
+        &mut self,
+        query: &Query,
+        params: PlHashMap<String, AnyValue<'static>>,
+    ) -> PolarsResult<LazyFrame> {
+        let outer_params = std::mem::replace(&mut self.params, params);
+        let res = self.execute_query(query);
+        self.params = outer_params;
+        res
+    }
+
+    /// Drop the cached plans of all prepared statements (eg: when the registered tables change).
+    fn clear_prepared_plans(&mut self) {
+        self.prepared
+            .values_mut()
+            .for_each(|prepared| prepared.plan = None);
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = if with.recursive
+                    && matches!(
+                        *cte.query.body,
+                        SetExpr::SetOperation {
+                            op: SetOperator::Union,
+                            ..
+                        }
+                    ) {
+                    self.execute_recursive_cte(cte)?
+                } else {
+                    let lf = self.execute_query(&cte.query)?;
+                    self.rename_columns_from_table_alias(lf, &cte.alias)?
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Execute a recursive CTE of the form `<anchor> UNION [ALL] <recursive term>`: the
+    /// recursive term is evaluated against the rows produced by the previous iteration
+    /// until no new rows are produced. As every iteration depends on the (materialised)
+    /// result of the previous one, the CTE is collected eagerly.
+    fn execute_recursive_cte(&mut self, cte: &Cte) -> PolarsResult<LazyFrame> {
+        let cte_name = &cte.alias.name.value;
+        let query = &cte.query;
+        let (anchor, recursive_term, distinct) = match &*query.body {
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                set_quantifier,
+                left,
+                right,
+            } => match set_quantifier {
+                SetQuantifier::All => (left, right, false),
+                SetQuantifier::Distinct | SetQuantifier::None => (left, right, true),
+                _ => polars_bail!(
+                    SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'",
+                    set_quantifier, cte_name
+                ),
+            },
+            _ => polars_bail!(
+                SQLSyntax: "recursive CTE '{}' must have the form '<anchor> UNION [ALL] <recursive term>'",
+                cte_name
+            ),
+        };
+        if query.order_by.is_some() || query.limit.is_some() || query.offset.is_some() {
+            polars_bail!(SQLInterface: "ORDER BY, LIMIT and OFFSET are not supported in recursive CTE '{}'", cte_name)
+        }
+        self.register_ctes(query)?;
+
+        let lf = self.process_query(anchor, query)?;
+        let mut lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+        if distinct {
+            lf = lf.unique_stable(None, UniqueKeepStrategy::First);
+        }
+        let mut result = lf.collect()?;
+        let schema = result.schema();
+
+        let mut working_table = result.clone();
+        for iteration in 1.. {
+            // the recursive term refers to the rows produced by the previous iteration
+            self.register_cte(cte_name, working_table.lazy());
+            self.referenced_ctes.borrow_mut().remove(cte_name);
+            let mut lf = self.process_query(recursive_term, query)?;
+            let is_recursive = self.referenced_ctes.borrow().contains(cte_name);
+
+            let lf_schema = self.get_frame_schema(&mut lf)?;
+            if lf_schema.len() != schema.len() {
+                polars_bail!(
+                    SQLSyntax: "the recursive term of CTE '{}' must return {} columns (found {})",
+                    cte_name, schema.len(), lf_schema.len()
+                )
+            }
+            // the names and dtypes of the result are determined by the anchor
+            let rows = lf
+                .select(
+                    lf_schema
+                        .iter_names()
+                        .zip(schema.iter())
+                        .map(|(name, (anchor_name, dtype))| {
+                            col(name.clone())
+                                .strict_cast(dtype.clone())
+                                .alias(anchor_name.clone())
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .collect()?;
+
+            let new_rows = if distinct {
+                // only the rows that are not in the result yet are new
+                let height = result.height();
+                result = result
+                    .vstack(&rows)?
+                    .lazy()
+                    .unique_stable(None, UniqueKeepStrategy::First)
+                    .collect()?;
+                result.slice(height as i64, result.height() - height)
+            } else {
+                result.vstack_mut(&rows)?;
+                rows
+            };
+            // a CTE that does not refer to itself is a plain UNION of its terms
+            if !is_recursive || new_rows.height() == 0 {
+                break;
+            }
+            if iteration >= self.recursion_limit {
+                polars_bail!(
+                    SQLInterface: "recursive CTE '{}' did not complete within {} iterations",
+                    cte_name, self.recursion_limit
+                )
+            }
+            working_table = new_rows;
+        }
+        Ok(result.lazy())
+    }
+
+    /// Resolve the definitions of a 'WINDOW' clause, in order; each definition can
+    /// refer to the windows defined before it.
+    fn resolve_named_windows(
+        &self,
+        definitions: &[NamedWindowDefinition],
+    ) -> PolarsResult<PlHashMap<String, WindowSpec>> {
+        let mut windows = PlHashMap::with_capacity(definitions.len());
+        for NamedWindowDefinition(name, window_expr) in definitions {
+            let spec = match window_expr {
+                NamedWindowExpr::NamedWindow(base) => named_window(&windows, base)?.clone(),
+                NamedWindowExpr::WindowSpec(spec) => resolve_window_spec(&windows, spec)?,
+            };
+            if windows.insert(name.value.clone(), spec).is_some() {
+                polars_bail!(SQLSyntax: "window '{}' is defined more than once", name.value)
+            }
+        }
+        Ok(windows)
+    }
+
+    /// Resolve the window of an `OVER` clause into a window spec that does not refer to
+    /// any named window.
+    pub(crate) fn resolve_window_type(&self, window_type: &WindowType) -> PolarsResult<WindowSpec> {
+        match window_type {
+            WindowType::NamedWindow(name) => Ok(named_window(&self.named_windows, name)?.clone()),
+            WindowType::WindowSpec(spec) => resolve_window_spec(&self.named_windows, spec),
+        }
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
-        let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
-        if !tbl_expr.joins.is_empty() {
-            for join in &tbl_expr.joins {
-                let (r_name, mut rf) = self.get_table(&join.relation)?;
-                let left_schema = self.get_frame_schema(&mut lf)?;
-                let right_schema = self.get_frame_schema(&mut rf)?;
-
-                lf = match &join.join_operator {
-                    op @ (JoinOperator::FullOuter(constraint)
-                    | JoinOperator::LeftOuter(constraint)
-                    | JoinOperator::Inner(constraint)
-                    | JoinOperator::LeftAnti(constraint)
-                    | JoinOperator::LeftSemi(constraint)
-                    | JoinOperator::RightAnti(constraint)
-                    | JoinOperator::RightSemi(constraint)) => {
-                        let (lf, rf) = match op {
-                            JoinOperator::RightAnti(_) | JoinOperator::RightSemi(_) => (rf, lf),
-                            _ => (lf, rf),
-                        };
-                        self.process_join(
+        let (l_name, lf) = self.get_table(&tbl_expr.relation)?;
+        let relation_names = PlHashSet::from_iter([l_name.clone()]);
+        self.execute_joins(lf, &l_name, &tbl_expr.joins, relation_names)
+    }
+
+    /// Join the given relations to a frame (named `l_name`); `relation_names` are the
+    /// relations of the frame, which a LATERAL subquery can refer to.
+    fn execute_joins(
+        &mut self,
+        mut lf: LazyFrame,
+        l_name: &str,
+        joins: &[Join],
+        mut relation_names: PlHashSet<String>,
+    ) -> PolarsResult<LazyFrame> {
+        for join in joins {
+            let lateral = match &join.relation {
+                TableFactor::Derived {
+                    lateral: true,
+                    subquery,
+                    alias,
+                } => Some(self.decorrelate_lateral(subquery, alias.as_ref(), &relation_names)?),
+                _ => None,
+            };
+            let (r_name, mut rf) = match &lateral {
+                Some((name, subquery)) => (name.clone(), subquery.frame.clone()),
+                None => self.get_table(&join.relation)?,
+            };
+            relation_names.insert(r_name.clone());
+            let left_schema = self.get_frame_schema(&mut lf)?;
+            let right_schema = self.get_frame_schema(&mut rf)?;
+
+            lf = match &join.join_operator {
+                op if lateral.is_some() => {
+                    let Some((_, subquery)) = lateral else {
+                        unreachable!()
+                    };
+                    let join_type = match op {
+                        JoinOperator::CrossJoin => JoinType::Inner,
+                        JoinOperator::Inner(constraint) if is_on_true(constraint) => {
+                            JoinType::Inner
+                        },
+                        JoinOperator::LeftOuter(constraint) if is_on_true(constraint) => {
+                            JoinType::Left
+                        },
+                        _ => polars_bail!(
+                            SQLInterface: "LATERAL subqueries must be joined with CROSS JOIN or [LEFT] JOIN ... ON TRUE; found {:?}",
+                            op
+                        ),
+                    };
+                    self.join_decorrelated(lf, subquery, join_type, &r_name)?
+                },
+                op @ (JoinOperator::FullOuter(constraint)
+                | JoinOperator::LeftOuter(constraint)
+                | JoinOperator::Inner(constraint)
+                | JoinOperator::LeftAnti(constraint)
+                | JoinOperator::LeftSemi(constraint)
+                | JoinOperator::RightAnti(constraint)
+                | JoinOperator::RightSemi(constraint)) => {
+                    let (lf, rf) = match op {
+                        JoinOperator::RightAnti(_) | JoinOperator::RightSemi(_) => (rf, lf),
+                        _ => (lf, rf),
+                    };
+                    self.process_join(
                             &TableInfo {
                                 frame: lf,
-                                name: l_name.clone(),
+                                name: l_name.to_string(),
                                 schema: left_schema.clone(),
                             },
                             &TableInfo {
@@ -593,57 +1166,176 @@ impl SQLContext {
                                 join_type => polars_bail!(SQLInterface: "join type '{:?}' not currently supported", join_type),
                             },
                         )?
-                    },
-                    JoinOperator::CrossJoin => {
-                        lf.cross_join(rf, Some(format_pl_smallstr!(":{}", r_name)))
-                    },
-                    join_type => {
-                        polars_bail!(SQLInterface: "join type '{:?}' not currently supported", join_type)
-                    },
-                };
+                },
+                JoinOperator::CrossJoin => {
+                    lf.cross_join(rf, Some(format_pl_smallstr!(":{}", r_name)))
+                },
+                join_type => {
+                    polars_bail!(SQLInterface: "join type '{:?}' not currently supported", join_type)
+                },
+            };
 
-                // track join-aliased columns so we can resolve them later
-                let joined_schema = self.get_frame_schema(&mut lf)?;
+            // track join-aliased columns so we can resolve them later
+            self.track_joined_aliases(&mut lf, &r_name, &left_schema, &right_schema)?;
+        }
+        Ok(lf)
+    }
 
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.to_string(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{}:{}", name, r_name);
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+    /// Execute a FROM clause with multiple (comma-separated) tables, joining them in
+    /// order. The equality predicates of the WHERE clause that relate a table to the
+    /// preceding ones become the keys of an inner join; a table without such predicates
+    /// is cross-joined. Returns the joined frame and the remaining WHERE predicates.
+    fn execute_implicit_joins(
+        &mut self,
+        from: &[TableWithJoins],
+        selection: Option<&SQLExpr>,
+    ) -> PolarsResult<(LazyFrame, Option<SQLExpr>)> {
+        let mut predicates = selection.map(split_conjuncts).unwrap_or_default();
+        let mut lf = self.execute_from_statement(&from[0])?;
+        let mut relation_names = PlHashSet::from_iter(table_with_joins_names(&from[0]));
+
+        for tbl_expr in &from[1..] {
+            // joining the tables of the FROM clause in order is only equivalent to
+            // joining them independently for joins that retain the rows of the left side
+            if let Some(join) = tbl_expr.joins.iter().find(|join| {
+                matches!(
+                    join.join_operator,
+                    JoinOperator::FullOuter(_)
+                        | JoinOperator::RightOuter(_)
+                        | JoinOperator::RightAnti(_)
+                        | JoinOperator::RightSemi(_)
+                )
+            }) {
+                polars_bail!(SQLInterface: "join type '{:?}' not currently supported in a FROM clause with multiple tables", join.join_operator)
             }
-        };
-        Ok(lf)
+            let left_schema = self.get_frame_schema(&mut lf)?;
+            let (r_name, right_schema) = match &tbl_expr.relation {
+                TableFactor::Derived {
+                    lateral: true,
+                    subquery,
+                    alias,
+                } => {
+                    let (r_name, subquery) =
+                        self.decorrelate_lateral(subquery, alias.as_ref(), &relation_names)?;
+                    let right_schema = self.get_frame_schema(&mut subquery.frame.clone())?;
+                    lf = self.join_decorrelated(lf, subquery, JoinType::Inner, &r_name)?;
+                    (r_name, right_schema)
+                },
+                relation => {
+                    let (r_name, rf) = self.get_table(relation)?;
+                    let right_schema = self.get_frame_schema(&mut rf.clone())?;
+                    let mut left_on = vec![];
+                    let mut right_on = vec![];
+                    let mut remaining = vec![];
+                    for expr in predicates {
+                        match implicit_join_keys(
+                            expr,
+                            &relation_names,
+                            &r_name,
+                            &left_schema,
+                            &right_schema,
+                        ) {
+                            Some((l, r)) => {
+                                let l = parse_sql_expr(l, self, Some(left_schema.as_ref()))?;
+                                let r = parse_sql_expr(r, self, Some(right_schema.as_ref()))?;
+                                // the keys are compared as (and must be cast to) their supertype
+                                let l_dtype = l.to_field(&left_schema, Context::Default)?.dtype;
+                                let r_dtype = r.to_field(&right_schema, Context::Default)?.dtype;
+                                let dtype = try_get_supertype(&l_dtype, &r_dtype)?;
+                                left_on.push(if l_dtype == dtype {
+                                    l
+                                } else {
+                                    l.cast(dtype.clone())
+                                });
+                                right_on.push(if r_dtype == dtype { r } else { r.cast(dtype) });
+                            },
+                            None => remaining.push(expr),
+                        }
+                    }
+                    predicates = remaining;
+                    lf = if left_on.is_empty() {
+                        lf.cross_join(rf, Some(format_pl_smallstr!(":{}", r_name)))
+                    } else {
+                        lf.join_builder()
+                            .with(rf)
+                            .left_on(left_on)
+                            .right_on(right_on)
+                            .how(JoinType::Inner)
+                            .suffix(format!(":{}", r_name))
+                            .coalesce(JoinCoalesce::KeepColumns)
+                            .finish()
+                    };
+                    (r_name, right_schema)
+                },
+            };
+            self.track_joined_aliases(&mut lf, &r_name, &left_schema, &right_schema)?;
+            relation_names.insert(r_name.clone());
+            lf = self.execute_joins(lf, &r_name, &tbl_expr.joins, relation_names.clone())?;
+            relation_names.extend(table_with_joins_names(&tbl_expr));
+        }
+        let selection = conjunction(predicates.into_iter().cloned().collect());
+        Ok((lf, selection))
+    }
+
+    /// Track the columns of a joined relation that are aliased in the joined frame (as
+    /// `name:relation`), as they exist in both sides of the join.
+    fn track_joined_aliases(
+        &mut self,
+        lf: &mut LazyFrame,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+    ) -> PolarsResult<()> {
+        let joined_schema = self.get_frame_schema(lf)?;
+
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{}:{}", name, r_name);
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+        Ok(())
     }
 
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
-        let mut lf = if select_stmt.from.is_empty() {
-            DataFrame::empty().lazy()
-        } else {
-            // Note: implicit joins need more work to support properly,
-            // explicit joins are preferred for now (ref: #16662)
-            let from = select_stmt.clone().from;
-            if from.len() > 1 {
-                polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
-            }
-            self.execute_from_statement(from.first().unwrap())?
+        // Named windows (WINDOW clause) and decorrelated subqueries are only visible in
+        // the SELECT that defines them.
+        let named_windows = self.resolve_named_windows(&select_stmt.named_window)?;
+        let outer_windows = std::mem::replace(&mut self.named_windows, named_windows);
+        let outer_subqueries = std::mem::take(&mut self.decorrelated_subqueries);
+        let res = self.process_select(select_stmt, query);
+        self.named_windows = outer_windows;
+        self.decorrelated_subqueries = outer_subqueries;
+        res
+    }
+
+    fn process_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
+        let (mut lf, selection) = match select_stmt.from.as_slice() {
+            [] => (DataFrame::empty().lazy(), select_stmt.selection.clone()),
+            [tbl_expr] => (
+                self.execute_from_statement(tbl_expr)?,
+                select_stmt.selection.clone(),
+            ),
+            // implicit joins consume the WHERE predicates that serve as join keys
+            from => self.execute_implicit_joins(from, select_stmt.selection.as_ref())?,
         };
 
+        // Subqueries in the WHERE and SELECT clauses are joined (as hidden columns)
+        lf = self.decorrelate_subqueries(lf, select_stmt)?;
+
         // Filter expression (WHERE clause)
         let schema = self.get_frame_schema(&mut lf)?;
-        lf = self.process_where(lf, &select_stmt.selection)?;
+        lf = self.process_where(lf, &selection)?;
 
         // 'SELECT *' modifiers
         let mut select_modifiers = SelectModifiers {
@@ -657,32 +1349,28 @@ impl SQLContext {
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets: Option<Vec<Vec<Expr>>> = None;
         match &select_stmt.group_by {
+            // "GROUP BY ROLLUP|CUBE|GROUPING SETS (...)" syntax (optionally combined with
+            // standard group keys), and the "GROUP BY x, y, z WITH ROLLUP|CUBE" modifiers
+            GroupByExpr::Expressions(group_by_exprs, modifiers)
+                if !modifiers.is_empty() || group_by_exprs.iter().any(is_grouping_sets_expr) =>
+            {
+                let elements = group_by_exprs
+                    .iter()
+                    .map(|e| self.expr_to_grouping_sets(e, &projections, &schema))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                grouping_sets = Some(apply_group_by_modifiers(elements, modifiers)?);
+            },
             // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
-            GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
+            GroupByExpr::Expressions(group_by_exprs, _) => {
                 // translate the group expressions, allowing ordinal values
-                group_by_keys = group_by_exprs
-                    .iter()
-                    .map(|e| {
-                        self.expr_or_ordinal(
-                            e,
-                            &projections,
-                            None,
-                            Some(schema.deref()),
-                            "GROUP BY",
-                        )
-                    })
-                    .collect::<PolarsResult<_>>()?
+                group_by_keys =
+                    self.group_by_exprs_or_ordinals(group_by_exprs, &projections, &schema)?;
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
             GroupByExpr::All(modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
                 projections.iter().for_each(|expr| match expr {
                     // immediately match the most common cases (col|agg|len|lit, optionally aliased).
                     Expr::Agg(_) | Expr::Len | Expr::Literal(_) => (),
@@ -705,10 +1393,31 @@ impl SQLContext {
                         }
                     },
                 });
+                if !modifiers.is_empty() {
+                    let elements = group_by_keys.drain(..).map(|e| vec![vec![e]]).collect();
+                    grouping_sets = Some(apply_group_by_modifiers(elements, modifiers)?);
+                }
             },
         };
 
-        lf = if group_by_keys.is_empty() {
+        // A query that only groups by the empty set is a plain aggregation.
+        if matches!(grouping_sets.as_deref(), Some([set]) if set.is_empty()) {
+            grouping_sets = None;
+        }
+
+        // The GROUPING function depends on the grouping set of each row, so plain
+        // group keys are treated as a single grouping set when it is used.
+        if uses_grouping_function(&projections) {
+            if grouping_sets.is_none() && !group_by_keys.is_empty() {
+                grouping_sets = Some(vec![std::mem::take(&mut group_by_keys)]);
+            }
+            polars_ensure!(
+                grouping_sets.is_some(),
+                SQLSyntax: "GROUPING function is only valid in a query with GROUP BY"
+            );
+        }
+
+        lf = if group_by_keys.is_empty() && grouping_sets.is_none() {
             // The 'having' clause is only valid inside 'group by'
             if select_stmt.having.is_some() {
                 polars_bail!(SQLSyntax: "HAVING clause not valid outside of GROUP BY; found:\n{:?}", select_stmt.having);
@@ -717,11 +1426,12 @@ impl SQLContext {
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
             let have_order_by = query.order_by.is_some();
+            let have_qualify = select_stmt.qualify.is_some();
 
-            // Note: if there is an 'order by' then we project everything (original cols
-            // and new projections) and *then* select the final cols; the retained cols
-            // are used to ensure a correct final projection. If there's no 'order by',
-            // clause then we can project the final column *expressions* directly.
+            // Note: if there is an 'order by' (or 'qualify') then we project everything
+            // (original cols and new projections) and *then* select the final cols; the
+            // retained cols are used to ensure a correct final projection. If there's no
+            // 'order by', clause then we can project the final column *expressions* directly.
             for p in projections.iter() {
                 let name = p
                     .to_field(schema.deref(), Context::Default)?
@@ -730,7 +1440,7 @@ impl SQLContext {
                 if select_modifiers.matches_ilike(&name)
                     && !select_modifiers.exclude.contains(&name)
                 {
-                    retained_cols.push(if have_order_by {
+                    retained_cols.push(if have_order_by || have_qualify {
                         col(name.as_str())
                     } else {
                         p.clone()
@@ -739,7 +1449,7 @@ impl SQLContext {
             }
 
             // Apply the remaining modifiers and establish the final projection
-            if have_order_by {
+            if have_order_by || have_qualify {
                 lf = lf.with_columns(projections);
             }
             if !select_modifiers.replace.is_empty() {
@@ -749,6 +1459,9 @@ impl SQLContext {
                 lf = lf.with_columns(select_modifiers.renamed_cols());
             }
 
+            // Apply optional 'qualify' clause, after the (window) projections.
+            lf = self.process_where(lf, &select_stmt.qualify)?;
+
             lf = self.process_order_by(lf, &query.order_by, Some(&retained_cols))?;
             lf = lf.select(retained_cols);
 
@@ -761,16 +1474,22 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf = match &grouping_sets {
+                Some(sets) => self.process_grouping_sets(lf, sets, &projections)?,
+                None => self.process_group_by(lf, &group_by_keys, &projections)?,
+            };
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
             let schema = Some(self.get_frame_schema(&mut lf)?);
-            match select_stmt.having.as_ref() {
+            lf = match select_stmt.having.as_ref() {
                 Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
                 None => lf,
-            }
-        };
+            };
+
+            // Apply optional 'qualify' clause, post-aggregation (and post-'having').
+            self.process_where(lf, &select_stmt.qualify)?
+        };
 
         // Apply optional DISTINCT clause.
         lf = match &select_stmt.distinct {
@@ -826,6 +1545,7 @@ impl SQLContext {
                 SelectItem::Wildcard(wildcard_options) => {
                     let cols = schema
                         .iter_names()
+                        .filter(|name| !name.starts_with(SUBQUERY_COLUMN_PREFIX))
                         .map(|name| col(name.clone()))
                         .collect::<Vec<_>>();
 
@@ -932,6 +1652,296 @@ impl SQLContext {
         }
     }
 
+    /// Get the expression that replaces a subquery of the current SELECT that was
+    /// decorrelated into a join (see [`SQLContext::decorrelate_subqueries`]).
+    pub(crate) fn decorrelated_subquery(&self, expr: &SQLExpr) -> Option<Expr> {
+        subquery_key(expr).and_then(|key| self.decorrelated_subqueries.get(&key).cloned())
+    }
+
+    /// Decorrelate the EXISTS, scalar and correlated IN subqueries of the WHERE and SELECT
+    /// clauses, left-joining their results to the frame as hidden columns; the subquery
+    /// expressions then resolve to (an expression on) these columns.
+    fn decorrelate_subqueries(
+        &mut self,
+        mut lf: LazyFrame,
+        select_stmt: &Select,
+    ) -> PolarsResult<LazyFrame> {
+        let mut subqueries = vec![];
+        let projected = select_stmt.projection.iter().filter_map(select_item_expr);
+        for expr in select_stmt.selection.iter().chain(projected) {
+            walk_sql_expr(expr, &mut |e| {
+                if subquery_key(e).is_some() {
+                    subqueries.push(e)
+                }
+            });
+        }
+        if subqueries.is_empty() {
+            return Ok(lf);
+        }
+        let relation_names = select_stmt
+            .from
+            .iter()
+            .flat_map(table_with_joins_names)
+            .collect::<PlHashSet<_>>();
+
+        for expr in subqueries {
+            let key = subquery_key(expr).unwrap();
+            if self.decorrelated_subqueries.contains_key(&key) {
+                continue;
+            }
+            let name = format_pl_smallstr!(
+                "{}{}",
+                SUBQUERY_COLUMN_PREFIX,
+                self.decorrelated_subqueries.len()
+            );
+            let (subquery, kind) = match expr {
+                SQLExpr::Exists { subquery, .. } => (subquery, SubqueryKind::Exists),
+                SQLExpr::Subquery(subquery) => (subquery, SubqueryKind::Scalar),
+                SQLExpr::InSubquery { expr, subquery, .. } => {
+                    // uncorrelated IN subqueries are evaluated as a (semi-join) context
+                    if !is_correlated(subquery, &relation_names) {
+                        continue;
+                    }
+                    (subquery, SubqueryKind::In(expr))
+                },
+                _ => unreachable!(),
+            };
+            let subquery = self.decorrelate_subquery(subquery, &relation_names, kind)?;
+            let mut frame = subquery.frame;
+            let schema = self.get_frame_schema(&mut frame)?;
+            let value = match kind {
+                SubqueryKind::Exists | SubqueryKind::In(_) => lit(true),
+                _ => {
+                    if schema.len() != subquery.key_names.len() + 1 {
+                        polars_bail!(SQLSyntax: "SQL subquery returns more than one column")
+                    }
+                    col(schema.get_at_index(0).unwrap().0.clone())
+                },
+            };
+            let columns = subquery
+                .key_names
+                .iter()
+                .map(|key| col(key.clone()))
+                .chain([value.alias(name.clone())])
+                .collect::<Vec<_>>();
+            let mut frame = frame.select(columns);
+            if matches!(kind, SubqueryKind::Scalar) && !subquery.aggregated {
+                frame = frame.filter(single_row_check(&subquery.key_names));
+            }
+            let matched = format_pl_smallstr!("{}:matched", name);
+            if subquery.empty.is_some() {
+                frame = frame.with_column(lit(true).alias(matched.clone()));
+            }
+            let empty = subquery.empty.clone();
+            let subquery = DecorrelatedSubquery { frame, ..subquery };
+            let replacement = match kind {
+                SubqueryKind::Exists | SubqueryKind::In(_) => col(name.clone()).is_not_null(),
+                _ => col(name.clone()),
+            };
+            lf = self.join_decorrelated(lf, subquery, JoinType::Left, &name)?;
+            if let Some(mut empty) = empty {
+                // the keys without rows get the result of the subquery over no rows
+                let empty_schema = self.get_frame_schema(&mut empty)?;
+                let empty_name = format_pl_smallstr!("{}:empty", name);
+                let value = col(empty_schema.get_at_index(0).unwrap().0.clone());
+                lf = lf
+                    .cross_join(empty.select([value.alias(empty_name.clone())]), None)
+                    .with_column(
+                        when(col(matched.clone()).is_null())
+                            .then(col(empty_name.clone()))
+                            .otherwise(col(name.clone()))
+                            .alias(name.clone()),
+                    )
+                    .drop([matched, empty_name]);
+            }
+            self.decorrelated_subqueries.insert(key, replacement);
+        }
+        Ok(lf)
+    }
+
+    /// Decorrelate a LATERAL subquery that is joined to the given relations.
+    fn decorrelate_lateral(
+        &mut self,
+        subquery: &Query,
+        alias: Option<&TableAlias>,
+        relation_names: &PlHashSet<String>,
+    ) -> PolarsResult<(String, DecorrelatedSubquery)> {
+        let Some(alias) = alias else {
+            polars_bail!(SQLSyntax: "derived tables must have aliases");
+        };
+        let mut subquery =
+            self.decorrelate_subquery(subquery, relation_names, SubqueryKind::Lateral)?;
+        if !alias.columns.is_empty() {
+            let schema = self.get_frame_schema(&mut subquery.frame)?;
+            let width = schema.len() - subquery.key_names.len();
+            if alias.columns.len() != width {
+                polars_bail!(
+                    SQLSyntax: "number of columns ({}) in alias '{}' does not match the number of columns in the table/query ({})",
+                    alias.columns.len(), alias.name.value, width
+                )
+            }
+            let existing_columns = schema.iter_names().take(width).cloned().collect::<Vec<_>>();
+            let new_columns = alias.columns.iter().map(|c| c.value.clone());
+            subquery.frame = subquery.frame.rename(existing_columns, new_columns, true);
+        }
+        let name = alias.name.value.clone();
+        self.table_map.insert(name.clone(), subquery.frame.clone());
+        Ok((name, subquery))
+    }
+
+    /// Decorrelate a subquery into a frame with the result of the subquery for every value
+    /// of the (outer) expressions that its WHERE clause compares with (with `=`); these
+    /// values are added as key columns. A subquery that is not correlated gets a single
+    /// constant key instead, so that it can be joined in the same way.
+    fn decorrelate_subquery(
+        &mut self,
+        subquery: &Query,
+        relation_names: &PlHashSet<String>,
+        kind: SubqueryKind,
+    ) -> PolarsResult<DecorrelatedSubquery> {
+        if subquery.with.is_some() {
+            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
+        }
+        let mut query = subquery.clone();
+        let mut correlation = vec![];
+        let mut aggregated = false;
+        let mut empty = None;
+        if let SetExpr::Select(select) = &*subquery.body {
+            let mut select = select.clone();
+            let (selection, pairs) = split_correlated_predicates(&select, relation_names)?;
+            select.selection = selection;
+            correlation = pairs;
+
+            let projection = select
+                .projection
+                .iter()
+                .filter_map(select_item_expr)
+                .collect::<Vec<_>>();
+            if let SubqueryKind::In(expr) = kind {
+                match projection.as_slice() {
+                    [inner] => correlation.push(((*inner).clone(), expr.clone())),
+                    _ => polars_bail!(SQLSyntax: "SQL subquery returns more than one column"),
+                }
+            }
+            let has_group_by = !matches!(
+                &select.group_by,
+                GroupByExpr::Expressions(exprs, _) if exprs.is_empty()
+            );
+            aggregated = !has_group_by
+                && matches!(kind, SubqueryKind::Scalar | SubqueryKind::Lateral)
+                && projection.iter().any(|e| has_aggregate(e, self));
+
+            if !correlation.is_empty() {
+                if query.limit.is_some() || query.offset.is_some() || query.fetch.is_some() {
+                    polars_bail!(SQLInterface: "LIMIT and OFFSET are not supported in correlated subqueries")
+                }
+                // the result for the keys without rows (which the grouped result lacks),
+                // as an aggregate over no rows need not be NULL (eg: COUNT or COALESCE(SUM))
+                if aggregated && matches!(kind, SubqueryKind::Scalar) {
+                    let mut empty_select = select.clone();
+                    empty_select.selection = Some(SQLExpr::Value(SQLValue::Boolean(false)));
+                    let mut empty_query = query.clone();
+                    empty_query.body = Box::new(SetExpr::Select(empty_select));
+                    empty = Some(self.execute_query_no_ctes(&empty_query)?);
+                }
+                if has_group_by && matches!(kind, SubqueryKind::Scalar) {
+                    polars_bail!(SQLInterface: "GROUP BY is not supported in correlated scalar subqueries")
+                }
+                let keys = correlation.iter().enumerate().map(|(i, (inner, _))| {
+                    SelectItem::ExprWithAlias {
+                        expr: inner.clone(),
+                        alias: Ident::new(subquery_key_name(i).as_str()),
+                    }
+                });
+                match kind {
+                    SubqueryKind::Exists | SubqueryKind::In(_) => {
+                        select.projection = keys.collect();
+                        select.distinct = Some(Distinct::Distinct);
+                    },
+                    SubqueryKind::Scalar | SubqueryKind::Lateral => {
+                        select.projection.extend(keys);
+                    },
+                }
+                // each (group of each) value of the keys is aggregated separately
+                if has_group_by || aggregated {
+                    if let GroupByExpr::Expressions(exprs, _) = &mut select.group_by {
+                        exprs.extend(correlation.iter().map(|(inner, _)| inner.clone()));
+                    }
+                }
+            }
+            query.body = Box::new(SetExpr::Select(select));
+        }
+
+        let mut frame = self.execute_query_no_ctes(&query)?;
+        let (key_names, outer_keys) = if correlation.is_empty() {
+            if !matches!(kind, SubqueryKind::Scalar | SubqueryKind::Lateral) {
+                frame = frame.limit(1);
+            }
+            let key = subquery_key_name(0);
+            frame = frame.with_column(lit(true).alias(key.clone()));
+            (vec![key], vec![SQLExpr::Value(SQLValue::Boolean(true))])
+        } else {
+            correlation
+                .into_iter()
+                .enumerate()
+                .map(|(i, (_, outer))| (subquery_key_name(i), outer))
+                .unzip()
+        };
+        Ok(DecorrelatedSubquery {
+            frame,
+            key_names,
+            outer_keys,
+            aggregated,
+            empty,
+        })
+    }
+
+    /// Join a decorrelated subquery to a frame on its key columns.
+    fn join_decorrelated(
+        &mut self,
+        mut lf: LazyFrame,
+        subquery: DecorrelatedSubquery,
+        join_type: JoinType,
+        name: &str,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let mut frame = subquery.frame;
+        let frame_schema = self.get_frame_schema(&mut frame)?;
+        let outer_keys = subquery
+            .outer_keys
+            .iter()
+            .zip(&subquery.key_names)
+            .map(|(expr, key)| {
+                let dtype = frame_schema.try_get(key)?.clone();
+                Ok(parse_sql_expr(expr, self, Some(schema.as_ref()))?
+                    .cast(dtype)
+                    .alias(key.clone()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let keys = subquery
+            .key_names
+            .iter()
+            .map(|key| col(key.clone()))
+            .collect::<Vec<_>>();
+        // an aggregate without GROUP BY returns exactly one row, even without input rows
+        let join_type = if subquery.aggregated {
+            JoinType::Left
+        } else {
+            join_type
+        };
+        Ok(lf
+            .with_columns(outer_keys)
+            .join_builder()
+            .with(frame)
+            .left_on(keys.clone())
+            .right_on(keys)
+            .how(join_type)
+            .suffix(format!(":{}", name))
+            .coalesce(JoinCoalesce::CoalesceColumns)
+            .finish()
+            .drop(subquery.key_names))
+    }
+
     fn execute_create_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::CreateTable(CreateTable {
             if_not_exists,
@@ -963,6 +1973,221 @@ impl SQLContext {
         }
     }
 
+    fn execute_create_view(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::CreateView {
+            or_replace,
+            materialized,
+            name,
+            columns,
+            query,
+            if_not_exists,
+            ..
+        } = stmt
+        {
+            polars_ensure!(!materialized, SQLInterface: "materialized views are not supported");
+            let view_name = name.to_string();
+            let response = df! {
+                "Response" => ["CREATE VIEW"]
+            }
+            .unwrap()
+            .lazy();
+
+            // CREATE VIEW IF NOT EXISTS / CREATE OR REPLACE VIEW
+            if self.table_map.contains_key(&view_name) {
+                if *if_not_exists {
+                    return Ok(response);
+                }
+                polars_ensure!(
+                    *or_replace && self.views.contains_key(&view_name),
+                    SQLInterface: "relation '{}' already exists", view_name
+                );
+            }
+            let view = View {
+                query: query.clone(),
+                alias: TableAlias {
+                    name: Ident::new(view_name.as_str()),
+                    columns: columns.iter().map(|c| c.name.clone()).collect(),
+                },
+            };
+            // plan the view to validate it (and to register its current plan as a table)
+            let mut lf = self.execute_view(&view)?;
+            self.get_frame_schema(&mut lf)?;
+            self.register(&view_name, lf);
+            self.views.insert(view_name, view);
+            Ok(response)
+        } else {
+            unreachable!()
+        }
+    }
+
+    // INSERT INTO tbl [(cols)] { SELECT ... | VALUES ... }
+    fn execute_insert(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Insert(Insert {
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            on,
+            returning,
+            ..
+        }) = stmt
+        {
+            if *overwrite || partitioned.is_some() || on.is_some() || returning.is_some() {
+                polars_bail!(SQLInterface: "INSERT only supports inserting the rows of a query into a table");
+            }
+            let Some(source) = source else {
+                polars_bail!(SQLInterface: "INSERT requires a SELECT or VALUES clause")
+            };
+            let tbl_name = table_name.to_string();
+            let mut lf = self.get_modifiable_table(&tbl_name)?;
+            let schema = self.get_frame_schema(&mut lf)?;
+
+            let mut inserted = self.execute_query(source)?;
+            let inserted_schema = self.get_frame_schema(&mut inserted)?;
+            let target_names = if columns.is_empty() {
+                schema
+                    .iter_names()
+                    .map(|name| name.as_str())
+                    .collect::<Vec<_>>()
+            } else {
+                columns.iter().map(|c| c.value.as_str()).collect()
+            };
+            polars_ensure!(
+                target_names.len() == inserted_schema.len(),
+                SQLInterface: "INSERT has {} target columns, but the inserted rows have {}",
+                target_names.len(), inserted_schema.len()
+            );
+
+            // insert the rows positionally, casting them to the table schema (any
+            // columns that are not given are set to null)
+            let mut values = PlHashMap::with_capacity(target_names.len());
+            for (tgt, src) in target_names.iter().zip(inserted_schema.iter_names()) {
+                polars_ensure!(
+                    schema.contains(tgt),
+                    SQLInterface: "column '{}' does not exist in table '{}'", tgt, tbl_name
+                );
+                polars_ensure!(
+                    values.insert(*tgt, col(src.clone())).is_none(),
+                    SQLInterface: "column '{}' is specified more than once", tgt
+                );
+            }
+            let exprs = schema
+                .iter()
+                .map(|(name, dtype)| {
+                    values
+                        .remove(name.as_str())
+                        .unwrap_or(Expr::Literal(LiteralValue::Null))
+                        .strict_cast(dtype.clone())
+                        .alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+
+            // the table stays lazy, so values that cannot be cast raise when it is
+            // collected; as its plan grows with each modification, the node is cached
+            // so that it is only computed once in a query that refers to it repeatedly
+            let lf =
+                polars_lazy::dsl::concat(vec![lf, inserted.select(exprs)], UnionArgs::default())?
+                    .cache();
+            self.register(&tbl_name, lf);
+            Ok(df! {
+                "Response" => ["INSERT"]
+            }
+            .unwrap()
+            .lazy())
+        } else {
+            unreachable!()
+        }
+    }
+
+    // DELETE FROM tbl [WHERE ...]
+    fn execute_delete(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Delete(Delete {
+            tables,
+            from,
+            using,
+            selection,
+            returning,
+            order_by,
+            limit,
+        }) = stmt
+        {
+            if !tables.is_empty()
+                || using.is_some()
+                || returning.is_some()
+                || !order_by.is_empty()
+                || limit.is_some()
+            {
+                polars_bail!(SQLInterface: "DELETE only supports deleting the rows of a single table that match a WHERE clause");
+            }
+            let (FromTable::WithFromKeyword(from) | FromTable::WithoutKeyword(from)) = from;
+            let [TableWithJoins {
+                relation: relation @ TableFactor::Table { name, .. },
+                joins,
+            }] = from.as_slice()
+            else {
+                polars_bail!(SQLInterface: "DELETE only supports deleting from a single table");
+            };
+            polars_ensure!(joins.is_empty(), SQLInterface: "DELETE does not support joins");
+
+            let tbl_name = name.to_string();
+            let _ = self.get_modifiable_table(&tbl_name)?;
+            let (_, mut lf) = self.get_table(relation)?;
+            let schema = self.get_frame_schema(&mut lf)?;
+            let lf = match selection {
+                None => DataFrame::empty_with_schema(schema.as_ref()).lazy(),
+                Some(expr) => {
+                    let mut predicate = parse_sql_expr(expr, self, Some(schema.as_ref()))?;
+                    lf = self.process_subqueries(lf, vec![&mut predicate]);
+                    // keep the rows for which the predicate is not true (ie: false or null)
+                    lf.filter(predicate.fill_null(lit(false)).not())
+                },
+            };
+            // lazy (and cached), as with INSERT
+            self.register(&tbl_name, lf.cache());
+            Ok(df! {
+                "Response" => ["DELETE"]
+            }
+            .unwrap()
+            .lazy())
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Plan the query of a view, in a scope of its own (so that it does not see the CTEs
+    /// and aliases of the query that refers to it).
+    fn execute_view(&mut self, view: &View) -> PolarsResult<LazyFrame> {
+        let name = &view.alias.name.value;
+        polars_ensure!(
+            self.expanding_views.borrow_mut().insert(name.clone()),
+            SQLInterface: "infinite recursion detected in view '{}'", name
+        );
+        let cte_map = self.cte_map.take();
+        let table_aliases = self.table_aliases.take();
+        let joined_aliases = self.joined_aliases.take();
+        let res = self
+            .execute_query(&view.query)
+            .and_then(|lf| self.rename_columns_from_table_alias(lf, &view.alias));
+        self.cte_map.replace(cte_map);
+        self.table_aliases.replace(table_aliases);
+        self.joined_aliases.replace(joined_aliases);
+        self.expanding_views.borrow_mut().remove(name);
+        res
+    }
+
+    /// Get a registered table that can be modified (eg: by INSERT or DELETE); views cannot be.
+    fn get_modifiable_table(&self, name: &str) -> PolarsResult<LazyFrame> {
+        polars_ensure!(
+            !self.views.contains_key(name),
+            SQLInterface: "cannot modify view '{}'", name
+        );
+        self.table_map
+            .get(name)
+            .cloned()
+            .ok_or_else(|| polars_err!(SQLInterface: "table '{}' does not exist", name))
+    }
+
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
@@ -972,7 +2197,11 @@ impl SQLContext {
                     return self.execute_table_function(name, alias, args);
                 }
                 let tbl_name = name.0.first().unwrap().value.as_str();
-                if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
+                let lf = match self.views.get(tbl_name).cloned() {
+                    Some(view) => Some(self.execute_view(&view)?),
+                    None => self.get_table_from_current_scope(tbl_name),
+                };
+                if let Some(lf) = lf {
                     match alias {
                         Some(alias) => {
                             self.table_aliases
@@ -991,7 +2220,7 @@ impl SQLContext {
                 subquery,
                 alias,
             } => {
-                polars_ensure!(!(*lateral), SQLInterface: "LATERAL not supported");
+                polars_ensure!(!(*lateral), SQLInterface: "LATERAL subqueries must be the right side of a JOIN");
                 if let Some(alias) = alias {
                     let mut lf = self.execute_query_no_ctes(subquery)?;
                     lf = self.rename_columns_from_table_alias(lf, alias)?;
@@ -1063,6 +2292,37 @@ impl SQLContext {
                     polars_bail!(SQLSyntax: "UNNEST table must have an alias");
                 }
             },
+            #[cfg(feature = "pivot")]
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (tbl_name, lf) = self.get_table(table)?;
+                let lf = self.execute_pivot(
+                    lf,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null.as_ref(),
+                )?;
+                self.alias_table_operator(tbl_name, lf, alias)
+            },
+            #[cfg(feature = "pivot")]
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                let (tbl_name, lf) = self.get_table(table)?;
+                let lf = self.execute_unpivot(lf, value, name, columns)?;
+                self.alias_table_operator(tbl_name, lf, alias)
+            },
             TableFactor::NestedJoin {
                 table_with_joins,
                 alias,
@@ -1097,6 +2357,145 @@ impl SQLContext {
         Ok((tbl_name, lf))
     }
 
+    /// Register the result of a table operator (such as PIVOT) under its alias, if any;
+    /// otherwise it keeps the name of the table it operates on.
+    #[cfg(feature = "pivot")]
+    fn alias_table_operator(
+        &mut self,
+        tbl_name: String,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((tbl_name, lf)),
+        }
+    }
+
+    /// Execute a PIVOT table operator. The pivot values must be listed explicitly (so that
+    /// the output schema is known without materializing the table); each aggregate is then
+    /// evaluated over the rows with a given pivot value, grouped by the remaining columns.
+    #[cfg(feature = "pivot")]
+    fn execute_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let PivotValueSource::List(values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT requires an explicit list of values; found IN ({})", value_source)
+        };
+        let [pivot_column] = value_column else {
+            polars_bail!(SQLSyntax: "PIVOT expects a single column name; found {}", ObjectName(value_column.to_vec()))
+        };
+        let pivot_column = pivot_column.value.as_str();
+        let schema = self.get_frame_schema(&mut lf)?;
+        if !schema.contains(pivot_column) {
+            polars_bail!(SQLInterface: "PIVOT column '{}' was not found", pivot_column)
+        }
+
+        let aggregates = aggregate_functions
+            .iter()
+            .map(|agg| Ok((parse_sql_expr(&agg.expr, self, Some(schema.as_ref()))?, agg)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let default = default_on_null
+            .map(|expr| parse_sql_expr(expr, self, Some(schema.as_ref())))
+            .transpose()?;
+
+        // the table is grouped by the columns that are neither pivoted nor aggregated
+        let mut excluded = aggregates
+            .iter()
+            .flat_map(|(expr, _)| expr_to_leaf_column_names(expr))
+            .collect::<PlHashSet<_>>();
+        excluded.insert(PlSmallStr::from_str(pivot_column));
+        let index = schema
+            .iter_names()
+            .filter(|name| !excluded.contains(*name))
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+
+        let mut pivoted = Vec::with_capacity(values.len() * aggregates.len());
+        for value in values {
+            let is_value =
+                col(pivot_column).eq(parse_sql_expr(&value.expr, self, Some(schema.as_ref()))?);
+            let value_name = match (&value.alias, &value.expr) {
+                (Some(alias), _) => alias.value.clone(),
+                (None, SQLExpr::Value(SQLValue::SingleQuotedString(s))) => s.clone(),
+                (None, expr) => expr.to_string(),
+            };
+            for (expr, agg) in &aggregates {
+                // restrict the (columns of the) aggregate to the rows with the pivot value;
+                // as with a regular pivot, the result is null if there are no such rows
+                let expr = expr.clone().map_expr(|e| match e {
+                    Expr::Column(name) => col(name).filter(is_value.clone()),
+                    Expr::Len => is_value.clone().sum(),
+                    e => e,
+                });
+                let mut expr = when(is_value.clone().any(false))
+                    .then(expr)
+                    .otherwise(lit(LiteralValue::Null));
+                if let Some(default) = &default {
+                    expr = expr.fill_null(default.clone());
+                }
+                // with multiple aggregates, the columns are named "{value}_{aggregate}"
+                let name = match &agg.alias {
+                    Some(alias) => format!("{}_{}", value_name, alias.value),
+                    None if aggregates.len() == 1 => value_name.clone(),
+                    None => format!("{}_{}", value_name, agg.expr),
+                };
+                pivoted.push(expr.alias(name));
+            }
+        }
+        Ok(if index.is_empty() {
+            lf.select(pivoted)
+        } else {
+            lf.group_by_stable(index).agg(pivoted)
+        })
+    }
+
+    /// Execute an UNPIVOT table operator, unpivoting the given columns into `name` (the
+    /// column names) and `value` (the column values) columns.
+    #[cfg(feature = "pivot")]
+    fn execute_unpivot(
+        &mut self,
+        mut lf: LazyFrame,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let on = columns
+            .iter()
+            .map(|c| c.value.as_str())
+            .collect::<PlHashSet<_>>();
+        if let Some(missing) = on.iter().find(|&&c| !schema.contains(c)) {
+            polars_bail!(SQLInterface: "UNPIVOT column '{}' was not found", missing)
+        }
+        let args = UnpivotArgsDSL {
+            on: columns
+                .iter()
+                .map(|c| Selector::from(c.value.as_str()))
+                .collect(),
+            index: schema
+                .iter_names()
+                .filter(|name| !on.contains(name.as_str()))
+                .map(|name| Selector::from(name.clone()))
+                .collect(),
+            variable_name: Some(PlSmallStr::from_str(name.value.as_str())),
+            value_name: Some(PlSmallStr::from_str(value.value.as_str())),
+        };
+        // as in other engines, rows with a null value are excluded
+        Ok(lf
+            .unpivot(args)
+            .filter(col(value.value.as_str()).is_not_null()))
+    }
+
     fn process_order_by(
         &mut self,
         mut lf: LazyFrame,
@@ -1128,20 +2527,23 @@ impl SQLContext {
         } else {
             let columns = &columns_iter.collect::<Vec<_>>();
             for ob in order_by {
+                // translate order expression, allowing ordinal values
+                let expr =
+                    self.expr_or_ordinal(&ob.expr, columns, selected, Some(&schema), "ORDER BY")?;
+                // a constant (such as a placeholder, whose value is bound after planning
+                // a prepared statement) does not order the rows
+                if matches!(expr.clone().meta().undo_aliases(), Expr::Literal(_)) {
+                    continue;
+                }
                 // note: if not specified 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
                 // https://www.postgresql.org/docs/current/queries-order.html
                 let desc_order = !ob.asc.unwrap_or(true);
                 nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
                 descending.push(desc_order);
-
-                // translate order expression, allowing ordinal values
-                by.push(self.expr_or_ordinal(
-                    &ob.expr,
-                    columns,
-                    selected,
-                    Some(&schema),
-                    "ORDER BY",
-                )?)
+                by.push(expr);
+            }
+            if by.is_empty() {
+                return Ok(lf);
             }
         }
         Ok(lf.sort_by_exprs(
@@ -1239,6 +2641,115 @@ impl SQLContext {
         Ok(aggregated.select(&final_projection))
     }
 
+    fn group_by_exprs_or_ordinals(
+        &mut self,
+        exprs: &[SQLExpr],
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<Vec<Expr>> {
+        exprs
+            .iter()
+            .map(|e| self.expr_or_ordinal(e, projections, None, Some(schema), "GROUP BY"))
+            .collect()
+    }
+
+    /// Translate a single GROUP BY element into the grouping sets that it represents; a
+    /// plain group key is a single grouping set that contains only that key.
+    fn expr_to_grouping_sets(
+        &mut self,
+        expr: &SQLExpr,
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<Vec<Vec<Expr>>> {
+        Ok(match expr {
+            SQLExpr::Rollup(elements) | SQLExpr::Cube(elements) => {
+                let elements = elements
+                    .iter()
+                    .map(|exprs| self.group_by_exprs_or_ordinals(exprs, projections, schema))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                if matches!(expr, SQLExpr::Rollup(_)) {
+                    rollup(elements)
+                } else {
+                    cube(elements)
+                }
+            },
+            SQLExpr::GroupingSets(sets) => sets
+                .iter()
+                .map(|exprs| self.group_by_exprs_or_ordinals(exprs, projections, schema))
+                .collect::<PolarsResult<_>>()?,
+            SQLExpr::Tuple(exprs) => {
+                vec![self.group_by_exprs_or_ordinals(exprs, projections, schema)?]
+            },
+            _ => vec![self.group_by_exprs_or_ordinals(
+                std::slice::from_ref(expr),
+                projections,
+                schema,
+            )?],
+        })
+    }
+
+    /// Aggregate each grouping set separately and combine the results; keys that are not
+    /// part of a grouping set are NULL in the rows that it produces.
+    fn process_grouping_sets(
+        &mut self,
+        mut lf: LazyFrame,
+        grouping_sets: &[Vec<Expr>],
+        projections: &[Expr],
+    ) -> PolarsResult<LazyFrame> {
+        let schema_before = self.get_frame_schema(&mut lf)?;
+
+        // Every distinct key, in order of first appearance.
+        let mut keys: Vec<Expr> = Vec::new();
+        for key in grouping_sets.iter().flatten() {
+            if !keys.contains(key) {
+                keys.push(key.clone());
+            }
+        }
+        let keys_schema = expressions_to_schema(&keys, &schema_before, Context::Default)?;
+
+        // GROUPING arguments must refer to one of the keys.
+        for expr in projections {
+            for e in expr {
+                if let Expr::Column(name) = e {
+                    if let Some(key) = name.strip_prefix(GROUPING_INDICATOR_PREFIX) {
+                        polars_ensure!(
+                            keys_schema.contains(key),
+                            SQLSyntax: "GROUPING argument '{}' is not a GROUP BY expression", key
+                        );
+                    }
+                }
+            }
+        }
+
+        let frames = grouping_sets
+            .iter()
+            .map(|set| {
+                if keys.is_empty() {
+                    return Ok(lf.clone().select(projections));
+                }
+                let mut indicators = Vec::with_capacity(keys.len());
+                let mut set_keys = Vec::with_capacity(keys.len() * 2);
+                for (key, (name, dtype)) in keys.iter().zip(keys_schema.iter()) {
+                    let is_grouped = set.contains(key);
+                    let indicator_name = grouping_indicator_name(name);
+                    indicators.push(typed_lit(!is_grouped as i32).alias(indicator_name.clone()));
+                    set_keys.push(if is_grouped {
+                        key.clone()
+                    } else {
+                        lit(LiteralValue::Null)
+                            .cast(dtype.clone())
+                            .alias(name.clone())
+                    });
+                    set_keys.push(col(indicator_name));
+                }
+                let set_lf = lf.clone().with_columns(indicators);
+                self.process_group_by(set_lf, &set_keys, projections)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        polars_lazy::dsl::concat(frames, UnionArgs::default())
+    }
+
     fn process_limit_offset(
         &self,
         lf: LazyFrame,
@@ -1407,6 +2918,729 @@ impl SQLContext {
     }
 }
 
+/// Wrap the LATERAL relations that follow a comma (as in `FROM a, LATERAL (...) t`) in
+/// parentheses, so that they are parsed as a relation of the FROM clause. With trailing
+/// commas, the parser would otherwise end the FROM clause at such a comma, as LATERAL is
+/// a reserved keyword.
+fn parse_comma_laterals(
+    tokens: &mut Vec<TokenWithLocation>,
+    options: &ParserOptions,
+) -> PolarsResult<()> {
+    // the last one is wrapped first, as a LATERAL subquery can contain another one
+    let mut end = tokens.len();
+    while let Some(pos) = tokens[..end]
+        .iter()
+        .rposition(|tok| matches!(&tok.token, Token::Word(w) if w.keyword == Keyword::LATERAL))
+    {
+        end = pos;
+        let follows_comma = tokens[..pos]
+            .iter()
+            .rfind(|tok| !matches!(tok.token, Token::Whitespace(_)))
+            .is_some_and(|tok| tok.token == Token::Comma);
+        if !follows_comma {
+            continue;
+        }
+        let mut parser = Parser::new(&GenericDialect)
+            .with_options(options.clone())
+            .with_tokens_with_locations(tokens[pos..].to_vec());
+        parser.parse_table_factor().map_err(to_sql_interface_err)?;
+        let relation_end = (pos + parser.index()).min(tokens.len());
+
+        let rparen = TokenWithLocation {
+            token: Token::RParen,
+            location: tokens[relation_end - 1].location,
+        };
+        let lparen = TokenWithLocation {
+            token: Token::LParen,
+            location: tokens[pos].location,
+        };
+        tokens.insert(relation_end, rparen);
+        tokens.insert(pos, lparen);
+    }
+    Ok(())
+}
+
+/// Key positional parameter values by their (1-indexed) position.
+fn positional_params(params: &[AnyValue]) -> PlHashMap<String, AnyValue<'static>> {
+    params
+        .iter()
+        .enumerate()
+        .map(|(i, av)| ((i + 1).to_string(), av.clone().into_static()))
+        .collect()
+}
+
+/// Prefix of the aliases that mark the placeholders of a prepared statement's plan.
+const PARAM_PLACEHOLDER_PREFIX: &str = "__POLARS_PARAM_";
+
+fn param_placeholder_name(key: &str) -> PlSmallStr {
+    format_pl_smallstr!("{}{}", PARAM_PLACEHOLDER_PREFIX, key)
+}
+
+/// Bind the values of the parameters to the placeholders of a plan (see
+/// [`SQLContext::get_param_expr`]), raising an error for any placeholder without a value.
+fn bind_params(
+    plan: &DslPlan,
+    params: &PlHashMap<String, AnyValue<'static>>,
+) -> PolarsResult<DslPlan> {
+    let input = |input: &Arc<DslPlan>| bind_params(input, params).map(Arc::new);
+    let inputs = |inputs: &[DslPlan]| {
+        inputs
+            .iter()
+            .map(|i| bind_params(i, params))
+            .collect::<PolarsResult<Vec<_>>>()
+    };
+    let exprs = |exprs: &[Expr]| {
+        exprs
+            .iter()
+            .map(|e| bind_expr_params(e, params))
+            .collect::<PolarsResult<Vec<_>>>()
+    };
+    Ok(match plan {
+        DslPlan::Filter {
+            input: i,
+            predicate,
+        } => DslPlan::Filter {
+            input: input(i)?,
+            predicate: bind_expr_params(predicate, params)?,
+        },
+        DslPlan::Cache { input: i, id } => DslPlan::Cache {
+            input: input(i)?,
+            id: *id,
+        },
+        DslPlan::Select {
+            expr,
+            input: i,
+            options,
+        } => DslPlan::Select {
+            expr: exprs(expr)?,
+            input: input(i)?,
+            options: *options,
+        },
+        DslPlan::GroupBy {
+            input: i,
+            keys,
+            aggs,
+            apply,
+            maintain_order,
+            options,
+        } => DslPlan::GroupBy {
+            input: input(i)?,
+            keys: exprs(keys)?,
+            aggs: exprs(aggs)?,
+            apply: apply.clone(),
+            maintain_order: *maintain_order,
+            options: options.clone(),
+        },
+        DslPlan::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            predicates,
+            options,
+        } => DslPlan::Join {
+            input_left: input(input_left)?,
+            input_right: input(input_right)?,
+            left_on: exprs(left_on)?,
+            right_on: exprs(right_on)?,
+            predicates: exprs(predicates)?,
+            options: options.clone(),
+        },
+        DslPlan::HStack {
+            input: i,
+            exprs: e,
+            options,
+        } => DslPlan::HStack {
+            input: input(i)?,
+            exprs: exprs(e)?,
+            options: *options,
+        },
+        DslPlan::Distinct { input: i, options } => DslPlan::Distinct {
+            input: input(i)?,
+            options: options.clone(),
+        },
+        DslPlan::Sort {
+            input: i,
+            by_column,
+            slice,
+            sort_options,
+        } => DslPlan::Sort {
+            input: input(i)?,
+            by_column: exprs(by_column)?,
+            slice: *slice,
+            sort_options: sort_options.clone(),
+        },
+        DslPlan::Slice {
+            input: i,
+            offset,
+            len,
+        } => DslPlan::Slice {
+            input: input(i)?,
+            offset: *offset,
+            len: *len,
+        },
+        DslPlan::MapFunction { input: i, function } => DslPlan::MapFunction {
+            input: input(i)?,
+            function: bind_function_params(function, params)?,
+        },
+        DslPlan::Union { inputs: i, args } => DslPlan::Union {
+            inputs: inputs(i)?,
+            args: *args,
+        },
+        DslPlan::HConcat { inputs: i, options } => DslPlan::HConcat {
+            inputs: inputs(i)?,
+            options: *options,
+        },
+        DslPlan::ExtContext { input: i, contexts } => DslPlan::ExtContext {
+            input: input(i)?,
+            contexts: inputs(contexts)?,
+        },
+        DslPlan::Sink { input: i, payload } => DslPlan::Sink {
+            input: input(i)?,
+            payload: payload.clone(),
+        },
+        // the resolved plan holds the literals of the placeholders (their aliases are
+        // pruned when resolving), so the bound plan is resolved again from the DSL
+        DslPlan::IR { dsl, .. } => bind_params(dsl, params)?,
+        DslPlan::Scan { .. } | DslPlan::DataFrameScan { .. } => plan.clone(),
+        // python scans (which hold no expressions)
+        #[allow(unreachable_patterns)]
+        _ => plan.clone(),
+    })
+}
+
+fn bind_function_params(
+    function: &DslFunction,
+    params: &PlHashMap<String, AnyValue<'static>>,
+) -> PolarsResult<DslFunction> {
+    Ok(match function {
+        DslFunction::FillNan(e) => DslFunction::FillNan(bind_expr_params(e, params)?),
+        DslFunction::Stats(StatsFunction::Quantile { quantile, method }) => {
+            DslFunction::Stats(StatsFunction::Quantile {
+                quantile: bind_expr_params(quantile, params)?,
+                method: *method,
+            })
+        },
+        DslFunction::Stats(_)
+        | DslFunction::FunctionIR(_)
+        | DslFunction::Explode { .. }
+        | DslFunction::RowIndex { .. }
+        | DslFunction::Rename { .. }
+        | DslFunction::Unnest(_)
+        | DslFunction::Drop(_) => function.clone(),
+        // python functions and UNPIVOT (which hold no expressions)
+        #[allow(unreachable_patterns)]
+        _ => function.clone(),
+    })
+}
+
+fn bind_expr_params(
+    expr: &Expr,
+    params: &PlHashMap<String, AnyValue<'static>>,
+) -> PolarsResult<Expr> {
+    expr.clone().try_map_expr(|e| match e {
+        Expr::Alias(inner, name) if matches!(&*inner, Expr::Literal(_)) => {
+            match name.strip_prefix(PARAM_PLACEHOLDER_PREFIX) {
+                Some(key) => match params.get(key) {
+                    Some(av) => Ok(Expr::Literal(av.clone().into())),
+                    None => polars_bail!(SQLInterface: "no value bound to parameter '{}'", key),
+                },
+                None => Ok(Expr::Alias(inner, name)),
+            }
+        },
+        e => Ok(e),
+    })
+}
+
+/// Prefix of the hidden columns that hold, for each GROUP BY key, whether that key is
+/// aggregated in the grouping set of the current row.
+const GROUPING_INDICATOR_PREFIX: &str = "__POLARS_GROUPING_";
+
+/// Name of the hidden column that is used to evaluate `GROUPING(key)`.
+pub(crate) fn grouping_indicator_name(key: &str) -> PlSmallStr {
+    format_pl_smallstr!("{}{}", GROUPING_INDICATOR_PREFIX, key)
+}
+
+fn uses_grouping_function(exprs: &[Expr]) -> bool {
+    exprs.iter().any(|expr| {
+        has_expr(
+            expr,
+            |e| matches!(e, Expr::Column(name) if name.starts_with(GROUPING_INDICATOR_PREFIX)),
+        )
+    })
+}
+
+fn is_grouping_sets_expr(expr: &SQLExpr) -> bool {
+    match expr {
+        SQLExpr::Rollup(_) | SQLExpr::Cube(_) | SQLExpr::GroupingSets(_) => true,
+        SQLExpr::Tuple(exprs) => exprs.is_empty(),
+        _ => false,
+    }
+}
+
+/// ROLLUP (a, b, c) is equivalent to GROUPING SETS ((a, b, c), (a, b), (a), ()).
+fn rollup(elements: Vec<Vec<Expr>>) -> Vec<Vec<Expr>> {
+    (0..=elements.len())
+        .rev()
+        .map(|n| elements[..n].concat())
+        .collect()
+}
+
+/// CUBE (a, b) is equivalent to GROUPING SETS ((a, b), (a), (b), ()).
+fn cube(elements: Vec<Vec<Expr>>) -> Vec<Vec<Expr>> {
+    let n = elements.len();
+    (0..1usize << n)
+        .rev()
+        .map(|mask| {
+            elements
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << (n - 1 - i)) != 0)
+                .flat_map(|(_, exprs)| exprs.iter().cloned())
+                .collect()
+        })
+        .collect()
+}
+
+/// Combine the grouping sets of every GROUP BY element (the cross product of the sets),
+/// applying any "WITH ROLLUP" or "WITH CUBE" modifier to the plain group keys.
+fn apply_group_by_modifiers(
+    elements: Vec<Vec<Vec<Expr>>>,
+    modifiers: &[GroupByWithModifier],
+) -> PolarsResult<Vec<Vec<Expr>>> {
+    match modifiers {
+        [] => Ok(elements.into_iter().fold(vec![vec![]], |sets, element| {
+            sets.iter()
+                .flat_map(|set| element.iter().map(move |other| [&set[..], other].concat()))
+                .collect()
+        })),
+        [modifier @ (GroupByWithModifier::Rollup | GroupByWithModifier::Cube)] => {
+            let keys = elements
+                .into_iter()
+                .map(|element| match <[Vec<Expr>; 1]>::try_from(element) {
+                    Ok([key]) => Ok(key),
+                    Err(_) => Err(polars_err!(
+                        SQLSyntax: "{} cannot be combined with ROLLUP, CUBE, or GROUPING SETS", modifier
+                    )),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Ok(match modifier {
+                GroupByWithModifier::Rollup => rollup(keys),
+                _ => cube(keys),
+            })
+        },
+        _ if modifiers.contains(&GroupByWithModifier::Totals) => {
+            polars_bail!(SQLInterface: "GROUP BY does not support the TOTALS modifier")
+        },
+        _ => polars_bail!(SQLSyntax: "GROUP BY supports a single ROLLUP or CUBE modifier"),
+    }
+}
+
+fn named_window<'a>(
+    windows: &'a PlHashMap<String, WindowSpec>,
+    name: &Ident,
+) -> PolarsResult<&'a WindowSpec> {
+    windows
+        .get(&name.value)
+        .ok_or_else(|| polars_err!(SQLInterface: "window '{}' is not defined", name.value))
+}
+
+/// Resolve a window spec that refers to an existing window (e.g. `OVER (w ORDER BY x)`);
+/// as in the SQL standard, it inherits the partitioning of the existing window, and can
+/// only add an ORDER BY and/or a frame that the existing window does not have.
+fn resolve_window_spec(
+    windows: &PlHashMap<String, WindowSpec>,
+    spec: &WindowSpec,
+) -> PolarsResult<WindowSpec> {
+    let Some(name) = &spec.window_name else {
+        return Ok(spec.clone());
+    };
+    let base = named_window(windows, name)?;
+    if !spec.partition_by.is_empty() {
+        polars_bail!(SQLSyntax: "cannot override PARTITION BY of window '{}'", name.value)
+    }
+    if !spec.order_by.is_empty() && !base.order_by.is_empty() {
+        polars_bail!(SQLSyntax: "cannot override ORDER BY of window '{}'", name.value)
+    }
+    if base.window_frame.is_some() {
+        polars_bail!(SQLSyntax: "cannot copy window '{}' because it has a frame clause", name.value)
+    }
+    Ok(WindowSpec {
+        window_name: None,
+        partition_by: base.partition_by.clone(),
+        order_by: if spec.order_by.is_empty() {
+            base.order_by.clone()
+        } else {
+            spec.order_by.clone()
+        },
+        window_frame: spec.window_frame.clone(),
+    })
+}
+
+/// Prefix of the hidden columns that hold the results of decorrelated subqueries.
+const SUBQUERY_COLUMN_PREFIX: &str = "__POLARS_SUBQUERY_";
+
+fn subquery_key_name(idx: usize) -> PlSmallStr {
+    format_pl_smallstr!("{}KEY_{}", SUBQUERY_COLUMN_PREFIX, idx)
+}
+
+/// How the result of a subquery is used by the outer query.
+#[derive(Clone, Copy)]
+enum SubqueryKind<'a> {
+    /// `EXISTS (subquery)`
+    Exists,
+    /// `expr IN (subquery)`, where the subquery is correlated
+    In(&'a SQLExpr),
+    /// `(subquery)`, returning a single value
+    Scalar,
+    /// `JOIN LATERAL (subquery)`
+    Lateral,
+}
+
+/// A subquery that is decorrelated into a frame that is joined to the outer query.
+struct DecorrelatedSubquery {
+    /// The result of the subquery, with a column for each key.
+    frame: LazyFrame,
+    /// The names of the key columns.
+    key_names: Vec<PlSmallStr>,
+    /// The expressions of the outer query that are joined with the key columns.
+    outer_keys: Vec<SQLExpr>,
+    /// Whether the subquery aggregates (all its rows) without GROUP BY.
+    aggregated: bool,
+    /// The result of an aggregating scalar subquery over no rows, for the keys that have
+    /// no rows (and are therefore missing from the grouped result).
+    empty: Option<LazyFrame>,
+}
+
+/// A predicate that raises an error if a scalar subquery returns more than one row for
+/// any value of its keys (otherwise it keeps all rows).
+fn single_row_check(key_names: &[PlSmallStr]) -> Expr {
+    let keys = key_names
+        .iter()
+        .map(|key| col(key.clone()))
+        .collect::<Vec<_>>();
+    len().over(keys).map(
+        |c| {
+            let max_rows = c.as_materialized_series().max::<IdxSize>()?;
+            polars_ensure!(
+                max_rows.unwrap_or(0) <= 1,
+                SQLInterface: "more than one row returned by a subquery used as an expression"
+            );
+            Ok(Some(
+                BooleanChunked::full(c.name().clone(), true, c.len()).into_column(),
+            ))
+        },
+        GetOutput::from_type(DataType::Boolean),
+    )
+}
+
+/// The key under which a subquery expression is decorrelated (if it is decorrelated).
+fn subquery_key(expr: &SQLExpr) -> Option<String> {
+    match expr {
+        SQLExpr::Exists { subquery, .. } => Some(format!("EXISTS ({subquery})")),
+        SQLExpr::Subquery(subquery) => Some(format!("({subquery})")),
+        SQLExpr::InSubquery { expr, subquery, .. } => Some(format!("{expr} IN ({subquery})")),
+        _ => None,
+    }
+}
+
+fn select_item_expr(item: &SelectItem) -> Option<&SQLExpr> {
+    match item {
+        SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => Some(expr),
+        _ => None,
+    }
+}
+
+/// Call `f` on an expression and its sub-expressions (not descending into subqueries).
+fn walk_sql_expr<'a>(expr: &'a SQLExpr, f: &mut impl FnMut(&'a SQLExpr)) {
+    f(expr);
+    match expr {
+        SQLExpr::BinaryOp { left, right, .. }
+        | SQLExpr::AnyOp { left, right, .. }
+        | SQLExpr::AllOp { left, right, .. }
+        | SQLExpr::IsDistinctFrom(left, right)
+        | SQLExpr::IsNotDistinctFrom(left, right)
+        | SQLExpr::Like {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | SQLExpr::ILike {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | SQLExpr::RLike {
+            expr: left,
+            pattern: right,
+            ..
+        }
+        | SQLExpr::Position {
+            expr: left,
+            r#in: right,
+        } => {
+            walk_sql_expr(left, f);
+            walk_sql_expr(right, f);
+        },
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::IsNotTrue(expr)
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::IsNotFalse(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::Ceil { expr, .. }
+        | SQLExpr::Floor { expr, .. }
+        | SQLExpr::Extract { expr, .. }
+        | SQLExpr::Trim { expr, .. }
+        | SQLExpr::InSubquery { expr, .. } => walk_sql_expr(expr, f),
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => {
+            walk_sql_expr(expr, f);
+            walk_sql_expr(low, f);
+            walk_sql_expr(high, f);
+        },
+        SQLExpr::InList { expr, list, .. } => {
+            walk_sql_expr(expr, f);
+            list.iter().for_each(|e| walk_sql_expr(e, f));
+        },
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            let exprs = operand.iter().chain(else_result).map(|e| &**e);
+            exprs
+                .chain(conditions)
+                .chain(results)
+                .for_each(|e| walk_sql_expr(e, f));
+        },
+        SQLExpr::Function(function) => {
+            if let FunctionArguments::List(list) = &function.args {
+                for arg in &list.args {
+                    let (FunctionArg::Named { arg, .. } | FunctionArg::Unnamed(arg)) = arg;
+                    if let FunctionArgExpr::Expr(e) = arg {
+                        walk_sql_expr(e, f)
+                    }
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Check if an expression contains an aggregate function (that is not a window function).
+fn has_aggregate(expr: &SQLExpr, ctx: &SQLContext) -> bool {
+    let mut found = false;
+    walk_sql_expr(expr, &mut |e| {
+        if let SQLExpr::Function(function) = e {
+            found |= PolarsSQLFunctions::is_aggregate(function, ctx);
+        }
+    });
+    found
+}
+
+/// The names by which the relations of a FROM clause can be referred to.
+fn table_with_joins_names(tbl_expr: &TableWithJoins) -> Vec<String> {
+    std::iter::once(&tbl_expr.relation)
+        .chain(tbl_expr.joins.iter().map(|join| &join.relation))
+        .filter_map(|relation| match relation {
+            TableFactor::Table {
+                alias: Some(alias), ..
+            }
+            | TableFactor::Derived {
+                alias: Some(alias), ..
+            }
+            | TableFactor::UNNEST {
+                alias: Some(alias), ..
+            } => Some(alias.name.value.clone()),
+            TableFactor::Table { name, .. } => name.0.last().map(|ident| ident.value.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check if an expression refers to one of the given (outer) relations, as `relation.column`.
+fn has_outer_reference(expr: &SQLExpr, outer_names: &PlHashSet<String>) -> bool {
+    let mut found = false;
+    walk_sql_expr(expr, &mut |e| {
+        if let SQLExpr::CompoundIdentifier(idents) = e {
+            found |= idents.len() > 1 && outer_names.contains(&idents[0].value);
+        }
+    });
+    found
+}
+
+/// The relations of an outer query that a subquery can refer to; these exclude the relations
+/// (with the same name) of the subquery itself.
+fn outer_relation_names(select: &Select, relation_names: &PlHashSet<String>) -> PlHashSet<String> {
+    let mut outer_names = relation_names.clone();
+    for name in select.from.iter().flat_map(table_with_joins_names) {
+        outer_names.remove(&name);
+    }
+    outer_names
+}
+
+/// Check if (the WHERE clause of) a subquery refers to any of the given (outer) relations.
+fn is_correlated(subquery: &Query, relation_names: &PlHashSet<String>) -> bool {
+    match &*subquery.body {
+        SetExpr::Select(select) => {
+            let outer_names = outer_relation_names(select, relation_names);
+            select
+                .selection
+                .as_ref()
+                .is_some_and(|e| has_outer_reference(e, &outer_names))
+        },
+        _ => false,
+    }
+}
+
+/// The (inner, outer) sides of an equality predicate that correlates a subquery with
+/// the outer query.
+type Correlation = (SQLExpr, SQLExpr);
+
+/// Split the WHERE clause of a subquery into the predicates that only refer to the
+/// subquery itself, and the correlations with the given (outer) relations.
+fn split_correlated_predicates(
+    select: &Select,
+    relation_names: &PlHashSet<String>,
+) -> PolarsResult<(Option<SQLExpr>, Vec<Correlation>)> {
+    let outer_names = outer_relation_names(select, relation_names);
+    let has_outer = |e: &SQLExpr| has_outer_reference(e, &outer_names);
+
+    let group_by: &[SQLExpr] = match &select.group_by {
+        GroupByExpr::Expressions(exprs, _) => exprs,
+        GroupByExpr::All(_) => &[],
+    };
+    let projection = select.projection.iter().filter_map(select_item_expr);
+    for expr in projection.chain(group_by).chain(&select.having) {
+        if has_outer(expr) {
+            polars_bail!(SQLInterface: "correlated subqueries can only refer to the outer query in their WHERE clause; found {}", expr)
+        }
+    }
+
+    let mut predicates = vec![];
+    let mut correlation = vec![];
+    for expr in select.selection.iter().flat_map(split_conjuncts) {
+        match expr {
+            _ if !has_outer(expr) => predicates.push(expr.clone()),
+            SQLExpr::BinaryOp {
+                left,
+                op: BinaryOperator::Eq,
+                right,
+            } if has_outer(left) != has_outer(right) => {
+                let (inner, outer) = if has_outer(right) {
+                    (left, right)
+                } else {
+                    (right, left)
+                };
+                correlation.push(((**inner).clone(), (**outer).clone()));
+            },
+            _ => polars_bail!(
+                SQLInterface: "correlated subqueries only support equality predicates that refer to the outer query; found {}",
+                expr
+            ),
+        }
+    }
+    Ok((conjunction(predicates), correlation))
+}
+
+/// Split a predicate into the predicates that are combined with `AND`.
+fn split_conjuncts(expr: &SQLExpr) -> Vec<&SQLExpr> {
+    let mut conjuncts = vec![];
+    let mut stack = vec![expr];
+    while let Some(expr) = stack.pop() {
+        match expr {
+            SQLExpr::BinaryOp {
+                left,
+                op: BinaryOperator::And,
+                right,
+            } => stack.extend([&**right, &**left]),
+            SQLExpr::Nested(expr) => stack.push(&**expr),
+            _ => conjuncts.push(expr),
+        }
+    }
+    conjuncts
+}
+
+/// Combine predicates with `AND` (the inverse of [`split_conjuncts`]).
+fn conjunction(predicates: Vec<SQLExpr>) -> Option<SQLExpr> {
+    predicates
+        .into_iter()
+        .reduce(|left, right| SQLExpr::BinaryOp {
+            left: Box::new(left),
+            op: BinaryOperator::And,
+            right: Box::new(right),
+        })
+}
+
+/// If a predicate is an equality between an expression on the (left) relations that
+/// precede a table in the FROM clause and an expression on the (right) table itself,
+/// return these expressions as the keys of an implicit join.
+fn implicit_join_keys<'a>(
+    expr: &'a SQLExpr,
+    left_names: &PlHashSet<String>,
+    right_name: &str,
+    left_schema: &Schema,
+    right_schema: &Schema,
+) -> Option<(&'a SQLExpr, &'a SQLExpr)> {
+    let SQLExpr::BinaryOp {
+        left,
+        op: BinaryOperator::Eq,
+        right,
+    } = expr
+    else {
+        return None;
+    };
+    // the side of the join an expression refers to (true for the right side); unqualified
+    // columns are attributed to the side whose schema uniquely contains them
+    let side = |expr: &SQLExpr| {
+        let (mut in_left, mut in_right, mut valid) = (false, false, true);
+        walk_sql_expr(expr, &mut |e| match e {
+            SQLExpr::Identifier(ident) => {
+                match (
+                    left_schema.contains(&ident.value),
+                    right_schema.contains(&ident.value),
+                ) {
+                    (true, false) => in_left = true,
+                    (false, true) => in_right = true,
+                    _ => valid = false,
+                }
+            },
+            SQLExpr::CompoundIdentifier(idents) if idents.len() == 2 => {
+                if idents[0].value == right_name {
+                    in_right = true
+                } else if left_names.contains(&idents[0].value) {
+                    in_left = true
+                } else {
+                    valid = false
+                }
+            },
+            SQLExpr::CompoundIdentifier(_) => valid = false,
+            e if subquery_key(e).is_some() => valid = false,
+            _ => {},
+        });
+        (valid && in_left != in_right).then_some(in_right)
+    };
+    match (side(left)?, side(right)?) {
+        (false, true) => Some((left, right)),
+        (true, false) => Some((right, left)),
+        _ => None,
+    }
+}
+
+/// Check if a join constraint is `ON TRUE` (or missing).
+fn is_on_true(constraint: &JoinConstraint) -> bool {
+    matches!(
+        constraint,
+        JoinConstraint::None | JoinConstraint::On(SQLExpr::Value(SQLValue::Boolean(true)))
+    )
+}
+
 fn collect_compound_identifiers(
     left: &[Ident],
     right: &[Ident],
@@ -0,0 +1,432 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use polars_core::df;
+use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::write::ParquetCompression;
+#[cfg(any(feature = "csv", feature = "ipc", feature = "json"))]
+use polars_io::SerWriter;
+use polars_lazy::prelude::*;
+use polars_plan::frame::AllowedOptimizations;
+use polars_plan::prelude::{FileType, SinkType, UdfSchema, IR};
+use sqlparser::ast::{CopySource, Expr as SQLExpr, Value as SQLValue};
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::{IsOptional, Parser, ParserError};
+use sqlparser::tokenizer::Token;
+
+/// Default size (in bytes) of the files written by a partitioned `COPY`.
+#[cfg(feature = "parquet")]
+const PARTITION_CHUNK_SIZE: usize = 4_294_967_296;
+
+/// A `COPY ... TO` statement.
+/// ```sql
+/// COPY (SELECT * FROM tbl) TO 'path/to/file.parquet' (FORMAT parquet, COMPRESSION zstd)
+/// ```
+/// This is parsed here (rather than by sqlparser) as we support options that are specific
+/// to the Polars writers, such as `COMPRESSION` and `PARTITION_BY`.
+pub(crate) struct CopyTo {
+    pub(crate) source: CopySource,
+    pub(crate) path: String,
+    options: CopyOptions,
+}
+
+/// File formats that can be written by `COPY ... TO`.
+enum CopyFormat {
+    /// Comma-separated values.
+    #[cfg(feature = "csv")]
+    Csv,
+    /// Apache Parquet; supports `PARTITION_BY`.
+    #[cfg(feature = "parquet")]
+    Parquet,
+    /// Arrow IPC (Feather v2).
+    #[cfg(feature = "ipc")]
+    Ipc,
+    /// Newline-delimited JSON.
+    #[cfg(feature = "json")]
+    Json,
+}
+
+impl FromStr for CopyFormat {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            #[cfg(feature = "csv")]
+            "csv" => CopyFormat::Csv,
+            #[cfg(feature = "parquet")]
+            "parquet" => CopyFormat::Parquet,
+            #[cfg(feature = "ipc")]
+            "ipc" | "arrow" | "feather" => CopyFormat::Ipc,
+            #[cfg(feature = "json")]
+            "json" | "ndjson" | "jsonl" => CopyFormat::Json,
+            _ => polars_bail!(SQLInterface: "'{}' is not a supported COPY format", s),
+        })
+    }
+}
+
+impl CopyFormat {
+    /// Infer the format from the extension of the target path.
+    fn from_path(path: &str) -> PolarsResult<Self> {
+        match std::path::Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+        {
+            Some(ext) => ext.parse(),
+            None => polars_bail!(
+                SQLInterface: "cannot infer the COPY format of '{}' (use the FORMAT option)", path
+            ),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum CopyOptionValue {
+    Value(String),
+    List(Vec<String>),
+}
+
+/// The options of a `COPY` statement, keyed by (uppercase) name; options are removed
+/// as they are applied, so that any that are left over can be reported as unsupported.
+#[derive(Clone)]
+struct CopyOptions(Vec<(String, CopyOptionValue)>);
+
+impl CopyOptions {
+    fn take(&mut self, name: &str) -> Option<CopyOptionValue> {
+        let idx = self.0.iter().position(|(opt, _)| opt == name)?;
+        Some(self.0.remove(idx).1)
+    }
+
+    fn take_str(&mut self, name: &str) -> PolarsResult<Option<String>> {
+        match self.take(name) {
+            Some(CopyOptionValue::Value(s)) => Ok(Some(s)),
+            Some(CopyOptionValue::List(values)) => polars_bail!(
+                SQLSyntax: "COPY option {} expects a single value; found ({})", name, values.join(", ")
+            ),
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(any(feature = "csv", feature = "parquet"))]
+    fn take_parsed<T: FromStr>(&mut self, name: &str) -> PolarsResult<Option<T>> {
+        self.take_str(name)?
+            .map(|s| {
+                s.to_lowercase().parse::<T>().map_err(
+                    |_| polars_err!(SQLSyntax: "invalid value for COPY option {}: '{}'", name, s),
+                )
+            })
+            .transpose()
+    }
+
+    #[cfg(feature = "csv")]
+    fn take_char(&mut self, name: &str) -> PolarsResult<Option<u8>> {
+        self.take_str(name)?
+            .map(|s| match s.as_bytes() {
+                [c] => Ok(*c),
+                _ => polars_bail!(SQLSyntax: "COPY option {} expects a single character; found '{}'", name, s),
+            })
+            .transpose()
+    }
+
+    #[cfg(feature = "parquet")]
+    fn take_list(&mut self, name: &str) -> Option<Vec<String>> {
+        match self.take(name)? {
+            CopyOptionValue::Value(s) => Some(vec![s]),
+            CopyOptionValue::List(values) => Some(values),
+        }
+    }
+}
+
+/// Parse a `COPY ... TO` statement (following the `COPY` keyword).
+pub(crate) fn parse_copy_to(parser: &mut Parser) -> Result<CopyTo, ParserError> {
+    let source = if parser.consume_token(&Token::LParen) {
+        let query = parser.parse_boxed_query()?;
+        parser.expect_token(&Token::RParen)?;
+        CopySource::Query(query)
+    } else {
+        let table_name = parser.parse_object_name(false)?;
+        let columns = parser.parse_parenthesized_column_list(IsOptional::Optional, false)?;
+        CopySource::Table {
+            table_name,
+            columns,
+        }
+    };
+    parser.expect_keyword(Keyword::TO)?;
+    let path = parser.parse_literal_string()?;
+
+    let _ = parser.parse_keyword(Keyword::WITH);
+    let mut options = vec![];
+    if parser.consume_token(&Token::LParen) {
+        options = parser.parse_comma_separated(parse_copy_option)?;
+        parser.expect_token(&Token::RParen)?;
+    }
+    let _ = parser.consume_token(&Token::SemiColon);
+    if parser.peek_token().token != Token::EOF {
+        return parser.expected("end of statement", parser.peek_token());
+    }
+    Ok(CopyTo {
+        source,
+        path,
+        options: CopyOptions(options),
+    })
+}
+
+// eg: `FORMAT parquet`, `COMPRESSION 'zstd'`, `HEADER`, `PARTITION_BY (a, b)`
+fn parse_copy_option(parser: &mut Parser) -> Result<(String, CopyOptionValue), ParserError> {
+    let name = parser.parse_identifier(false)?.value.to_uppercase();
+    let value = if parser.consume_token(&Token::LParen) {
+        let columns = parser.parse_comma_separated(|p| p.parse_identifier(false))?;
+        parser.expect_token(&Token::RParen)?;
+        CopyOptionValue::List(columns.into_iter().map(|c| c.value).collect())
+    } else if matches!(parser.peek_token().token, Token::Comma | Token::RParen) {
+        CopyOptionValue::Value("true".to_string())
+    } else {
+        CopyOptionValue::Value(match parser.parse_expr()? {
+            SQLExpr::Identifier(ident) => ident.value,
+            SQLExpr::Value(SQLValue::SingleQuotedString(s) | SQLValue::Number(s, _)) => s,
+            SQLExpr::Value(SQLValue::Boolean(b)) => b.to_string(),
+            expr => {
+                return Err(ParserError::ParserError(format!(
+                    "invalid value for COPY option {name}: {expr}"
+                )))
+            },
+        })
+    };
+    // no writer supports LZO (parquet only reads it)
+    if let CopyOptionValue::Value(compression) = &value {
+        if name == "COMPRESSION" && compression.eq_ignore_ascii_case("lzo") {
+            return Err(ParserError::ParserError(
+                "LZO compression is not supported by COPY".to_string(),
+            ));
+        }
+    }
+    Ok((name, value))
+}
+
+/// A configured writer for the target file of a `COPY` statement.
+enum CopyWriter {
+    #[cfg(feature = "csv")]
+    Csv(CsvWriterOptions),
+    #[cfg(feature = "parquet")]
+    Parquet(ParquetWriteOptions, Option<Vec<String>>),
+    #[cfg(feature = "ipc")]
+    Ipc(IpcWriterOptions),
+    #[cfg(feature = "json")]
+    Json(JsonWriterOptions),
+}
+
+impl CopyTo {
+    /// Get a [`LazyFrame`] that, when collected, writes the given frame to the target file.
+    pub(crate) fn execute(&self, lf: LazyFrame) -> PolarsResult<LazyFrame> {
+        let mut options = self.options.clone();
+        let format = match options.take_str("FORMAT")? {
+            Some(format) => format.parse::<CopyFormat>()?,
+            None => CopyFormat::from_path(&self.path)?,
+        };
+        let writer = CopyWriter::new(format, &mut options)?;
+
+        let path = self.path.clone();
+        let schema: Arc<dyn UdfSchema> = Arc::new(|_: &Schema| {
+            Ok(Arc::new(Schema::from_iter([Field::new(
+                PlSmallStr::from_static("Response"),
+                DataType::String,
+            )])))
+        });
+        Ok(DataFrame::empty().lazy().map(
+            move |_| {
+                writer.write(lf.clone(), &path)?;
+                df! { "Response" => ["COPY"] }
+            },
+            AllowedOptimizations::default(),
+            Some(schema),
+            Some("COPY"),
+        ))
+    }
+}
+
+impl CopyWriter {
+    fn new(format: CopyFormat, options: &mut CopyOptions) -> PolarsResult<Self> {
+        let writer = match format {
+            #[cfg(feature = "csv")]
+            CopyFormat::Csv => {
+                let mut csv_options = CsvWriterOptions {
+                    maintain_order: true,
+                    ..Default::default()
+                };
+                if let Some(header) = options.take_parsed("HEADER")? {
+                    csv_options.include_header = header;
+                }
+                if let Some(delimiter) = options.take_char("DELIMITER")? {
+                    csv_options.serialize_options.separator = delimiter;
+                }
+                if let Some(quote) = options.take_char("QUOTE")? {
+                    csv_options.serialize_options.quote_char = quote;
+                }
+                if let Some(null) = options.take_str("NULL")? {
+                    csv_options.serialize_options.null = null;
+                }
+                CopyWriter::Csv(csv_options)
+            },
+            #[cfg(feature = "parquet")]
+            CopyFormat::Parquet => {
+                let level = options.take_parsed("COMPRESSION_LEVEL")?;
+                let compression = match options.take_str("COMPRESSION")? {
+                    Some(compression) => parquet_compression(&compression, level)?,
+                    None => Default::default(),
+                };
+                let mut parquet_options = ParquetWriteOptions {
+                    compression,
+                    row_group_size: options.take_parsed("ROW_GROUP_SIZE")?,
+                    maintain_order: true,
+                    ..Default::default()
+                };
+                if let Some(false) = options.take_parsed("STATISTICS")? {
+                    parquet_options.statistics =
+                        polars_io::parquet::write::StatisticsOptions::empty();
+                }
+                CopyWriter::Parquet(parquet_options, options.take_list("PARTITION_BY"))
+            },
+            #[cfg(feature = "ipc")]
+            CopyFormat::Ipc => {
+                let compression = options.take_str("COMPRESSION")?;
+                let compression = match compression.map(|c| c.to_lowercase()).as_deref() {
+                    None | Some("uncompressed") => None,
+                    Some("lz4") => Some(polars_io::ipc::IpcCompression::LZ4),
+                    Some("zstd") => Some(polars_io::ipc::IpcCompression::ZSTD),
+                    Some(other) => {
+                        polars_bail!(SQLSyntax: "unsupported ipc compression '{}'", other)
+                    },
+                };
+                CopyWriter::Ipc(IpcWriterOptions {
+                    compression,
+                    maintain_order: true,
+                })
+            },
+            #[cfg(feature = "json")]
+            CopyFormat::Json => CopyWriter::Json(JsonWriterOptions {
+                maintain_order: true,
+            }),
+        };
+        if let Some((name, _)) = options.0.first() {
+            polars_bail!(SQLInterface: "COPY option {} is not supported for this format", name);
+        }
+        Ok(writer)
+    }
+
+    /// Write the frame to the target file (or directory, if partitioned). The frame is
+    /// streamed through the sink of the format when the whole plan can run in the streaming
+    /// engine; otherwise (or when partitioning) it is collected and written eagerly.
+    fn write(&self, lf: LazyFrame, path: &str) -> PolarsResult<()> {
+        match self.sink_file_type() {
+            Some(file_type) if can_sink(&lf, path, file_type)? => self.sink(lf, path),
+            _ => self.write_collected(lf.collect()?, Path::new(path)),
+        }
+    }
+
+    /// The file type of the sink that can stream to the target, if it is a single file.
+    fn sink_file_type(&self) -> Option<FileType> {
+        match self {
+            #[cfg(feature = "csv")]
+            CopyWriter::Csv(options) => Some(FileType::Csv(options.clone())),
+            #[cfg(feature = "parquet")]
+            CopyWriter::Parquet(options, None) => Some(FileType::Parquet(options.clone())),
+            #[cfg(feature = "parquet")]
+            CopyWriter::Parquet(_, Some(_)) => None,
+            #[cfg(feature = "ipc")]
+            CopyWriter::Ipc(options) => Some(FileType::Ipc(*options)),
+            #[cfg(feature = "json")]
+            CopyWriter::Json(options) => Some(FileType::Json(*options)),
+        }
+    }
+
+    fn sink(&self, lf: LazyFrame, path: &str) -> PolarsResult<()> {
+        match self {
+            #[cfg(feature = "csv")]
+            CopyWriter::Csv(options) => lf.sink_csv(path, options.clone()),
+            #[cfg(feature = "parquet")]
+            CopyWriter::Parquet(options, _) => lf.sink_parquet(path, options.clone()),
+            #[cfg(feature = "ipc")]
+            CopyWriter::Ipc(options) => lf.sink_ipc(path, *options),
+            #[cfg(feature = "json")]
+            CopyWriter::Json(options) => lf.sink_json(path, *options),
+        }
+    }
+
+    fn write_collected(&self, mut df: DataFrame, path: &Path) -> PolarsResult<()> {
+        match self {
+            #[cfg(feature = "csv")]
+            CopyWriter::Csv(options) => {
+                let serialize_options = &options.serialize_options;
+                polars_io::csv::write::CsvWriter::new(polars_utils::create_file(path)?)
+                    .include_bom(options.include_bom)
+                    .include_header(options.include_header)
+                    .with_separator(serialize_options.separator)
+                    .with_quote_char(serialize_options.quote_char)
+                    .with_null_value(serialize_options.null.clone())
+                    .finish(&mut df)
+            },
+            #[cfg(feature = "parquet")]
+            CopyWriter::Parquet(options, None) => options
+                .to_writer(polars_utils::create_file(path)?)
+                .finish(&mut df)
+                .map(|_| ()),
+            #[cfg(feature = "parquet")]
+            CopyWriter::Parquet(options, Some(partition_by)) => {
+                polars_io::partition::write_partitioned_dataset(
+                    &mut df,
+                    path,
+                    partition_by.iter().map(|c| c.as_str()),
+                    options,
+                    PARTITION_CHUNK_SIZE,
+                )
+            },
+            #[cfg(feature = "ipc")]
+            CopyWriter::Ipc(options) => options
+                .to_writer(polars_utils::create_file(path)?)
+                .finish(&mut df),
+            #[cfg(feature = "json")]
+            CopyWriter::Json(_) => {
+                polars_io::json::JsonWriter::new(polars_utils::create_file(path)?)
+                    .with_json_format(polars_io::json::JsonFormat::JsonLines)
+                    .finish(&mut df)
+            },
+        }
+    }
+}
+
+/// Whether the whole plan can run in the streaming engine when sunk to the given file; a
+/// streamable plan has its sink replaced by the streaming pipeline when optimized.
+fn can_sink(lf: &LazyFrame, path: &str, file_type: FileType) -> PolarsResult<bool> {
+    let mut lf = lf.clone().with_streaming(true);
+    lf.logical_plan = DslPlan::Sink {
+        input: Arc::new(lf.logical_plan),
+        payload: SinkType::File {
+            path: Arc::new(PathBuf::from(path)),
+            file_type,
+        },
+    };
+    let plan = lf.to_alp_optimized()?;
+    Ok(!matches!(plan.lp_arena.get(plan.lp_top), IR::Sink { .. }))
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_compression(name: &str, level: Option<i32>) -> PolarsResult<ParquetCompression> {
+    use polars_io::parquet::write::{BrotliLevel, GzipLevel, ZstdLevel};
+
+    Ok(match name.to_lowercase().as_str() {
+        "uncompressed" => ParquetCompression::Uncompressed,
+        "snappy" => ParquetCompression::Snappy,
+        "gzip" => {
+            ParquetCompression::Gzip(level.map(|lvl| GzipLevel::try_new(lvl as u8)).transpose()?)
+        },
+        "brotli" => ParquetCompression::Brotli(
+            level
+                .map(|lvl| BrotliLevel::try_new(lvl as u32))
+                .transpose()?,
+        ),
+        "lz4" => ParquetCompression::Lz4Raw,
+        "zstd" => ParquetCompression::Zstd(level.map(ZstdLevel::try_new).transpose()?),
+        _ => polars_bail!(SQLSyntax: "unsupported parquet compression '{}'", name),
+    })
+}
@@ -3,23 +3,32 @@ use std::ops::Sub;
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::export::regex;
 use polars_core::prelude::{
-    polars_bail, polars_err, DataType, PolarsResult, QuantileMethod, Schema, TimeUnit,
+    polars_bail, polars_err, ChunkFilter, DataType, FillNullStrategy, GroupsProxy, IdxCa, IdxSize,
+    PolarsResult, QuantileMethod, RollingOptionsFixedWindow, Schema, TimeUnit, IDX_DTYPE,
 };
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
-use polars_plan::plans::{typed_lit, LiteralValue};
+use polars_plan::dsl::{
+    coalesce, concat_str, int_range, len, max_horizontal, min_horizontal, when, GetOutput,
+    WindowMapping,
+};
+use polars_plan::plans::{typed_lit, Context, LiteralValue};
 use polars_plan::prelude::LiteralValue::Null;
 use polars_plan::prelude::{col, cols, lit, StrptimeOptions};
+use polars_plan::utils::expr_output_name;
+use polars_time::prelude::{ClosedWindow, Duration, RollingOptionsDynamicWindow};
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
 };
 
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::context::grouping_indicator_name;
+use crate::sql_expr::{
+    adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr, parse_sql_interval,
+};
 use crate::SQLContext;
 
 pub(crate) struct SQLFunctionVisitor<'a> {
@@ -517,6 +526,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function
+    /// Returns a bit mask of the given GROUP BY expressions that are not part of the
+    /// grouping set of the current row (the last argument is the least significant bit).
+    /// ```sql
+    /// SELECT column_1, GROUPING(column_1), SUM(column_2) FROM df GROUP BY ROLLUP(column_1);
+    /// ```
+    Grouping,
     /// SQL 'last' function
     /// Returns the last element of the grouping.
     /// ```sql
@@ -574,6 +590,28 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Variance,
 
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'dense_rank' function
+    /// Returns the rank of the current row within its window partition, without gaps.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'rank' function
+    /// Returns the rank of the current row within its window partition, with gaps.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'row_number' function
+    /// Returns the number of the current row within its window partition.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+
     // ----
     // Array functions
     // ----
@@ -706,12 +744,14 @@ impl PolarsSQLFunctions {
             "count",
             "date",
             "date_part",
+            "dense_rank",
             "degrees",
             "ends_with",
             "exp",
             "first",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
@@ -739,11 +779,13 @@ impl PolarsSQLFunctions {
             "quantile_cont",
             "quantile_disc",
             "radians",
+            "rank",
             "regexp_like",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -876,6 +918,7 @@ impl PolarsSQLFunctions {
             "avg" => Self::Avg,
             "count" => Self::Count,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -886,6 +929,13 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "dense_rank" => Self::DenseRank,
+            "rank" => Self::Rank,
+            "row_number" => Self::RowNumber,
+
             // ----
             // Array functions
             // ----
@@ -916,6 +966,28 @@ impl PolarsSQLFunctions {
             },
         })
     }
+
+    /// Check if a function call aggregates its input (i.e. is not used as a window function).
+    pub(crate) fn is_aggregate(function: &SQLFunction, ctx: &SQLContext) -> bool {
+        use PolarsSQLFunctions::*;
+        function.over.is_none()
+            && matches!(
+                Self::try_from_sql(function, ctx),
+                Ok(Avg
+                    | ArrayAgg
+                    | Count
+                    | First
+                    | Last
+                    | Max
+                    | Median
+                    | Min
+                    | QuantileCont
+                    | QuantileDisc
+                    | StdDev
+                    | Sum
+                    | Variance)
+            )
+    }
 }
 
 impl SQLFunctionVisitor<'_> {
@@ -1309,11 +1381,12 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_unary_with_opt_frame(Expr::mean, FrameAggregate::Avg),
             Count => self.visit_count(),
             First => self.visit_unary(Expr::first),
+            Grouping => self.visit_grouping(),
             Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
+            Max => self.visit_unary_with_opt_frame(Expr::max, FrameAggregate::Max),
             Median => self.visit_unary(Expr::median),
             QuantileCont => {
                 let args = extract_args(function)?;
@@ -1367,10 +1440,17 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "QUANTILE_DISC expects 2 arguments (found {})", args.len()),
                 }
             },
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
-            StdDev => self.visit_unary(|e| e.std(1)),
-            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
-            Variance => self.visit_unary(|e| e.var(1)),
+            Min => self.visit_unary_with_opt_frame(Expr::min, FrameAggregate::Min),
+            StdDev => self.visit_unary_with_opt_frame(|e| e.std(1), FrameAggregate::StdDev),
+            Sum => self.visit_unary_with_opt_frame(Expr::sum, FrameAggregate::Sum),
+            Variance => self.visit_unary_with_opt_frame(|e| e.var(1), FrameAggregate::Variance),
+
+            // ----
+            // Window functions
+            // ----
+            DenseRank => self.visit_ranking(Ranking::DenseRank),
+            Rank => self.visit_ranking(Ranking::Rank),
+            RowNumber => self.visit_ranking(Ranking::RowNumber),
 
             // ----
             // Array functions
@@ -1461,45 +1541,6 @@ impl SQLFunctionVisitor<'_> {
             .call(args)
     }
 
-    /// Window specs without partition bys are essentially cumulative functions
-    /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
-    fn apply_cumulative_window(
-        &mut self,
-        f: impl Fn(Expr) -> Expr,
-        cumulative_f: impl Fn(Expr, bool) -> Expr,
-        WindowSpec {
-            partition_by,
-            order_by,
-            ..
-        }: &WindowSpec,
-    ) -> PolarsResult<Expr> {
-        if !order_by.is_empty() && partition_by.is_empty() {
-            let (order_by, desc): (Vec<Expr>, Vec<bool>) = order_by
-                .iter()
-                .map(|o| {
-                    let expr = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
-                    Ok(match o.asc {
-                        Some(b) => (expr, !b),
-                        None => (expr, false),
-                    })
-                })
-                .collect::<PolarsResult<Vec<_>>>()?
-                .into_iter()
-                .unzip();
-            self.visit_unary_no_window(|e| {
-                cumulative_f(
-                    e.sort_by(
-                        &order_by,
-                        SortMultipleOptions::default().with_order_descending_multi(desc.clone()),
-                    ),
-                    false,
-                )
-            })
-        } else {
-            self.visit_unary(f)
-        }
-    }
-
     fn visit_unary(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
         self.try_visit_unary(|e| Ok(f(e)))
     }
@@ -1517,27 +1558,22 @@ impl SQLFunctionVisitor<'_> {
             )?),
             _ => self.not_supported_error(),
         }
-        .and_then(|e| self.apply_window_spec(e, &self.func.over))
+        .and_then(|e| self.apply_window_spec(e))
     }
 
-    /// Some functions have cumulative equivalents that can be applied to window specs
-    /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
-    /// visit_unary_with_cumulative_window will take in a function & a cumulative function
-    /// if there is a cumulative window spec, it will apply the cumulative function,
-    /// otherwise it will apply the function
-    fn visit_unary_with_opt_cumulative(
+    /// Aggregate functions that are applied over a window frame (or an ordered window,
+    /// which implies a frame) are evaluated over the rows of that frame,
+    /// e.g. SUM(a) OVER (PARTITION BY b ORDER BY c ROWS BETWEEN 1 PRECEDING AND CURRENT ROW)
+    fn visit_unary_with_opt_frame(
         &mut self,
         f: impl Fn(Expr) -> Expr,
-        cumulative_f: impl Fn(Expr, bool) -> Expr,
+        agg: FrameAggregate,
     ) -> PolarsResult<Expr> {
-        match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
+        match self.window_spec()? {
+            Some(spec) if spec.window_frame.is_some() || !spec.order_by.is_empty() => {
+                let expr = self.visit_unary_no_window(|e| e)?;
+                self.apply_window_frame(expr, agg, &spec)
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
             _ => self.visit_unary(f),
         }
     }
@@ -1621,6 +1657,24 @@ impl SQLFunctionVisitor<'_> {
         Ok(f())
     }
 
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        // Each GROUP BY key has a hidden column that indicates if it is aggregated in the
+        // grouping set of the current row (see `SQLContext::process_grouping_sets`).
+        self.try_visit_variadic(|exprs| {
+            let indicators = exprs
+                .iter()
+                .map(|e| Ok(col(grouping_indicator_name(&expr_output_name(e)?))))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            match indicators
+                .into_iter()
+                .reduce(|acc, e| acc * typed_lit(2i32) + e)
+            {
+                Some(mask) => Ok(mask.alias("grouping")),
+                None => polars_bail!(SQLSyntax: "GROUPING expects at least one argument"),
+            }
+        })
+    }
+
     fn visit_arr_agg(&mut self) -> PolarsResult<Expr> {
         let (args, is_distinct, clauses) = extract_args_and_clauses(self.func)?;
         match args.as_slice() {
@@ -1691,23 +1745,98 @@ impl SQLFunctionVisitor<'_> {
         let (args, is_distinct) = extract_args_distinct(self.func)?;
         match (is_distinct, args.as_slice()) {
             // count(*), count()
-            (false, [FunctionArgExpr::Wildcard] | []) => Ok(len()),
+            (false, [FunctionArgExpr::Wildcard] | []) => match self.window_spec()? {
+                Some(spec) if spec.window_frame.is_some() || !spec.order_by.is_empty() => {
+                    // count the (never null) row indices that fall in the frame
+                    let row_idx = int_range(lit(0), len(), 1, IDX_DTYPE);
+                    let expr = self.apply_window_frame(row_idx, FrameAggregate::Count, &spec)?;
+                    Ok(expr.alias("len"))
+                },
+                _ => self.apply_window_spec(len()),
+            },
             // count(column_name)
             (false, [FunctionArgExpr::Expr(sql_expr)]) => {
                 let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
-                let expr = self.apply_window_spec(expr, &self.func.over)?;
-                Ok(expr.count())
+                match self.window_spec()? {
+                    Some(spec) if spec.window_frame.is_some() || !spec.order_by.is_empty() => {
+                        self.apply_window_frame(expr, FrameAggregate::Count, &spec)
+                    },
+                    _ => Ok(self.apply_window_spec(expr)?.count()),
+                }
             },
             // count(distinct column_name)
             (true, [FunctionArgExpr::Expr(sql_expr)]) => {
                 let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
-                let expr = self.apply_window_spec(expr, &self.func.over)?;
+                let expr = self.apply_window_spec(expr)?;
                 Ok(expr.clone().n_unique().sub(expr.null_count().gt(lit(0))))
             },
             _ => self.not_supported_error(),
         }
     }
 
+    /// Number the rows of each window partition, in the order of the window's ORDER BY.
+    fn visit_ranking(&mut self, ranking: Ranking) -> PolarsResult<Expr> {
+        if !extract_args(self.func)?.is_empty() {
+            polars_bail!(SQLSyntax: "{} expects no arguments; found {}", ranking, self.func)
+        }
+        let Some(window_spec) = self.window_spec()? else {
+            polars_bail!(SQLSyntax: "{} requires an OVER clause; found {}", ranking, self.func)
+        };
+        if window_spec.window_frame.is_some() {
+            polars_bail!(SQLSyntax: "{} does not support window frames; found {}", ranking, self.func)
+        }
+        if window_spec.order_by.is_empty() && ranking != Ranking::RowNumber {
+            polars_bail!(SQLSyntax: "{} requires an ordered window; found {}", ranking, self.func)
+        }
+
+        // the (zero-based) index of each row, and the indices of the rows in sorted order
+        let row_idx = int_range(lit(0), len(), 1, IDX_DTYPE);
+        let (order_by, sorted_idx) = if window_spec.order_by.is_empty() {
+            (vec![], row_idx.clone())
+        } else {
+            let order_by = window_spec
+                .order_by
+                .iter()
+                .map(|ob| parse_sql_expr(&ob.expr, self.ctx, self.active_schema))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let sorted_idx = self.apply_order_by(row_idx.clone(), &window_spec.order_by)?;
+            (order_by, sorted_idx)
+        };
+        let sorted_rank = match ranking {
+            Ranking::RowNumber => row_idx.clone() + lit(1),
+            Ranking::Rank | Ranking::DenseRank => {
+                // rows that differ from the preceding (sorted) row start a group of peers
+                let is_first_peer = order_by
+                    .iter()
+                    .map(|e| {
+                        let sorted = e.clone().gather(sorted_idx.clone());
+                        sorted.clone().neq_missing(sorted.shift(lit(1)))
+                    })
+                    .fold(row_idx.clone().eq(lit(0)), |acc, e| acc.or(e));
+                if ranking == Ranking::Rank {
+                    when(is_first_peer)
+                        .then(row_idx.clone() + lit(1))
+                        .otherwise(lit(Null))
+                        .forward_fill(None)
+                } else {
+                    is_first_peer.cast(IDX_DTYPE).cum_sum(false)
+                }
+            },
+        };
+        // map the ranks of the sorted rows back to the rows they came from
+        let rank = sorted_rank.gather(sorted_idx.arg_sort(SortOptions::default()));
+        Ok(if window_spec.partition_by.is_empty() {
+            rank
+        } else {
+            let partition_by = window_spec
+                .partition_by
+                .iter()
+                .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            rank.over(partition_by)
+        })
+    }
+
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
         let mut by = Vec::with_capacity(order_by.len());
         let mut descending = Vec::with_capacity(order_by.len());
@@ -1730,43 +1859,246 @@ impl SQLFunctionVisitor<'_> {
         ))
     }
 
-    fn apply_window_spec(
+    /// The window spec of the function's OVER clause (if any), with named windows resolved.
+    fn window_spec(&self) -> PolarsResult<Option<WindowSpec>> {
+        self.func
+            .over
+            .as_ref()
+            .map(|window_type| self.ctx.resolve_window_type(window_type))
+            .transpose()
+    }
+
+    fn apply_window_spec(&mut self, expr: Expr) -> PolarsResult<Expr> {
+        let Some(window_spec) = self.window_spec()? else {
+            return Ok(expr);
+        };
+        if window_spec.window_frame.is_some() {
+            polars_bail!(
+                SQLInterface: "window frames are only supported for the AVG, COUNT, MAX, MIN, STDDEV, SUM and VARIANCE functions; found {}",
+                self.func
+            )
+        }
+        if window_spec.partition_by.is_empty() && window_spec.order_by.is_empty() {
+            // an empty window spans all the rows, e.g. SUM(a) OVER ()
+            return Ok(expr);
+        }
+        // without PARTITION BY, an ordered window spans all the rows (in that order)
+        let partition_by = if window_spec.partition_by.is_empty() {
+            vec![lit(true)]
+        } else {
+            window_spec
+                .partition_by
+                .iter()
+                .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+                .collect::<PolarsResult<Vec<_>>>()?
+        };
+        let order_by = self.window_order_by(&window_spec.order_by)?;
+        Ok(expr.over_with_options(partition_by, order_by, WindowMapping::default()))
+    }
+
+    /// Evaluate an aggregate over the frame of a window; without an explicit frame,
+    /// an ordered window spans the rows up to (and including the peers of) the current row.
+    fn apply_window_frame(
         &mut self,
         expr: Expr,
-        window_type: &Option<WindowType>,
+        agg: FrameAggregate,
+        window_spec: &WindowSpec,
     ) -> PolarsResult<Expr> {
-        Ok(match &window_type {
-            Some(WindowType::WindowSpec(window_spec)) => {
-                if window_spec.partition_by.is_empty() {
-                    let exprs = window_spec
-                        .order_by
-                        .iter()
-                        .map(|o| {
-                            let e = parse_sql_expr(&o.expr, self.ctx, self.active_schema)?;
-                            Ok(o.asc.map_or(e.clone(), |b| {
-                                e.sort(SortOptions::default().with_order_descending(!b))
-                            }))
-                        })
-                        .collect::<PolarsResult<Vec<_>>>()?;
-                    expr.over(exprs)
-                } else {
-                    // Process for simple window specification, partition by first
-                    let partition_by = window_spec
-                        .partition_by
-                        .iter()
-                        .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
-                        .collect::<PolarsResult<Vec<_>>>()?;
-                    expr.over(partition_by)
+        use WindowFrameBound::*;
+
+        let partition_by = if window_spec.partition_by.is_empty() {
+            vec![lit(true)]
+        } else {
+            window_spec
+                .partition_by
+                .iter()
+                .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+                .collect::<PolarsResult<Vec<_>>>()?
+        };
+        let order_by = self.window_order_by(&window_spec.order_by)?;
+        let frame = window_spec.window_frame.clone().unwrap_or(WindowFrame {
+            units: WindowFrameUnits::Range,
+            start_bound: Preceding(None),
+            end_bound: None,
+        });
+        let end_bound = frame.end_bound.as_ref().unwrap_or(&CurrentRow);
+        let over_frame = |e: Expr, order_by| {
+            e.over_with_options(partition_by.clone(), order_by, WindowMapping::default())
+        };
+
+        Ok(match (&frame.units, &frame.start_bound, end_bound) {
+            (WindowFrameUnits::Rows, start_bound, end_bound) => {
+                let start = self.rows_frame_offset(start_bound, true)?;
+                let end = self.rows_frame_offset(end_bound, false)?;
+                if matches!((start, end), (Some(start), Some(end)) if start > end) {
+                    polars_bail!(SQLSyntax: "window frame cannot end before it starts; found {}", window_spec)
+                }
+                over_frame(agg.rows_frame(expr, start, end)?, order_by)
+            },
+            (
+                WindowFrameUnits::Range,
+                Preceding(None) | CurrentRow,
+                CurrentRow | Following(None),
+            ) if order_by.is_none() => {
+                // without ORDER BY, all the rows of the partition are peers
+                agg.aggregate(expr).over(partition_by)
+            },
+            (WindowFrameUnits::Range, Preceding(None), Following(None)) => {
+                agg.aggregate(expr).over(partition_by)
+            },
+            (WindowFrameUnits::Range, CurrentRow, CurrentRow) => {
+                let (order_exprs, _) = order_by.unwrap();
+                let peers = partition_by
+                    .into_iter()
+                    .chain(order_exprs)
+                    .collect::<Vec<_>>();
+                agg.aggregate(expr).over(peers)
+            },
+            (WindowFrameUnits::Range, Preceding(None), CurrentRow) => {
+                let (order_exprs, _) = order_by.clone().unwrap();
+                let framed = agg.rows_frame(expr, None, Some(0))?;
+                over_frame(at_peer(framed, &order_exprs, true), order_by)
+            },
+            (WindowFrameUnits::Range, CurrentRow, Following(None)) => {
+                let (order_exprs, _) = order_by.clone().unwrap();
+                let framed = agg.rows_frame(expr, Some(0), None)?;
+                over_frame(at_peer(framed, &order_exprs, false), order_by)
+            },
+            (WindowFrameUnits::Range, start_bound, end_bound) => {
+                // (at least) one of the bounds is an offset from the ORDER BY value of the row
+                let (order_exprs, descending) = match &order_by {
+                    Some((order_exprs, options)) if order_exprs.len() == 1 => {
+                        (order_exprs.clone(), options.descending)
+                    },
+                    _ => polars_bail!(
+                        SQLSyntax: "RANGE frames with an offset require a single ORDER BY expression; found {}",
+                        window_spec
+                    ),
+                };
+                let Some(schema) = self.active_schema else {
+                    polars_bail!(
+                        SQLInterface: "RANGE frames with an offset are not supported in this context; found {}",
+                        window_spec
+                    )
+                };
+                let dtype = order_exprs[0].to_field(schema, Context::Default)?.dtype;
+                if !dtype.is_integer() && !dtype.is_temporal() {
+                    polars_bail!(
+                        SQLSyntax: "RANGE frames with an offset require an integer or temporal ORDER BY expression; found {}",
+                        dtype
+                    )
+                }
+                let start = self.range_frame_bound(start_bound, true)?;
+                let end = self.range_frame_bound(end_bound, false)?;
+                let start = start.map(|o| o.to_physical(&dtype)).transpose()?;
+                let end = end.map(|o| o.to_physical(&dtype)).transpose()?;
+                if matches!((start, end), (Some(start), Some(end)) if start > end) {
+                    polars_bail!(SQLSyntax: "window frame cannot end before it starts; found {}", window_spec)
                 }
+                // in descending order the values that precede a row are the larger ones, so
+                // negate the keys (but not the offsets) to find the frames in ascending order
+                let key = order_exprs[0].clone().to_physical().cast(DataType::Int64);
+                let key = if descending { lit(0i64) - key } else { key };
+                let framed = agg.range_frame(expr, key, &order_exprs, start, end)?;
+                over_frame(framed, order_by)
+            },
+            (WindowFrameUnits::Groups, ..) => polars_bail!(
+                SQLInterface: "GROUPS frames are not currently supported; found {}",
+                window_spec
+            ),
+        })
+    }
+
+    /// The ORDER BY of a window, which orders the rows of each partition.
+    fn window_order_by(
+        &mut self,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<Option<(Vec<Expr>, SortOptions)>> {
+        let mut exprs = Vec::with_capacity(order_by.len());
+        let mut sort_options: Option<SortOptions> = None;
+        for ob in order_by {
+            // note: if not specified 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
+            let descending = !ob.asc.unwrap_or(true);
+            let options = SortOptions::default()
+                .with_order_descending(descending)
+                .with_nulls_last(!ob.nulls_first.unwrap_or(descending));
+            if sort_options.is_some_and(|opts| opts != options) {
+                polars_bail!(
+                    SQLInterface: "ordered windows require all ORDER BY expressions to have the same direction; found {}",
+                    ob
+                )
+            }
+            sort_options = Some(options);
+            exprs.push(parse_sql_expr(&ob.expr, self.ctx, self.active_schema)?);
+        }
+        Ok(sort_options.map(|options| (exprs, options)))
+    }
+
+    /// The offset of a ROWS frame bound relative to the current row (`None` if unbounded).
+    fn rows_frame_offset(
+        &mut self,
+        bound: &WindowFrameBound,
+        is_start: bool,
+    ) -> PolarsResult<Option<i64>> {
+        let offset = |expr: &SQLExpr, ctx: &mut SQLContext| match parse_sql_expr(expr, ctx, None)? {
+            Expr::Literal(LiteralValue::Int(n)) if n >= 0 => i64::try_from(n).map_err(
+                |_| polars_err!(SQLSyntax: "ROWS frame offset is too large; found {}", expr),
+            ),
+            _ => {
+                polars_bail!(SQLSyntax: "ROWS frame offsets must be non-negative integers; found {}", expr)
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
+        };
+        Ok(match bound {
+            WindowFrameBound::CurrentRow => Some(0),
+            WindowFrameBound::Preceding(Some(expr)) => Some(-offset(expr, self.ctx)?),
+            WindowFrameBound::Following(Some(expr)) => Some(offset(expr, self.ctx)?),
+            WindowFrameBound::Preceding(None) if is_start => None,
+            WindowFrameBound::Following(None) if !is_start => None,
+            _ => polars_bail!(
+                SQLSyntax: "window frame cannot {} at {}",
+                if is_start { "start" } else { "end" },
+                bound
             ),
-            None => expr,
         })
     }
 
+    /// The offset of a RANGE frame bound from the ORDER BY value of the current row
+    /// (`None` if unbounded).
+    fn range_frame_bound(
+        &mut self,
+        bound: &WindowFrameBound,
+        is_start: bool,
+    ) -> PolarsResult<Option<RangeOffset>> {
+        Ok(match bound {
+            WindowFrameBound::CurrentRow => Some(RangeOffset::Int(0)),
+            WindowFrameBound::Preceding(Some(expr)) => Some(self.range_frame_offset(expr)?.neg()),
+            WindowFrameBound::Following(Some(expr)) => Some(self.range_frame_offset(expr)?),
+            WindowFrameBound::Preceding(None) if is_start => None,
+            WindowFrameBound::Following(None) if !is_start => None,
+            _ => polars_bail!(
+                SQLSyntax: "window frame cannot {} at {}",
+                if is_start { "start" } else { "end" },
+                bound
+            ),
+        })
+    }
+
+    /// The size of a RANGE frame offset; an integer, or an INTERVAL for temporal columns.
+    fn range_frame_offset(&mut self, expr: &SQLExpr) -> PolarsResult<RangeOffset> {
+        match expr {
+            SQLExpr::Interval(interval) => Ok(RangeOffset::Interval(parse_sql_interval(interval)?)),
+            _ => match parse_sql_expr(expr, self.ctx, None)? {
+                Expr::Literal(LiteralValue::Int(n)) if n >= 0 => i64::try_from(n)
+                    .map(RangeOffset::Int)
+                    .map_err(|_| polars_err!(SQLSyntax: "RANGE frame offset is too large; found {}", expr)),
+                _ => polars_bail!(
+                    SQLSyntax: "RANGE frame offsets must be non-negative integers or intervals; found {}",
+                    expr
+                ),
+            },
+        }
+    }
+
     fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
@@ -1776,6 +2108,446 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// Window functions that number the rows of a window partition.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Ranking {
+    DenseRank,
+    Rank,
+    RowNumber,
+}
+
+impl std::fmt::Display for Ranking {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Ranking::DenseRank => "DENSE_RANK",
+            Ranking::Rank => "RANK",
+            Ranking::RowNumber => "ROW_NUMBER",
+        })
+    }
+}
+
+/// Aggregate functions that can be evaluated over a window frame.
+#[derive(Clone, Copy)]
+enum FrameAggregate {
+    Avg,
+    Count,
+    Max,
+    Min,
+    StdDev,
+    Sum,
+    Variance,
+}
+
+impl FrameAggregate {
+    /// The aggregate over all the rows (e.g. of a partition).
+    fn aggregate(self, expr: Expr) -> Expr {
+        match self {
+            Self::Avg => expr.mean(),
+            Self::Count => expr.count(),
+            Self::Max => expr.max(),
+            Self::Min => expr.min(),
+            Self::StdDev => expr.std(1),
+            Self::Sum => expr.sum(),
+            Self::Variance => expr.var(1),
+        }
+    }
+
+    /// The aggregate over the rows from `start` to `end` (inclusive) relative to the current
+    /// row, where `None` is unbounded; to be evaluated over an ordered window.
+    fn rows_frame(self, expr: Expr, start: Option<i64>, end: Option<i64>) -> PolarsResult<Expr> {
+        if start.is_none() && end.is_none() {
+            return Ok(self.aggregate(expr));
+        }
+        self.frame(expr, &|e, agg| rows_frame_reduce(e, agg, start, end))
+    }
+
+    /// The aggregate over the rows whose `key` lies from `start` to `end` (inclusive) relative
+    /// to that of the current row, where `None` is unbounded; to be evaluated over a window
+    /// ordered by the single expression `order_by`, of which `key` is the ascending integer
+    /// representation.
+    fn range_frame(
+        self,
+        expr: Expr,
+        key: Expr,
+        order_by: &[Expr],
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> PolarsResult<Expr> {
+        self.frame(expr, &|e, agg| {
+            range_frame_reduce(e, agg, key.clone(), order_by, start, end)
+        })
+    }
+
+    /// The aggregate over a frame, given a function that reduces (with SUM, MIN or MAX) an
+    /// expression over the rows of that frame. The count, mean and variance are derived from
+    /// the sums of the (non-null) values in the frame.
+    fn frame(
+        self,
+        expr: Expr,
+        reduce: &dyn Fn(Expr, Self) -> PolarsResult<Expr>,
+    ) -> PolarsResult<Expr> {
+        // the sum of the values in the frame, zero if there are none
+        let sum = |e: Expr| -> PolarsResult<Expr> {
+            Ok(reduce(e.fill_null(lit(0)), Self::Sum)?.fill_null(lit(0)))
+        };
+        let count = sum(expr.clone().is_not_null().cast(IDX_DTYPE))?;
+        let if_count_above =
+            |e: Expr, n: u32| when(count.clone().gt(lit(n))).then(e).otherwise(lit(Null));
+        Ok(match self {
+            Self::Count => count.clone(),
+            Self::Sum => if_count_above(sum(expr)?, 0),
+            // null values can't be the extremum of a frame, so replace them with the opposite
+            // extremum (of the partition); a frame with only null values is null all the same
+            Self::Max => {
+                let expr = expr
+                    .fill_null_with_strategy(FillNullStrategy::Min)
+                    .fill_null_with_strategy(FillNullStrategy::Zero);
+                if_count_above(reduce(expr, self)?, 0)
+            },
+            Self::Min => {
+                let expr = expr
+                    .fill_null_with_strategy(FillNullStrategy::Max)
+                    .fill_null_with_strategy(FillNullStrategy::Zero);
+                if_count_above(reduce(expr, self)?, 0)
+            },
+            Self::Avg | Self::StdDev | Self::Variance => {
+                // center the values on their mean; the variance is derived from the sums of
+                // the values and their squares, which are otherwise prone to catastrophic
+                // cancellation
+                let x = expr.cast(DataType::Float64);
+                let mean = x.clone().mean();
+                let x = x - mean.clone();
+                let n = count.clone().cast(DataType::Float64);
+                let sum_x = sum(x.clone())?;
+                if matches!(self, Self::Avg) {
+                    return Ok(if_count_above(sum_x / n + mean, 0));
+                }
+                let m2 = sum(x.clone() * x)? - sum_x.clone() * sum_x / n.clone();
+                // guard against (tiny) negative values due to floating point error
+                let m2 = when(m2.clone().gt(lit(0.0))).then(m2).otherwise(lit(0.0));
+                let var = if_count_above(m2 / (n - lit(1.0)), 1);
+                if matches!(self, Self::StdDev) {
+                    var.sqrt()
+                } else {
+                    var
+                }
+            },
+        })
+    }
+}
+
+/// The offset of a RANGE frame bound from the ORDER BY value of the current row.
+#[derive(Clone, Copy, Debug)]
+enum RangeOffset {
+    Int(i64),
+    Interval(Duration),
+}
+
+impl RangeOffset {
+    fn neg(self) -> Self {
+        match self {
+            Self::Int(n) => Self::Int(-n),
+            Self::Interval(d) => Self::Interval(d * -1),
+        }
+    }
+
+    /// The offset in the physical (integer) representation of values of type `dtype`.
+    fn to_physical(self, dtype: &DataType) -> PolarsResult<i64> {
+        let signed = |d: Duration, n: i64| if d.negative() { -n } else { n };
+        Ok(match (self, dtype) {
+            (Self::Int(n), dt) if dt.is_integer() || n == 0 => n,
+            (Self::Interval(d), DataType::Date) if d.months() == 0 && d.is_full_days() => {
+                signed(d, d.weeks() * 7 + d.days())
+            },
+            (Self::Interval(d), DataType::Datetime(tu, tz))
+                if d.is_constant_duration(tz.as_deref()) =>
+            {
+                signed(d, interval_in_time_unit(d, *tu))
+            },
+            (Self::Interval(d), DataType::Duration(tu)) if d.is_constant_duration(None) => {
+                signed(d, interval_in_time_unit(d, *tu))
+            },
+            (offset, dt) => polars_bail!(
+                SQLSyntax: "invalid RANGE frame offset {:?} for an ORDER BY expression of type {}",
+                offset, dt
+            ),
+        })
+    }
+}
+
+fn interval_in_time_unit(d: Duration, tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => d.duration_ns(),
+        TimeUnit::Microseconds => d.duration_us(),
+        TimeUnit::Milliseconds => d.duration_ms(),
+    }
+}
+
+/// Reduce (with SUM, MIN or MAX) the rows from `start` to `end` (inclusive) relative to the
+/// current row, where `None` is unbounded, using the rolling and cumulative kernels.
+fn rows_frame_reduce(
+    expr: Expr,
+    agg: FrameAggregate,
+    start: Option<i64>,
+    end: Option<i64>,
+) -> PolarsResult<Expr> {
+    // reduce the `n` rows up to and including the current row (all of them if unbounded)
+    let trailing = |e: Expr, n: Option<i64>| match n {
+        Some(n) => {
+            let options = RollingOptionsFixedWindow {
+                window_size: n as usize,
+                min_periods: 1,
+                ..Default::default()
+            };
+            match agg {
+                FrameAggregate::Max => e.rolling_max(options),
+                FrameAggregate::Min => e.rolling_min(options),
+                _ => e.rolling_sum(options),
+            }
+        },
+        None => match agg {
+            FrameAggregate::Max => e.cum_max(false),
+            FrameAggregate::Min => e.cum_min(false),
+            _ => e.cum_sum(false),
+        }
+        .forward_fill(None),
+    };
+    // reduce the `n` rows from the current row onwards (all of them if unbounded)
+    let leading = |e: Expr, n: Option<i64>| trailing(e.reverse(), n).reverse();
+    let shifted = |e: Expr, n: i64| if n == 0 { e } else { e.shift(lit(n)) };
+
+    Ok(match (start, end) {
+        (Some(start), end) if start >= 0 => shifted(
+            leading(
+                expr,
+                end.map(|end| end.saturating_sub(start).saturating_add(1)),
+            ),
+            -start,
+        ),
+        (start, Some(end)) if end <= 0 => shifted(
+            trailing(
+                expr,
+                start.map(|start| end.saturating_sub(start).saturating_add(1)),
+            ),
+            -end,
+        ),
+        // the frame spans the current row; combine the rows up to and after it
+        (start, end) => {
+            let before = trailing(expr.clone(), start.map(|start| 1i64.saturating_sub(start)));
+            let after = shifted(leading(expr, end), -1);
+            match agg {
+                FrameAggregate::Max => max_horizontal([before, after])?,
+                FrameAggregate::Min => min_horizontal([before, after])?,
+                _ => coalesce(&[before.clone() + after.clone(), before, after]),
+            }
+        },
+    })
+}
+
+/// Reduce (with SUM, MIN or MAX) the rows whose `key` lies from `start` to `end` (inclusive)
+/// relative to that of the current row, where `None` is unbounded, using the rolling (by)
+/// kernels; see [`FrameAggregate::range_frame`]. The rows without a key are each other's peers.
+fn range_frame_reduce(
+    expr: Expr,
+    agg: FrameAggregate,
+    key: Expr,
+    order_by: &[Expr],
+    start: Option<i64>,
+    end: Option<i64>,
+) -> PolarsResult<Expr> {
+    // the rolling kernels don't accept null keys, so the rows without a key take part in
+    // the frames of the other rows with a value that doesn't change the reduction
+    let has_key = key.clone().is_not_null();
+    let neutral = match agg {
+        FrameAggregate::Max => expr.clone().min(),
+        FrameAggregate::Min => expr.clone().max(),
+        _ => lit(0),
+    };
+    let keyed = when(has_key.clone()).then(expr.clone()).otherwise(neutral);
+    let filled_key = key.clone().fill_null(lit(0i64));
+
+    // reduce the rows whose key lies within `size` before (or after) that of the current row,
+    // where `closed` is the side of the window that includes the rows at that distance
+    let rolling = |size: i64, closed: ClosedWindow, after: bool| {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::new(size),
+            min_periods: 1,
+            closed_window: closed,
+            fn_params: None,
+        };
+        // mirror the keys to look ahead of the current row
+        let by = if after {
+            lit(0i64) - filled_key.clone()
+        } else {
+            filled_key.clone()
+        };
+        match agg {
+            FrameAggregate::Max => keyed.clone().rolling_max_by(by, options),
+            FrameAggregate::Min => keyed.clone().rolling_min_by(by, options),
+            _ => keyed.clone().rolling_sum_by(by, options).fill_null(lit(0)),
+        }
+    };
+    // reduce the rows up to (or from) the current row and its peers
+    let cumulative = |after: bool| -> PolarsResult<Expr> {
+        let (start, end) = if after {
+            (Some(0), None)
+        } else {
+            (None, Some(0))
+        };
+        Ok(at_peer(
+            rows_frame_reduce(expr.clone(), agg, start, end)?,
+            order_by,
+            !after,
+        ))
+    };
+    let union = |a: Expr, b: Expr| match agg {
+        FrameAggregate::Max => max_horizontal([a, b]),
+        FrameAggregate::Min => min_horizontal([a, b]),
+        _ => Ok(a + b),
+    };
+    // the rows of frame `a` that are not in frame `b` (which is part of `a`)
+    let difference = |a: Expr, b: Expr| a - b;
+
+    // as the keys are integers, the peers of a row are the rows within a distance of less than 1
+    let framed = match (start, end) {
+        // the extremum of a frame that excludes the current row can't be derived from those
+        // of the frames that end (or start) at the current row
+        _ if matches!(agg, FrameAggregate::Max | FrameAggregate::Min)
+            && (start.is_some_and(|start| start > 0) || end.is_some_and(|end| end < 0)) =>
+        {
+            offset_range_extremum(expr.clone(), agg, key.clone(), start, end)
+        },
+        (Some(0), Some(0)) => rolling(1, ClosedWindow::Right, false),
+        (Some(start), Some(0)) => rolling(-start, ClosedWindow::Both, false),
+        (Some(0), Some(end)) if end > 0 => rolling(end, ClosedWindow::Both, true),
+        (Some(start), Some(end)) if start < 0 && end > 0 => union(
+            rolling(-start, ClosedWindow::Both, false),
+            rolling(end, ClosedWindow::Left, true),
+        )?,
+        (Some(start), Some(end)) if end < 0 => difference(
+            rolling(-start, ClosedWindow::Both, false),
+            rolling(-end, ClosedWindow::Right, false),
+        ),
+        (Some(start), Some(end)) => difference(
+            rolling(end, ClosedWindow::Both, true),
+            rolling(start, ClosedWindow::Right, true),
+        ),
+        (None, Some(0)) => cumulative(false)?,
+        (None, Some(end)) if end > 0 => {
+            union(cumulative(false)?, rolling(end, ClosedWindow::Left, true))?
+        },
+        (None, Some(end)) => difference(
+            cumulative(false)?,
+            rolling(-end, ClosedWindow::Right, false),
+        ),
+        (Some(0), None) => cumulative(true)?,
+        (Some(start), None) if start < 0 => union(
+            rolling(-start, ClosedWindow::Left, false),
+            cumulative(true)?,
+        )?,
+        (Some(start), None) => {
+            difference(cumulative(true)?, rolling(start, ClosedWindow::Right, true))
+        },
+        (None, None) => agg.aggregate(expr.clone()),
+    };
+    let null_key_peers = expr.filter(key.is_null());
+    let null_key_peers = match agg {
+        FrameAggregate::Max => null_key_peers.max(),
+        FrameAggregate::Min => null_key_peers.min(),
+        _ => null_key_peers.sum(),
+    };
+    Ok(when(has_key).then(framed).otherwise(null_key_peers))
+}
+
+/// Reduce (with MIN or MAX) the rows whose `key` lies from `start` to `end` (inclusive)
+/// relative to that of the current row, where `None` is unbounded, for a frame that needn't
+/// include the current row; see [`range_frame_reduce`]. As the windows of the rolling (by)
+/// kernels end at the current row, the bounds of the frames are looked up in the (ascending)
+/// keys of the partition instead, and the rows within them reduced with the rolling kernels
+/// of the (sliced) groups. The rows without a key are not part of any frame.
+fn offset_range_extremum(
+    expr: Expr,
+    agg: FrameAggregate,
+    key: Expr,
+    start: Option<i64>,
+    end: Option<i64>,
+) -> Expr {
+    expr.apply_many(
+        move |c| {
+            let key = c[1].i64()?;
+            let has_key = key.is_not_null();
+            let keys = key.filter(&has_key)?.rechunk();
+            let keys = keys.cont_slice()?;
+            let values = c[0].filter(&has_key)?.rechunk();
+
+            // the index of the first row with a key at or after (`after`: beyond) `k + offset`
+            let bound = |k: i64, offset: Option<i64>, after: bool| match offset {
+                Some(offset) => {
+                    let k = k.saturating_add(offset);
+                    keys.partition_point(|&v| v < k || (after && v == k))
+                },
+                None if after => keys.len(),
+                None => 0,
+            };
+            let groups = keys
+                .iter()
+                .map(|&k| {
+                    let first = bound(k, start, false);
+                    let last = bound(k, end, true).max(first);
+                    [first as IdxSize, (last - first) as IdxSize]
+                })
+                .collect::<Vec<_>>();
+            let groups = GroupsProxy::Slice {
+                groups,
+                rolling: true,
+            };
+            // SAFETY: the groups are within the bounds of the values
+            let framed = unsafe {
+                match agg {
+                    FrameAggregate::Max => values.agg_max(&groups),
+                    _ => values.agg_min(&groups),
+                }
+            };
+
+            // put the frames back in the rows with a key
+            let mut n_keyed: IdxSize = 0;
+            let idx = (&has_key)
+                .into_iter()
+                .map(|has_key| {
+                    has_key.unwrap_or(false).then(|| {
+                        n_keyed += 1;
+                        n_keyed - 1
+                    })
+                })
+                .collect::<IdxCa>();
+            Ok(Some(framed.take(&idx)?))
+        },
+        &[key],
+        GetOutput::same_type(),
+    )
+}
+
+/// Take the value of a frame ending (`last_peer`) or starting at the current row from
+/// the last or first of its peers, the rows with the same ORDER BY values; as such, the
+/// frame includes all the peers of the current row. To be evaluated over an ordered window.
+fn at_peer(framed: Expr, order_by: &[Expr], last_peer: bool) -> Expr {
+    let offset = if last_peer { -1 } else { 1 };
+    let is_boundary = order_by
+        .iter()
+        .map(|e| e.clone().neq_missing(e.clone().shift(lit(offset))))
+        .reduce(|acc, e| acc.or(e))
+        .unwrap();
+    let row_idx = when(is_boundary)
+        .then(int_range(lit(0), len(), 1, IDX_DTYPE))
+        .otherwise(lit(Null));
+    let peer_idx = if last_peer {
+        row_idx.backward_fill(None)
+    } else {
+        row_idx.forward_fill(None)
+    };
+    framed.gather(peer_idx)
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
@@ -19,29 +19,42 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::ARRAY,
         keywords::AS,
         keywords::ASC,
+        keywords::BETWEEN,
         keywords::BOOLEAN,
         keywords::BY,
         keywords::CASE,
+        keywords::COPY,
         keywords::CREATE,
+        keywords::CUBE,
+        keywords::CURRENT,
         keywords::DATE,
         keywords::DATETIME,
+        keywords::DEALLOCATE,
+        keywords::DELETE,
         keywords::DESC,
         keywords::DISTINCT,
         keywords::DOUBLE,
         keywords::DROP,
         keywords::EXCEPT,
         keywords::EXCLUDE,
+        keywords::EXECUTE,
+        keywords::EXISTS,
         keywords::FLOAT,
+        keywords::FOLLOWING,
         keywords::FROM,
         keywords::FULL,
         keywords::GROUP,
+        keywords::GROUPING,
         keywords::HAVING,
         keywords::IN,
         keywords::INNER,
+        keywords::INSERT,
         keywords::INT,
         keywords::INTERSECT,
         keywords::INTERVAL,
+        keywords::INTO,
         keywords::JOIN,
+        keywords::LATERAL,
         keywords::LEFT,
         keywords::LIMIT,
         keywords::NOT,
@@ -51,24 +64,40 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::OR,
         keywords::ORDER,
         keywords::OUTER,
+        keywords::OVER,
+        keywords::PARTITION,
+        keywords::PIVOT,
+        keywords::PRECEDING,
+        keywords::PREPARE,
+        keywords::QUALIFY,
+        keywords::RANGE,
         keywords::REGEXP,
         keywords::RENAME,
         keywords::REPLACE,
         keywords::RIGHT,
         keywords::RLIKE,
+        keywords::ROLLUP,
+        keywords::ROW,
+        keywords::ROWS,
         keywords::SELECT,
         keywords::SEMI,
+        keywords::SETS,
         keywords::SHOW,
         keywords::TABLE,
         keywords::TABLES,
         keywords::THEN,
         keywords::TIME,
         keywords::TRUNCATE,
+        keywords::UNBOUNDED,
         keywords::UNION,
+        keywords::UNPIVOT,
         keywords::USING,
+        keywords::VALUES,
         keywords::VARCHAR,
+        keywords::VIEW,
         keywords::WHEN,
         keywords::WHERE,
+        keywords::WINDOW,
         keywords::WITH,
     ];
     keywords.extend_from_slice(sql_keywords);
@@ -2,6 +2,13 @@
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
 mod context;
+#[cfg(any(
+    feature = "csv",
+    feature = "ipc",
+    feature = "json",
+    feature = "parquet"
+))]
+mod copy;
 pub mod function_registry;
 mod functions;
 pub mod keywords;
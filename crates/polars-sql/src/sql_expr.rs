@@ -12,8 +12,8 @@ use std::ops::Div;
 use polars_core::export::regex;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
-use polars_plan::prelude::typed_lit;
 use polars_plan::prelude::LiteralValue::Null;
+use polars_plan::prelude::{typed_lit, Context};
 use polars_time::Duration;
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
@@ -110,6 +110,14 @@ impl SQLExprVisitor<'_> {
             } => self.visit_cast(expr, data_type, format, kind),
             SQLExpr::Ceil { expr, .. } => Ok(self.visit_expr(expr)?.ceil()),
             SQLExpr::CompoundIdentifier(idents) => self.visit_compound_identifier(idents),
+            SQLExpr::CompositeAccess { expr, key } => {
+                let expr = self.visit_expr(expr)?;
+                self.field_access_expr(&expr, &[key.value.as_str()], false)
+            },
+            SQLExpr::Exists { negated, .. } => {
+                let exists = self.visit_decorrelated_subquery(expr)?;
+                Ok(if *negated { exists.not() } else { exists })
+            },
             SQLExpr::Extract { field, expr } => {
                 parse_extract_date_part(self.visit_expr(expr)?, field)
             },
@@ -126,6 +134,12 @@ impl SQLExprVisitor<'_> {
                 let is_in = expr.is_in(elems);
                 Ok(if *negated { is_in.not() } else { is_in })
             },
+            SQLExpr::InSubquery { negated, .. }
+                if self.ctx.decorrelated_subquery(expr).is_some() =>
+            {
+                let is_in = self.visit_decorrelated_subquery(expr)?;
+                Ok(if *negated { is_in.not() } else { is_in })
+            },
             SQLExpr::InSubquery {
                 expr,
                 subquery,
@@ -180,7 +194,7 @@ impl SQLExprVisitor<'_> {
                 Ok(if *negated { matches.not() } else { matches })
             },
             SQLExpr::Subscript { expr, subscript } => self.visit_subscript(expr, subscript),
-            SQLExpr::Subquery(_) => polars_bail!(SQLInterface: "unexpected subquery"),
+            SQLExpr::Subquery(_) => self.visit_decorrelated_subquery(expr),
             SQLExpr::Trim {
                 expr,
                 trim_where,
@@ -273,6 +287,13 @@ impl SQLExprVisitor<'_> {
         polars_bail!(SQLInterface: "subquery type not supported");
     }
 
+    /// Visit a subquery that was decorrelated into a join with the current SELECT.
+    fn visit_decorrelated_subquery(&self, expr: &SQLExpr) -> PolarsResult<Expr> {
+        self.ctx.decorrelated_subquery(expr).ok_or_else(|| {
+            polars_err!(SQLInterface: "subqueries are only supported in the WHERE and SELECT clauses; found {}", expr)
+        })
+    }
+
     /// Visit a single SQL identifier.
     ///
     /// e.g. column
@@ -288,27 +309,15 @@ impl SQLExprVisitor<'_> {
     }
 
     fn visit_interval(&self, interval: &Interval) -> PolarsResult<Expr> {
-        if interval.last_field.is_some()
-            || interval.leading_field.is_some()
-            || interval.leading_precision.is_some()
-            || interval.fractional_seconds_precision.is_some()
-        {
-            polars_bail!(SQLSyntax: "unsupported interval syntax ('{}')", interval)
-        }
-        let s = match &*interval.value {
-            SQLExpr::UnaryOp { .. } => {
-                polars_bail!(SQLSyntax: "unary ops are not valid on interval strings; found {}", interval.value)
-            },
-            SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s),
-            _ => None,
-        };
-        match s {
-            Some(s) if s.contains('-') => {
-                polars_bail!(SQLInterface: "minus signs are not yet supported in interval strings; found '{}'", s)
-            },
-            Some(s) => Ok(lit(Duration::parse_interval(s))),
-            None => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
+        let duration = parse_sql_interval(interval)?;
+        // only intervals with calendar (month, week or day) parts need the Interval type;
+        // fixed-length ones remain durations, which can also be compared to durations
+        #[cfg(feature = "dtype-interval")]
+        if duration.months() != 0 || duration.weeks() != 0 || duration.days() != 0 {
+            let value = AnyValue::Interval(duration.try_to_interval()?);
+            return Ok(lit(Scalar::new(DataType::Interval, value)));
         }
+        Ok(lit(duration))
     }
 
     fn visit_like(
@@ -353,9 +362,12 @@ impl SQLExprVisitor<'_> {
     fn visit_subscript(&mut self, expr: &SQLExpr, subscript: &Subscript) -> PolarsResult<Expr> {
         let expr = self.visit_expr(expr)?;
         Ok(match subscript {
-            Subscript::Index { index } => {
-                let idx = adjust_one_indexed_param(self.visit_expr(index)?, true);
-                expr.list().get(idx, true)
+            Subscript::Index { index } => match self.visit_expr(index)? {
+                // map-style field access, eg: "col['field']"
+                Expr::Literal(LiteralValue::String(field)) => {
+                    self.field_access_expr(&expr, &[field.as_str()], false)?
+                },
+                idx => expr.list().get(adjust_one_indexed_param(idx, true), true),
             },
             Subscript::Slice { .. } => {
                 polars_bail!(SQLSyntax: "array slice syntax is not currently supported")
@@ -428,6 +440,17 @@ impl SQLExprVisitor<'_> {
         }
     }
 
+    /// Resolve the dtype of an expression against the active schema (if available).
+    fn resolve_dtype(&self, expr: &Expr) -> Option<DataType> {
+        let schema = self.active_schema?;
+        expr.to_field(schema, Context::Default)
+            .ok()
+            .map(|fld| fld.dtype)
+    }
+
+    /// Handle the `->`, `->>`, `#>` and `#>>` path operators.
+    ///
+    /// e.g. "col -> 'a'", "col #> '{a,b,0}'"
     fn struct_field_access_expr(
         &mut self,
         expr: &Expr,
@@ -439,11 +462,27 @@ impl SQLExprVisitor<'_> {
         } else {
             path
         }
-        .split(',');
+        .split(',')
+        .map(|p| p.trim())
+        .collect::<Vec<_>>();
 
+        self.field_access_expr(expr, &path_elems, infer_index)
+    }
+
+    /// Access nested fields of a Struct value, or of a String value containing JSON.
+    ///
+    /// If `infer_index` is set, integer path elements index into List (or JSON array) values.
+    fn field_access_expr(
+        &mut self,
+        expr: &Expr,
+        path_elems: &[&str],
+        infer_index: bool,
+    ) -> PolarsResult<Expr> {
+        if let Some(DataType::String) = self.resolve_dtype(expr) {
+            return json_path_match_expr(expr, path_elems, infer_index);
+        }
         let mut expr = expr.clone();
         for p in path_elems {
-            let p = p.trim();
             expr = if infer_index {
                 match p.parse::<i64>() {
                     Ok(idx) => expr.list().get(lit(idx), true),
@@ -767,6 +806,7 @@ impl SQLExprVisitor<'_> {
                 bitstring_to_bytes_literal(b)?
             },
             SQLValue::SingleQuotedString(s) => lit(s.clone()),
+            SQLValue::Placeholder(p) => self.ctx.get_param_expr(p)?,
             other => {
                 polars_bail!(SQLInterface: "value {:?} is not a supported literal type", other)
             },
@@ -822,6 +862,7 @@ impl SQLExprVisitor<'_> {
                 }
             },
             SQLValue::SingleQuotedString(s) => AnyValue::StringOwned(s.as_str().into()),
+            SQLValue::Placeholder(p) if op.is_none() => self.ctx.get_param(p)?.clone(),
             other => polars_bail!(SQLInterface: "value {:?} is not currently supported", other),
         })
     }
@@ -1039,6 +1080,44 @@ pub(crate) fn parse_sql_array(expr: &SQLExpr, ctx: &mut SQLContext) -> PolarsRes
     }
 }
 
+/// Parse a SQL literal (or bound placeholder) into an [`AnyValue`].
+pub(crate) fn parse_sql_any_value(
+    expr: &SQLExpr,
+    ctx: &mut SQLContext,
+) -> PolarsResult<AnyValue<'static>> {
+    let mut visitor = SQLExprVisitor {
+        ctx,
+        active_schema: None,
+    };
+    let s = visitor.array_expr_to_series(std::slice::from_ref(expr))?;
+    Ok(s.get(0)?.into_static())
+}
+
+/// Parse a SQL `INTERVAL` into a [`Duration`].
+pub(crate) fn parse_sql_interval(interval: &Interval) -> PolarsResult<Duration> {
+    if interval.last_field.is_some()
+        || interval.leading_field.is_some()
+        || interval.leading_precision.is_some()
+        || interval.fractional_seconds_precision.is_some()
+    {
+        polars_bail!(SQLSyntax: "unsupported interval syntax ('{}')", interval)
+    }
+    let s = match &*interval.value {
+        SQLExpr::UnaryOp { .. } => {
+            polars_bail!(SQLSyntax: "unary ops are not valid on interval strings; found {}", interval.value)
+        },
+        SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s),
+        _ => None,
+    };
+    match s {
+        Some(s) if s.contains('-') => {
+            polars_bail!(SQLInterface: "minus signs are not yet supported in interval strings; found '{}'", s)
+        },
+        Some(s) => Ok(Duration::parse_interval(s)),
+        None => polars_bail!(SQLSyntax: "invalid interval {:?}", interval),
+    }
+}
+
 pub(crate) fn parse_extract_date_part(expr: Expr, field: &DateTimeField) -> PolarsResult<Expr> {
     let field = match field {
         // handle 'DATE_PART' and all valid abbreviations/alternates
@@ -1128,6 +1207,27 @@ pub(crate) fn parse_extract_date_part(expr: Expr, field: &DateTimeField) -> Pola
     })
 }
 
+/// Extract a value from a String column containing JSON, using a jsonpath built from the
+/// given path elements (integer elements index into JSON arrays if `infer_index` is set).
+fn json_path_match_expr(expr: &Expr, path_elems: &[&str], infer_index: bool) -> PolarsResult<Expr> {
+    #[cfg(feature = "json")]
+    {
+        let mut json_path = String::from("$");
+        for p in path_elems {
+            match p.parse::<i64>() {
+                Ok(idx) if infer_index => json_path.push_str(&format!("[{}]", idx)),
+                _ => json_path.push_str(&format!("['{}']", p.replace('\'', "\\'"))),
+            }
+        }
+        Ok(expr.clone().str().json_path_match(lit(json_path)))
+    }
+    #[cfg(not(feature = "json"))]
+    {
+        let _ = (expr, path_elems, infer_index);
+        polars_bail!(SQLInterface: "JSON path extraction requires the 'json' feature")
+    }
+}
+
 /// Allow an expression that represents a 1-indexed parameter to
 /// be adjusted from 1-indexed (SQL) to 0-indexed (Rust/Polars)
 pub(crate) fn adjust_one_indexed_param(idx: Expr, null_if_zero: bool) -> Expr {
@@ -151,6 +151,9 @@ pub(crate) fn map_sql_dtype_to_polars(dtype: &SQLDataType) -> PolarsResult<DataT
         // temporal
         // ---------------------------------
         SQLDataType::Date => DataType::Date,
+        #[cfg(feature = "dtype-interval")]
+        SQLDataType::Interval => DataType::Interval,
+        #[cfg(not(feature = "dtype-interval"))]
         SQLDataType::Interval => DataType::Duration(TimeUnit::Microseconds),
         SQLDataType::Time(_, tz) => match tz {
             TimezoneInfo::None => DataType::Time,
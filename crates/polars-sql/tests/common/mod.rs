@@ -0,0 +1,188 @@
+//! Helpers shared by the SQL integration tests.
+// each test crate only uses some of the helpers
+#![allow(dead_code)]
+
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::SQLContext;
+
+/// Register the frames as tables (under the given names) with the context.
+pub fn register_tables<'a>(
+    mut ctx: SQLContext,
+    tables: impl IntoIterator<Item = (&'a str, DataFrame)>,
+) -> SQLContext {
+    for (name, df) in tables {
+        ctx.register(name, df.lazy());
+    }
+    ctx
+}
+
+/// Create a context with the frames registered as tables (under the given names).
+pub fn create_ctx<'a>(tables: impl IntoIterator<Item = (&'a str, DataFrame)>) -> SQLContext {
+    register_tables(SQLContext::new(), tables)
+}
+
+/// Collect the named columns of the frame as (nullable) integers.
+pub fn collect_cols(lf: LazyFrame, names: &[&str]) -> Vec<Vec<Option<i64>>> {
+    let df = lf.collect().unwrap();
+    names
+        .iter()
+        .map(|name| {
+            let s = df.column(name).unwrap().cast(&DataType::Int64).unwrap();
+            s.i64().unwrap().into_iter().collect()
+        })
+        .collect()
+}
+
+/// Collect the named column of the frame as (nullable) integers.
+pub fn collect_col(lf: LazyFrame, name: &str) -> Vec<Option<i64>> {
+    collect_cols(lf, &[name]).pop().unwrap()
+}
+
+/// Execute the query and collect the named columns of the result as (nullable) integers.
+pub fn execute_cols(ctx: &mut SQLContext, sql: &str, names: &[&str]) -> Vec<Vec<Option<i64>>> {
+    collect_cols(ctx.execute(sql).unwrap(), names)
+}
+
+/// Execute the query and collect the named column of the result as (nullable) integers.
+pub fn execute_col(ctx: &mut SQLContext, sql: &str, name: &str) -> Vec<Option<i64>> {
+    collect_col(ctx.execute(sql).unwrap(), name)
+}
+
+/// The `org` (employees and their managers) and `edges` (a graph with a cycle) tables.
+pub fn org_tables() -> [(&'static str, DataFrame); 2] {
+    let org = df! {
+      "id" => [1, 2, 3, 4, 5],
+      "manager" => [None, Some(1), Some(1), Some(2), Some(4)],
+    }
+    .unwrap();
+    let edges = df! {
+      "src" => [1, 2, 3, 4],
+      "dst" => [2, 3, 1, 5],
+    }
+    .unwrap();
+    [("org", org), ("edges", edges)]
+}
+
+/// The `customers` and `orders` tables (with an order of a customer that doesn't exist).
+pub fn customer_order_tables() -> [(&'static str, DataFrame); 2] {
+    let customers = df! {
+      "id" => [1, 2, 3],
+      "name" => ["a", "b", "c"],
+    }
+    .unwrap();
+    let orders = df! {
+      "cust" => [1, 1, 2, 4],
+      "amount" => [10, 20, 30, 40],
+    }
+    .unwrap();
+    [("customers", customers), ("orders", orders)]
+}
+
+/// The (TPC-H style) `customer`, `orders` and `lineitem` tables.
+pub fn tpch_tables() -> [(&'static str, DataFrame); 3] {
+    let customer = df! {
+      "c_custkey" => [1, 2, 3],
+      "c_name" => ["a", "b", "c"],
+    }
+    .unwrap();
+    let orders = df! {
+      "o_orderkey" => [10, 11, 12],
+      "o_custkey" => [1, 1, 2],
+    }
+    .unwrap();
+    let lineitem = df! {
+      "l_orderkey" => [10, 10, 11, 12, 13],
+      "l_qty" => [1, 2, 3, 4, 5],
+    }
+    .unwrap();
+    [
+        ("customer", customer),
+        ("orders", orders),
+        ("lineitem", lineitem),
+    ]
+}
+
+/// The `df` table of values by key and timestamp (with a tie in the timestamps).
+pub fn ranking_tables() -> [(&'static str, DataFrame); 1] {
+    let df = df! {
+      "k" => ["a", "a", "a", "b", "b"],
+      "ts" => [1, 3, 2, 5, 5],
+      "v" => [10, 20, 30, 40, 50],
+    }
+    .unwrap();
+    [("df", df)]
+}
+
+/// The `sales` (long) and `wide` tables.
+pub fn pivot_tables() -> [(&'static str, DataFrame); 2] {
+    let sales = df! {
+      "region" => ["n", "n", "s", "s", "n"],
+      "month" => ["jan", "feb", "jan", "jan", "mar"],
+      "amount" => [1, 2, 3, 4, 5],
+    }
+    .unwrap();
+    let wide = df! {
+      "id" => [1, 2],
+      "a" => [Some(10), None],
+      "b" => [Some(20), Some(30)],
+    }
+    .unwrap();
+    [("sales", sales), ("wide", wide)]
+}
+
+/// The `df` table of integers with distinct letters.
+pub fn letter_tables() -> [(&'static str, DataFrame); 1] {
+    let df = df! {
+      "a" => [1, 2, 3, 4],
+      "b" => ["w", "x", "y", "z"],
+    }
+    .unwrap();
+    [("df", df)]
+}
+
+/// The `tbl` table of (nullable) scores by id.
+pub fn score_tables() -> [(&'static str, DataFrame); 1] {
+    let df = df! {
+      "id" => [1, 2, 3],
+      "name" => ["a", "b", "c"],
+      "score" => [Some(1.5), None, Some(3.5)],
+    }
+    .unwrap();
+    [("tbl", df)]
+}
+
+/// The `df` table of integers with alternating letters.
+pub fn alternating_tables() -> [(&'static str, DataFrame); 1] {
+    let df = df! {
+      "a" => [1, 2, 3, 4],
+      "b" => ["x", "y", "x", "y"],
+    }
+    .unwrap();
+    [("df", df)]
+}
+
+/// The `tbl` table with a (nested) struct column and a column of JSON strings.
+pub fn struct_tables() -> [(&'static str, DataFrame); 1] {
+    let df = df! {
+      "id" => [1, 2, 3],
+      "a" => [10, 20, 30],
+      "c" => ["x", "y", "z"],
+      "js" => [
+        r#"{"a": 1, "b": {"c": "x"}, "d": [4, 5]}"#,
+        r#"{"a": 2, "b": {"c": "y"}, "d": [6]}"#,
+        r#"{"a": 3, "b": {}, "d": []}"#,
+      ],
+    }
+    .unwrap()
+    .lazy()
+    .select([
+        col("id"),
+        as_struct(vec![col("a"), as_struct(vec![col("c")]).alias("b")]).alias("s"),
+        col("js"),
+    ])
+    .collect()
+    .unwrap();
+    [("tbl", df)]
+}
@@ -125,20 +125,17 @@ fn iss_8419() {
     }
     .unwrap()
     .lazy();
-    let expected = df
-        .clone()
-        .select(&[
-            col("Year"),
-            col("Country"),
-            col("Sales"),
-            col("Sales")
-                .sort(SortOptions::default().with_order_descending(true))
-                .cum_sum(false)
-                .alias("SalesCumulative"),
-        ])
-        .sort(["SalesCumulative"], Default::default())
-        .collect()
-        .unwrap();
+    // With ORDER BY the frame is RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW, so each
+    // row sums the sales that are at least its own. (The cumulative sum in descending order
+    // is not assigned to the rows in their original order, which gave 6000 to the 1000 sales
+    // of 2018/US.)
+    let expected = df! {
+      "Year"=> [2020, 2020, 2019, 2019, 2018, 2018],
+      "Country"=> ["UK", "US", "UK", "US", "UK", "US"],
+      "Sales"=> [6000, 5000, 4000, 3000, 2000, 1000],
+      "SalesCumulative"=> [6000, 11000, 15000, 18000, 20000, 21000]
+    }
+    .unwrap();
     let mut ctx = SQLContext::new();
     ctx.register("df", df);
 
@@ -0,0 +1,147 @@
+#![cfg(all(feature = "csv", feature = "parquet"))]
+
+use std::path::PathBuf;
+
+use polars_core::df;
+use polars_lazy::prelude::*;
+
+mod common;
+use common::{alternating_tables, create_ctx};
+
+fn temp_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("polars_sql_copy_{}_{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&path);
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn test_copy_to_parquet() {
+    let mut ctx = create_ctx(alternating_tables());
+    let path = temp_path("out.parquet");
+    let sql = format!(
+        "COPY (SELECT a, b FROM df WHERE a > 1 ORDER BY a DESC) TO '{}' (FORMAT parquet, COMPRESSION zstd, COMPRESSION_LEVEL 3)",
+        path.display()
+    );
+
+    // nothing is written until the frame is collected
+    let lf = ctx.execute(&sql).unwrap();
+    assert!(!path.exists());
+    lf.collect().unwrap();
+
+    let df = LazyFrame::scan_parquet(&path, Default::default())
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+      "a" => [4, 3, 2],
+      "b" => ["y", "x", "y"],
+    }
+    .unwrap();
+    assert!(df.equals(&expected));
+}
+
+#[test]
+fn test_copy_table_to_csv() {
+    let mut ctx = create_ctx(alternating_tables());
+    let path = temp_path("out.csv");
+    let sql = format!(
+        "COPY df (b) TO '{}' WITH (HEADER false, DELIMITER ';')",
+        path.display()
+    );
+    ctx.execute(&sql).unwrap().collect().unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "x\ny\nx\ny\n");
+}
+
+#[test]
+fn test_copy_from_scan() {
+    let mut ctx = create_ctx(alternating_tables());
+    let src = temp_path("src.parquet");
+    ctx.execute(&format!("COPY df TO '{}'", src.display()))
+        .unwrap()
+        .collect()
+        .unwrap();
+    ctx.register(
+        "scanned",
+        LazyFrame::scan_parquet(&src, Default::default()).unwrap(),
+    );
+
+    // the scan is streamed into the target file
+    let path = temp_path("from_scan.csv");
+    let sql = format!(
+        "COPY (SELECT a * 10 AS a, b FROM scanned WHERE b = 'x') TO '{}'",
+        path.display()
+    );
+    ctx.execute(&sql).unwrap().collect().unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "a,b\n10,x\n30,x\n");
+}
+
+#[test]
+fn test_copy_window_query() {
+    let mut ctx = create_ctx(alternating_tables());
+
+    // window functions can't be streamed, so the result is collected before it is written
+    let path = temp_path("window.csv");
+    let sql = format!(
+        "COPY (SELECT a, SUM(a) OVER (PARTITION BY b) AS s FROM df) TO '{}'",
+        path.display()
+    );
+    ctx.execute(&sql).unwrap().collect().unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "a,s\n1,4\n2,6\n3,4\n4,6\n"
+    );
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_copy_to_json() {
+    let mut ctx = create_ctx(alternating_tables());
+    let path = temp_path("out.json");
+    let sql = format!(
+        "COPY (SELECT a, b FROM df WHERE b = 'x') TO '{}'",
+        path.display()
+    );
+    ctx.execute(&sql).unwrap().collect().unwrap();
+
+    let df = LazyJsonLineReader::new(&path)
+        .finish()
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+      "a" => [1i64, 3],
+      "b" => ["x", "x"],
+    }
+    .unwrap();
+    assert!(df.equals(&expected));
+}
+
+#[test]
+fn test_copy_partitioned() {
+    let mut ctx = create_ctx(alternating_tables());
+    let path = temp_path("partitioned");
+    let sql = format!(
+        "COPY (SELECT * FROM df) TO '{}' (FORMAT parquet, PARTITION_BY (b))",
+        path.display()
+    );
+    ctx.execute(&sql).unwrap().collect().unwrap();
+    assert!(path.join("b=x").is_dir());
+    assert!(path.join("b=y").is_dir());
+}
+
+#[test]
+fn test_copy_errors() {
+    let mut ctx = create_ctx(alternating_tables());
+    for sql in [
+        "COPY df TO 'out.xyz'",
+        "COPY df TO 'out' (FORMAT xyz)",
+        "COPY df TO 'out.csv' (PARTITION_BY (b))",
+        "COPY df TO 'out.parquet' (COMPRESSION xyz)",
+        "COPY df TO 'out.parquet' (COMPRESSION 'lzo')",
+        "COPY df TO 'out.parquet' (ROW_GROUP_SIZE 'many')",
+        "COPY missing TO 'out.parquet'",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
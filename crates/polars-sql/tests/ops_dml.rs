@@ -0,0 +1,178 @@
+use polars_core::df;
+
+mod common;
+use common::{create_ctx, execute_col, score_tables};
+
+#[test]
+fn test_create_view() {
+    let mut ctx = create_ctx(score_tables());
+    ctx.execute("CREATE VIEW v (k) AS SELECT id * 10 FROM tbl WHERE id > 1")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(ctx.get_tables(), ["tbl", "v"]);
+    assert_eq!(
+        execute_col(&mut ctx, "SELECT k FROM v ORDER BY k", "k"),
+        [Some(20), Some(30)]
+    );
+
+    assert!(ctx.execute("CREATE VIEW v AS SELECT id FROM tbl").is_err());
+    assert!(ctx
+        .execute("CREATE OR REPLACE VIEW tbl AS SELECT 1")
+        .is_err());
+    ctx.execute("CREATE VIEW IF NOT EXISTS v AS SELECT id AS k FROM tbl")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(
+        execute_col(&mut ctx, "SELECT k FROM v ORDER BY k", "k"),
+        [Some(20), Some(30)]
+    );
+    ctx.execute("CREATE OR REPLACE VIEW v AS SELECT id AS k FROM tbl")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(
+        execute_col(&mut ctx, "SELECT k FROM v ORDER BY k", "k"),
+        [Some(1), Some(2), Some(3)]
+    );
+
+    // views cannot be modified, or dropped as tables
+    assert!(ctx.execute("INSERT INTO v VALUES (4)").is_err());
+    assert!(ctx.execute("DELETE FROM v").is_err());
+    assert!(ctx.execute("DROP TABLE v").is_err());
+    assert!(ctx.execute("DROP VIEW tbl").is_err());
+    ctx.execute("DROP VIEW v").unwrap().collect().unwrap();
+    assert_eq!(ctx.get_tables(), ["tbl"]);
+}
+
+#[test]
+fn test_view_is_replanned() {
+    let mut ctx = create_ctx(score_tables());
+    ctx.execute("CREATE VIEW v AS SELECT id FROM tbl WHERE id > 1")
+        .unwrap()
+        .collect()
+        .unwrap();
+    ctx.execute("CREATE VIEW w AS WITH t AS (SELECT id FROM v) SELECT SUM(id) AS s FROM t")
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    // views reflect the current contents of the tables (and views) they refer to
+    ctx.execute("INSERT INTO tbl VALUES (4, 'd', 4.5)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(
+        execute_col(&mut ctx, "SELECT id FROM v ORDER BY id", "id"),
+        [Some(2), Some(3), Some(4)]
+    );
+    ctx.execute("CREATE OR REPLACE VIEW v AS SELECT id FROM tbl WHERE id < 3")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(execute_col(&mut ctx, "SELECT s FROM w", "s"), [Some(3)]);
+
+    // the CTEs of a query are not visible to the views it refers to
+    let sql = "WITH t AS (SELECT 100 AS id) SELECT s FROM w, t";
+    assert_eq!(execute_col(&mut ctx, sql, "s"), [Some(3)]);
+
+    // a view cannot refer to itself
+    assert!(ctx
+        .execute("CREATE OR REPLACE VIEW v AS SELECT id FROM v")
+        .is_err());
+}
+
+#[test]
+fn test_insert_into() {
+    let mut ctx = create_ctx(score_tables());
+    ctx.execute("INSERT INTO tbl VALUES (4, 'd', 4.5), (5, 'e', NULL)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    ctx.execute("INSERT INTO tbl (name, id) SELECT name, id + 5 FROM tbl WHERE id < 3")
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    let df = ctx
+        .execute("SELECT * FROM tbl ORDER BY id")
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+      "id" => [1, 2, 3, 4, 5, 6, 7],
+      "name" => ["a", "b", "c", "d", "e", "a", "b"],
+      "score" => [Some(1.5), None, Some(3.5), Some(4.5), None, None, None],
+    }
+    .unwrap();
+    assert!(df.equals_missing(&expected));
+}
+
+#[test]
+fn test_insert_errors() {
+    let mut ctx = create_ctx(score_tables());
+    for sql in [
+        "INSERT INTO tbl VALUES (4, 'd')",
+        "INSERT INTO tbl (id, id) VALUES (4, 5)",
+        "INSERT INTO tbl (id, xyz) VALUES (4, 5)",
+        "INSERT INTO missing VALUES (4)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+    // the table is modified lazily, so values that cannot be cast to the table schema
+    // raise when it is collected
+    ctx.execute("INSERT INTO tbl (id) VALUES ('xyz')")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(ctx
+        .execute("SELECT id FROM tbl")
+        .unwrap()
+        .collect()
+        .is_err());
+}
+
+#[test]
+fn test_delete_from() {
+    let mut ctx = create_ctx(score_tables());
+
+    // rows for which the predicate is null are kept
+    ctx.execute("DELETE FROM tbl WHERE score > 2")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(
+        execute_col(&mut ctx, "SELECT id FROM tbl ORDER BY id", "id"),
+        [Some(1), Some(2)]
+    );
+    ctx.execute("DELETE FROM tbl AS t WHERE t.name IN (SELECT name FROM tbl WHERE id = 1)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(execute_col(&mut ctx, "SELECT id FROM tbl", "id"), [Some(2)]);
+
+    ctx.execute("DELETE FROM tbl").unwrap().collect().unwrap();
+    let df = ctx.execute("SELECT * FROM tbl").unwrap().collect().unwrap();
+    assert_eq!(df.shape(), (0, 3));
+}
+
+#[test]
+fn test_repeated_modifications() {
+    let mut ctx = create_ctx(score_tables());
+    for i in 4..54 {
+        ctx.execute(&format!("INSERT INTO tbl VALUES ({i}, 'x', {i}.5)"))
+            .unwrap()
+            .collect()
+            .unwrap();
+        ctx.execute(&format!("DELETE FROM tbl WHERE id = {}", i - 3))
+            .unwrap()
+            .collect()
+            .unwrap();
+    }
+    // the table refers to itself in both sides of the join
+    let sql = "
+      SELECT t.id FROM tbl t JOIN tbl u ON t.id = u.id + 1 ORDER BY t.id
+    ";
+    assert_eq!(execute_col(&mut ctx, sql, "id"), [Some(52), Some(53)]);
+}
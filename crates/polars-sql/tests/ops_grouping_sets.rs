@@ -0,0 +1,137 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_sql::*;
+
+mod common;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "region" => ["east", "east", "east", "west", "west"],
+      "product" => ["a", "b", "b", "a", "a"],
+      "sales" => [10, 20, 30, 40, 50],
+    }
+    .unwrap();
+    common::create_ctx([("df", df)])
+}
+
+fn execute_sorted(ctx: &mut SQLContext, sql: &str, by: &[&str]) -> DataFrame {
+    ctx.execute(sql)
+        .unwrap()
+        .sort(
+            by.iter().copied(),
+            SortMultipleOptions::default().with_nulls_last(true),
+        )
+        .collect()
+        .unwrap()
+}
+
+#[test]
+fn test_group_by_rollup() {
+    let mut ctx = create_ctx();
+    let expected = df! {
+      "region" => [Some("east"), Some("east"), Some("east"), Some("west"), Some("west"), None],
+      "product" => [Some("a"), Some("b"), None, Some("a"), None, None],
+      "total" => [10, 50, 60, 90, 90, 150],
+    }
+    .unwrap();
+
+    for sql in [
+        "SELECT region, product, SUM(sales) AS total FROM df GROUP BY ROLLUP (region, product)",
+        "SELECT region, product, SUM(sales) AS total FROM df GROUP BY region, product WITH ROLLUP",
+        "SELECT region, product, SUM(sales) AS total FROM df GROUP BY ROLLUP (1, 2)",
+        "SELECT region, product, SUM(sales) AS total FROM df
+         GROUP BY GROUPING SETS ((region, product), (region), ())",
+    ] {
+        let actual = execute_sorted(&mut ctx, sql, &["region", "product"]);
+        assert!(actual.equals_missing(&expected), "{sql}\n{actual}");
+    }
+}
+
+#[test]
+fn test_group_by_cube() {
+    let mut ctx = create_ctx();
+    let sql = "SELECT region, product, COUNT(*) AS n FROM df GROUP BY CUBE (region, product)";
+    let actual = execute_sorted(&mut ctx, sql, &["region", "product"]);
+    let expected = df! {
+      "region" => [Some("east"), Some("east"), Some("east"), Some("west"), Some("west"), None, None, None],
+      "product" => [Some("a"), Some("b"), None, Some("a"), None, Some("a"), Some("b"), None],
+      "n" => [1u32, 2, 3, 2, 2, 3, 2, 5],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual}");
+}
+
+#[test]
+fn test_group_by_keys_and_grouping_sets() {
+    let mut ctx = create_ctx();
+    let sql = "
+      SELECT region, product, MAX(sales) AS max_sales
+      FROM df
+      GROUP BY region, GROUPING SETS ((product), ())
+    ";
+    let actual = execute_sorted(&mut ctx, sql, &["region", "product"]);
+    let expected = df! {
+      "region" => ["east", "east", "east", "west", "west"],
+      "product" => [Some("a"), Some("b"), None, Some("a"), None],
+      "max_sales" => [10, 30, 30, 50, 50],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual}");
+}
+
+#[test]
+fn test_grouping_function() {
+    let mut ctx = create_ctx();
+    let sql = "
+      SELECT
+        region,
+        product,
+        GROUPING(region) AS g_region,
+        GROUPING(region, product) AS g_both,
+        SUM(sales) AS total
+      FROM df
+      GROUP BY ROLLUP (region, product)
+    ";
+    let actual = execute_sorted(&mut ctx, sql, &["region", "product"]);
+    let expected = df! {
+      "region" => [Some("east"), Some("east"), Some("east"), Some("west"), Some("west"), None],
+      "product" => [Some("a"), Some("b"), None, Some("a"), None, None],
+      "g_region" => [0, 0, 0, 0, 0, 1],
+      "g_both" => [0, 0, 1, 0, 1, 3],
+      "total" => [10, 50, 60, 90, 90, 150],
+    }
+    .unwrap();
+    assert!(actual.equals_missing(&expected), "{actual}");
+
+    // without grouping sets, every key is part of the (single) grouping set
+    let sql = "SELECT region, GROUPING(region) AS g FROM df GROUP BY region";
+    let actual = execute_sorted(&mut ctx, sql, &["region"]);
+    let expected = df! {
+      "region" => ["east", "west"],
+      "g" => [0, 0],
+    }
+    .unwrap();
+    assert!(actual.equals(&expected), "{actual}");
+}
+
+#[test]
+fn test_grouping_sets_invalid() {
+    let mut ctx = create_ctx();
+    for (sql, msg) in [
+        (
+            "SELECT region, SUM(sales) FROM df GROUP BY region WITH TOTALS",
+            "TOTALS",
+        ),
+        (
+            "SELECT region, GROUPING(product) FROM df GROUP BY ROLLUP (region)",
+            "GROUPING argument 'product' is not a GROUP BY expression",
+        ),
+        (
+            "SELECT GROUPING(region) FROM df",
+            "GROUPING function is only valid in a query with GROUP BY",
+        ),
+    ] {
+        let err = ctx.execute(sql).and_then(|lf| lf.collect()).unwrap_err();
+        assert!(err.to_string().contains(msg), "{sql}: {err}");
+    }
+}
@@ -0,0 +1,128 @@
+use polars_core::df;
+use polars_lazy::prelude::*;
+
+mod common;
+use common::{create_ctx, execute_col, tpch_tables};
+
+#[test]
+fn test_implicit_join_keys() {
+    let mut ctx = create_ctx(tpch_tables());
+    let sql = "
+      SELECT c_name, SUM(l_qty) AS qty
+      FROM customer, orders, lineitem
+      WHERE c_custkey = o_custkey AND o_orderkey = l_orderkey AND l_qty < 4
+      GROUP BY c_name
+      ORDER BY c_name
+    ";
+    assert_eq!(execute_col(&mut ctx, sql, "qty"), [Some(6)]);
+
+    let sql = "
+      SELECT c.c_custkey AS key, l.l_qty AS qty
+      FROM customer c, orders o LEFT JOIN lineitem l ON o.o_orderkey = l.l_orderkey
+      WHERE o.o_custkey = c.c_custkey
+      ORDER BY qty
+    ";
+    assert_eq!(
+        execute_col(&mut ctx, sql, "key"),
+        [1, 1, 1, 2].map(Some).to_vec()
+    );
+    assert_eq!(
+        execute_col(&mut ctx, sql, "qty"),
+        [1, 2, 3, 4].map(Some).to_vec()
+    );
+}
+
+#[test]
+fn test_implicit_join_key_supertypes() {
+    let mut ctx = create_ctx(tpch_tables());
+    let nations = df! {
+      "n_custkey" => [1i64, 3],
+      "n_name" => ["x", "y"],
+    }
+    .unwrap();
+    ctx.register("nation", nations.lazy());
+    let sql = "
+      SELECT c_name, n_name
+      FROM customer, nation
+      WHERE c_custkey = n_custkey
+      ORDER BY c_name
+    ";
+    let df = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "c_name" => ["a", "c"],
+      "n_name" => ["x", "y"],
+    }
+    .unwrap();
+    assert!(df.equals(&expected), "{df}");
+}
+
+#[test]
+fn test_implicit_cross_join() {
+    let mut ctx = create_ctx(tpch_tables());
+    let sql = "
+      SELECT c_custkey, o_orderkey
+      FROM customer, orders
+      WHERE c_custkey < o_custkey
+    ";
+    assert_eq!(execute_col(&mut ctx, sql, "c_custkey"), [Some(1)]);
+    assert_eq!(execute_col(&mut ctx, sql, "o_orderkey"), [Some(12)]);
+}
+
+#[test]
+fn test_implicit_lateral_join() {
+    let mut ctx = create_ctx(tpch_tables());
+    let sql = "
+      SELECT c_custkey, t.k
+      FROM customer c, LATERAL (
+        SELECT MAX(o_orderkey) AS k FROM orders o WHERE o.o_custkey = c.c_custkey
+      ) AS t
+      ORDER BY c_custkey
+    ";
+    assert_eq!(execute_col(&mut ctx, sql, "k"), [Some(11), Some(12), None]);
+}
+
+#[test]
+fn test_implicit_lateral_join_trailing_commas() {
+    let mut ctx = create_ctx(tpch_tables());
+    let sql = "
+      SELECT c_custkey, t.k,
+      FROM customer c, LATERAL (
+        SELECT MAX(o_orderkey) AS k, FROM orders o WHERE o.o_custkey = c.c_custkey
+      ) AS t
+      ORDER BY c_custkey, t.k,
+    ";
+    assert_eq!(execute_col(&mut ctx, sql, "k"), [Some(11), Some(12), None]);
+}
+
+#[test]
+fn test_implicit_lateral_join_in_view() {
+    let mut ctx = create_ctx(tpch_tables());
+    ctx.execute(
+        "CREATE VIEW latest AS
+         SELECT c_custkey, t.k
+         FROM customer c, LATERAL (
+           SELECT MAX(o_orderkey) AS k FROM orders o WHERE o.o_custkey = c.c_custkey
+         ) AS t",
+    )
+    .unwrap();
+
+    let sql = "
+      SELECT c_custkey, t.k
+      FROM customer c, LATERAL (
+        SELECT MIN(o_orderkey) AS k FROM orders o WHERE o.o_custkey = c.c_custkey
+      ) AS t
+      ORDER BY c_custkey
+    ";
+    assert_eq!(execute_col(&mut ctx, sql, "k"), [Some(10), Some(12), None]);
+    let sql = "SELECT c_custkey, k FROM latest ORDER BY c_custkey";
+    assert_eq!(execute_col(&mut ctx, sql, "k"), [Some(11), Some(12), None]);
+}
+
+#[test]
+fn test_implicit_join_errors() {
+    let mut ctx = create_ctx(tpch_tables());
+    let sql = "
+      SELECT * FROM customer, orders FULL JOIN lineitem ON orders.o_orderkey = lineitem.l_orderkey
+    ";
+    assert!(ctx.execute(sql).is_err());
+}
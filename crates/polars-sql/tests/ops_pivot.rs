@@ -0,0 +1,81 @@
+#![cfg(feature = "pivot")]
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::{create_ctx, pivot_tables};
+
+fn execute(ctx: &mut SQLContext, sql: &str) -> DataFrame {
+    ctx.execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_pivot() {
+    let mut ctx = create_ctx(pivot_tables());
+    let df = execute(
+        &mut ctx,
+        "SELECT * FROM sales PIVOT (SUM(amount) FOR month IN ('jan', 'feb'))",
+    );
+    let expected = df! {
+      "region" => ["n", "s"],
+      "jan" => [Some(1), Some(7)],
+      "feb" => [Some(2), None],
+    }
+    .unwrap();
+    assert!(df.equals_missing(&expected), "{df}");
+
+    let df = execute(
+        &mut ctx,
+        "SELECT p.r, p.feb
+         FROM sales PIVOT (SUM(amount) FOR month IN ('jan', 'feb') DEFAULT ON NULL (0)) AS p(r, jan, feb)
+         ORDER BY p.r",
+    );
+    let expected = df! {
+      "r" => ["n", "s"],
+      "feb" => [2, 0],
+    }
+    .unwrap();
+    assert!(df.equals(&expected), "{df}");
+
+    let df = execute(
+        &mut ctx,
+        "SELECT region, j_total, CAST(j_n AS INTEGER) AS j_n
+         FROM sales PIVOT (SUM(amount) AS total, COUNT(*) AS n FOR month IN ('jan' AS j))",
+    );
+    let expected = df! {
+      "region" => ["n", "s"],
+      "j_total" => [1, 7],
+      "j_n" => [1, 2],
+    }
+    .unwrap();
+    assert!(df.equals(&expected), "{df}");
+}
+
+#[test]
+fn test_unpivot() {
+    let mut ctx = create_ctx(pivot_tables());
+    let df = execute(
+        &mut ctx,
+        "SELECT * FROM wide UNPIVOT (val FOR attr IN (a, b)) ORDER BY id, attr",
+    );
+    let expected = df! {
+      "id" => [1, 1, 2],
+      "attr" => ["a", "b", "b"],
+      "val" => [10, 20, 30],
+    }
+    .unwrap();
+    assert!(df.equals(&expected), "{df}");
+}
+
+#[test]
+fn test_pivot_errors() {
+    let mut ctx = create_ctx(pivot_tables());
+    for sql in [
+        "SELECT * FROM sales PIVOT (SUM(amount) FOR month IN (ANY))",
+        "SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN ('q1'))",
+        "SELECT * FROM wide UNPIVOT (val FOR attr IN (a, c))",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
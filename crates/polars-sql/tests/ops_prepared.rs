@@ -0,0 +1,173 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+mod common;
+use common::{collect_col, create_ctx, letter_tables};
+
+#[test]
+fn test_positional_params() {
+    let mut ctx = create_ctx(letter_tables());
+    for sql in [
+        "SELECT a FROM df WHERE a > $1 AND b <> $2 ORDER BY a",
+        "SELECT a FROM df WHERE a > ? AND b <> ? ORDER BY a",
+        "SELECT a FROM df WHERE b <> $2 AND a > $1 ORDER BY a",
+    ] {
+        let params = [AnyValue::Int32(1), AnyValue::StringOwned("y".into())];
+        let lf = ctx.execute_with_params(sql, &params).unwrap();
+        assert_eq!(collect_col(lf, "a"), [Some(2), Some(4)], "{sql}");
+    }
+
+    // anonymous placeholders are bound in order of appearance
+    let sql = "SELECT a + ? AS a FROM df WHERE a IN (?, ?) ORDER BY a";
+    let params = [AnyValue::Int64(10), AnyValue::Int64(2), AnyValue::Int64(3)];
+    let lf = ctx.execute_with_params(sql, &params).unwrap();
+    assert_eq!(collect_col(lf, "a"), [Some(12), Some(13)]);
+}
+
+#[test]
+fn test_named_params() {
+    let mut ctx = create_ctx(letter_tables());
+    let sql = "SELECT a FROM df WHERE a BETWEEN :lo AND :hi ORDER BY a";
+    let params = [("lo", AnyValue::Int32(2)), ("hi", AnyValue::Int32(3))];
+    let lf = ctx.execute_with_named_params(sql, &params).unwrap();
+    assert_eq!(collect_col(lf, "a"), [Some(2), Some(3)]);
+}
+
+#[test]
+fn test_missing_params() {
+    let mut ctx = create_ctx(letter_tables());
+    assert!(ctx.execute("SELECT a FROM df WHERE a > $1").is_err());
+    assert!(ctx
+        .execute_with_params("SELECT a FROM df WHERE a > $2", &[AnyValue::Int32(1)])
+        .is_err());
+
+    // the values (and CTEs) of a failed execution are not kept for the next one
+    let sql = "WITH t AS (SELECT a FROM df) SELECT a FROM t, missing WHERE a > $1";
+    assert!(ctx.execute_with_params(sql, &[AnyValue::Int32(1)]).is_err());
+    assert!(ctx.execute("SELECT a FROM df WHERE a > $1").is_err());
+    assert!(ctx.execute("SELECT a FROM t").is_err());
+}
+
+#[test]
+fn test_prepared_statements() {
+    let mut ctx = create_ctx(letter_tables());
+    ctx.execute("PREPARE q AS SELECT a FROM df WHERE a >= $1 ORDER BY a")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(ctx.get_prepared_statements(), ["q"]);
+
+    let lf = ctx.execute("EXECUTE q(3)").unwrap();
+    assert_eq!(collect_col(lf, "a"), [Some(3), Some(4)]);
+
+    let lf = ctx.execute_prepared("q", &[AnyValue::Int32(4)]).unwrap();
+    assert_eq!(collect_col(lf, "a"), [Some(4)]);
+    let lf = ctx.execute_prepared("q", &[AnyValue::Int32(4)]).unwrap();
+    assert_eq!(collect_col(lf, "a"), [Some(4)]);
+
+    // the cached plan is invalidated when the registered tables change
+    ctx.register("df", df! { "a" => [5, 6] }.unwrap().lazy());
+    let lf = ctx.execute_prepared("q", &[AnyValue::Int32(4)]).unwrap();
+    assert_eq!(collect_col(lf, "a"), [Some(5), Some(6)]);
+
+    ctx.execute("DEALLOCATE PREPARE q")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!(ctx.get_prepared_statements().is_empty());
+    assert!(ctx.execute("EXECUTE q(3)").is_err());
+}
+
+#[test]
+fn test_prepared_statement_types() {
+    let mut ctx = create_ctx(letter_tables());
+    ctx.execute("PREPARE q(TEXT, INT) AS SELECT a FROM df WHERE b = $1 OR a = $2")
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    let lf = ctx.execute("EXECUTE q('w', 3)").unwrap();
+    assert_eq!(collect_col(lf, "a"), [Some(1), Some(3)]);
+
+    let lf = ctx
+        .execute_prepared("q", &[AnyValue::StringOwned("z".into()), AnyValue::Null])
+        .unwrap();
+    assert_eq!(collect_col(lf, "a"), [Some(4)]);
+
+    assert!(ctx.execute("EXECUTE q('w')").is_err());
+    assert!(ctx.execute("PREPARE t AS DROP TABLE df").is_err());
+}
+
+#[test]
+fn test_prepared_statement_binding() {
+    let mut ctx = create_ctx(letter_tables());
+    ctx.execute("PREPARE q AS SELECT a * $2 AS a FROM df WHERE a > $1 ORDER BY a")
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    // the statement is planned once, and the values are bound to the cached plan
+    for (lo, factor, expected) in [
+        (2, 10, vec![Some(30), Some(40)]),
+        (0, 1, vec![Some(1), Some(2), Some(3), Some(4)]),
+        (3, -1, vec![Some(-4)]),
+    ] {
+        let params = [AnyValue::Int32(lo), AnyValue::Int32(factor)];
+        let lf = ctx.execute_prepared("q", &params).unwrap();
+        assert_eq!(collect_col(lf, "a"), expected);
+    }
+    // as are values of other types
+    let params = [AnyValue::Float64(2.5), AnyValue::Int64(2)];
+    let lf = ctx.execute_prepared("q", &params).unwrap();
+    assert_eq!(collect_col(lf, "a"), [Some(6), Some(8)]);
+
+    // the values are bound throughout the plan (such as in every input of a UNION)
+    ctx.execute(
+        "PREPARE u AS SELECT a FROM df WHERE a < $1 UNION ALL SELECT a * $1 AS a FROM df WHERE a > $1",
+    )
+    .unwrap()
+    .collect()
+    .unwrap();
+    for (param, expected) in [
+        (2, [Some(1), Some(6), Some(8)]),
+        (3, [Some(1), Some(2), Some(12)]),
+    ] {
+        let lf = ctx
+            .execute_prepared("u", &[AnyValue::Int32(param)])
+            .unwrap();
+        assert_eq!(collect_col(lf, "a"), expected);
+    }
+
+    // a plan that depends on the values is planned on each execution instead
+    ctx.execute("PREPARE v AS SELECT a FROM df WHERE a IN ($1, $2) ORDER BY a")
+        .unwrap()
+        .collect()
+        .unwrap();
+    for (params, expected) in [([1, 2], [Some(1), Some(2)]), ([4, 3], [Some(3), Some(4)])] {
+        let params = params.map(AnyValue::Int32);
+        let lf = ctx.execute_prepared("v", &params).unwrap();
+        assert_eq!(collect_col(lf, "a"), expected);
+    }
+}
+
+#[test]
+fn test_order_by_placeholder() {
+    let mut ctx = create_ctx(letter_tables());
+    ctx.execute("PREPARE q AS SELECT a, b FROM df ORDER BY $1, a DESC")
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    // a placeholder is a constant (not an ordinal), however the value is bound
+    let sql = "SELECT a, b FROM df ORDER BY $1, a DESC";
+    let expected = [Some(4), Some(3), Some(2), Some(1)];
+    for param in [AnyValue::Int32(2), AnyValue::Float64(1.5)] {
+        let lf = ctx.execute_with_params(sql, &[param.clone()]).unwrap();
+        assert_eq!(collect_col(lf, "a"), expected);
+        let lf = ctx.execute_prepared("q", &[param.clone()]).unwrap();
+        assert_eq!(collect_col(lf, "a"), expected);
+        let lf = ctx.execute(&format!("EXECUTE q({param})")).unwrap();
+        assert_eq!(collect_col(lf, "a"), expected);
+    }
+}
@@ -0,0 +1,77 @@
+mod common;
+use common::{create_ctx, execute_col, ranking_tables};
+
+#[test]
+fn test_ranking_functions() {
+    let mut ctx = create_ctx(ranking_tables());
+    for (func, expected) in [
+        (
+            "ROW_NUMBER() OVER (PARTITION BY k ORDER BY ts)",
+            [1, 3, 2, 1, 2],
+        ),
+        ("ROW_NUMBER() OVER (ORDER BY ts DESC, v)", [5, 3, 4, 1, 2]),
+        ("RANK() OVER (ORDER BY k)", [1, 1, 1, 4, 4]),
+        ("DENSE_RANK() OVER (ORDER BY k)", [1, 1, 1, 2, 2]),
+        (
+            "RANK() OVER (PARTITION BY k ORDER BY ts DESC)",
+            [3, 1, 2, 1, 1],
+        ),
+    ] {
+        let sql = format!("SELECT {func} AS x FROM df");
+        assert_eq!(
+            execute_col(&mut ctx, &sql, "x"),
+            expected.map(Some).to_vec(),
+            "{sql}"
+        );
+    }
+}
+
+#[test]
+fn test_qualify() {
+    let mut ctx = create_ctx(ranking_tables());
+    for (sql, expected) in [
+        (
+            "SELECT k, v FROM df
+             QUALIFY ROW_NUMBER() OVER (PARTITION BY k ORDER BY ts DESC) = 1
+             ORDER BY k",
+            vec![20, 40],
+        ),
+        (
+            "SELECT k, v, RANK() OVER (PARTITION BY k ORDER BY ts DESC) AS r FROM df
+             QUALIFY r = 1
+             ORDER BY v",
+            vec![20, 40, 50],
+        ),
+        (
+            "SELECT v FROM df
+             WHERE ts < 5
+             QUALIFY v > AVG(v) OVER (PARTITION BY k)",
+            vec![30],
+        ),
+        (
+            "SELECT k, SUM(v) AS v FROM df
+             GROUP BY k
+             QUALIFY ROW_NUMBER() OVER (ORDER BY v DESC) = 1",
+            vec![90],
+        ),
+    ] {
+        assert_eq!(
+            execute_col(&mut ctx, sql, "v"),
+            expected.into_iter().map(Some).collect::<Vec<_>>(),
+            "{sql}"
+        );
+    }
+}
+
+#[test]
+fn test_ranking_errors() {
+    let mut ctx = create_ctx(ranking_tables());
+    for sql in [
+        "SELECT ROW_NUMBER() FROM df",
+        "SELECT ROW_NUMBER(v) OVER (ORDER BY ts) FROM df",
+        "SELECT RANK() OVER (PARTITION BY k) FROM df",
+        "SELECT RANK() OVER (ORDER BY ts ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
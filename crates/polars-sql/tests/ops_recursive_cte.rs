@@ -0,0 +1,71 @@
+use polars_sql::*;
+
+mod common;
+use common::{create_ctx, execute_col, org_tables, register_tables};
+
+#[test]
+fn test_recursive_cte_union_all() {
+    let mut ctx = create_ctx(org_tables());
+    let sql = "
+      WITH RECURSIVE chain AS (
+        SELECT id, manager, 0 AS depth FROM org WHERE manager IS NULL
+        UNION ALL
+        SELECT o.id, o.manager, c.depth + 1 FROM org o JOIN chain c ON o.manager = c.id
+      )
+      SELECT id, depth FROM chain ORDER BY id
+    ";
+    assert_eq!(
+        execute_col(&mut ctx, sql, "id"),
+        [1, 2, 3, 4, 5].map(Some).to_vec()
+    );
+    assert_eq!(
+        execute_col(&mut ctx, sql, "depth"),
+        [0, 1, 1, 2, 3].map(Some).to_vec()
+    );
+}
+
+#[test]
+fn test_recursive_cte_union_distinct() {
+    // the edges contain a cycle (1 -> 2 -> 3 -> 1), which UNION terminates
+    let mut ctx = create_ctx(org_tables());
+    let sql = "
+      WITH RECURSIVE reachable(node) AS (
+        SELECT src FROM edges WHERE src = 1
+        UNION
+        SELECT e.dst FROM edges e JOIN reachable r ON e.src = r.node
+      )
+      SELECT node FROM reachable ORDER BY node
+    ";
+    assert_eq!(
+        execute_col(&mut ctx, sql, "node"),
+        [1, 2, 3].map(Some).to_vec()
+    );
+}
+
+#[test]
+fn test_recursive_cte_recursion_limit() {
+    let mut ctx = register_tables(SQLContext::new().with_recursion_limit(10), org_tables());
+    let sql = "
+      WITH RECURSIVE reachable(node) AS (
+        SELECT src FROM edges WHERE src = 1
+        UNION ALL
+        SELECT e.dst FROM edges e JOIN reachable r ON e.src = r.node
+      )
+      SELECT node FROM reachable
+    ";
+    assert!(ctx.execute(sql).is_err());
+}
+
+#[test]
+fn test_recursive_cte_without_self_reference() {
+    let mut ctx = create_ctx(org_tables());
+    let sql = "
+      WITH RECURSIVE ids AS (
+        SELECT id FROM org WHERE id < 2
+        UNION ALL
+        SELECT id FROM org WHERE id > 4
+      )
+      SELECT id FROM ids ORDER BY id
+    ";
+    assert_eq!(execute_col(&mut ctx, sql, "id"), [1, 5].map(Some).to_vec());
+}
@@ -0,0 +1,81 @@
+use polars_core::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::{create_ctx, struct_tables};
+
+fn execute_col(ctx: &mut SQLContext, sql: &str) -> Series {
+    let df = ctx.execute(sql).unwrap().collect().unwrap();
+    df.select_at_idx(0)
+        .unwrap()
+        .as_materialized_series()
+        .clone()
+}
+
+#[test]
+fn test_struct_subscript_access() {
+    let mut ctx = create_ctx(struct_tables());
+    for sql in [
+        "SELECT s['a'] FROM tbl ORDER BY id",
+        "SELECT (s).a FROM tbl ORDER BY id",
+        "SELECT s.a FROM tbl ORDER BY id",
+        "SELECT s -> 'a' FROM tbl ORDER BY id",
+    ] {
+        let s = execute_col(&mut ctx, sql);
+        let values: Vec<_> = s.i32().unwrap().into_iter().collect();
+        assert_eq!(values, [Some(10), Some(20), Some(30)], "{sql}");
+    }
+    for sql in [
+        "SELECT s['b']['c'] FROM tbl ORDER BY id",
+        "SELECT s.b.c FROM tbl ORDER BY id",
+        "SELECT s #>> '{b,c}' FROM tbl ORDER BY id",
+    ] {
+        let s = execute_col(&mut ctx, sql);
+        let values: Vec<_> = s.str().unwrap().into_iter().collect();
+        assert_eq!(values, [Some("x"), Some("y"), Some("z")], "{sql}");
+    }
+    assert!(ctx
+        .execute("SELECT s['xyz'] FROM tbl")
+        .and_then(|lf| lf.collect())
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_json_operators() {
+    let mut ctx = create_ctx(struct_tables());
+    for (sql, expected) in [
+        (
+            "SELECT js -> 'a' FROM tbl ORDER BY id",
+            [Some("1"), Some("2"), Some("3")],
+        ),
+        (
+            "SELECT js['b'] ->> 'c' FROM tbl ORDER BY id",
+            [Some("x"), Some("y"), None],
+        ),
+        (
+            "SELECT js #> '{b,c}' FROM tbl ORDER BY id",
+            [Some("x"), Some("y"), None],
+        ),
+        (
+            "SELECT js -> 'd' -> 0 FROM tbl ORDER BY id",
+            [Some("4"), Some("6"), None],
+        ),
+        (
+            "SELECT js #>> '{d,1}' FROM tbl ORDER BY id",
+            [Some("5"), None, None],
+        ),
+    ] {
+        let s = execute_col(&mut ctx, sql);
+        let values: Vec<_> = s.str().unwrap().into_iter().collect();
+        assert_eq!(values, expected, "{sql}");
+    }
+
+    // json values can be used in predicates (after casting)
+    let s = execute_col(
+        &mut ctx,
+        "SELECT id FROM tbl WHERE (js ->> 'a')::int > 1 ORDER BY id",
+    );
+    let values: Vec<_> = s.i32().unwrap().into_iter().collect();
+    assert_eq!(values, [Some(2), Some(3)]);
+}
@@ -0,0 +1,181 @@
+mod common;
+use common::{create_ctx, customer_order_tables, execute_cols};
+
+#[test]
+fn test_correlated_exists() {
+    let mut ctx = create_ctx(customer_order_tables());
+    for (sql, expected) in [
+        (
+            "SELECT id FROM customers c
+             WHERE EXISTS (SELECT 1 FROM orders o WHERE o.cust = c.id AND o.amount > 15)
+             ORDER BY id",
+            vec![Some(1), Some(2)],
+        ),
+        (
+            "SELECT id FROM customers c
+             WHERE NOT EXISTS (SELECT * FROM orders o WHERE o.cust = c.id)
+             ORDER BY id",
+            vec![Some(3)],
+        ),
+        (
+            "SELECT id FROM customers c
+             WHERE 20 IN (SELECT amount FROM orders o WHERE o.cust = c.id)
+             ORDER BY id",
+            vec![Some(1)],
+        ),
+    ] {
+        assert_eq!(execute_cols(&mut ctx, sql, &["id"]), [expected], "{sql}");
+    }
+}
+
+#[test]
+fn test_scalar_subqueries() {
+    let mut ctx = create_ctx(customer_order_tables());
+    let sql = "
+      SELECT
+        id,
+        (SELECT SUM(amount) FROM orders o WHERE o.cust = c.id) AS total,
+        (SELECT COUNT(*) FROM orders o WHERE o.cust = c.id) AS n
+      FROM customers c
+      WHERE id > (SELECT MIN(cust) FROM orders) - 1
+      ORDER BY id
+    ";
+    assert_eq!(
+        execute_cols(&mut ctx, sql, &["id", "total", "n"]),
+        [
+            vec![Some(1), Some(2), Some(3)],
+            // as the subquery over no rows, a (polars) SUM without rows is zero
+            vec![Some(30), Some(30), Some(0)],
+            vec![Some(2), Some(1), Some(0)],
+        ]
+    );
+}
+
+#[test]
+fn test_scalar_subquery_counts() {
+    let mut ctx = create_ctx(customer_order_tables());
+    let sql = "
+      SELECT
+        id,
+        (SELECT COUNT(*) + 1 FROM orders o WHERE o.cust = c.id) AS n,
+        (SELECT COUNT(amount) * 10 + MAX(amount) FROM orders o WHERE o.cust = c.id) AS m
+      FROM customers c
+      ORDER BY id
+    ";
+    assert_eq!(
+        execute_cols(&mut ctx, sql, &["id", "n", "m"]),
+        [
+            vec![Some(1), Some(2), Some(3)],
+            vec![Some(3), Some(2), Some(1)],
+            vec![Some(40), Some(40), None],
+        ]
+    );
+}
+
+#[test]
+fn test_scalar_subquery_without_rows() {
+    let mut ctx = create_ctx(customer_order_tables());
+    let sql = "
+      SELECT
+        id,
+        (SELECT COALESCE(SUM(amount), 0) FROM orders o WHERE o.cust = c.id) AS total,
+        (SELECT COALESCE(MAX(amount), -1) FROM orders o WHERE o.cust = c.id) AS top
+      FROM customers c
+      ORDER BY id
+    ";
+    // customer 3 has no orders, so gets the result of the subqueries over no rows
+    assert_eq!(
+        execute_cols(&mut ctx, sql, &["id", "total", "top"]),
+        [
+            vec![Some(1), Some(2), Some(3)],
+            vec![Some(30), Some(30), Some(0)],
+            vec![Some(20), Some(30), Some(-1)],
+        ]
+    );
+}
+
+#[test]
+fn test_scalar_subquery_more_than_one_row() {
+    let mut ctx = create_ctx(customer_order_tables());
+    for sql in [
+        "SELECT id, (SELECT amount FROM orders o WHERE o.cust = c.id) AS amount FROM customers c",
+        "SELECT id FROM customers c WHERE id = (SELECT cust FROM orders o WHERE o.amount > 15)",
+        "SELECT id, (SELECT MAX(amount) FROM orders GROUP BY cust) AS amount FROM customers",
+    ] {
+        let err = ctx
+            .execute(sql)
+            .and_then(|lf| lf.collect())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("more than one row returned by a subquery used as an expression"),
+            "{sql}: {err}"
+        );
+    }
+    // a single row per key is fine
+    let sql =
+        "SELECT id, (SELECT amount FROM orders o WHERE o.cust = c.id AND o.amount > 15) AS amount
+               FROM customers c ORDER BY id";
+    assert_eq!(
+        execute_cols(&mut ctx, sql, &["id", "amount"]),
+        [
+            vec![Some(1), Some(2), Some(3)],
+            vec![Some(20), Some(30), None]
+        ]
+    );
+}
+
+#[test]
+fn test_lateral_join() {
+    let mut ctx = create_ctx(customer_order_tables());
+    for (sql, expected) in [
+        (
+            "SELECT c.id AS id, t.amount AS amount
+             FROM customers c
+             CROSS JOIN LATERAL (SELECT amount FROM orders o WHERE o.cust = c.id) AS t
+             ORDER BY id, amount",
+            [
+                vec![Some(1), Some(1), Some(2)],
+                vec![Some(10), Some(20), Some(30)],
+            ],
+        ),
+        (
+            "SELECT c.id AS id, t.amount AS amount
+             FROM customers c
+             LEFT JOIN LATERAL (SELECT amount FROM orders o WHERE o.cust = c.id) AS t ON TRUE
+             ORDER BY id, amount",
+            [
+                vec![Some(1), Some(1), Some(2), Some(3)],
+                vec![Some(10), Some(20), Some(30), None],
+            ],
+        ),
+        (
+            "SELECT c.id AS id, t.amount AS amount
+             FROM customers c
+             JOIN LATERAL (SELECT MAX(amount) AS amount FROM orders o WHERE o.cust = c.id) AS t ON TRUE
+             ORDER BY id",
+            [
+                vec![Some(1), Some(2), Some(3)],
+                vec![Some(20), Some(30), None],
+            ],
+        ),
+    ] {
+        assert_eq!(
+            execute_cols(&mut ctx, sql, &["id", "amount"]),
+            expected,
+            "{sql}"
+        );
+    }
+}
+
+#[test]
+fn test_unsupported_correlation() {
+    let mut ctx = create_ctx(customer_order_tables());
+    for sql in [
+        "SELECT id FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.amount > c.id)",
+        "SELECT id FROM customers c WHERE EXISTS (SELECT c.id FROM orders o WHERE o.cust = 1)",
+        "SELECT c.id FROM customers c JOIN LATERAL (SELECT amount FROM orders o WHERE o.cust = c.id) AS t ON t.amount > 10",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,389 @@
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "grp" => ["a", "a", "a", "b", "b"],
+      "idx" => [1, 2, 3, 1, 2],
+      "k" => [1, 1, 2, 1, 1],
+      "day" => [1, 2, 5, 1, 3],
+      "val" => [10i64, 20, 30, 40, 50],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("day").cast(DataType::Date).alias("dt"));
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+/// Execute a query that selects `grp`, `idx` and `x`, returning `x` (as floats) in
+/// the order of `grp` and `idx`.
+fn execute_x(ctx: &mut SQLContext, sql: &str) -> Vec<Option<f64>> {
+    let df = ctx
+        .execute(sql)
+        .unwrap()
+        .sort(["grp", "idx"], Default::default())
+        .collect()
+        .unwrap();
+    let x = df.column("x").unwrap().cast(&DataType::Float64).unwrap();
+    x.f64().unwrap().into_iter().collect()
+}
+
+#[test]
+fn test_rows_frame() {
+    let mut ctx = create_ctx();
+    for (frame, expected) in [
+        (
+            "ROWS BETWEEN 1 PRECEDING AND CURRENT ROW",
+            [10.0, 30.0, 50.0, 40.0, 90.0],
+        ),
+        (
+            "ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING",
+            [30.0, 60.0, 50.0, 90.0, 90.0],
+        ),
+        (
+            "ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING",
+            [60.0, 50.0, 30.0, 90.0, 50.0],
+        ),
+        (
+            "ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING",
+            [f64::NAN, 10.0, 30.0, f64::NAN, 40.0],
+        ),
+    ] {
+        let sql = format!(
+            "SELECT grp, idx, SUM(val) OVER (PARTITION BY grp ORDER BY idx {frame}) AS x FROM df"
+        );
+        let expected = expected.map(|v| (!v.is_nan()).then_some(v)).to_vec();
+        assert_eq!(execute_x(&mut ctx, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_rows_frame_aggregates() {
+    let mut ctx = create_ctx();
+    for (func, expected) in [
+        ("AVG(val)", [10.0, 15.0, 25.0, 40.0, 45.0]),
+        ("MIN(val)", [10.0, 10.0, 20.0, 40.0, 40.0]),
+        ("MAX(val)", [10.0, 20.0, 30.0, 40.0, 50.0]),
+        ("COUNT(*)", [1.0, 2.0, 2.0, 1.0, 2.0]),
+    ] {
+        let sql = format!(
+            "SELECT grp, idx, {func} OVER (
+               PARTITION BY grp ORDER BY idx ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+             ) AS x FROM df"
+        );
+        let expected = expected.map(Some).to_vec();
+        assert_eq!(execute_x(&mut ctx, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_range_frame() {
+    let mut ctx = create_ctx();
+    for (window, expected) in [
+        // the default frame of an ordered window includes the peers of the current row
+        ("PARTITION BY grp ORDER BY k", [30.0, 30.0, 60.0, 90.0, 90.0]),
+        (
+            "PARTITION BY grp ORDER BY k RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING",
+            [60.0, 60.0, 30.0, 90.0, 90.0],
+        ),
+        (
+            "PARTITION BY grp ORDER BY day RANGE BETWEEN 1 PRECEDING AND CURRENT ROW",
+            [10.0, 30.0, 30.0, 40.0, 50.0],
+        ),
+        (
+            "PARTITION BY grp ORDER BY dt RANGE BETWEEN INTERVAL '2 days' PRECEDING AND CURRENT ROW",
+            [10.0, 30.0, 30.0, 40.0, 90.0],
+        ),
+        (
+            "PARTITION BY grp ORDER BY day RANGE BETWEEN CURRENT ROW AND 2 FOLLOWING",
+            [30.0, 20.0, 30.0, 90.0, 50.0],
+        ),
+        (
+            "PARTITION BY grp ORDER BY day RANGE BETWEEN 1 PRECEDING AND 1 FOLLOWING",
+            [30.0, 30.0, 30.0, 40.0, 50.0],
+        ),
+        (
+            "PARTITION BY grp ORDER BY day DESC RANGE BETWEEN 2 PRECEDING AND CURRENT ROW",
+            [30.0, 20.0, 30.0, 90.0, 50.0],
+        ),
+        (
+            "PARTITION BY grp ORDER BY dt RANGE BETWEEN CURRENT ROW AND INTERVAL '3 days' FOLLOWING",
+            [30.0, 50.0, 30.0, 90.0, 50.0],
+        ),
+        (
+            "PARTITION BY grp ORDER BY day RANGE BETWEEN 1 PRECEDING AND UNBOUNDED FOLLOWING",
+            [60.0, 60.0, 30.0, 90.0, 50.0],
+        ),
+        (
+            "PARTITION BY grp ORDER BY day RANGE BETWEEN 2 PRECEDING AND 1 PRECEDING",
+            [f64::NAN, 10.0, f64::NAN, f64::NAN, 40.0],
+        ),
+    ] {
+        let sql = format!("SELECT grp, idx, SUM(val) OVER ({window}) AS x FROM df");
+        let expected = expected.map(|v| (!v.is_nan()).then_some(v)).to_vec();
+        assert_eq!(execute_x(&mut ctx, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_range_frame_aggregates() {
+    let mut ctx = create_ctx();
+    for (func, expected) in [
+        ("MIN(val)", [10.0, 20.0, 30.0, 40.0, 50.0]),
+        ("MAX(val)", [20.0, 20.0, 30.0, 50.0, 50.0]),
+        ("AVG(val)", [15.0, 20.0, 30.0, 45.0, 50.0]),
+        ("COUNT(*)", [2.0, 1.0, 1.0, 2.0, 1.0]),
+    ] {
+        let sql = format!(
+            "SELECT grp, idx, {func} OVER (
+               PARTITION BY grp ORDER BY day RANGE BETWEEN CURRENT ROW AND 2 FOLLOWING
+             ) AS x FROM df"
+        );
+        let expected = expected.map(Some).to_vec();
+        assert_eq!(execute_x(&mut ctx, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_range_frame_extremum_excluding_current_row() {
+    let mut ctx = create_ctx();
+    for (func, window, expected) in [
+        (
+            "MIN(val)",
+            "PARTITION BY grp ORDER BY day RANGE BETWEEN 2 PRECEDING AND 1 PRECEDING",
+            [f64::NAN, 10.0, f64::NAN, f64::NAN, 40.0],
+        ),
+        (
+            "MAX(val)",
+            "PARTITION BY grp ORDER BY day RANGE BETWEEN 1 FOLLOWING AND 3 FOLLOWING",
+            [20.0, 30.0, f64::NAN, 50.0, f64::NAN],
+        ),
+        (
+            "MAX(val)",
+            "PARTITION BY grp ORDER BY day RANGE BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING",
+            [f64::NAN, 10.0, 20.0, f64::NAN, 40.0],
+        ),
+        (
+            "MIN(val)",
+            "PARTITION BY grp ORDER BY day RANGE BETWEEN 2 FOLLOWING AND UNBOUNDED FOLLOWING",
+            [30.0, 30.0, f64::NAN, 50.0, f64::NAN],
+        ),
+        (
+            "MAX(val)",
+            "PARTITION BY grp ORDER BY day DESC RANGE BETWEEN 3 PRECEDING AND 1 PRECEDING",
+            [20.0, 30.0, f64::NAN, 50.0, f64::NAN],
+        ),
+        (
+            "MIN(val)",
+            "PARTITION BY grp ORDER BY dt RANGE BETWEEN INTERVAL '4 days' PRECEDING AND INTERVAL '1 day' PRECEDING",
+            [f64::NAN, 10.0, 10.0, f64::NAN, 40.0],
+        ),
+    ] {
+        let sql = format!("SELECT grp, idx, {func} OVER ({window}) AS x FROM df");
+        let expected = expected.map(|v| (!v.is_nan()).then_some(v)).to_vec();
+        assert_eq!(execute_x(&mut ctx, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_ordered_window_peers() {
+    // without PARTITION BY, the frame of an ordered window still includes the peers
+    let mut ctx = create_ctx();
+    for (func, expected) in [
+        (
+            "SUM(val) OVER (ORDER BY k)",
+            [120.0, 120.0, 150.0, 120.0, 120.0],
+        ),
+        (
+            "MIN(val) OVER (ORDER BY k DESC)",
+            [10.0, 10.0, 30.0, 10.0, 10.0],
+        ),
+        ("MAX(val) OVER (ORDER BY k)", [50.0, 50.0, 50.0, 50.0, 50.0]),
+    ] {
+        let sql = format!("SELECT grp, idx, {func} AS x FROM df");
+        let expected = expected.map(Some).to_vec();
+        assert_eq!(execute_x(&mut ctx, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_ordered_window_without_partition() {
+    // the ORDER BY of a window orders its rows, rather than partitioning them
+    let mut ctx = create_ctx();
+    for (func, expected) in [
+        (
+            "FIRST(val) OVER (ORDER BY idx DESC)",
+            [30.0, 30.0, 30.0, 30.0, 30.0],
+        ),
+        (
+            "LAST(val) OVER (ORDER BY k)",
+            [30.0, 30.0, 30.0, 30.0, 30.0],
+        ),
+    ] {
+        let sql = format!("SELECT grp, idx, {func} AS x FROM df");
+        let expected = expected.map(Some).to_vec();
+        assert_eq!(execute_x(&mut ctx, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_range_frame_nulls() {
+    // rows without an ORDER BY value are each other's peers, and null values are skipped
+    let df = df! {
+        "grp" => ["a", "a", "a", "a", "a"],
+        "idx" => [1, 2, 3, 4, 5],
+        "k" => [Some(1), None, Some(2), None, Some(4)],
+        "val" => [Some(10i64), Some(20), None, Some(40), Some(50)],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+
+    for (func, expected) in [
+        (
+            "SUM(val)",
+            [Some(10.0), Some(60.0), Some(10.0), Some(60.0), Some(50.0)],
+        ),
+        (
+            "MAX(val)",
+            [Some(10.0), Some(40.0), Some(10.0), Some(40.0), Some(50.0)],
+        ),
+        (
+            "COUNT(val)",
+            [Some(1.0), Some(2.0), Some(1.0), Some(2.0), Some(1.0)],
+        ),
+        (
+            "AVG(val)",
+            [Some(10.0), Some(30.0), Some(10.0), Some(30.0), Some(50.0)],
+        ),
+    ] {
+        let sql = format!(
+            "SELECT grp, idx, {func} OVER (
+               ORDER BY k RANGE BETWEEN 1 PRECEDING AND CURRENT ROW
+             ) AS x FROM df"
+        );
+        assert_eq!(execute_x(&mut ctx, &sql), expected.to_vec(), "{sql}");
+    }
+
+    // frames that exclude the current row don't include the rows without a key either
+    let sql = "SELECT grp, idx, MIN(val) OVER (
+                 ORDER BY k RANGE BETWEEN 1 FOLLOWING AND 2 FOLLOWING
+               ) AS x FROM df";
+    assert_eq!(
+        execute_x(&mut ctx, sql),
+        [None, Some(20.0), Some(50.0), Some(20.0), None],
+        "{sql}"
+    );
+}
+
+#[test]
+fn test_frame_variance_precision() {
+    // a large offset makes the naive sum of squares lose all precision
+    let df = df! {
+        "grp" => ["a", "a", "a", "a"],
+        "idx" => [1, 2, 3, 4],
+        "v" => [4.0, 7.0, 13.0, 16.0],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("v") + lit(1e9));
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+
+    for (frame, expected) in [
+        (
+            "ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW",
+            [None, Some(4.5), Some(21.0), Some(30.0)],
+        ),
+        (
+            "ROWS BETWEEN 1 PRECEDING AND CURRENT ROW",
+            [None, Some(4.5), Some(18.0), Some(4.5)],
+        ),
+        (
+            "RANGE BETWEEN 1 PRECEDING AND 1 FOLLOWING",
+            [Some(4.5), Some(21.0), Some(21.0), Some(4.5)],
+        ),
+    ] {
+        let sql = format!("SELECT grp, idx, VAR_SAMP(v) OVER (ORDER BY idx {frame}) AS x FROM df");
+        let out = execute_x(&mut ctx, &sql);
+        for (x, expected) in out.iter().zip(expected) {
+            match (x, expected) {
+                (Some(x), Some(expected)) => assert!((x - expected).abs() < 1e-6, "{sql}: {out:?}"),
+                (x, expected) => assert_eq!(*x, expected, "{sql}"),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rows_frame_large_offsets() {
+    let mut ctx = create_ctx();
+    for (frame, expected) in [
+        (
+            "ROWS BETWEEN 9223372036854775807 PRECEDING AND CURRENT ROW",
+            [10.0, 30.0, 60.0, 40.0, 90.0],
+        ),
+        (
+            "ROWS BETWEEN CURRENT ROW AND 9223372036854775807 FOLLOWING",
+            [60.0, 50.0, 30.0, 90.0, 50.0],
+        ),
+    ] {
+        let sql = format!(
+            "SELECT grp, idx, SUM(val) OVER (PARTITION BY grp ORDER BY idx {frame}) AS x FROM df"
+        );
+        let expected = expected.map(Some).to_vec();
+        assert_eq!(execute_x(&mut ctx, &sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_named_windows() {
+    let mut ctx = create_ctx();
+    for (sql, expected) in [
+        (
+            "SELECT grp, idx, SUM(val) OVER w AS x FROM df WINDOW w AS (PARTITION BY grp)",
+            [60.0, 60.0, 60.0, 90.0, 90.0],
+        ),
+        (
+            "SELECT grp, idx, SUM(val) OVER (w ORDER BY idx) AS x FROM df
+             WINDOW w AS (PARTITION BY grp)",
+            [10.0, 30.0, 60.0, 40.0, 90.0],
+        ),
+        (
+            "SELECT grp, idx, AVG(val) OVER w2 AS x FROM df
+             WINDOW w1 AS (PARTITION BY grp), w2 AS (w1 ORDER BY idx)",
+            [10.0, 15.0, 20.0, 40.0, 45.0],
+        ),
+    ] {
+        let expected = expected.map(Some).to_vec();
+        assert_eq!(execute_x(&mut ctx, sql), expected, "{sql}");
+    }
+}
+
+#[test]
+fn test_window_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT SUM(val) OVER w FROM df",
+        "SELECT SUM(val) OVER w FROM df WINDOW w AS (ORDER BY idx), w AS (ORDER BY k)",
+        "SELECT SUM(val) OVER (w ORDER BY k) FROM df WINDOW w AS (ORDER BY idx)",
+        "SELECT SUM(val) OVER (ORDER BY idx ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING) FROM df",
+        "SELECT SUM(val) OVER (ORDER BY idx GROUPS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT FIRST(val) OVER (ORDER BY idx ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT SUM(val) OVER (ORDER BY day RANGE BETWEEN 1 FOLLOWING AND CURRENT ROW) FROM df",
+        "SELECT SUM(val) OVER (ORDER BY grp RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT SUM(val) OVER (ORDER BY dt RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT SUM(val) OVER (ORDER BY idx, k RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT SUM(val) OVER (ORDER BY idx RANGE BETWEEN 1.5 PRECEDING AND CURRENT ROW) FROM df",
+    ] {
+        // some errors are only raised once the type of the ORDER BY expression is known
+        assert!(
+            ctx.execute(sql).and_then(|lf| lf.collect()).is_err(),
+            "{sql}"
+        );
+    }
+}
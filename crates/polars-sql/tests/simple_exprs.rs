@@ -207,7 +207,15 @@ fn test_literal_exprs() {
             lit("foo").alias("string_lit"),
             lit(true).alias("bool_lit"),
             lit(NULL).alias("null_lit"),
+            #[cfg(not(feature = "dtype-interval"))]
             lit(Duration::parse("1q2w1d50s")).alias("duration_lit"),
+            // (an interval with calendar parts has the Interval type)
+            #[cfg(feature = "dtype-interval")]
+            lit(Scalar::new(
+                DataType::Interval,
+                AnyValue::Interval(Duration::parse("1q2w1d50s").try_to_interval().unwrap()),
+            ))
+            .alias("duration_lit"),
         ])
         .collect()
         .unwrap();
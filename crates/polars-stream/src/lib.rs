@@ -19,3 +19,4 @@ const DEFAULT_DISTRIBUTOR_BUFFER_SIZE: usize = 4;
 const DEFAULT_ZIP_HEAD_BUFFER_SIZE: usize = 4;
 
 const GROUP_BY_MIN_ROWS_PER_PARTITION: usize = 128;
+const HASH_JOIN_MIN_ROWS_PER_PARTITION: usize = 128;
@@ -0,0 +1,395 @@
+use std::sync::Arc;
+
+use polars_core::frame::UniqueKeepStrategy;
+use polars_core::prelude::{IdxSize, PlSmallStr};
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+use polars_expr::groups::Grouper;
+use polars_utils::cardinality_sketch::CardinalitySketch;
+use polars_utils::hashing::HashPartitioner;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::GROUP_BY_MIN_ROWS_PER_PARTITION;
+
+/// The row we currently keep for a group.
+#[derive(Clone, Copy, Default)]
+struct KeptRow {
+    /// The position of the row in the input stream as (morsel seq, row in morsel).
+    order: (u64, IdxSize),
+    /// The index of the row in the payload of the local state that owns it.
+    payload_idx: IdxSize,
+    /// The local state that owns the row, only meaningful after combining.
+    local: u32,
+    /// The number of times the key has been seen.
+    count: IdxSize,
+}
+
+impl KeptRow {
+    /// Merges another candidate for the same group into this one, returns true
+    /// if the other row should replace the row we keep.
+    fn update(&mut self, order: (u64, IdxSize), count: IdxSize, keep: UniqueKeepStrategy) -> bool {
+        let replace = self.count == 0
+            || match keep {
+                UniqueKeepStrategy::First => order < self.order,
+                UniqueKeepStrategy::Last => order > self.order,
+                UniqueKeepStrategy::Any | UniqueKeepStrategy::None => false,
+            };
+        self.count += count;
+        if replace {
+            self.order = order;
+        }
+        replace
+    }
+
+    fn is_selected(&self, keep: UniqueKeepStrategy) -> bool {
+        self.count > 0 && (keep != UniqueKeepStrategy::None || self.count == 1)
+    }
+}
+
+struct LocalDistinctSinkState {
+    grouper: Box<dyn Grouper>,
+    kept: Vec<KeptRow>,
+    /// Candidate rows referenced by `kept`.
+    payload: Vec<DataFrame>,
+    payload_len: usize,
+}
+
+impl LocalDistinctSinkState {
+    /// Drops all the payload rows that are no longer referenced.
+    fn compact(&mut self, keep: UniqueKeepStrategy) {
+        let payload = accumulate_dataframes_vertical_unchecked(self.payload.drain(..));
+        let mut idxs = Vec::with_capacity(self.kept.len());
+        for kept in self.kept.iter_mut() {
+            if kept.is_selected(keep) {
+                let new_idx = idxs.len() as IdxSize;
+                idxs.push(kept.payload_idx);
+                kept.payload_idx = new_idx;
+            }
+        }
+        let payload = unsafe { payload._take_unchecked_slice(&idxs, false) };
+        self.payload_len = payload.height();
+        self.payload.push(payload);
+    }
+}
+
+struct DistinctSinkState {
+    key: Arc<[PlSmallStr]>,
+    keep: UniqueKeepStrategy,
+    maintain_order: bool,
+    grouper: Box<dyn Grouper>,
+    local: Vec<LocalDistinctSinkState>,
+}
+
+impl DistinctSinkState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(receivers.len() >= self.local.len());
+        self.local
+            .resize_with(receivers.len(), || LocalDistinctSinkState {
+                grouper: self.grouper.new_empty(),
+                kept: Vec::new(),
+                payload: Vec::new(),
+                payload_len: 0,
+            });
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.local) {
+            let key = &self.key;
+            let keep = self.keep;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let mut group_idxs = Vec::new();
+                let mut take_idxs = Vec::new();
+                while let Ok(morsel) = recv.recv().await {
+                    let (df, seq, _, _) = morsel.into_inner();
+                    if df.height() == 0 {
+                        continue;
+                    }
+
+                    let keys = df.select(key.iter().cloned())?;
+                    local.grouper.insert_keys(&keys, &mut group_idxs);
+                    local
+                        .kept
+                        .resize(local.grouper.num_groups() as usize, KeptRow::default());
+
+                    // Find the rows that replace the row we keep for their group.
+                    take_idxs.clear();
+                    let seq = seq.to_u64();
+                    let base_idx = local.payload_len as IdxSize;
+                    for (row, g) in group_idxs.iter().enumerate() {
+                        let kept = unsafe { local.kept.get_unchecked_mut(*g as usize) };
+                        if kept.update((seq, row as IdxSize), 1, keep) {
+                            kept.payload_idx = base_idx + take_idxs.len() as IdxSize;
+                            take_idxs.push(row as IdxSize);
+                        }
+                    }
+
+                    if !take_idxs.is_empty() {
+                        let payload = unsafe { df._take_unchecked_slice(&take_idxs, false) };
+                        local.payload_len += payload.height();
+                        local.payload.push(payload);
+                    }
+
+                    // Keeping the last row can make every row a candidate, so
+                    // we periodically throw away the rows that were replaced.
+                    if local.payload_len > 2 * local.kept.len() + get_ideal_morsel_size() {
+                        local.compact(keep);
+                    }
+                }
+                Ok(())
+            }));
+        }
+    }
+
+    /// Combines the kept rows of all locals for the groups in the given
+    /// partitions, returns the selected rows as (order, local, payload_idx).
+    fn combine_partition(
+        &self,
+        partition_idxs: Option<&[Vec<Vec<IdxSize>>]>,
+        p: usize,
+        estimated_cardinality: usize,
+    ) -> Vec<((u64, IdxSize), u32, IdxSize)> {
+        let mut grouper = self.grouper.new_empty();
+        grouper.reserve(estimated_cardinality);
+        let mut combined: Vec<KeptRow> = Vec::with_capacity(estimated_cardinality);
+        let mut group_idxs = Vec::new();
+        for (l, local) in self.local.iter().enumerate() {
+            let subset = partition_idxs.map(|idxs| &idxs[l][p][..]);
+            match subset {
+                None => grouper.combine(&*local.grouper, &mut group_idxs),
+                Some(subset) => unsafe {
+                    grouper.gather_combine(&*local.grouper, subset, &mut group_idxs)
+                },
+            }
+            combined.resize(grouper.num_groups() as usize, KeptRow::default());
+
+            for (i, g) in group_idxs.iter().enumerate() {
+                let local_idx = subset.map(|s| s[i]).unwrap_or(i as IdxSize);
+                let other = &local.kept[local_idx as usize];
+                let kept = &mut combined[*g as usize];
+                if kept.update(other.order, other.count, self.keep) {
+                    kept.local = l as u32;
+                    kept.payload_idx = other.payload_idx;
+                }
+            }
+        }
+
+        combined
+            .into_iter()
+            .filter(|k| k.is_selected(self.keep))
+            .map(|k| (k.order, k.local, k.payload_idx))
+            .collect()
+    }
+
+    fn combine_locals_parallel(
+        &self,
+        num_partitions: usize,
+    ) -> Vec<((u64, IdxSize), u32, IdxSize)> {
+        let partitioner = HashPartitioner::new(num_partitions, 0);
+        POOL.install(|| {
+            let l_partitions: Vec<_> = self
+                .local
+                .as_slice()
+                .into_par_iter()
+                .with_max_len(1)
+                .map(|local| {
+                    let mut partition_idxs = vec![Vec::new(); num_partitions];
+                    let mut sketches = vec![CardinalitySketch::new(); num_partitions];
+                    local.grouper.gen_partition_idxs(
+                        &partitioner,
+                        &mut partition_idxs,
+                        &mut sketches,
+                    );
+                    (partition_idxs, sketches)
+                })
+                .collect();
+            let (partition_idxs, sketches): (Vec<_>, Vec<_>) = l_partitions.into_iter().unzip();
+
+            let selected: Vec<_> = (0..num_partitions)
+                .into_par_iter()
+                .with_max_len(1)
+                .map(|p| {
+                    // Estimate combined cardinality.
+                    let mut combined_sketch = CardinalitySketch::new();
+                    for l_sketches in &sketches {
+                        combined_sketch.combine(&l_sketches[p]);
+                    }
+                    let combined_cardinality = combined_sketch.estimate() * 5 / 4;
+                    self.combine_partition(Some(&partition_idxs), p, combined_cardinality)
+                })
+                .collect();
+            selected.into_iter().flatten().collect()
+        })
+    }
+
+    fn into_source(mut self, output_schema: &Schema) -> PolarsResult<InMemorySourceNode> {
+        let num_pipelines = self.local.len();
+        let num_rows: usize = self
+            .local
+            .iter()
+            .map(|l| l.grouper.num_groups() as usize)
+            .sum();
+        let ideal_num_partitions = num_rows.div_ceil(GROUP_BY_MIN_ROWS_PER_PARTITION);
+        let num_partitions = if ideal_num_partitions >= 4 {
+            ideal_num_partitions.min(self.local.len())
+        } else {
+            // If the ideal number of partitions is this low, don't even bother.
+            1
+        };
+
+        let mut selected = if num_partitions == 1 {
+            self.combine_partition(None, 0, 0)
+        } else {
+            self.combine_locals_parallel(num_partitions)
+        };
+
+        // Gather the selected rows from the payloads of all locals.
+        let mut offset = 0;
+        let mut local_offsets = Vec::with_capacity(self.local.len());
+        let mut payloads = Vec::new();
+        for local in &mut self.local {
+            local_offsets.push(offset as IdxSize);
+            offset += local.payload_len;
+            payloads.append(&mut local.payload);
+        }
+        if self.maintain_order {
+            POOL.install(|| selected.par_sort_unstable_by_key(|(order, _, _)| *order));
+        }
+        let take_idxs: Vec<IdxSize> = selected
+            .into_iter()
+            .map(|(_, l, idx)| local_offsets[l as usize] + idx)
+            .collect();
+
+        let df = if payloads.is_empty() {
+            DataFrame::empty_with_schema(output_schema)
+        } else {
+            let payload = accumulate_dataframes_vertical_unchecked(payloads);
+            unsafe { payload._take_unchecked_slice(&take_idxs, true) }
+        };
+
+        let mut source_node = InMemorySourceNode::new(Arc::new(df));
+        source_node.initialize(num_pipelines);
+        Ok(source_node)
+    }
+}
+
+enum DistinctState {
+    Sink(DistinctSinkState),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+pub struct DistinctNode {
+    state: DistinctState,
+    output_schema: Arc<Schema>,
+}
+
+impl DistinctNode {
+    pub fn new(
+        key: Arc<[PlSmallStr]>,
+        keep: UniqueKeepStrategy,
+        maintain_order: bool,
+        grouper: Box<dyn Grouper>,
+        output_schema: Arc<Schema>,
+    ) -> Self {
+        Self {
+            state: DistinctState::Sink(DistinctSinkState {
+                key,
+                keep,
+                maintain_order,
+                grouper,
+                local: Vec::new(),
+            }),
+            output_schema,
+        }
+    }
+}
+
+impl ComputeNode for DistinctNode {
+    fn name(&self) -> &str {
+        "distinct"
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = DistinctState::Done;
+            },
+            // Input is done, transition to being a source.
+            DistinctState::Sink(_) if matches!(recv[0], PortState::Done) => {
+                let DistinctState::Sink(sink) =
+                    core::mem::replace(&mut self.state, DistinctState::Done)
+                else {
+                    unreachable!()
+                };
+                self.state = DistinctState::Source(sink.into_source(&self.output_schema)?);
+            },
+            // Defer to source node implementation.
+            DistinctState::Source(src) => {
+                src.update_state(&mut [], send)?;
+                if send[0] == PortState::Done {
+                    self.state = DistinctState::Done;
+                }
+            },
+            // Nothing to change.
+            DistinctState::Done | DistinctState::Sink(_) => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            DistinctState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            DistinctState::Source(..) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            DistinctState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, DistinctState::Sink { .. })
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match &mut self.state {
+            DistinctState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                sink.spawn(
+                    scope,
+                    recv_ports[0].take().unwrap().parallel(),
+                    join_handles,
+                )
+            },
+            DistinctState::Source(source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            DistinctState::Done => unreachable!(),
+        }
+    }
+}
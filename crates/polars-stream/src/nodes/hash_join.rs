@@ -0,0 +1,622 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use hashbrown::hash_table::{Entry, HashTable};
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_unordered;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::utils::arrow::array::BinaryArray;
+use polars_core::utils::arrow::compute::utils::combine_validities_and_many;
+use polars_core::POOL;
+use polars_ops::frame::{JoinArgs, JoinType};
+use polars_utils::aliases::PlRandomState;
+use polars_utils::hashing::HashPartitioner;
+use polars_utils::idx_vec::IdxVec;
+use polars_utils::itertools::Itertools;
+use polars_utils::pl_str::PlSmallStr;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::expression::StreamExpr;
+use crate::morsel::{MorselSeq, SourceToken};
+use crate::HASH_JOIN_MIN_ROWS_PER_PARTITION;
+
+/// Parameters of the hash join which stay the same between the build and
+/// probe phases.
+struct JoinParams {
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    left_input_schema: Arc<Schema>,
+    /// The columns of the right input which end up in the output.
+    right_payload_schema: Arc<Schema>,
+    output_schema: Arc<Schema>,
+    args: JoinArgs,
+    random_state: PlRandomState,
+}
+
+impl JoinParams {
+    /// Whether the output contains columns from the build side.
+    fn emits_build_columns(&self) -> bool {
+        matches!(
+            self.args.how,
+            JoinType::Inner | JoinType::Left | JoinType::Full
+        )
+    }
+}
+
+/// Evaluates the given key selectors on a morsel and row-encodes the keys,
+/// such that equal keys have equal byte representations.
+///
+/// If nulls are not considered equal, a key with a null in any of its columns
+/// is marked as null in the returned array so that it never matches.
+async fn select_keys(
+    df: &DataFrame,
+    selectors: &[StreamExpr],
+    params: &JoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<BinaryArray<i64>> {
+    let mut key_columns = Vec::with_capacity(selectors.len());
+    for selector in selectors {
+        let s = selector.evaluate(df, state).await?;
+        key_columns.push(s.into_column());
+    }
+    let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+    let keys = keys
+        .get_columns()
+        .iter()
+        .map(|c| prepare_key(c.as_materialized_series()))
+        .try_collect_vec()?;
+
+    let rows = _get_rows_encoded_unordered(&keys)?.into_array();
+    if params.args.join_nulls {
+        return Ok(rows);
+    }
+
+    let validities = keys
+        .iter()
+        .flat_map(|s| {
+            s.chunks()
+                .iter()
+                .map(|arr| arr.validity().cloned())
+                .collect_vec()
+        })
+        .collect_vec();
+    Ok(rows.with_validity_typed(combine_validities_and_many(&validities)))
+}
+
+/// Normalizes a key column so its row-encoding can be compared between the
+/// two sides of the join.
+fn prepare_key(s: &Series) -> PolarsResult<Series> {
+    let s = match s.dtype() {
+        // Categoricals of the two sides may have differing physical encodings.
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => s.cast(&DataType::String)?,
+        DataType::Float32 => s.f32().unwrap().to_canonical().into_series(),
+        DataType::Float64 => s.f64().unwrap().to_canonical().into_series(),
+        _ => s.clone(),
+    };
+    Ok(s.rechunk())
+}
+
+/// The row-encoded keys and payload of a single build morsel.
+struct BuildChunk {
+    payload: DataFrame,
+    keys: BinaryArray<i64>,
+    hashes: Vec<u64>,
+}
+
+struct BuildState {
+    local: Vec<Vec<BuildChunk>>,
+}
+
+impl BuildState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        params: &'env JoinParams,
+        state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(receivers.len() >= self.local.len());
+        self.local.resize_with(receivers.len(), Vec::new);
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.local) {
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    let df = morsel.into_df();
+                    let keys = select_keys(&df, &params.right_key_selectors, params, state).await?;
+                    let hashes = keys
+                        .values_iter()
+                        .map(|key| params.random_state.hash_one(key))
+                        .collect();
+                    let payload = if params.emits_build_columns() {
+                        df.select(params.right_payload_schema.iter_names_cloned())?
+                    } else {
+                        DataFrame::empty()
+                    };
+                    local.push(BuildChunk {
+                        payload,
+                        keys,
+                        hashes,
+                    });
+                }
+                Ok(())
+            }));
+        }
+    }
+
+    /// Partitions the collected build morsels by key hash and builds a hash
+    /// table for each partition in parallel.
+    fn finalize(self, params: &JoinParams) -> ProbeTable {
+        let num_pipelines = self.local.len();
+        let chunks = self.local.into_iter().flatten().collect_vec();
+        let num_rows: usize = chunks.iter().map(|c| c.hashes.len()).sum();
+        let ideal_num_partitions = num_rows.div_ceil(HASH_JOIN_MIN_ROWS_PER_PARTITION);
+        let num_partitions = if ideal_num_partitions >= 4 {
+            ideal_num_partitions.min(num_pipelines)
+        } else {
+            // If the ideal number of partitions is this low, don't even bother.
+            1
+        };
+        let partitioner = HashPartitioner::new(num_partitions, 0);
+
+        POOL.install(|| {
+            let chunk_partition_idxs: Vec<Vec<Vec<IdxSize>>> = chunks
+                .par_iter()
+                .with_max_len(1)
+                .map(|chunk| {
+                    let mut partition_idxs = vec![Vec::new(); num_partitions];
+                    for (i, (key, hash)) in chunk.keys.iter().zip(&chunk.hashes).enumerate() {
+                        // Null keys never match, don't insert them.
+                        if key.is_some() {
+                            partition_idxs[partitioner.hash_to_partition(*hash)].push(i as IdxSize);
+                        }
+                    }
+                    partition_idxs
+                })
+                .collect();
+
+            let chunk_offsets = chunks
+                .iter()
+                .scan(0 as IdxSize, |offset, chunk| {
+                    let chunk_offset = *offset;
+                    *offset += chunk.hashes.len() as IdxSize;
+                    Some(chunk_offset)
+                })
+                .collect_vec();
+
+            let partitions = (0..num_partitions)
+                .into_par_iter()
+                .with_max_len(1)
+                .map(|p| {
+                    let mut partition = BuildPartition::default();
+                    for ((chunk, offset), partition_idxs) in
+                        chunks.iter().zip(&chunk_offsets).zip(&chunk_partition_idxs)
+                    {
+                        for &i in &partition_idxs[p] {
+                            unsafe {
+                                let key = chunk.keys.value_unchecked(i as usize);
+                                let hash = *chunk.hashes.get_unchecked(i as usize);
+                                partition.insert(hash, key, offset + i);
+                            }
+                        }
+                    }
+                    partition
+                })
+                .collect();
+
+            let payload = if chunks.is_empty() || !params.emits_build_columns() {
+                DataFrame::empty_with_schema(&params.right_payload_schema)
+            } else {
+                let mut payload =
+                    accumulate_dataframes_vertical_unchecked(chunks.into_iter().map(|c| c.payload));
+                payload.as_single_chunk_par();
+                payload
+            };
+
+            // Only a full join has to know which build rows never matched.
+            let matched = if params.args.how == JoinType::Full {
+                (0..payload.height())
+                    .map(|_| AtomicBool::new(false))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            ProbeTable {
+                partitioner,
+                partitions,
+                payload,
+                matched,
+            }
+        })
+    }
+}
+
+/// A hash table mapping the keys of a single partition of the build side to
+/// the rows containing that key.
+#[derive(Default)]
+struct BuildPartition {
+    table: HashTable<IdxSize>,
+    group_hashes: Vec<u64>,
+    group_key_offsets: Vec<usize>,
+    group_key_data: Vec<u8>,
+    group_rows: Vec<IdxVec>,
+}
+
+impl BuildPartition {
+    fn insert(&mut self, hash: u64, key: &[u8], row: IdxSize) {
+        let entry = self.table.entry(
+            hash,
+            |g| {
+                self.group_hashes[*g as usize] == hash
+                    && key == Self::key_of(&self.group_key_offsets, &self.group_key_data, *g)
+            },
+            |g| self.group_hashes[*g as usize],
+        );
+
+        match entry {
+            Entry::Occupied(e) => self.group_rows[*e.get() as usize].push(row),
+            Entry::Vacant(e) => {
+                let group_idx = self.group_rows.len() as IdxSize;
+                e.insert(group_idx);
+                self.group_hashes.push(hash);
+                self.group_key_offsets.push(self.group_key_data.len());
+                self.group_key_data.extend_from_slice(key);
+                let mut rows = IdxVec::new();
+                rows.push(row);
+                self.group_rows.push(rows);
+            },
+        }
+    }
+
+    /// Returns the key of the given group. Takes the fields separately such that
+    /// it can be used while the table is mutably borrowed.
+    fn key_of<'a>(offsets: &[usize], data: &'a [u8], group_idx: IdxSize) -> &'a [u8] {
+        let start = offsets[group_idx as usize];
+        let end = offsets
+            .get(group_idx as usize + 1)
+            .copied()
+            .unwrap_or(data.len());
+        &data[start..end]
+    }
+
+    fn get(&self, hash: u64, key: &[u8]) -> Option<&IdxVec> {
+        self.table
+            .find(hash, |g| {
+                self.group_hashes[*g as usize] == hash
+                    && key == Self::key_of(&self.group_key_offsets, &self.group_key_data, *g)
+            })
+            .map(|g| &self.group_rows[*g as usize])
+    }
+}
+
+struct ProbeTable {
+    partitioner: HashPartitioner,
+    partitions: Vec<BuildPartition>,
+    /// The build side rows, indexed by the row indices in the partitions.
+    payload: DataFrame,
+    /// For full joins, whether each build row has been matched by a probe row.
+    matched: Vec<AtomicBool>,
+}
+
+impl ProbeTable {
+    fn is_empty(&self) -> bool {
+        self.partitions.iter().all(|p| p.group_rows.is_empty())
+    }
+
+    fn get(&self, hash: u64, key: &[u8]) -> Option<&IdxVec> {
+        let p = self.partitioner.hash_to_partition(hash);
+        self.partitions[p].get(hash, key)
+    }
+
+    fn probe(
+        &self,
+        df: DataFrame,
+        keys: &BinaryArray<i64>,
+        params: &JoinParams,
+    ) -> PolarsResult<DataFrame> {
+        let matches = keys.iter().map(|key| {
+            let key = key?;
+            self.get(params.random_state.hash_one(key), key)
+        });
+
+        match params.args.how {
+            JoinType::Inner => {
+                let mut probe_idxs = Vec::new();
+                let mut build_idxs = Vec::new();
+                for (i, rows) in matches.enumerate() {
+                    if let Some(rows) = rows {
+                        probe_idxs.extend(std::iter::repeat(i as IdxSize).take(rows.len()));
+                        build_idxs.extend_from_slice(rows);
+                    }
+                }
+                let (left, right) = unsafe {
+                    (
+                        df._take_unchecked_slice(&probe_idxs, false),
+                        self.payload._take_unchecked_slice(&build_idxs, false),
+                    )
+                };
+                Ok(finish_join(left, right, &params.output_schema))
+            },
+            JoinType::Left | JoinType::Full => {
+                let track_matches = params.args.how == JoinType::Full;
+                let mut probe_idxs = Vec::with_capacity(df.height());
+                let mut build_idxs = Vec::with_capacity(df.height());
+                for (i, rows) in matches.enumerate() {
+                    match rows {
+                        Some(rows) => {
+                            if track_matches {
+                                for r in rows.iter() {
+                                    self.matched[*r as usize].store(true, Ordering::Relaxed);
+                                }
+                            }
+                            probe_idxs.extend(std::iter::repeat(i as IdxSize).take(rows.len()));
+                            build_idxs.extend(rows.iter().map(|r| Some(*r)));
+                        },
+                        None => {
+                            probe_idxs.push(i as IdxSize);
+                            build_idxs.push(None);
+                        },
+                    }
+                }
+                let left = unsafe { df._take_unchecked_slice(&probe_idxs, false) };
+                let right = if self.payload.height() == 0 {
+                    let columns = self
+                        .payload
+                        .get_columns()
+                        .iter()
+                        .map(|c| Column::full_null(c.name().clone(), left.height(), c.dtype()))
+                        .collect();
+                    unsafe { DataFrame::new_no_checks(left.height(), columns) }
+                } else {
+                    let build_idxs =
+                        IdxCa::from_iter_options(PlSmallStr::EMPTY, build_idxs.into_iter());
+                    unsafe { self.payload.take_unchecked_impl(&build_idxs, false) }
+                };
+                Ok(finish_join(left, right, &params.output_schema))
+            },
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => {
+                let keep_matches = params.args.how == JoinType::Semi;
+                let mask: BooleanChunked = matches
+                    .map(|rows| rows.is_some() == keep_matches)
+                    .collect_ca(PlSmallStr::EMPTY);
+                df._filter_seq(&mask)
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// The build rows that were never matched, joined with a null probe row.
+    /// These make up the remainder of the output of a full join.
+    fn unmatched_build_rows(&self, params: &JoinParams) -> DataFrame {
+        let mask: BooleanChunked = self
+            .matched
+            .iter()
+            .map(|m| !m.load(Ordering::Relaxed))
+            .collect_ca(PlSmallStr::EMPTY);
+        let right = self.payload._filter_seq(&mask).unwrap();
+        let left = params
+            .left_input_schema
+            .iter()
+            .map(|(name, dtype)| Column::full_null(name.clone(), right.height(), dtype))
+            .collect();
+        let left = unsafe { DataFrame::new_no_checks(right.height(), left) };
+        finish_join(left, right, &params.output_schema)
+    }
+}
+
+/// Horizontally concatenates the gathered left and right rows, naming the
+/// columns according to the output schema.
+fn finish_join(left: DataFrame, right: DataFrame, output_schema: &Schema) -> DataFrame {
+    let height = left.height();
+    let columns = left
+        .take_columns()
+        .into_iter()
+        .chain(right.take_columns())
+        .zip(output_schema.iter_names())
+        .map(|(c, name)| c.with_name(name.clone()))
+        .collect();
+    unsafe { DataFrame::new_no_checks(height, columns) }
+}
+
+enum HashJoinState {
+    Build(BuildState),
+    Probe(ProbeTable),
+    /// A full join sends the unmatched build rows once probing is done.
+    EmitUnmatched(Option<DataFrame>),
+    Done,
+}
+
+/// A hash join which builds a partitioned hash table from the right input,
+/// after which the morsels of the left input are streamed through it.
+pub struct HashJoinNode {
+    state: HashJoinState,
+    params: JoinParams,
+    max_seq_sent: MorselSeq,
+}
+
+impl HashJoinNode {
+    pub fn new(
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        left_input_schema: Arc<Schema>,
+        right_payload_schema: Arc<Schema>,
+        output_schema: Arc<Schema>,
+        args: JoinArgs,
+    ) -> Self {
+        Self {
+            state: HashJoinState::Build(BuildState { local: Vec::new() }),
+            params: JoinParams {
+                left_key_selectors,
+                right_key_selectors,
+                left_input_schema,
+                right_payload_schema,
+                output_schema,
+                args,
+                random_state: PlRandomState::new(),
+            },
+            max_seq_sent: MorselSeq::new(0),
+        }
+    }
+}
+
+impl ComputeNode for HashJoinNode {
+    fn name(&self) -> &str {
+        "hash_join"
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done {
+            self.state = HashJoinState::Done;
+        }
+
+        // If the build side is done, transition to probing.
+        if let HashJoinState::Build(_) = &self.state {
+            if recv[1] == PortState::Done {
+                let HashJoinState::Build(build) =
+                    core::mem::replace(&mut self.state, HashJoinState::Done)
+                else {
+                    unreachable!()
+                };
+                self.state = HashJoinState::Probe(build.finalize(&self.params));
+            }
+        }
+
+        // If the probe side is done, or no probe row can produce output, we
+        // are done.
+        if let HashJoinState::Probe(table) = &self.state {
+            let output_needs_match = match self.params.args.how {
+                JoinType::Inner => true,
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi => true,
+                _ => false,
+            };
+            if recv[0] == PortState::Done && self.params.args.how == JoinType::Full {
+                let unmatched = table.unmatched_build_rows(&self.params);
+                self.state = if unmatched.is_empty() {
+                    HashJoinState::Done
+                } else {
+                    HashJoinState::EmitUnmatched(Some(unmatched))
+                };
+            } else if recv[0] == PortState::Done || (output_needs_match && table.is_empty()) {
+                self.state = HashJoinState::Done;
+            }
+        }
+
+        // We have sent the unmatched build rows, we are done.
+        if let HashJoinState::EmitUnmatched(None) = &self.state {
+            self.state = HashJoinState::Done;
+        }
+
+        // Communicate our state.
+        match &self.state {
+            HashJoinState::Build(_) => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Blocked;
+                recv[1] = PortState::Ready;
+            },
+            HashJoinState::Probe(_) => {
+                core::mem::swap(&mut recv[0], &mut send[0]);
+                recv[1] = PortState::Done;
+            },
+            HashJoinState::EmitUnmatched(_) => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            HashJoinState::Done => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, HashJoinState::Build(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+        let params = &self.params;
+        let max_seq_sent = &mut self.max_seq_sent;
+        match &mut self.state {
+            HashJoinState::Build(build) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[0].is_none());
+                build.spawn(
+                    scope,
+                    recv_ports[1].take().unwrap().parallel(),
+                    params,
+                    state,
+                    join_handles,
+                )
+            },
+            HashJoinState::Probe(table) => {
+                assert!(recv_ports[1].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let senders = send_ports[0].take().unwrap().parallel();
+                let table = &*table;
+                let mut inner_handles = Vec::new();
+                for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+                    inner_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let mut max_seq = MorselSeq::new(0);
+                        while let Ok(morsel) = recv.recv().await {
+                            max_seq = max_seq.max(morsel.seq());
+                            let morsel = morsel
+                                .async_try_map(|df| async move {
+                                    let keys =
+                                        select_keys(&df, &params.left_key_selectors, params, state)
+                                            .await?;
+                                    table.probe(df, &keys, params)
+                                })
+                                .await?;
+
+                            if morsel.df().is_empty() {
+                                continue;
+                            }
+
+                            if send.send(morsel).await.is_err() {
+                                break;
+                            }
+                        }
+
+                        PolarsResult::Ok(max_seq)
+                    }));
+                }
+
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    for handle in inner_handles {
+                        *max_seq_sent = (*max_seq_sent).max(handle.await?);
+                    }
+                    Ok(())
+                }));
+            },
+            HashJoinState::EmitUnmatched(df) => {
+                assert!(recv_ports[0].is_none() && recv_ports[1].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+                let seq = max_seq_sent.successor();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let morsel = Morsel::new(df.take().unwrap(), seq, SourceToken::new());
+                    let _ = send.send(morsel).await;
+                    Ok(())
+                }));
+            },
+            HashJoinState::Done => unreachable!(),
+        }
+    }
+}
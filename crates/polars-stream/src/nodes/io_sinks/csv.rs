@@ -0,0 +1,92 @@
+use std::io::Write;
+
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_expr::state::ExecutionState;
+use polars_io::csv::write::{BatchedWriter, CsvWriter, CsvWriterOptions};
+use polars_io::SerWriter;
+
+use crate::nodes::{ComputeNode, JoinHandle, PortState, TaskPriority, TaskScope};
+use crate::pipe::{RecvPort, SendPort};
+
+pub struct CsvSinkNode {
+    is_finished: bool,
+    writer: BatchedWriter<Box<dyn Write + Send>>,
+}
+
+impl CsvSinkNode {
+    pub fn new(
+        input_schema: SchemaRef,
+        writer: Box<dyn Write + Send>,
+        write_options: &CsvWriterOptions,
+    ) -> PolarsResult<Self> {
+        let serialize_options = write_options.serialize_options.clone();
+        let writer = CsvWriter::new(writer)
+            .include_bom(write_options.include_bom)
+            .include_header(write_options.include_header)
+            .with_separator(serialize_options.separator)
+            .with_line_terminator(serialize_options.line_terminator)
+            .with_quote_char(serialize_options.quote_char)
+            .with_batch_size(write_options.batch_size)
+            .with_datetime_format(serialize_options.datetime_format)
+            .with_date_format(serialize_options.date_format)
+            .with_time_format(serialize_options.time_format)
+            .with_float_scientific(serialize_options.float_scientific)
+            .with_float_precision(serialize_options.float_precision)
+            .with_null_value(serialize_options.null)
+            .with_quote_style(serialize_options.quote_style)
+            .batched(&input_schema)?;
+
+        Ok(Self {
+            is_finished: false,
+            writer,
+        })
+    }
+}
+
+impl ComputeNode for CsvSinkNode {
+    fn name(&self) -> &str {
+        "csv_sink"
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(send.is_empty());
+        assert!(recv.len() == 1);
+
+        if recv[0] == PortState::Done && !self.is_finished {
+            // @NOTE: This function can be called afterwards multiple times. So make sure to only
+            // finish the writer once.
+            self.is_finished = true;
+            self.writer.finish()?;
+        }
+
+        // We are always ready to receive, unless the sender is done, then we're
+        // also done.
+        if recv[0] != PortState::Done {
+            recv[0] = PortState::Ready;
+        }
+
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.is_empty());
+        assert!(recv_ports.len() == 1);
+        let mut receiver = recv_ports[0].take().unwrap().serial();
+
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = receiver.recv().await {
+                self.writer.write_batch(&morsel.into_df())?;
+            }
+
+            Ok(())
+        }));
+    }
+}
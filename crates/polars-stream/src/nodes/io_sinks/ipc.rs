@@ -1,5 +1,4 @@
-use std::fs::{File, OpenOptions};
-use std::path::Path;
+use std::io::Write;
 
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
@@ -12,17 +11,16 @@ use crate::pipe::{RecvPort, SendPort};
 
 pub struct IpcSinkNode {
     is_finished: bool,
-    writer: BatchedWriter<File>,
+    writer: BatchedWriter<Box<dyn Write + Send>>,
 }
 
 impl IpcSinkNode {
     pub fn new(
         input_schema: SchemaRef,
-        path: &Path,
+        writer: Box<dyn Write + Send>,
         write_options: &IpcWriterOptions,
     ) -> PolarsResult<Self> {
-        let file = OpenOptions::new().write(true).open(path)?;
-        let writer = IpcWriter::new(file)
+        let writer = IpcWriter::new(writer)
             .with_compression(write_options.compression)
             .batched(&input_schema)?;
 
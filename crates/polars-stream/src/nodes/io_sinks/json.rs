@@ -0,0 +1,61 @@
+use std::io::Write;
+
+use polars_error::PolarsResult;
+use polars_expr::state::ExecutionState;
+use polars_io::json::BatchedWriter;
+
+use crate::nodes::{ComputeNode, JoinHandle, PortState, TaskPriority, TaskScope};
+use crate::pipe::{RecvPort, SendPort};
+
+/// Writes the input as newline-delimited JSON.
+pub struct NDJsonSinkNode {
+    writer: BatchedWriter<Box<dyn Write + Send>>,
+}
+
+impl NDJsonSinkNode {
+    pub fn new(writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            writer: BatchedWriter::new(writer),
+        }
+    }
+}
+
+impl ComputeNode for NDJsonSinkNode {
+    fn name(&self) -> &str {
+        "ndjson_sink"
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(send.is_empty());
+        assert!(recv.len() == 1);
+
+        // We are always ready to receive, unless the sender is done, then we're
+        // also done.
+        if recv[0] != PortState::Done {
+            recv[0] = PortState::Ready;
+        }
+
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.is_empty());
+        assert!(recv_ports.len() == 1);
+        let mut receiver = recv_ports[0].take().unwrap().serial();
+
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = receiver.recv().await {
+                self.writer.write_batch(&morsel.into_df())?;
+            }
+
+            Ok(())
+        }));
+    }
+}
@@ -1 +1,25 @@
+use std::fs::File;
+use std::io::Write;
+
+use polars_error::PolarsResult;
+use polars_io::cloud::CloudWriter;
+use polars_io::pl_async::get_runtime;
+
+use crate::physical_plan::SinkTarget;
+
+pub mod csv;
 pub mod ipc;
+pub mod json;
+pub mod parquet;
+
+/// Opens the destination of a file sink for writing.
+pub fn open_sink_target(target: &SinkTarget) -> PolarsResult<Box<dyn Write + Send>> {
+    match target {
+        SinkTarget::Path(path) => Ok(Box::new(File::create(path.as_ref())?)),
+        SinkTarget::Cloud { uri, cloud_options } => {
+            let writer = get_runtime()
+                .block_on_potential_spawn(CloudWriter::new(uri, cloud_options.as_ref()))?;
+            Ok(Box::new(writer))
+        },
+    }
+}
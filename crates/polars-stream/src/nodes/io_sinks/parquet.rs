@@ -0,0 +1,132 @@
+use std::io::Write;
+
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::PolarsResult;
+use polars_expr::state::ExecutionState;
+use polars_io::parquet::write::{BatchedWriter, ParquetWriteOptions, ParquetWriter};
+
+use crate::nodes::{ComputeNode, JoinHandle, PortState, TaskPriority, TaskScope};
+use crate::pipe::{RecvPort, SendPort};
+
+/// The row group size used if none is given in the write options, this
+/// matches the in-memory [`ParquetWriter`].
+const DEFAULT_ROW_GROUP_SIZE: usize = 512 * 512;
+
+pub struct ParquetSinkNode {
+    is_finished: bool,
+    writer: BatchedWriter<Box<dyn Write + Send>>,
+    row_group_size: usize,
+
+    /// Morsels that have not yet been written as part of a row group.
+    buffer: Vec<DataFrame>,
+    buffered_rows: usize,
+}
+
+impl ParquetSinkNode {
+    pub fn new(
+        input_schema: SchemaRef,
+        writer: Box<dyn Write + Send>,
+        write_options: &ParquetWriteOptions,
+        parallel: bool,
+    ) -> PolarsResult<Self> {
+        let writer = ParquetWriter::new(writer)
+            .with_compression(write_options.compression)
+            .with_data_page_size(write_options.data_page_size)
+            .with_statistics(write_options.statistics)
+            .with_row_group_size(write_options.row_group_size)
+            .with_bloom_filters(write_options.bloom_filters.clone())
+            .set_parallel(parallel)
+            .batched(&input_schema)?;
+
+        Ok(Self {
+            is_finished: false,
+            writer,
+            row_group_size: write_options
+                .row_group_size
+                .unwrap_or(DEFAULT_ROW_GROUP_SIZE)
+                .max(1),
+            buffer: Vec::new(),
+            buffered_rows: 0,
+        })
+    }
+
+    /// Writes all buffered rows as a single row group.
+    fn flush(&mut self) -> PolarsResult<()> {
+        if self.buffered_rows == 0 {
+            return Ok(());
+        }
+
+        let mut row_group = accumulate_dataframes_vertical_unchecked(self.buffer.drain(..));
+        row_group.as_single_chunk_par();
+        self.buffered_rows = 0;
+        self.writer.write_batch(&row_group)
+    }
+
+    /// Buffers the given morsel, writing out row groups once enough rows have
+    /// been buffered.
+    fn push(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        while self.buffered_rows + df.height() >= self.row_group_size {
+            let (head, tail) = df.split_at((self.row_group_size - self.buffered_rows) as i64);
+            self.buffered_rows += head.height();
+            self.buffer.push(head);
+            self.flush()?;
+            df = tail;
+        }
+
+        if df.height() > 0 {
+            self.buffered_rows += df.height();
+            self.buffer.push(df);
+        }
+        Ok(())
+    }
+}
+
+impl ComputeNode for ParquetSinkNode {
+    fn name(&self) -> &str {
+        "parquet_sink"
+    }
+
+    fn update_state(&mut self, recv: &mut [PortState], send: &mut [PortState]) -> PolarsResult<()> {
+        assert!(send.is_empty());
+        assert!(recv.len() == 1);
+
+        if recv[0] == PortState::Done && !self.is_finished {
+            // @NOTE: This function can be called afterwards multiple times. So make sure to only
+            // finish the writer once.
+            self.is_finished = true;
+            self.flush()?;
+            self.writer.finish()?;
+        }
+
+        // We are always ready to receive, unless the sender is done, then we're
+        // also done.
+        if recv[0] != PortState::Done {
+            recv[0] = PortState::Ready;
+        }
+
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.is_empty());
+        assert!(recv_ports.len() == 1);
+        let mut receiver = recv_ports[0].take().unwrap().serial();
+
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = receiver.recv().await {
+                self.push(morsel.into_df())?;
+            }
+
+            Ok(())
+        }));
+    }
+}
@@ -1,5 +1,7 @@
+pub mod distinct;
 pub mod filter;
 pub mod group_by;
+pub mod hash_join;
 pub mod in_memory_map;
 pub mod in_memory_sink;
 pub mod in_memory_source;
@@ -3,10 +3,14 @@ use std::sync::Arc;
 
 use polars_core::prelude::{ArrowSchema, InitHashMaps, PlHashMap};
 use polars_core::series::IsSorted;
+use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_core::utils::operation_exceeded_idxsize_msg;
 use polars_error::{polars_err, PolarsResult};
 use polars_io::predicates::PhysicalIoExpr;
-use polars_io::prelude::_internal::read_this_row_group;
+use polars_io::prelude::_internal::{
+    bloom_filter_byte_ranges, compute_page_mask, page_index_byte_ranges, read_this_row_group,
+    read_this_row_group_bloom,
+};
 use polars_io::prelude::{create_sorting_map, FileMetadata};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
 use polars_io::utils::slice::SplitSlicePosition;
@@ -25,6 +29,8 @@ pub(super) struct RowGroupData {
     pub(super) path_index: usize,
     pub(super) row_offset: usize,
     pub(super) slice: Option<(usize, usize)>,
+    /// The rows of the row group that were not pruned using the page indexes.
+    pub(super) page_mask: Option<Bitmap>,
     pub(super) file_max_row_group_height: usize,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: PlHashMap<usize, IsSorted>,
@@ -112,6 +118,30 @@ impl RowGroupDataFetcher {
                     continue;
                 }
 
+                if let (true, Some(predicate)) = (self.use_statistics, self.predicate.as_deref()) {
+                    match fetch_read_this_row_group_bloom(
+                        predicate,
+                        &row_group_metadata,
+                        self.reader_schema.as_ref(),
+                        &self.current_byte_source,
+                    )
+                    .await
+                    {
+                        Ok(true) => {},
+                        Ok(false) => {
+                            if self.verbose {
+                                eprintln!(
+                                    "[ParquetSource]: Predicate pushdown (bloom filter): \
+                                    Skipped row group {} in file {} ({} rows)",
+                                    current_row_group_idx, self.current_path_index, num_rows
+                                );
+                            }
+                            continue;
+                        },
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+
                 if num_rows > IdxSize::MAX as usize {
                     let msg = operation_exceeded_idxsize_msg(
                         format!("number of rows in row group ({})", num_rows).as_str(),
@@ -163,8 +193,25 @@ impl RowGroupDataFetcher {
                 let io_runtime = polars_io::pl_async::get_runtime();
                 let current_path_index = self.current_path_index;
                 let current_max_row_group_height = self.current_max_row_group_height;
+                let page_index_predicate = self
+                    .use_statistics
+                    .then(|| self.predicate.clone())
+                    .flatten();
+                let reader_schema = self.reader_schema.clone();
 
                 let handle = io_runtime.spawn(async move {
+                    let page_mask = if let Some(predicate) = page_index_predicate.as_deref() {
+                        fetch_page_mask(
+                            predicate,
+                            &row_group_metadata,
+                            &reader_schema,
+                            &current_byte_source,
+                        )
+                        .await?
+                    } else {
+                        None
+                    };
+
                     let fetched_bytes = if let DynByteSource::MemSlice(mem_slice) =
                         current_byte_source.as_ref()
                     {
@@ -244,6 +291,7 @@ impl RowGroupDataFetcher {
                         path_index: current_path_index,
                         row_offset: current_row_offset,
                         slice,
+                        page_mask,
                         file_max_row_group_height: current_max_row_group_height,
                         row_group_metadata,
                         sorting_map,
@@ -347,6 +395,48 @@ impl futures::stream::Stream for RowGroupDataStream {
     }
 }
 
+/// Fetch the page indexes of the predicate columns and use them to compute which rows of the row
+/// group can match `predicate`.
+async fn fetch_page_mask(
+    predicate: &dyn PhysicalIoExpr,
+    row_group_metadata: &RowGroupMetadata,
+    reader_schema: &ArrowSchema,
+    byte_source: &DynByteSource,
+) -> PolarsResult<Option<Bitmap>> {
+    let ranges = page_index_byte_ranges(predicate, row_group_metadata, reader_schema);
+    if ranges.is_empty() {
+        return Ok(None);
+    }
+
+    let bytes = byte_source.get_ranges(ranges.as_ref()).await?;
+    assert_eq!(bytes.len(), ranges.len());
+
+    compute_page_mask(predicate, row_group_metadata, reader_schema, |range| {
+        let i = ranges.iter().position(|r| *r == range).unwrap();
+        Ok(bytes[i].clone())
+    })
+}
+
+async fn fetch_read_this_row_group_bloom(
+    predicate: &dyn PhysicalIoExpr,
+    row_group_metadata: &RowGroupMetadata,
+    reader_schema: &ArrowSchema,
+    byte_source: &DynByteSource,
+) -> PolarsResult<bool> {
+    let ranges = bloom_filter_byte_ranges(predicate, row_group_metadata, reader_schema);
+    if ranges.is_empty() {
+        return Ok(true);
+    }
+
+    let bytes = byte_source.get_ranges(ranges.as_ref()).await?;
+    assert_eq!(bytes.len(), ranges.len());
+
+    read_this_row_group_bloom(predicate, row_group_metadata, reader_schema, |range| {
+        let i = ranges.iter().position(|r| *r == range).unwrap();
+        Ok(bytes[i].clone())
+    })
+}
+
 fn get_row_group_byte_ranges_for_projection<'a>(
     row_group_metadata: &'a RowGroupMetadata,
     columns: &'a [PlSmallStr],
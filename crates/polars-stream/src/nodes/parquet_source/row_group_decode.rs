@@ -73,19 +73,38 @@ impl RowGroupDecoder {
 
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
-        if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
+        // Restrict the rows that were not pruned by the page index to the slice.
+        let row_mask = row_group_data.page_mask.as_ref().map(|page_mask| {
+            let mut mask = MutableBitmap::with_capacity(page_mask.len());
+            mask.extend_constant(slice_range.start, false);
+            mask.extend_from_bitmap(
+                &page_mask
+                    .clone()
+                    .sliced(slice_range.start, slice_range.len()),
+            );
+            mask.extend_constant(page_mask.len() - slice_range.end, false);
+            mask.freeze()
+        });
+
+        if let Some(s) = self.materialize_row_index(
+            row_group_data.as_ref(),
+            slice_range.clone(),
+            row_mask.as_ref(),
+        )? {
             out_columns.push(s);
         }
 
-        self.decode_all_columns(
-            &mut out_columns,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
+        let filter = match row_mask {
+            None => polars_parquet::read::Filter::Range(slice_range.clone()),
+            Some(row_mask) => polars_parquet::read::Filter::Mask(row_mask),
+        };
+        let filter_height = filter.num_rows();
+
+        self.decode_all_columns(&mut out_columns, &row_group_data, Some(filter))
+            .await?;
 
         let projection_height = if self.projected_arrow_schema.is_empty() {
-            slice_range.len()
+            filter_height
         } else {
             debug_assert!(out_columns.len() > self.row_index.is_some() as usize);
             out_columns.last().unwrap().len()
@@ -173,10 +192,13 @@ impl RowGroupDecoder {
         }
     }
 
+    /// Materializes the row index for the rows in `slice_range`, or only for the rows selected by
+    /// `row_mask` if it is given.
     fn materialize_row_index(
         &self,
         row_group_data: &RowGroupData,
         slice_range: core::ops::Range<usize>,
+        row_mask: Option<&Bitmap>,
     ) -> PolarsResult<Option<Column>> {
         if let Some(RowIndex { name, offset }) = self.row_index.as_ref() {
             let projection_height = slice_range.len();
@@ -199,10 +221,14 @@ impl RowGroupDecoder {
             // The DataFrame can be empty at this point if no columns were projected from the file,
             // so we create the row index column manually instead of using `df.with_row_index` to
             // ensure it has the correct number of rows.
-            let mut ca = IdxCa::from_vec(
-                name.clone(),
-                (offset..offset + projection_height as IdxSize).collect(),
-            );
+            let values = match row_mask {
+                None => (offset..offset + projection_height as IdxSize).collect(),
+                Some(row_mask) => row_mask
+                    .true_idx_iter()
+                    .map(|i| offset + (i - slice_range.start) as IdxSize)
+                    .collect(),
+            };
+            let mut ca = IdxCa::from_vec(name.clone(), values);
             ca.set_sorted_flag(IsSorted::Ascending);
 
             Ok(Some(ca.into_column()))
@@ -472,9 +498,12 @@ impl RowGroupDecoder {
                 + self.include_file_paths.is_some() as usize,
         );
 
+        let page_mask = row_group_data.page_mask.as_ref();
+
         if let Some(s) = self.materialize_row_index(
             row_group_data.as_ref(),
             0..row_group_data.row_group_metadata.num_rows(),
+            page_mask,
         )? {
             live_columns.push(s);
         }
@@ -486,7 +515,12 @@ impl RowGroupDecoder {
 
         assert_eq!(shared_file_state.path_index, row_group_data.path_index);
 
-        let projection_height = row_group_data.row_group_metadata.num_rows();
+        // The live columns are only decoded for the rows that were not pruned by the page index.
+        let projection_height = page_mask
+            .map_or(row_group_data.row_group_metadata.num_rows(), |m| {
+                m.set_bits()
+            });
+        let live_filter = page_mask.cloned().map(polars_parquet::read::Filter::Mask);
 
         for s in &shared_file_state.hive_series {
             debug_assert!(s.len() >= projection_height);
@@ -503,15 +537,18 @@ impl RowGroupDecoder {
             .iter()
             .map(|&i| self.projected_arrow_schema.get_at_index(i).unwrap())
             .map(|(_, arrow_field)| {
-                decode_column(arrow_field, &row_group_data, None, projection_height)
+                decode_column(
+                    arrow_field,
+                    &row_group_data,
+                    live_filter.clone(),
+                    projection_height,
+                )
             })
         {
             live_columns.push(s?);
         }
 
-        let live_df = unsafe {
-            DataFrame::new_no_checks(row_group_data.row_group_metadata.num_rows(), live_columns)
-        };
+        let live_df = unsafe { DataFrame::new_no_checks(projection_height, live_columns) };
         let mask = self
             .physical_predicate
             .as_deref()
@@ -546,8 +583,20 @@ impl RowGroupDecoder {
 
         assert_eq!(mask_bitmap.len(), projection_height);
 
-        let prefilter_cost = calc_prefilter_cost(&mask_bitmap);
-        let expected_num_rows = mask_bitmap.set_bits();
+        // The mask over all the rows of the row group.
+        let rg_mask_bitmap = match page_mask {
+            None => mask_bitmap,
+            Some(page_mask) => {
+                let mut values = mask_bitmap.iter();
+                page_mask
+                    .iter()
+                    .map(|selected| selected && values.next().unwrap())
+                    .collect::<Bitmap>()
+            },
+        };
+
+        let prefilter_cost = calc_prefilter_cost(&rg_mask_bitmap);
+        let expected_num_rows = rg_mask_bitmap.set_bits();
 
         let dead_cols_filtered = self
             .non_predicate_arrow_field_indices
@@ -560,7 +609,8 @@ impl RowGroupDecoder {
                     prefilter_cost,
                     prefilter_setting,
                     mask,
-                    &mask_bitmap,
+                    &rg_mask_bitmap,
+                    live_filter.clone(),
                     expected_num_rows,
                 )
             })
@@ -597,6 +647,9 @@ impl RowGroupDecoder {
     }
 }
 
+/// Decodes a column that is not used in the predicate. `mask_bitmap` selects rows of the full row
+/// group, while `mask` selects rows of the rows that are decoded with `post_filter`.
+#[allow(clippy::too_many_arguments)]
 fn decode_column_prefiltered(
     arrow_field: &ArrowField,
     row_group_data: &RowGroupData,
@@ -604,6 +657,7 @@ fn decode_column_prefiltered(
     prefilter_setting: &PrefilterMaskSetting,
     mask: &BooleanChunked,
     mask_bitmap: &Bitmap,
+    post_filter: Option<polars_parquet::read::Filter>,
     expected_num_rows: usize,
 ) -> PolarsResult<Column> {
     let Some(iter) = row_group_data
@@ -632,8 +686,11 @@ fn decode_column_prefiltered(
 
     let prefilter = prefilter_setting.should_prefilter(prefilter_cost, &arrow_field.dtype);
 
-    let deserialize_filter =
-        prefilter.then(|| polars_parquet::read::Filter::Mask(mask_bitmap.clone()));
+    let deserialize_filter = if prefilter {
+        Some(polars_parquet::read::Filter::Mask(mask_bitmap.clone()))
+    } else {
+        post_filter
+    };
 
     let array = polars_io::prelude::_internal::to_deserializer(
         columns_to_deserialize,
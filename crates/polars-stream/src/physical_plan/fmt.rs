@@ -37,6 +37,7 @@ fn visualize_plan_rec(
     visited.insert(node_key, ());
 
     use std::slice::from_ref;
+    let join_inputs;
     let (label, inputs) = match &phys_sm[node_key].kind {
         PhysNodeKind::InMemorySource { df } => (
             format!(
@@ -203,6 +204,44 @@ fn visualize_plan_rec(
                 from_ref(input),
             )
         },
+        PhysNodeKind::Distinct {
+            input,
+            key,
+            keep,
+            maintain_order,
+        } => {
+            let label = if *maintain_order {
+                "distinct-stable"
+            } else {
+                "distinct"
+            };
+            (
+                format!(
+                    "{label}\\nkey: [{}]\\nkeep: {}",
+                    key.join(", "),
+                    <&str>::from(keep)
+                ),
+                from_ref(input),
+            )
+        },
+        PhysNodeKind::HashJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let label = format!("{}-hash-join", args.how).to_lowercase();
+            join_inputs = [*input_left, *input_right];
+            (
+                format!(
+                    "{label}\\nleft_on:\\n{}\\nright_on:\\n{}",
+                    fmt_exprs(left_on, expr_arena),
+                    fmt_exprs(right_on, expr_arena)
+                ),
+                &join_inputs[..],
+            )
+        },
     };
 
     out.push(format!(
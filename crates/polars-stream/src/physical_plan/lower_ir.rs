@@ -1,16 +1,17 @@
 use std::sync::Arc;
 
-use polars_core::prelude::{InitHashMaps, PlHashMap, PlIndexMap};
+use polars_core::prelude::{InitHashMaps, PlHashMap, PlIndexMap, PlSmallStr};
 use polars_core::schema::Schema;
 use polars_error::{polars_ensure, PolarsResult};
+use polars_ops::frame::{JoinType, JoinValidation};
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::{AExpr, FunctionIR, IRAggExpr, IR};
-use polars_plan::prelude::{FileType, SinkType};
+use polars_plan::prelude::SinkType;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
 use slotmap::SlotMap;
 
-use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, SinkTarget};
 use crate::physical_plan::lower_expr::{build_select_node, is_elementwise, lower_exprs, ExprCache};
 
 fn build_slice_node(
@@ -208,22 +209,32 @@ pub fn lower_ir(
                 PhysNodeKind::InMemorySink { input: phys_input }
             },
             SinkType::File { path, file_type } => {
-                let path = path.clone();
+                let target = SinkTarget::Path(path.clone());
                 let file_type = file_type.clone();
-
-                match file_type {
-                    FileType::Ipc(_) => {
-                        let phys_input = lower_ir!(*input)?;
-                        PhysNodeKind::FileSink {
-                            path,
-                            file_type,
-                            input: phys_input,
-                        }
-                    },
-                    _ => todo!(),
+                let phys_input = lower_ir!(*input)?;
+                PhysNodeKind::FileSink {
+                    target,
+                    file_type,
+                    input: phys_input,
+                }
+            },
+            SinkType::Cloud {
+                uri,
+                file_type,
+                cloud_options,
+            } => {
+                let target = SinkTarget::Cloud {
+                    uri: uri.clone(),
+                    cloud_options: cloud_options.clone(),
+                };
+                let file_type = file_type.clone();
+                let phys_input = lower_ir!(*input)?;
+                PhysNodeKind::FileSink {
+                    target,
+                    file_type,
+                    input: phys_input,
                 }
             },
-            SinkType::Cloud { .. } => todo!(),
         },
 
         IR::MapFunction { input, function } => {
@@ -413,8 +424,100 @@ pub fn lower_ir(
             }
             return Ok(node);
         },
-        IR::Join { .. } => todo!(),
-        IR::Distinct { .. } => todo!(),
+        IR::Join {
+            input_left,
+            input_right,
+            schema: _,
+            left_on,
+            right_on,
+            options,
+        } => {
+            let args = options.args.clone();
+            match args.how {
+                JoinType::Inner | JoinType::Left => {},
+                // Coalescing the keys of a full join is not yet supported.
+                JoinType::Full if !args.should_coalesce() => {},
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi | JoinType::Anti => {},
+                _ => todo!(),
+            }
+
+            if args.validation != JoinValidation::ManyToMany {
+                todo!()
+            }
+
+            if !left_on
+                .iter()
+                .chain(right_on)
+                .all(|e| is_elementwise(e.node(), expr_arena, expr_cache))
+            {
+                todo!()
+            }
+
+            // The output schema drops the right columns named after coalesced key
+            // expressions, which only correspond to the key values for plain columns.
+            if args.should_coalesce()
+                && matches!(args.how, JoinType::Inner | JoinType::Left)
+                && !right_on
+                    .iter()
+                    .all(|e| matches!(expr_arena.get(e.node()), AExpr::Column(_)))
+            {
+                todo!()
+            }
+
+            let input_left = *input_left;
+            let input_right = *input_right;
+            let left_on = left_on.clone();
+            let right_on = right_on.clone();
+            let phys_left = lower_ir!(input_left)?;
+            let phys_right = lower_ir!(input_right)?;
+
+            let mut node = phys_sm.insert(PhysNode::new(
+                output_schema,
+                PhysNodeKind::HashJoin {
+                    input_left: phys_left,
+                    input_right: phys_right,
+                    left_on,
+                    right_on,
+                    args: args.clone(),
+                },
+            ));
+
+            if let Some((offset, len)) = args.slice {
+                node = build_slice_node(node, offset, len, phys_sm);
+            }
+            return Ok(node);
+        },
+        IR::Distinct { input, options } => {
+            let options = options.clone();
+            let phys_input = lower_ir!(*input)?;
+            let key: Arc<[PlSmallStr]> = match options.subset {
+                Some(subset) => subset,
+                None => phys_sm[phys_input]
+                    .output_schema
+                    .iter_names()
+                    .cloned()
+                    .collect(),
+            };
+            if key.is_empty() {
+                todo!()
+            }
+
+            let mut node = phys_sm.insert(PhysNode::new(
+                output_schema,
+                PhysNodeKind::Distinct {
+                    input: phys_input,
+                    key,
+                    keep: options.keep_strategy,
+                    maintain_order: options.maintain_order,
+                },
+            ));
+
+            if let Some((offset, len)) = options.slice {
+                node = build_slice_node(node, offset, len, phys_sm);
+            }
+            return Ok(node);
+        },
         IR::ExtContext { .. } => todo!(),
         IR::Invalid => unreachable!(),
     };
@@ -1,10 +1,12 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use polars_core::frame::DataFrame;
+use polars_core::frame::{DataFrame, UniqueKeepStrategy};
 use polars_core::prelude::{IdxSize, InitHashMaps, PlHashMap, SortMultipleOptions};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
+use polars_io::cloud::CloudOptions;
+use polars_ops::frame::JoinArgs;
 use polars_plan::plans::hive::HivePartitions;
 use polars_plan::plans::{AExpr, DataFrameUdf, FileInfo, FileScan, ScanSources, IR};
 use polars_plan::prelude::expr_ir::ExprIR;
@@ -47,6 +49,16 @@ impl PhysNode {
     }
 }
 
+/// The destination of a [`PhysNodeKind::FileSink`].
+#[derive(Clone, Debug)]
+pub enum SinkTarget {
+    Path(Arc<PathBuf>),
+    Cloud {
+        uri: Arc<String>,
+        cloud_options: Option<CloudOptions>,
+    },
+}
+
 #[derive(Clone, Debug)]
 pub enum PhysNodeKind {
     InMemorySource {
@@ -95,7 +107,7 @@ pub enum PhysNodeKind {
     },
 
     FileSink {
-        path: Arc<PathBuf>,
+        target: SinkTarget,
         file_type: FileType,
         input: PhysNodeKey,
     },
@@ -149,6 +161,21 @@ pub enum PhysNodeKind {
         key: Vec<ExprIR>,
         aggs: Vec<ExprIR>,
     },
+
+    Distinct {
+        input: PhysNodeKey,
+        key: Arc<[PlSmallStr]>,
+        keep: UniqueKeepStrategy,
+        maintain_order: bool,
+    },
+
+    HashJoin {
+        input_left: PhysNodeKey,
+        input_right: PhysNodeKey,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+    },
 }
 
 #[recursive::recursive]
@@ -194,7 +221,8 @@ fn insert_multiplexers(
             | PhysNodeKind::Map { input, .. }
             | PhysNodeKind::Sort { input, .. }
             | PhysNodeKind::Multiplexer { input }
-            | PhysNodeKind::GroupBy { input, .. } => {
+            | PhysNodeKind::GroupBy { input, .. }
+            | PhysNodeKind::Distinct { input, .. } => {
                 insert_multiplexers(*input, phys_sm, referenced);
             },
 
@@ -203,6 +231,16 @@ fn insert_multiplexers(
                     insert_multiplexers(input, phys_sm, referenced);
                 }
             },
+
+            PhysNodeKind::HashJoin {
+                input_left,
+                input_right,
+                ..
+            } => {
+                let input_right = *input_right;
+                insert_multiplexers(*input_left, phys_sm, referenced);
+                insert_multiplexers(input_right, phys_sm, referenced);
+            },
         }
     }
 }
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use parking_lot::Mutex;
+use polars_core::prelude::PlHashSet;
 use polars_core::schema::{Schema, SchemaExt};
 use polars_error::PolarsResult;
 use polars_expr::groups::new_hash_grouper;
@@ -17,7 +18,7 @@ use polars_utils::itertools::Itertools;
 use recursive::recursive;
 use slotmap::{SecondaryMap, SlotMap};
 
-use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, SinkTarget};
 use crate::expression::StreamExpr;
 use crate::graph::{Graph, GraphNodeKey};
 use crate::nodes;
@@ -205,19 +206,46 @@ fn to_graph_rec<'a>(
         },
 
         FileSink {
-            path,
+            target,
             file_type,
             input,
         } => {
             let input_schema = ctx.phys_sm[*input].output_schema.clone();
             let input_key = to_graph_rec(*input, ctx)?;
+            let writer = nodes::io_sinks::open_sink_target(target)?;
 
             match file_type {
                 FileType::Ipc(ipc_writer_options) => ctx.graph.add_node(
-                    nodes::io_sinks::ipc::IpcSinkNode::new(input_schema, path, ipc_writer_options)?,
+                    nodes::io_sinks::ipc::IpcSinkNode::new(
+                        input_schema,
+                        writer,
+                        ipc_writer_options,
+                    )?,
+                    [input_key],
+                ),
+                FileType::Parquet(parquet_writer_options) => ctx.graph.add_node(
+                    nodes::io_sinks::parquet::ParquetSinkNode::new(
+                        input_schema,
+                        writer,
+                        parquet_writer_options,
+                        // Writing to the cloud blocks on the async runtime,
+                        // which deadlocks if the writer itself is parallel.
+                        matches!(target, SinkTarget::Path(_)),
+                    )?,
+                    [input_key],
+                ),
+                FileType::Csv(csv_writer_options) => ctx.graph.add_node(
+                    nodes::io_sinks::csv::CsvSinkNode::new(
+                        input_schema,
+                        writer,
+                        csv_writer_options,
+                    )?,
+                    [input_key],
+                ),
+                FileType::Json(_) => ctx.graph.add_node(
+                    nodes::io_sinks::json::NDJsonSinkNode::new(writer),
                     [input_key],
                 ),
-                _ => todo!(),
             }
         },
 
@@ -408,6 +436,84 @@ fn to_graph_rec<'a>(
                 [input_key],
             )
         },
+
+        Distinct {
+            input,
+            key,
+            keep,
+            maintain_order,
+        } => {
+            let input_key = to_graph_rec(*input, ctx)?;
+
+            let input_schema = &ctx.phys_sm[*input].output_schema;
+            let key_schema = input_schema.try_project(key.iter())?;
+            let random_state = Default::default();
+            let grouper = new_hash_grouper(Arc::new(key_schema), random_state);
+
+            ctx.graph.add_node(
+                nodes::distinct::DistinctNode::new(
+                    key.clone(),
+                    *keep,
+                    *maintain_order,
+                    grouper,
+                    node.output_schema.clone(),
+                ),
+                [input_key],
+            )
+        },
+
+        HashJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let left_input_key = to_graph_rec(*input_left, ctx)?;
+            let right_input_key = to_graph_rec(*input_right, ctx)?;
+            let left_input_schema = ctx.phys_sm[*input_left].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[*input_right].output_schema.clone();
+
+            let left_key_selectors = left_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &left_input_schema))
+                .try_collect_vec()?;
+            let right_key_selectors = right_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &right_input_schema))
+                .try_collect_vec()?;
+
+            // Coalesced key columns of the right input are dropped from the output.
+            // Joins coalescing other key expressions are not lowered to this node.
+            let dropped_right_names: PlHashSet<_> = if args.should_coalesce() {
+                right_on
+                    .iter()
+                    .filter_map(|e| match ctx.expr_arena.get(e.node()) {
+                        AExpr::Column(name) => Some(name.clone()),
+                        _ => None,
+                    })
+                    .collect()
+            } else {
+                PlHashSet::default()
+            };
+            let right_payload_schema: Schema = right_input_schema
+                .iter()
+                .filter(|(name, _)| !dropped_right_names.contains(*name))
+                .map(|(name, dtype)| (name.clone(), dtype.clone()))
+                .collect();
+
+            ctx.graph.add_node(
+                nodes::hash_join::HashJoinNode::new(
+                    left_key_selectors,
+                    right_key_selectors,
+                    left_input_schema,
+                    Arc::new(right_payload_schema),
+                    node.output_schema.clone(),
+                    args.clone(),
+                ),
+                [left_input_key, right_input_key],
+            )
+        },
     };
 
     ctx.phys_to_graph.insert(phys_node_key, graph_key);
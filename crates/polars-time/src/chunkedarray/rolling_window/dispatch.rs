@@ -27,12 +27,44 @@ fn rolling_agg<T>(
         Option<RollingFnParams>,
     ) -> ArrayRef,
 ) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+{
+    match rolling_agg_array(ca, options, rolling_agg_fn, rolling_agg_fn_nulls)? {
+        Some(arr) => Series::try_from((ca.name().clone(), arr)),
+        None => Ok(Series::new_empty(ca.name().clone(), ca.dtype())),
+    }
+}
+
+/// Apply the rolling kernels, returns `None` if the array is empty.
+#[cfg(feature = "rolling_window")]
+#[allow(clippy::type_complexity)]
+fn rolling_agg_array<T>(
+    ca: &ChunkedArray<T>,
+    options: RollingOptionsFixedWindow,
+    rolling_agg_fn: &dyn Fn(
+        &[T::Native],
+        usize,
+        usize,
+        bool,
+        Option<&[f64]>,
+        Option<RollingFnParams>,
+    ) -> PolarsResult<ArrayRef>,
+    rolling_agg_fn_nulls: &dyn Fn(
+        &PrimitiveArray<T::Native>,
+        usize,
+        usize,
+        bool,
+        Option<&[f64]>,
+        Option<RollingFnParams>,
+    ) -> ArrayRef,
+) -> PolarsResult<Option<ArrayRef>>
 where
     T: PolarsNumericType,
 {
     polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
     if ca.is_empty() {
-        return Ok(Series::new_empty(ca.name().clone(), ca.dtype()));
+        return Ok(None);
     }
     let ca = ca.rechunk();
 
@@ -55,7 +87,7 @@ where
             options.fn_params,
         ),
     };
-    Series::try_from((ca.name().clone(), arr))
+    Ok(Some(arr))
 }
 
 #[cfg(feature = "rolling_window_by")]
@@ -153,6 +185,63 @@ where
     Series::try_from((ca.name().clone(), out))
 }
 
+#[cfg(feature = "rolling_window")]
+#[allow(clippy::type_complexity)]
+fn rolling_agg_positional<T>(
+    ca: &ChunkedArray<T>,
+    options: RollingOptionsFixedWindow,
+    rolling_agg_fn: &dyn Fn(
+        &PrimitiveArray<T::Native>,
+        usize,
+        usize,
+        bool,
+        Option<RollingFnParams>,
+    ) -> ArrayRef,
+) -> PolarsResult<Series>
+where
+    T: PolarsNumericType,
+{
+    polars_ensure!(options.min_periods <= options.window_size, InvalidOperation: "`min_periods` should be <= `window_size`");
+    polars_ensure!(options.weights.is_none(), InvalidOperation: "weights are not supported for this rolling operation");
+    let ca = ca.rechunk();
+
+    let arr = ca.downcast_iter().next().unwrap();
+    let arr = rolling_agg_fn(
+        arr,
+        options.window_size,
+        options.min_periods,
+        options.center,
+        options.fn_params,
+    );
+    Series::try_from((ca.name().clone(), arr))
+}
+
+// The positional rolling functions only look at the order of the values, so
+// they can work on the physical representation.
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn to_positional_physical(s: &Series, name: &str) -> PolarsResult<Series> {
+    let dtype = s.dtype();
+    if dtype.is_bool() {
+        s.cast(&DataType::UInt32)
+    } else if dtype.is_numeric() || dtype.is_temporal() {
+        Ok(s.to_physical_repr().into_owned())
+    } else {
+        polars_bail!(InvalidOperation: "`{}` operation not supported for dtype `{}`", name, dtype)
+    }
+}
+
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn to_product_dtype(s: &Series) -> PolarsResult<Series> {
+    use DataType::*;
+    match s.dtype() {
+        Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => s.cast(&Int64),
+        Int64 | UInt64 | Float32 | Float64 => Ok(s.clone()),
+        dt => {
+            polars_bail!(InvalidOperation: "`rolling_product` operation not supported for dtype `{}`", dt)
+        },
+    }
+}
+
 pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling mean to a Series based on another Series.
     #[cfg(feature = "rolling_window_by")]
@@ -211,6 +300,27 @@ pub trait SeriesOpsTime: AsSeries {
     #[cfg(feature = "rolling_window")]
     fn rolling_sum(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
         let mut s = self.as_series().clone();
+        #[cfg(feature = "dtype-decimal")]
+        if let (Ok(ca), None) = (s.decimal(), &options.weights) {
+            // Sum the physical values, integer window sums are exact.
+            let Some(arr) = rolling_agg_array(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_sum,
+                &rolling::nulls::rolling_sum,
+            )?
+            else {
+                return Ok(s);
+            };
+            let arr = arr
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap()
+                .clone();
+            let out = Int128Chunked::with_chunk(ca.name().clone(), arr)
+                .into_decimal(ca.precision(), ca.scale())?;
+            return Ok(out.into_series());
+        }
         if options.weights.is_some() {
             s = s.to_float()?;
         }
@@ -443,6 +553,214 @@ pub trait SeriesOpsTime: AsSeries {
             s
         })
     }
+
+    /// Apply a rolling kurtosis to a Series based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_kurtosis_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = self.as_series().to_float()?;
+        with_match_physical_float_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_kurtosis,
+            )
+        })
+    }
+
+    /// Apply a rolling kurtosis to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_kurtosis(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let s = self.as_series().to_float()?;
+        with_match_physical_float_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_kurtosis,
+                &rolling::nulls::rolling_kurtosis,
+            )
+        })
+    }
+
+    /// Apply a rolling product to a Series based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_product_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_product_dtype(self.as_series())?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_product,
+            )
+        })
+    }
+
+    /// Apply a rolling product to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_product(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let s = to_product_dtype(self.as_series())?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_product,
+                &rolling::nulls::rolling_product,
+            )
+        })
+    }
+
+    /// Compute the rank of each value within its window, as a fraction of the window length, with windows based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_rank_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_positional_physical(self.as_series(), "rolling_rank_by")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &DataType::Float64));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_rank,
+            )
+        })
+    }
+
+    /// Compute the rank of each value within its window, as a fraction of the window length.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rank(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let s = to_positional_physical(self.as_series(), "rolling_rank")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &DataType::Float64));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_positional(ca, options, &rolling::positional::rolling_rank)
+        })
+    }
+
+    /// Compute the number of unique values in each window, with windows based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_n_unique_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_positional_physical(self.as_series(), "rolling_n_unique_by")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_n_unique,
+            )
+        })
+    }
+
+    /// Compute the number of unique values in each window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_n_unique(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let s = to_positional_physical(self.as_series(), "rolling_n_unique")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_positional(ca, options, &rolling::positional::rolling_n_unique)
+        })
+    }
+
+    /// Compute the index of the minimum within each window, with windows based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_arg_min_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_positional_physical(self.as_series(), "rolling_arg_min_by")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_arg_min,
+            )
+        })
+    }
+
+    /// Compute the index of the minimum within each window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_arg_min(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let s = to_positional_physical(self.as_series(), "rolling_arg_min")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_positional(ca, options, &rolling::positional::rolling_arg_min)
+        })
+    }
+
+    /// Compute the index of the maximum within each window, with windows based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_arg_max_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_positional_physical(self.as_series(), "rolling_arg_max_by")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_arg_max,
+            )
+        })
+    }
+
+    /// Compute the index of the maximum within each window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_arg_max(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        let s = to_positional_physical(self.as_series(), "rolling_arg_max")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_positional(ca, options, &rolling::positional::rolling_arg_max)
+        })
+    }
 }
 
 impl SeriesOpsTime for Series {}
@@ -5,8 +5,9 @@ use std::ops::{AddAssign, Mul, SubAssign};
 use arrow::array::{ArrayRef, PrimitiveArray};
 use arrow::trusted_len::TrustedLen;
 use arrow::types::NativeType;
-use polars_core::export::num::{Bounded, Float, NumCast};
+use polars_core::export::num::{Bounded, Float, Num, NumCast};
 use polars_core::prelude::*;
 use polars_utils::float::IsFloat;
+use polars_utils::total_ord::{TotalEq, TotalHash, TotalOrd};
 
 use crate::prelude::*;
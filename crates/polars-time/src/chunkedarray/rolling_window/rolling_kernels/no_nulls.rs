@@ -1,5 +1,6 @@
 use arrow::bitmap::MutableBitmap;
 use arrow::legacy::kernels::rolling::no_nulls::{self, RollingAggWindowNoNulls};
+use arrow::legacy::kernels::rolling::positional::{self, RollingAggWindowPositional};
 use bytemuck::allocation::zeroed_vec;
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
@@ -135,6 +136,51 @@ where
     Ok(Box::new(out))
 }
 
+// Use a positional aggregation window, which also gets the index of the current value.
+// The output is scattered back to the original order if the values were sorted by time.
+pub(crate) fn rolling_apply_positional_window<'a, Agg, T, O>(
+    values: &'a [T],
+    offsets: O,
+    min_periods: usize,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    // items (offset, len) -> so offsets are offset, offset + len
+    Agg: RollingAggWindowPositional<'a, T>,
+    O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
+    T: NativeType,
+{
+    let mut agg_window = Agg::new(values, None, params);
+
+    let out = offsets
+        .enumerate()
+        .map(|(idx, result)| {
+            result.map(|(start, len)| {
+                if len < (min_periods as IdxSize) {
+                    None
+                } else {
+                    // SAFETY:
+                    // we are in bounds
+                    unsafe { agg_window.update(start as usize, (start + len) as usize, idx) }
+                }
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let out = match sorting_indices {
+        None => out,
+        Some(sorting_indices) => {
+            let mut scattered = vec![None; out.len()];
+            for (value, out_idx) in out.into_iter().zip(sorting_indices) {
+                scattered[*out_idx as usize] = value;
+            }
+            scattered
+        },
+    };
+    Ok(Box::new(PrimitiveArray::<Agg::Out>::from(out)))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn rolling_min<T>(
     values: &[T],
@@ -362,3 +408,221 @@ where
         )
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_kurtosis<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    if sorting_indices.is_none() {
+        rolling_apply_agg_window_sorted::<no_nulls::KurtosisWindow<_>, _, _>(
+            values,
+            offset_iter,
+            min_periods,
+            params,
+        )
+    } else {
+        rolling_apply_agg_window::<no_nulls::KurtosisWindow<_>, _, _>(
+            values,
+            offset_iter,
+            min_periods,
+            params,
+            sorting_indices,
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_product<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    _params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Num + IsFloat,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    if sorting_indices.is_none() {
+        rolling_apply_agg_window_sorted::<no_nulls::ProductWindow<_>, _, _>(
+            values,
+            offset_iter,
+            min_periods,
+            None,
+        )
+    } else {
+        rolling_apply_agg_window::<no_nulls::ProductWindow<_>, _, _>(
+            values,
+            offset_iter,
+            min_periods,
+            None,
+            sorting_indices,
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn rolling_positional<'a, Agg, T>(
+    values: &'a [T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    Agg: RollingAggWindowPositional<'a, T>,
+    T: NativeType,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    rolling_apply_positional_window::<Agg, _, _>(
+        values,
+        offset_iter,
+        min_periods,
+        params,
+        sorting_indices,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_rank<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalOrd,
+{
+    rolling_positional::<positional::RankWindow<_>, _>(
+        values,
+        period,
+        time,
+        closed_window,
+        min_periods,
+        tu,
+        tz,
+        params,
+        sorting_indices,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_n_unique<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalHash + TotalEq,
+{
+    rolling_positional::<positional::NUniqueWindow<_>, _>(
+        values,
+        period,
+        time,
+        closed_window,
+        min_periods,
+        tu,
+        tz,
+        params,
+        sorting_indices,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_arg_min<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    rolling_positional::<positional::ArgMinWindow<_>, _>(
+        values,
+        period,
+        time,
+        closed_window,
+        min_periods,
+        tu,
+        tz,
+        params,
+        sorting_indices,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_arg_max<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat,
+{
+    rolling_positional::<positional::ArgMaxWindow<_>, _>(
+        values,
+        period,
+        time,
+        closed_window,
+        min_periods,
+        tu,
+        tz,
+        params,
+        sorting_indices,
+    )
+}
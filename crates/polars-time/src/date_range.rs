@@ -134,12 +134,29 @@ pub(crate) fn datetime_range_i64(
         };
     }
 
-    let size = ((end - start) / duration + 1) as usize;
     let offset_fn = match tu {
         TimeUnit::Nanoseconds => Duration::add_ns,
         TimeUnit::Microseconds => Duration::add_us,
         TimeUnit::Milliseconds => Duration::add_ms,
     };
+    // Anchored intervals only produce anchor dates, so we start at the first one.
+    let (start, closed) = match interval.anchor_unit() {
+        Some(unit) => {
+            let anchored_start = offset_fn(&unit, offset_fn(&-unit, start, tz)?, tz)?;
+            if anchored_start == start {
+                (start, closed)
+            } else {
+                let closed = match closed {
+                    ClosedWindow::Right => ClosedWindow::Both,
+                    ClosedWindow::None => ClosedWindow::Left,
+                    closed => closed,
+                };
+                (anchored_start, closed)
+            }
+        },
+        None => (start, closed),
+    };
+    let size = ((end - start) / duration + 1) as usize;
     let mut ts = Vec::with_capacity(size);
     let mut i = match closed {
         ClosedWindow::Both | ClosedWindow::Left => 0,
@@ -163,6 +180,7 @@ pub(crate) fn datetime_range_i64(
             }
         },
     }
-    debug_assert!(size >= ts.len());
+    // business month ends can be less than 28 days apart
+    debug_assert!(interval.anchor().is_some() || size >= ts.len());
     Ok(ts)
 }
@@ -0,0 +1,176 @@
+use arrow::legacy::time_zone::Tz;
+use polars_core::prelude::arity::broadcast_try_binary_elementwise;
+use polars_core::prelude::*;
+
+use crate::Duration;
+
+type AddFn = fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
+
+impl Duration {
+    /// Convert into an interval with the same months, days and nanoseconds.
+    ///
+    /// Weeks become seven days each. Business days and anchored durations have no
+    /// interval equivalent.
+    pub fn try_to_interval(&self) -> PolarsResult<months_days_ns> {
+        polars_ensure!(
+            self.business_days() == 0 && self.anchor().is_none(),
+            InvalidOperation: "cannot convert a duration with business days or anchors to an interval"
+        );
+        let sign = if self.negative() { -1 } else { 1 };
+        let months = i32::try_from(sign * self.months()).ok();
+        let days = self
+            .weeks()
+            .checked_mul(7)
+            .and_then(|days| days.checked_add(self.days()))
+            .and_then(|days| i32::try_from(sign * days).ok());
+        match (months, days) {
+            (Some(months), Some(days)) => {
+                Ok(months_days_ns::new(months, days, sign * self.nanoseconds()))
+            },
+            _ => polars_bail!(ComputeError: "duration is out of range for an interval"),
+        }
+    }
+}
+
+/// Add the months, then the days, then the nanoseconds of an interval to a timestamp.
+///
+/// The components are applied one after the other, so that an interval of
+/// "1 month -1 day" is well defined even though the components have different signs.
+fn add_interval_to_timestamp(
+    t: i64,
+    interval: months_days_ns,
+    negate: bool,
+    add_fn: AddFn,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<i64> {
+    let sign = if negate { -1 } else { 1 };
+    let mut t = t;
+    if interval.months() != 0 {
+        t = add_fn(
+            &Duration::from_months(sign * interval.months() as i64),
+            t,
+            time_zone,
+        )?;
+    }
+    if interval.days() != 0 {
+        t = add_fn(
+            &Duration::from_days(sign * interval.days() as i64),
+            t,
+            time_zone,
+        )?;
+    }
+    if interval.ns() != 0 {
+        t = add_fn(&Duration::from_nsecs(sign * interval.ns()), t, time_zone)?;
+    }
+    Ok(t)
+}
+
+fn apply_intervals_to_datetime(
+    datetime: &DatetimeChunked,
+    intervals: &IntervalChunked,
+    negate: bool,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    let add_fn: AddFn = match datetime.time_unit() {
+        TimeUnit::Milliseconds => Duration::add_ms,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Nanoseconds => Duration::add_ns,
+    };
+    broadcast_try_binary_elementwise(datetime, intervals, |timestamp_opt, interval_opt| {
+        match (timestamp_opt, interval_opt) {
+            (Some(timestamp), Some(interval)) => add_interval_to_timestamp(
+                timestamp,
+                interval_from_i128(interval),
+                negate,
+                add_fn,
+                time_zone,
+            )
+            .map(Some),
+            _ => Ok(None),
+        }
+    })
+}
+
+/// Add (or subtract, if `negate` is set) intervals to dates or datetimes.
+///
+/// Months and days are calendar aware: adding a month keeps the day of the month
+/// (clamped to the end of the month), and adding a day keeps the wall-clock time in
+/// the time zone of the datetimes, even across daylight saving time transitions.
+pub fn impl_add_interval(ts: &Series, intervals: &Series, negate: bool) -> PolarsResult<Series> {
+    let intervals = intervals.interval()?;
+    match ts.dtype() {
+        DataType::Date => {
+            let ts = ts
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+                .unwrap();
+            let datetime = ts.datetime().unwrap();
+            let out = apply_intervals_to_datetime(datetime, intervals, negate, None)?;
+            out.into_datetime(TimeUnit::Milliseconds, None)
+                .into_series()
+                .cast(&DataType::Date)
+        },
+        DataType::Datetime(tu, tz) => {
+            let datetime = ts.datetime().unwrap();
+            let out = match tz {
+                #[cfg(feature = "timezones")]
+                Some(ref tz) => apply_intervals_to_datetime(
+                    datetime,
+                    intervals,
+                    negate,
+                    tz.parse::<Tz>().ok().as_ref(),
+                )?,
+                _ => apply_intervals_to_datetime(datetime, intervals, negate, None)?,
+            };
+            Ok(out.into_datetime(*tu, tz.clone()).into_series())
+        },
+        dt => polars_bail!(
+            InvalidOperation: "cannot add intervals to Series of datatype {}", dt,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn test_add_interval_to_date() {
+        let dates = [
+            NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+        ];
+        let s = DateChunked::from_naive_date("a".into(), dates).into_series();
+        let intervals = IntervalChunked::from_intervals(
+            "b".into(),
+            [
+                Some(months_days_ns::new(1, 1, 0)),
+                Some(months_days_ns::new(1, -1, 0)),
+            ],
+        )
+        .into_series();
+
+        let out = impl_add_interval(&s, &intervals, false).unwrap();
+        let expected = DateChunked::from_naive_date(
+            "a".into(),
+            [
+                NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            ],
+        )
+        .into_series();
+        assert!(out.equals(&expected));
+
+        let out = impl_add_interval(&out, &intervals, true).unwrap();
+        let expected = DateChunked::from_naive_date(
+            "a".into(),
+            [
+                NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            ],
+        )
+        .into_series();
+        assert!(out.equals(&expected));
+    }
+}
@@ -13,7 +13,7 @@ fn apply_offsets_to_datetime(
     match offsets.len() {
         1 => match offsets.get(0) {
             Some(offset) => {
-                let offset = &Duration::parse(offset);
+                let offset = &Duration::try_parse(offset)?;
                 if offset.is_constant_duration(datetime.time_zone().as_deref()) {
                     // fastpath!
                     let mut duration = match datetime.time_unit() {
@@ -49,7 +49,7 @@ fn apply_offsets_to_datetime(
                 offset_opt,
             ) {
                 (Some(timestamp), Some(offset)) => {
-                    offset_fn(&Duration::parse(offset), timestamp, time_zone).map(Some)
+                    offset_fn(&Duration::try_parse(offset)?, timestamp, time_zone).map(Some)
                 },
                 _ => Ok(None),
             })
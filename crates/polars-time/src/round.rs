@@ -31,7 +31,9 @@ impl PolarsRound for DatetimeChunked {
                     polars_bail!(ComputeError: "cannot round a Datetime to a negative duration")
                 }
                 if (time_zone.is_none() || time_zone.as_deref() == Some("UTC"))
-                    && (every_parsed.months() == 0 && every_parsed.weeks() == 0)
+                    && (every_parsed.months() == 0
+                        && every_parsed.weeks() == 0
+                        && every_parsed.business_days() == 0)
                 {
                     // ... yes we can! Weeks, months, and time zones require extra logic.
                     // But in this simple case, it's just simple integer arithmetic.
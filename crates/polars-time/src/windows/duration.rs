@@ -38,12 +38,103 @@ pub struct Duration {
     days: i64,
     // the number of nanoseconds for the duration
     nsecs: i64,
+    // the number of business days (Monday to Friday) for the duration
+    #[cfg_attr(feature = "serde", serde(default))]
+    business_days: i64,
+    // if set, `months` steps between the anchor dates of calendar periods
+    #[cfg_attr(feature = "serde", serde(default))]
+    anchor: Option<PeriodAnchor>,
     // indicates if the duration is negative
     pub(crate) negative: bool,
     // indicates if an integer string was passed. e.g. "2i"
     pub parsed_int: bool,
 }
 
+/// Calendar periods whose anchor dates an anchored [`Duration`] steps between.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PeriodAnchor {
+    /// The last day of the month.
+    MonthEnd,
+    /// The last business day (Monday to Friday) of the month.
+    BusinessMonthEnd,
+    /// The last day of the quarter.
+    QuarterEnd,
+    /// The last business day (Monday to Friday) of the quarter.
+    BusinessQuarterEnd,
+    /// The first day of a fiscal year starting in the given month (1 is January).
+    FiscalYearStart(u8),
+}
+
+impl PeriodAnchor {
+    /// The number of months between two anchor dates.
+    fn period(&self) -> i64 {
+        match self {
+            PeriodAnchor::MonthEnd | PeriodAnchor::BusinessMonthEnd => 1,
+            PeriodAnchor::QuarterEnd | PeriodAnchor::BusinessQuarterEnd => 3,
+            PeriodAnchor::FiscalYearStart(_) => 12,
+        }
+    }
+
+    /// The unit that parses to this anchor, without the number.
+    fn unit(&self) -> String {
+        match self {
+            PeriodAnchor::MonthEnd => "me".to_string(),
+            PeriodAnchor::BusinessMonthEnd => "bme".to_string(),
+            PeriodAnchor::QuarterEnd => "qe".to_string(),
+            PeriodAnchor::BusinessQuarterEnd => "bqe".to_string(),
+            PeriodAnchor::FiscalYearStart(1) => "fy".to_string(),
+            PeriodAnchor::FiscalYearStart(month) => {
+                format!("fy{}", MONTH_ABBREVIATIONS[*month as usize - 1])
+            },
+        }
+    }
+
+    /// The month (as `year * 12 + month0`) of the anchor date of the period of
+    /// `step` months that contains `month`.
+    fn anchor_month(&self, month: i64, step: i64) -> i64 {
+        let month_offset = match self {
+            PeriodAnchor::FiscalYearStart(start_month) => *start_month as i64 - 1,
+            _ => 0,
+        };
+        let position = (month - month_offset).rem_euclid(step);
+        match self {
+            PeriodAnchor::FiscalYearStart(_) => month - position,
+            _ => month + (step - 1 - position),
+        }
+    }
+
+    /// The anchor date within `month` (as `year * 12 + month0`).
+    fn anchor_date(&self, month: i64) -> NaiveDate {
+        let year = month.div_euclid(12) as i32;
+        let month0 = month.rem_euclid(12) as usize;
+        let day = match self {
+            PeriodAnchor::FiscalYearStart(_) => 1,
+            _ => DAYS_PER_MONTH[is_leap_year(year) as usize][month0] as u32,
+        };
+        let mut date = NaiveDate::from_ymd_opt(year, month0 as u32 + 1, day).expect(
+            "Expected valid date, please open an issue at https://github.com/pola-rs/polars/issues",
+        );
+        if matches!(
+            self,
+            PeriodAnchor::BusinessMonthEnd | PeriodAnchor::BusinessQuarterEnd
+        ) {
+            while date.weekday().num_days_from_monday() >= 5 {
+                date = date.pred_opt().unwrap();
+            }
+        }
+        date
+    }
+}
+
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+fn month_index(date: NaiveDate) -> i64 {
+    date.year() as i64 * 12 + date.month0() as i64
+}
+
 impl PartialOrd<Self> for Duration {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -65,6 +156,8 @@ impl Neg for Duration {
             weeks: self.weeks,
             days: self.days,
             nsecs: self.nsecs,
+            business_days: self.business_days,
+            anchor: self.anchor,
             negative: !self.negative,
             parsed_int: self.parsed_int,
         }
@@ -79,6 +172,9 @@ impl Display for Duration {
         if self.negative {
             write!(f, "-")?
         }
+        if let Some(anchor) = self.anchor {
+            return write!(f, "{}{}", self.months / anchor.period(), anchor.unit());
+        }
         if self.months > 0 {
             write!(f, "{}m", self.months)?
         }
@@ -88,6 +184,9 @@ impl Display for Duration {
         if self.days > 0 {
             write!(f, "{}d", self.days)?
         }
+        if self.business_days > 0 {
+            write!(f, "{}bd", self.business_days)?
+        }
         if self.nsecs > 0 {
             let secs = self.nsecs / NANOSECONDS;
             if secs * NANOSECONDS == self.nsecs {
@@ -113,6 +212,8 @@ impl Duration {
             weeks: 0,
             days: 0,
             nsecs: fixed_slots.abs(),
+            business_days: 0,
+            anchor: None,
             negative: fixed_slots < 0,
             parsed_int: true,
         }
@@ -139,6 +240,7 @@ impl Duration {
     /// * `m`:  minute
     /// * `h`:  hour
     /// * `d`:  day
+    /// * `bd`: business day (Monday to Friday)
     /// * `w`:  week
     /// * `mo`: calendar month
     /// * `q`: calendar quarter
@@ -150,6 +252,20 @@ impl Duration {
     /// Similarly for "calendar week", "calendar month", "calendar quarter",
     /// and "calendar year".
     ///
+    /// Anchored units step between the anchor dates of calendar periods, rather than
+    /// by a fixed number of months, and can't be combined with other units:
+    ///
+    /// * `me`:  month end
+    /// * `bme`: business month end (the last business day of the month)
+    /// * `qe`:  quarter end
+    /// * `bqe`: business quarter end
+    /// * `fy`:  start of a fiscal year; append a month to start the fiscal year in that
+    ///   month, e.g. `fyapr` for fiscal years starting on 1 April
+    ///
+    /// For example, adding `"1me"` to 15 January gives 31 January, and adding it to
+    /// 31 January gives 29 February (in a leap year). Windows with an anchored `every`
+    /// start on the anchor dates.
+    ///
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
@@ -192,6 +308,9 @@ impl Duration {
         let mut weeks = 0;
         let mut days = 0;
         let mut nsecs = 0;
+        let mut business_days = 0;
+        let mut anchor: Option<PeriodAnchor> = None;
+        let mut anchored_months = 0;
 
         let negative = s.starts_with('-');
         let mut iter = s.char_indices().peekable();
@@ -259,6 +378,31 @@ impl Duration {
                     "mo" => months += n,
                     "q" => months += n * 3,
                     "y" => months += n * 12,
+                    "bd" => business_days += n,
+                    "me" | "bme" | "qe" | "bqe" => {
+                        let unit_anchor = match &*unit {
+                            "me" => PeriodAnchor::MonthEnd,
+                            "bme" => PeriodAnchor::BusinessMonthEnd,
+                            "qe" => PeriodAnchor::QuarterEnd,
+                            _ => PeriodAnchor::BusinessQuarterEnd,
+                        };
+                        Self::set_anchor(&mut anchor, unit_anchor, parse_type)?;
+                        anchored_months += n * unit_anchor.period();
+                    },
+                    fiscal_year if fiscal_year.starts_with("fy") => {
+                        let start_month = match &fiscal_year[2..] {
+                            "" => 1,
+                            month => match MONTH_ABBREVIATIONS.iter().position(|&m| m == month) {
+                                Some(month0) => month0 as u8 + 1,
+                                None => polars_bail!(InvalidOperation:
+                                    "unit: '{unit}' not supported; fiscal years start in a month such as 'fyjan' or 'fyapr'"
+                                ),
+                            },
+                        };
+                        let unit_anchor = PeriodAnchor::FiscalYearStart(start_month);
+                        Self::set_anchor(&mut anchor, unit_anchor, parse_type)?;
+                        anchored_months += n * unit_anchor.period();
+                    },
                     "i" => {
                         nsecs += n;
                         parsed_int = true;
@@ -282,23 +426,48 @@ impl Duration {
                         },
                     },
                     _ => {
-                        polars_bail!(InvalidOperation: "unit: '{unit}' not supported; available units are: 'y', 'mo', 'q', 'w', 'd', 'h', 'm', 's', 'ms', 'us', 'ns'");
+                        polars_bail!(InvalidOperation: "unit: '{unit}' not supported; available units are: 'y', 'fy', 'qe', 'bqe', 'mo', 'me', 'bme', 'q', 'w', 'd', 'bd', 'h', 'm', 's', 'ms', 'us', 'ns'");
                     },
                 }
                 unit.clear();
             }
         }
 
+        if anchor.is_some() {
+            polars_ensure!(
+                months == 0 && weeks == 0 && days == 0 && nsecs == 0 && business_days == 0 && !parsed_int,
+                InvalidOperation: "anchored units ('me', 'bme', 'qe', 'bqe', 'fy') cannot be combined with other units in the {} string '{}'",
+                parse_type, s
+            );
+            months = anchored_months;
+        }
+
         Ok(Duration {
             nsecs: nsecs.abs(),
             days: days.abs(),
             weeks: weeks.abs(),
             months: months.abs(),
+            business_days: business_days.abs(),
+            anchor,
             negative,
             parsed_int,
         })
     }
 
+    fn set_anchor(
+        anchor: &mut Option<PeriodAnchor>,
+        unit_anchor: PeriodAnchor,
+        parse_type: &str,
+    ) -> PolarsResult<()> {
+        match anchor {
+            Some(anchor) if *anchor != unit_anchor => {
+                polars_bail!(InvalidOperation: "{} string can only contain a single anchored unit", parse_type)
+            },
+            _ => *anchor = Some(unit_anchor),
+        }
+        Ok(())
+    }
+
     fn to_positive(v: i64) -> (bool, i64) {
         if v < 0 {
             (true, -v)
@@ -360,6 +529,8 @@ impl Duration {
             weeks: 0,
             days: 0,
             nsecs,
+            business_days: 0,
+            anchor: None,
             negative,
             parsed_int: false,
         }
@@ -373,6 +544,8 @@ impl Duration {
             weeks: 0,
             days: 0,
             nsecs: 0,
+            business_days: 0,
+            anchor: None,
             negative,
             parsed_int: false,
         }
@@ -386,6 +559,8 @@ impl Duration {
             weeks,
             days: 0,
             nsecs: 0,
+            business_days: 0,
+            anchor: None,
             negative,
             parsed_int: false,
         }
@@ -399,14 +574,34 @@ impl Duration {
             weeks: 0,
             days,
             nsecs: 0,
+            business_days: 0,
+            anchor: None,
             negative,
             parsed_int: false,
         }
     }
 
+    /// A single unit of an anchored duration, e.g. `1qe` for `2qe`.
+    pub(crate) fn anchor_unit(&self) -> Option<Self> {
+        self.anchor.map(|anchor| Self {
+            months: anchor.period(),
+            weeks: 0,
+            days: 0,
+            nsecs: 0,
+            business_days: 0,
+            anchor: Some(anchor),
+            negative: false,
+            parsed_int: false,
+        })
+    }
+
     /// `true` if zero duration.
     pub fn is_zero(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.nsecs == 0
+            && self.business_days == 0
     }
 
     pub fn months_only(&self) -> bool {
@@ -433,6 +628,15 @@ impl Duration {
         self.days
     }
 
+    pub fn business_days(&self) -> i64 {
+        self.business_days
+    }
+
+    /// The calendar periods the duration is anchored to, if any.
+    pub fn anchor(&self) -> Option<PeriodAnchor> {
+        self.anchor
+    }
+
     /// Returns whether the duration consists of full days.
     ///
     /// Note that 24 hours is not considered a full day due to possible
@@ -443,11 +647,11 @@ impl Duration {
 
     pub fn is_constant_duration(&self, time_zone: Option<&str>) -> bool {
         if time_zone.is_none() || time_zone == Some("UTC") {
-            self.months == 0
+            self.months == 0 && self.business_days == 0
         } else {
             // For non-native, non-UTC time zones, 1 calendar day is not
             // necessarily 24 hours due to daylight savings time.
-            self.months == 0 && self.weeks == 0 && self.days == 0
+            self.months == 0 && self.weeks == 0 && self.days == 0 && self.business_days == 0
         }
     }
 
@@ -467,13 +671,17 @@ impl Duration {
         self.months * 28 * 24 * 3600 * NANOSECONDS
             + self.weeks * NS_WEEK
             + self.days * NS_DAY
+            + self.business_days * NS_WEEK / 5
             + self.nsecs
     }
 
     #[doc(hidden)]
     pub const fn duration_us(&self) -> i64 {
         self.months * 28 * 24 * 3600 * MICROSECONDS
-            + (self.weeks * NS_WEEK / 1000 + self.nsecs / 1000 + self.days * NS_DAY / 1000)
+            + (self.weeks * NS_WEEK / 1000
+                + self.nsecs / 1000
+                + self.days * NS_DAY / 1000
+                + self.business_days * NS_WEEK / 5_000)
     }
 
     #[doc(hidden)]
@@ -481,7 +689,8 @@ impl Duration {
         self.months * 28 * 24 * 3600 * MILLISECONDS
             + (self.weeks * NS_WEEK / 1_000_000
                 + self.nsecs / 1_000_000
-                + self.days * NS_DAY / 1_000_000)
+                + self.days * NS_DAY / 1_000_000
+                + self.business_days * NS_WEEK / 5_000_000)
     }
 
     #[doc(hidden)]
@@ -529,6 +738,52 @@ impl Duration {
         )
     }
 
+    /// Move `n` anchor dates forwards (or backwards, if `n` is negative), keeping the
+    /// time of day. If `ts` is not on an anchor date, the first step goes to the
+    /// nearest anchor date.
+    fn add_anchored(ts: NaiveDateTime, anchor: PeriodAnchor, n: i64) -> NaiveDateTime {
+        let period = anchor.period();
+        let date = ts.date();
+        let month = anchor.anchor_month(month_index(date), period);
+        let anchor_date = anchor.anchor_date(month);
+        let target_month = match n.cmp(&0) {
+            Ordering::Greater if date < anchor_date => month + period * (n - 1),
+            Ordering::Less if date > anchor_date => month + period * (n + 1),
+            Ordering::Equal => return ts,
+            _ => month + period * n,
+        };
+        NaiveDateTime::new(anchor.anchor_date(target_month), ts.time())
+    }
+
+    /// Move `n` business days (Monday to Friday) forwards (or backwards, if `n` is
+    /// negative), keeping the time of day. Adding a business day to a Saturday or a
+    /// Sunday gives the next Monday, subtracting one gives the previous Friday.
+    fn add_business_days(ts: NaiveDateTime, n: i64) -> NaiveDateTime {
+        let date = ts.date();
+        let mut day_of_week = date.weekday().num_days_from_monday() as i64;
+        let days = if n > 0 {
+            // start from Friday when on a weekend...
+            let to_friday = (day_of_week - 4).max(0);
+            day_of_week -= to_friday;
+            let remainder = n % 5;
+            let weekend = if day_of_week + remainder >= 5 { 2 } else { 0 };
+            -to_friday + (n / 5) * 7 + remainder + weekend
+        } else {
+            // ...and from Monday when going backwards.
+            let to_monday = if day_of_week >= 5 { 7 - day_of_week } else { 0 };
+            day_of_week = if to_monday > 0 { 0 } else { day_of_week };
+            let remainder = n % 5;
+            let weekend = if day_of_week + remainder < 0 { 2 } else { 0 };
+            to_monday + (n / 5) * 7 + remainder - weekend
+        };
+        let date = date
+            .checked_add_signed(chrono::Duration::days(days))
+            .expect(
+            "Expected valid date, please open an issue at https://github.com/pola-rs/polars/issues",
+        );
+        NaiveDateTime::new(date, ts.time())
+    }
+
     /// Localize result to given time zone, respecting DST fold of original datetime.
     /// For example, 2022-11-06 01:30:00 CST truncated by 1 hour becomes 2022-11-06 01:00:00 CST,
     /// whereas 2022-11-06 01:30:00 CDT truncated by 1 hour becomes 2022-11-06 01:00:00 CDT.
@@ -767,6 +1022,66 @@ impl Duration {
         }
     }
 
+    /// Truncate to midnight of the (local) date that `truncate_date` maps the date of `t` to.
+    fn truncate_by_date<G, J, D>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        timestamp_to_datetime: G,
+        datetime_to_timestamp: J,
+        truncate_date: D,
+    ) -> PolarsResult<i64>
+    where
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+        D: Fn(NaiveDate) -> NaiveDate,
+    {
+        let original_dt_utc = timestamp_to_datetime(t);
+        let original_dt_local = match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => unlocalize_datetime(original_dt_utc, tz),
+            _ => original_dt_utc,
+        };
+        let result_dt_local = NaiveDateTime::new(
+            truncate_date(original_dt_local.date()),
+            NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+        );
+        match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                let result_dt_utc =
+                    self.localize_result(original_dt_local, original_dt_utc, result_dt_local, tz)?;
+                Ok(datetime_to_timestamp(result_dt_utc))
+            },
+            _ => Ok(datetime_to_timestamp(result_dt_local)),
+        }
+    }
+
+    /// The latest anchor date on or before `date`, for windows of `self.months` months.
+    fn truncate_anchored_date(&self, date: NaiveDate, anchor: PeriodAnchor) -> NaiveDate {
+        let month = anchor.anchor_month(month_index(date), self.months);
+        let anchor_date = anchor.anchor_date(month);
+        if anchor_date <= date {
+            anchor_date
+        } else {
+            anchor.anchor_date(month - self.months)
+        }
+    }
+
+    /// Truncate `date` to a multiple of `self.business_days` business days since the
+    /// first Monday after the UNIX epoch. Weekends belong to the preceding Friday.
+    fn truncate_business_days_date(&self, date: NaiveDate) -> NaiveDate {
+        // 1970-01-05 is a Monday
+        let monday = NaiveDate::from_ymd_opt(1970, 1, 5).unwrap();
+        let days = (date - monday).num_days();
+        let business_day = days.div_euclid(7) * 5 + days.rem_euclid(7).min(4);
+        let business_day = business_day - business_day.rem_euclid(self.business_days);
+        let days = business_day.div_euclid(5) * 7 + business_day.rem_euclid(5);
+        monday + chrono::Duration::days(days)
+    }
+
     #[inline]
     pub fn truncate_impl<F, G, J>(
         &self,
@@ -781,10 +1096,26 @@ impl Duration {
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
     {
-        match (self.months, self.weeks, self.days, self.nsecs) {
-            (0, 0, 0, 0) => polars_bail!(ComputeError: "duration cannot be zero"),
+        if let Some(anchor) = self.anchor {
+            polars_ensure!(self.months > 0, ComputeError: "duration cannot be zero");
+            return self.truncate_by_date(
+                t,
+                tz,
+                timestamp_to_datetime,
+                datetime_to_timestamp,
+                |date| self.truncate_anchored_date(date, anchor),
+            );
+        }
+        match (
+            self.months,
+            self.weeks,
+            self.days,
+            self.business_days,
+            self.nsecs,
+        ) {
+            (0, 0, 0, 0, 0) => polars_bail!(ComputeError: "duration cannot be zero"),
             // truncate by ns/us/ms
-            (0, 0, 0, _) => {
+            (0, 0, 0, 0, _) => {
                 let duration = nsecs_to_unit(self.nsecs);
                 self.truncate_subweekly(
                     t,
@@ -795,7 +1126,7 @@ impl Duration {
                 )
             },
             // truncate by days
-            (0, 0, _, 0) => {
+            (0, 0, _, 0, 0) => {
                 let duration = self.days * nsecs_to_unit(NS_DAY);
                 self.truncate_subweekly(
                     t,
@@ -806,7 +1137,7 @@ impl Duration {
                 )
             },
             // truncate by weeks
-            (0, _, 0, 0) => {
+            (0, _, 0, 0, 0) => {
                 let duration = nsecs_to_unit(NS_DAY);
                 self.truncate_weekly(
                     t,
@@ -817,7 +1148,7 @@ impl Duration {
                 )
             },
             // truncate by months
-            (_, 0, 0, 0) => {
+            (_, 0, 0, 0, 0) => {
                 let duration = nsecs_to_unit(NS_DAY);
                 self.truncate_monthly(
                     t,
@@ -827,6 +1158,14 @@ impl Duration {
                     duration,
                 )
             },
+            // truncate by business days
+            (0, 0, 0, _, 0) => self.truncate_by_date(
+                t,
+                tz,
+                timestamp_to_datetime,
+                datetime_to_timestamp,
+                |date| self.truncate_business_days_date(date),
+            ),
             _ => {
                 polars_bail!(ComputeError: "duration may not mix month, weeks and nanosecond units")
             },
@@ -894,7 +1233,13 @@ impl Duration {
                 },
                 _ => timestamp_to_datetime(t),
             };
-            let dt = Self::add_month(ts, d.months, d.negative);
+            let dt = match d.anchor {
+                Some(anchor) => {
+                    let n = d.months / anchor.period();
+                    Self::add_anchored(ts, anchor, if d.negative { -n } else { n })
+                },
+                None => Self::add_month(ts, d.months, d.negative),
+            };
             new_t = match tz {
                 #[cfg(feature = "timezones")]
                 // for UTC, use fastpath below (same as naive)
@@ -952,6 +1297,31 @@ impl Duration {
             };
         }
 
+        if d.business_days > 0 {
+            let n = if d.negative {
+                -d.business_days
+            } else {
+                d.business_days
+            };
+            new_t = match tz {
+                #[cfg(feature = "timezones")]
+                // for UTC, use fastpath below (same as naive)
+                Some(tz) if tz != &chrono_tz::UTC => {
+                    let dt = Self::add_business_days(
+                        unlocalize_datetime(timestamp_to_datetime(new_t), tz),
+                        n,
+                    );
+                    datetime_to_timestamp(
+                        try_localize_datetime(dt, tz, Ambiguous::Raise, NonExistent::Raise)?
+                            .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
+                    )
+                },
+                _ => {
+                    datetime_to_timestamp(Self::add_business_days(timestamp_to_datetime(new_t), n))
+                },
+            };
+        }
+
         Ok(new_t)
     }
 
@@ -1007,6 +1377,7 @@ impl Mul<i64> for Duration {
         self.weeks *= rhs;
         self.days *= rhs;
         self.nsecs *= rhs;
+        self.business_days *= rhs;
         self
     }
 }
@@ -1106,6 +1477,87 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_parse_calendar_units() {
+        let out = Duration::parse("3bd");
+        assert_eq!(out.business_days(), 3);
+        assert!(!out.is_constant_duration(None));
+        let out = Duration::parse("2qe");
+        assert_eq!(out.months(), 6);
+        assert_eq!(out.anchor(), Some(PeriodAnchor::QuarterEnd));
+        let out = Duration::parse("1fyapr");
+        assert_eq!(out.months(), 12);
+        assert_eq!(out.anchor(), Some(PeriodAnchor::FiscalYearStart(4)));
+        assert_eq!(
+            Duration::parse("1fy").anchor(),
+            Some(PeriodAnchor::FiscalYearStart(1))
+        );
+
+        assert!(Duration::try_parse("1me1d").is_err());
+        assert!(Duration::try_parse("1me1qe").is_err());
+        assert!(Duration::try_parse("1fyfoo").is_err());
+
+        for duration in ["2bd", "-1bme", "2qe", "1fyapr", "1fy"] {
+            assert_eq!(format!("{}", Duration::parse(duration)), duration);
+        }
+    }
+
+    #[test]
+    fn test_add_calendar_units() {
+        let ns = |year, month, day, hour| {
+            NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp_nanos_opt()
+                .unwrap()
+        };
+        let add = |duration, t| Duration::parse(duration).add_ns(t, None).unwrap();
+
+        // Friday, Saturday and Monday
+        assert_eq!(add("1bd", ns(2024, 5, 24, 10)), ns(2024, 5, 27, 10));
+        assert_eq!(add("1bd", ns(2024, 5, 25, 10)), ns(2024, 5, 27, 10));
+        assert_eq!(add("-1bd", ns(2024, 5, 25, 10)), ns(2024, 5, 24, 10));
+        assert_eq!(add("-1bd", ns(2024, 5, 27, 10)), ns(2024, 5, 24, 10));
+        assert_eq!(add("7bd", ns(2024, 5, 22, 0)), ns(2024, 5, 31, 0));
+
+        assert_eq!(add("1me", ns(2024, 1, 15, 0)), ns(2024, 1, 31, 0));
+        assert_eq!(add("1me", ns(2024, 1, 31, 0)), ns(2024, 2, 29, 0));
+        assert_eq!(add("-1me", ns(2024, 1, 15, 0)), ns(2023, 12, 31, 0));
+        // 2024-03-31 is a Sunday
+        assert_eq!(add("1bme", ns(2024, 3, 30, 0)), ns(2024, 4, 30, 0));
+        assert_eq!(add("-1bme", ns(2024, 3, 30, 0)), ns(2024, 3, 29, 0));
+        assert_eq!(add("2qe", ns(2024, 2, 1, 0)), ns(2024, 6, 30, 0));
+        assert_eq!(add("1fyapr", ns(2024, 2, 1, 0)), ns(2024, 4, 1, 0));
+        assert_eq!(add("1fyapr", ns(2024, 4, 1, 0)), ns(2025, 4, 1, 0));
+    }
+
+    #[test]
+    fn test_truncate_calendar_units() {
+        let ns = |year, month, day, hour| {
+            NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp_nanos_opt()
+                .unwrap()
+        };
+        let truncate = |duration, t| Duration::parse(duration).truncate_ns(t, None).unwrap();
+
+        // weekends belong to the preceding Friday
+        assert_eq!(truncate("1bd", ns(2024, 5, 26, 10)), ns(2024, 5, 24, 0));
+        assert_eq!(truncate("1bd", ns(2024, 5, 27, 10)), ns(2024, 5, 27, 0));
+        assert_eq!(truncate("5bd", ns(2024, 5, 23, 10)), ns(2024, 5, 20, 0));
+
+        assert_eq!(truncate("1qe", ns(2024, 5, 10, 10)), ns(2024, 3, 31, 0));
+        assert_eq!(truncate("1qe", ns(2024, 6, 30, 10)), ns(2024, 6, 30, 0));
+        assert_eq!(truncate("1bme", ns(2024, 3, 30, 10)), ns(2024, 3, 29, 0));
+        assert_eq!(truncate("1fyapr", ns(2024, 3, 31, 10)), ns(2023, 4, 1, 0));
+        assert_eq!(truncate("1fyapr", ns(2024, 4, 1, 10)), ns(2024, 4, 1, 0));
+    }
+
     #[test]
     fn test_display() {
         let duration = Duration::parse("1h");
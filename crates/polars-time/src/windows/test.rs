@@ -77,6 +77,84 @@ fn test_feb_date_range() {
     assert_eq!(dates, expected);
 }
 
+#[test]
+fn test_anchored_date_range() {
+    let ns = |year, month, day| {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap()
+    };
+    let range = |start, end, interval| {
+        datetime_range_i64(
+            start,
+            end,
+            Duration::parse(interval),
+            ClosedWindow::Both,
+            TimeUnit::Nanoseconds,
+            None,
+        )
+        .unwrap() // unwrapping as we pass None as the time zone
+    };
+
+    // the start date is rolled forward to the first quarter end
+    let dates = range(ns(2024, 1, 15), ns(2024, 12, 31), "1qe");
+    let expected = [
+        ns(2024, 3, 31),
+        ns(2024, 6, 30),
+        ns(2024, 9, 30),
+        ns(2024, 12, 31),
+    ];
+    assert_eq!(dates, expected);
+
+    let dates = range(ns(2024, 3, 1), ns(2024, 6, 30), "1bme");
+    let expected = [
+        ns(2024, 3, 29),
+        ns(2024, 4, 30),
+        ns(2024, 5, 31),
+        ns(2024, 6, 28),
+    ];
+    assert_eq!(dates, expected);
+
+    // Friday to Tuesday
+    let dates = range(ns(2024, 5, 24), ns(2024, 5, 28), "1bd");
+    let expected = [ns(2024, 5, 24), ns(2024, 5, 27), ns(2024, 5, 28)];
+    assert_eq!(dates, expected);
+
+    let dates = range(ns(2023, 1, 1), ns(2024, 12, 31), "1fyapr");
+    let expected = [ns(2023, 4, 1), ns(2024, 4, 1)];
+    assert_eq!(dates, expected);
+}
+
+#[test]
+fn test_fiscal_year_window() {
+    let t = NaiveDate::from_ymd_opt(2024, 2, 10)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_nanos_opt()
+        .unwrap();
+    let every = Duration::parse("1fyapr");
+    let w = Window::new(every, every, Duration::new(0));
+    let b = w
+        .get_earliest_bounds_ns(t, ClosedWindow::Left, None)
+        .unwrap();
+    let start = NaiveDate::from_ymd_opt(2023, 4, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let stop = NaiveDate::from_ymd_opt(2024, 4, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    assert_eq!(timestamp_ns_to_datetime(b.start), start);
+    assert_eq!(timestamp_ns_to_datetime(b.stop), stop);
+}
+
 fn print_ns(ts: &[i64]) {
     for ts in ts {
         println!("{}", timestamp_ns_to_datetime(*ts));
@@ -302,12 +302,16 @@
 //! | Datetime                | dtype-datetime    |
 //! | Time                    | dtype-time        |
 //! | Duration                | dtype-duration    |
+//! | Interval                | dtype-interval    |
 //! | Int8                    | dtype-i8          |
 //! | Int16                   | dtype-i16         |
 //! | UInt8                   | dtype-u8          |
 //! | UInt16                  | dtype-u16         |
+//! | Float16                 | dtype-f16         |
 //! | Categorical             | dtype-categorical |
 //! | Struct                  | dtype-struct      |
+//! | Map                     | dtype-map         |
+//! | Union                   | dtype-union       |
 //!
 //!
 //! Or you can choose on of the preconfigured pre-sets.
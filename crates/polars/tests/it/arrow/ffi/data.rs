@@ -1,5 +1,5 @@
 use arrow::array::*;
-use arrow::datatypes::Field;
+use arrow::datatypes::{ArrowDataType, Field, UnionMode};
 use arrow::ffi;
 use polars_error::PolarsResult;
 
@@ -51,3 +51,33 @@ fn binview_nullable_buffered() -> PolarsResult<()> {
     ]);
     test_round_trip(data)
 }
+
+fn union_fields() -> Vec<Field> {
+    vec![
+        Field::new("a".into(), ArrowDataType::Int32, true),
+        Field::new("b".into(), ArrowDataType::Utf8View, true),
+    ]
+}
+
+#[test]
+fn union_sparse() -> PolarsResult<()> {
+    let dtype = ArrowDataType::Union(union_fields(), Some(vec![0, 1]), UnionMode::Sparse);
+    let fields = vec![
+        Int32Array::from(&[Some(1), None, Some(2), Some(3)]).boxed(),
+        Utf8ViewArray::from_slice([Some("a"), Some("b"), Some("c"), None]).boxed(),
+    ];
+    let data = UnionArray::new(dtype, vec![0, 1, 1, 0].into(), fields, None);
+    test_round_trip(data)
+}
+
+#[test]
+fn union_dense() -> PolarsResult<()> {
+    let dtype = ArrowDataType::Union(union_fields(), Some(vec![0, 1]), UnionMode::Dense);
+    let fields = vec![
+        Int32Array::from(&[Some(1), None]).boxed(),
+        Utf8ViewArray::from_slice([Some("b"), Some("c")]).boxed(),
+    ];
+    let offsets = Some(vec![0, 0, 1, 1].into());
+    let data = UnionArray::new(dtype, vec![0, 1, 1, 0].into(), fields, offsets);
+    test_round_trip(data)
+}
@@ -2,7 +2,7 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow::array::*;
-use arrow::datatypes::{ArrowSchema, ArrowSchemaRef, Field};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ArrowSchemaRef, Field, UnionMode};
 use arrow::io::ipc::read::{read_file_metadata, FileReader};
 use arrow::io::ipc::write::*;
 use arrow::io::ipc::IpcField;
@@ -83,3 +83,21 @@ fn write_binview() -> PolarsResult<()> {
     let columns = RecordBatchT::try_new(array.len(), vec![array])?;
     round_trip(columns, schema, None, Some(Compression::ZSTD))
 }
+
+#[test]
+fn write_union_binview() -> PolarsResult<()> {
+    let fields = vec![
+        Field::new("a".into(), ArrowDataType::Int32, true),
+        Field::new("b".into(), ArrowDataType::Utf8View, true),
+    ];
+    let dtype = ArrowDataType::Union(fields, None, UnionMode::Dense);
+    let values = vec![
+        Int32Array::from([Some(1), None]).boxed(),
+        Utf8ViewArray::from_slice([Some("foo"), Some("a string that is not inlined")]).boxed(),
+    ];
+    let offsets = Some(vec![0, 0, 1, 1].into());
+    let array = UnionArray::new(dtype, vec![0, 1, 1, 0].into(), values, offsets).boxed();
+    let schema = prep_schema(array.as_ref());
+    let columns = RecordBatchT::try_new(array.len(), vec![array])?;
+    round_trip(columns, schema, None, None)
+}
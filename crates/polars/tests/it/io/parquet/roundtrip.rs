@@ -1,8 +1,12 @@
 use std::io::Cursor;
 
-use arrow::array::{ArrayRef, Utf8ViewArray};
-use arrow::datatypes::{ArrowSchema, Field};
+use arrow::array::{ArrayRef, ListArray, MapArray, PrimitiveArray, StructArray, Utf8ViewArray};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit};
+use arrow::offset::OffsetsBuffer;
 use arrow::record_batch::RecordBatchT;
+use arrow::types::{f16, i256, months_days_ns};
+use ethnum::I256;
 use polars_error::PolarsResult;
 use polars_parquet::arrow::write::{FileWriter, WriteOptions};
 use polars_parquet::read::read_metadata;
@@ -79,3 +83,106 @@ fn roundtrip_binview() -> PolarsResult<()> {
         vec![Encoding::Plain],
     )
 }
+
+#[test]
+fn roundtrip_month_day_nano_interval() -> PolarsResult<()> {
+    let array = PrimitiveArray::from([
+        Some(months_days_ns::new(1, 2, 3)),
+        None,
+        Some(months_days_ns::new(-1, 0, i64::MIN)),
+    ])
+    .to(ArrowDataType::Interval(IntervalUnit::MonthDayNano));
+
+    round_trip(
+        &array.boxed(),
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::Plain],
+    )
+}
+
+#[test]
+fn roundtrip_decimal256() -> PolarsResult<()> {
+    // 76 nines, the largest value that fits in precision 76.
+    let max = I256::new(10).pow(76) - 1;
+    let array = PrimitiveArray::from([
+        Some(i256(max)),
+        None,
+        Some(i256(-max)),
+        Some(i256(I256::new(12345))),
+    ])
+    .to(ArrowDataType::Decimal256(76, 4));
+
+    round_trip(
+        &array.boxed(),
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::Plain],
+    )
+}
+
+#[test]
+fn roundtrip_float16() -> PolarsResult<()> {
+    let values = PrimitiveArray::from([
+        Some(f16::from_f32(0.1)),
+        None,
+        Some(f16::from_f32(-2.5)),
+        Some(f16::from_f32(65504.0)),
+    ]);
+
+    round_trip(
+        &values.clone().boxed(),
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::Plain],
+    )?;
+
+    let dtype = ListArray::<i64>::default_datatype(ArrowDataType::Float16);
+    let array = ListArray::<i64>::new(
+        dtype,
+        OffsetsBuffer::try_from(vec![0, 2, 2, 4]).unwrap(),
+        values.boxed(),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    round_trip(
+        &array.boxed(),
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::Plain],
+    )
+}
+
+#[test]
+fn roundtrip_map() -> PolarsResult<()> {
+    let entries_dtype = ArrowDataType::Struct(vec![
+        Field::new("key".into(), ArrowDataType::Utf8View, false),
+        Field::new("value".into(), ArrowDataType::Int32, true),
+    ]);
+    let entries = StructArray::new(
+        entries_dtype.clone(),
+        3,
+        vec![
+            Utf8ViewArray::from_slice_values(["a", "b", "a"]).boxed(),
+            PrimitiveArray::from([Some(1), None, Some(3)]).boxed(),
+        ],
+        None,
+    );
+    let dtype = ArrowDataType::Map(
+        Box::new(Field::new("entries".into(), entries_dtype, false)),
+        false,
+    );
+    let array = MapArray::new(
+        dtype,
+        OffsetsBuffer::try_from(vec![0, 2, 2, 3]).unwrap(),
+        entries.boxed(),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    round_trip(
+        &array.boxed(),
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::Plain, Encoding::Plain],
+    )
+}
@@ -48,13 +48,16 @@ from polars.datatypes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
     Int16,
     Int32,
     Int64,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -232,13 +235,16 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
     "Int16",
     "Int32",
     "Int64",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
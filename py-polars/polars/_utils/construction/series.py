@@ -31,6 +31,7 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Float16,
     List,
     Null,
     Object,
@@ -144,6 +145,7 @@ def sequence_to_pyseries(
             Boolean,
             Enum,
             Decimal,
+            Float16,
         ):
             if pyseries.dtype() != dtype:
                 pyseries = pyseries.cast(dtype, strict=strict, wrap_numerical=False)
@@ -451,11 +453,16 @@ def numpy_to_pyseries(
     values = np.ascontiguousarray(values)
 
     if values.ndim == 1:
+        is_float16 = values.dtype == np.float16
         values, dtype = numpy_values_and_dtype(values)
         constructor = numpy_type_to_constructor(values, dtype)
-        return constructor(
+        pys = constructor(
             name, values, nan_to_null if dtype in (np.float32, np.float64) else strict
         )
+        if is_float16:
+            # float16 is widened to float32 on construction; narrowing back is exact.
+            pys = pys.cast(Float16, strict=True, wrap_numerical=False)
+        return pys
     else:
         original_shape = values.shape
         values_1d = values.reshape(-1)
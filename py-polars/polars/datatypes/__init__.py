@@ -12,6 +12,7 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -19,7 +20,9 @@ from polars.datatypes.classes import (
     Int32,
     Int64,
     IntegerType,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -68,6 +71,7 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int16",
@@ -75,7 +79,9 @@ __all__ = [
     "Int64",
     "Int8",
     "IntegerType",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
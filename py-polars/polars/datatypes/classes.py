@@ -295,6 +295,22 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class Float16(FloatType):
+    """
+    16-bit floating point type.
+
+    Values are stored as the nearest half-precision float. Aggregations such as
+    `sum` and `mean` are computed with 32-bit precision and return `Float32`.
+
+    Notes
+    -----
+    In memory the values are held as 32-bit floats, so a `Float16` column takes as
+    much memory as a `Float32` one; only Parquet and IPC files store them with half
+    precision. Half-precision columns of files that were not written by Polars as
+    `Float16` are read as `Float32`.
+    """
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -505,6 +521,23 @@ class Duration(TemporalType):
         return f"{class_name}(time_unit={self.time_unit!r})"
 
 
+class Interval(TemporalType):
+    """
+    Data type representing a calendar interval.
+
+    Unlike a :class:`Duration`, an interval does not have a fixed length: adding
+    one month or one day to a date or datetime respects the calendar and the time
+    zone of the datetime.
+
+    Notes
+    -----
+    An interval consists of a number of months, a number of days and a number
+    of nanoseconds, like the Arrow `MonthDayNano` interval type. Each component
+    can be negative. Values are represented as `(months, days, nanoseconds)`
+    tuples in Python.
+    """
+
+
 class Categorical(DataType):
     """
     A categorical encoding of a set of strings.
@@ -791,6 +824,57 @@ class Array(NestedType):
         return self.size
 
 
+class Map(NestedType):
+    """
+    Map type, a list of key/value entries per row.
+
+    Map columns are physically stored as a list of structs with a `key` and a
+    `value` field, and are read from and written to Parquet and IPC as maps.
+
+    Parameters
+    ----------
+    key
+        The `DataType` of the keys.
+    value
+        The `DataType` of the values.
+
+    Examples
+    --------
+    >>> s = pl.Series(
+    ...     "a", [[{"key": "x", "value": 1}], [{"key": "y", "value": 2}]]
+    ... ).cast(pl.Map(pl.String, pl.Int64))
+    >>> s.dtype
+    Map(String, Int64)
+    """
+
+    key: PolarsDataType
+    value: PolarsDataType
+
+    def __init__(
+        self,
+        key: PolarsDataType | PythonDataType,
+        value: PolarsDataType | PythonDataType,
+    ) -> None:
+        self.key = polars.datatypes.parse_into_dtype(key)
+        self.value = polars.datatypes.parse_into_dtype(value)
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, Map):
+            return True
+        elif isinstance(other, Map):
+            return self.key == other.key and self.value == other.value
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.key, self.value))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}({self.key!r}, {self.value!r})"
+
+
 class Field:
     """
     Definition of a single field within a `Struct` DataType.
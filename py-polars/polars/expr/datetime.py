@@ -2040,6 +2040,7 @@ class ExprDateTimeNameSpace:
             - 1m    (1 minute)
             - 1h    (1 hour)
             - 1d    (1 calendar day)
+            - 1bd   (1 business day, Monday to Friday)
             - 1w    (1 calendar week)
             - 1mo   (1 calendar month)
             - 1q    (1 calendar quarter)
@@ -2049,6 +2050,16 @@ class ExprDateTimeNameSpace:
             not be 24 hours, due to daylight savings). Similarly for "calendar week",
             "calendar month", "calendar quarter", and "calendar year".
 
+            Anchored units step between the ends (or starts) of calendar periods, and
+            can't be combined with other units:
+
+            - 1me   (1 month end)
+            - 1bme  (1 business month end)
+            - 1qe   (1 quarter end)
+            - 1bqe  (1 business quarter end)
+            - 1fy   (1 fiscal year start; append a month to start fiscal years in
+              that month, e.g. "1fyapr")
+
         Returns
         -------
         Expr
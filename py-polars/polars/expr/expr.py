@@ -50,6 +50,7 @@ from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
 from polars.expr.datetime import ExprDateTimeNameSpace
 from polars.expr.list import ExprListNameSpace
+from polars.expr.map import ExprMapNameSpace
 from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
 from polars.expr.string import ExprStringNameSpace
@@ -110,6 +111,7 @@ class Expr:
         "cat",
         "dt",
         "list",
+        "map",
         "meta",
         "name",
         "str",
@@ -5340,7 +5342,11 @@ class Expr:
         other = parse_into_expression(other, str_as_lit=True)
         return self._from_pyexpr(self._pyexpr.neq_missing(other))
 
-    def add(self, other: Any) -> Expr:
+    def _decimal_arithmetic_non_strict(self, other: Any, op: str) -> Expr:
+        other = parse_into_expression(other)
+        return self._from_pyexpr(self._pyexpr.decimal_arithmetic(other, op, False))
+
+    def add(self, other: Any, *, strict: bool = True) -> Expr:
         """
         Method equivalent of addition operator `expr + other`.
 
@@ -5348,6 +5354,10 @@ class Expr:
         ----------
         other
             numeric or string value; accepts expression input.
+        strict
+            Only applies to decimals. If `True`, a result that doesn't fit in a
+            decimal raises an error, otherwise it becomes null. If `False`, both
+            operands must be decimals or integers.
 
         Examples
         --------
@@ -5384,6 +5394,8 @@ class Expr:
         │ g   ┆ h   ┆ i   ┆ ghi │
         └─────┴─────┴─────┴─────┘
         """
+        if not strict:
+            return self._decimal_arithmetic_non_strict(other, "add")
         return self.__add__(other)
 
     def floordiv(self, other: Any) -> Expr:
@@ -5501,7 +5513,7 @@ class Expr:
         """
         return self.__mod__(other)
 
-    def mul(self, other: Any) -> Expr:
+    def mul(self, other: Any, *, strict: bool = True) -> Expr:
         """
         Method equivalent of multiplication operator `expr * other`.
 
@@ -5509,6 +5521,10 @@ class Expr:
         ----------
         other
             Numeric literal or expression value.
+        strict
+            Only applies to decimals. If `True`, a result that doesn't fit in a
+            decimal raises an error, otherwise it becomes null. If `False`, both
+            operands must be decimals or integers.
 
         Examples
         --------
@@ -5530,9 +5546,11 @@ class Expr:
         │ 16  ┆ 32  ┆ 64.0      │
         └─────┴─────┴───────────┘
         """
+        if not strict:
+            return self._decimal_arithmetic_non_strict(other, "mul")
         return self.__mul__(other)
 
-    def sub(self, other: Any) -> Expr:
+    def sub(self, other: Any, *, strict: bool = True) -> Expr:
         """
         Method equivalent of subtraction operator `expr - other`.
 
@@ -5540,6 +5558,10 @@ class Expr:
         ----------
         other
             Numeric literal or expression value.
+        strict
+            Only applies to decimals. If `True`, a result that doesn't fit in a
+            decimal raises an error, otherwise it becomes null. If `False`, both
+            operands must be decimals or integers.
 
         Examples
         --------
@@ -5561,6 +5583,8 @@ class Expr:
         │ 4   ┆ 2   ┆ -6     │
         └─────┴─────┴────────┘
         """
+        if not strict:
+            return self._decimal_arithmetic_non_strict(other, "sub")
         return self.__sub__(other)
 
     def neg(self) -> Expr:
@@ -5585,7 +5609,7 @@ class Expr:
         """
         return self.__neg__()
 
-    def truediv(self, other: Any) -> Expr:
+    def truediv(self, other: Any, *, strict: bool = True) -> Expr:
         """
         Method equivalent of float division operator `expr / other`.
 
@@ -5593,6 +5617,10 @@ class Expr:
         ----------
         other
             Numeric literal or expression value.
+        strict
+            Only applies to decimals. If `True`, a result that doesn't fit in a
+            decimal raises an error, otherwise it becomes null. If `False`, both
+            operands must be decimals or integers.
 
         Notes
         -----
@@ -5627,6 +5655,8 @@ class Expr:
         │ 2   ┆ -0.5 ┆ 1.0  ┆ -4.0  │
         └─────┴──────┴──────┴───────┘
         """
+        if not strict:
+            return self._decimal_arithmetic_non_strict(other, "div")
         return self.__truediv__(other)
 
     def pow(self, exponent: IntoExprColumn | int | float) -> Expr:
@@ -7322,238 +7352,1305 @@ class Expr:
         )
 
     @unstable()
-    def rolling_min(
+    def rolling_kurtosis_by(
         self,
-        window_size: int,
-        weights: list[float] | None = None,
+        by: IntoExpr,
+        window_size: timedelta | str,
         *,
-        min_periods: int | None = None,
-        center: bool = False,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+        fisher: bool = True,
+        bias: bool = True,
     ) -> Expr:
         """
-        Apply a rolling min (moving min) over the values in this array.
+        Compute a rolling kurtosis based on another column.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their min.
+        Kurtosis is the fourth central moment divided by the square of the
+        variance. If Fisher's definition is used, then 3.0 is subtracted from
+        the result to give 0.0 for a normal distribution.
 
-        The window at a given row will include the row itself, and the `window_size - 1`
-        elements before it.
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
 
         Parameters
         ----------
         window_size
-            The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
         min_periods
             The number of values in the window that should be non-null before computing
-            a result. If set to `None` (default), it will be set equal to `window_size`.
-        center
-            Set the labels at the center of the window.
-
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
+            a result.
+        by
+            This column must of dtype `{Date, Datetime}`
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_min=pl.col("A").rolling_min(window_size=2),
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 6), "1d", eager=True
+        ...         ),
+        ...         "a": [1, 2, 3, 4, 10, 1],
+        ...     }
         ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_min │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.0         │
-        │ 3.0 ┆ 2.0         │
-        │ 4.0 ┆ 3.0         │
-        │ 5.0 ┆ 4.0         │
-        │ 6.0 ┆ 5.0         │
-        └─────┴─────────────┘
-
-        Specify weights to multiply the values in the window with:
-
         >>> df.with_columns(
-        ...     rolling_min=pl.col("A").rolling_min(
-        ...         window_size=2, weights=[0.25, 0.75]
+        ...     rolling_kurtosis=pl.col("a").rolling_kurtosis_by(
+        ...         "date", window_size="4d"
         ...     ),
         ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_min │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.25        │
-        │ 3.0 ┆ 0.5         │
-        │ 4.0 ┆ 0.75        │
-        │ 5.0 ┆ 1.0         │
-        │ 6.0 ┆ 1.25        │
-        └─────┴─────────────┘
-
-        Center the values in the window
-
-        >>> df.with_columns(
-        ...     rolling_min=pl.col("A").rolling_min(window_size=3, center=True),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_min │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.0         │
-        │ 3.0 ┆ 2.0         │
-        │ 4.0 ┆ 3.0         │
-        │ 5.0 ┆ 4.0         │
-        │ 6.0 ┆ null        │
-        └─────┴─────────────┘
+        shape: (6, 3)
+        ┌────────────┬─────┬──────────────────┐
+        │ date       ┆ a   ┆ rolling_kurtosis │
+        │ ---        ┆ --- ┆ ---              │
+        │ date       ┆ i64 ┆ f64              │
+        ╞════════════╪═════╪══════════════════╡
+        │ 2001-01-01 ┆ 1   ┆ NaN              │
+        │ 2001-01-02 ┆ 2   ┆ -2.0             │
+        │ 2001-01-03 ┆ 3   ┆ -1.5             │
+        │ 2001-01-04 ┆ 4   ┆ -1.36            │
+        │ 2001-01-05 ┆ 10  ┆ -0.798085        │
+        │ 2001-01-06 ┆ 1   ┆ -0.885926        │
+        └────────────┴─────┴──────────────────┘
         """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
         return self._from_pyexpr(
-            self._pyexpr.rolling_min(
+            self._pyexpr.rolling_kurtosis_by(
+                by,
                 window_size,
-                weights,
                 min_periods,
-                center=center,
+                closed,
+                fisher,
+                bias,
             )
         )
 
     @unstable()
-    def rolling_max(
+    def rolling_product_by(
         self,
-        window_size: int,
-        weights: list[float] | None = None,
+        by: IntoExpr,
+        window_size: timedelta | str,
         *,
-        min_periods: int | None = None,
-        center: bool = False,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
     ) -> Expr:
         """
-        Apply a rolling max (moving max) over the values in this array.
+        Apply a rolling product based on another column.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their max.
+        Boolean and integer types smaller than 64 bits are cast to Int64 before
+        computing the product, like in :meth:`product`.
 
-        The window at a given row will include the row itself, and the `window_size - 1`
-        elements before it.
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
 
         Parameters
         ----------
         window_size
-            The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
         min_periods
             The number of values in the window that should be non-null before computing
-            a result. If set to `None` (default), it will be set equal to `window_size`.
-        center
-            Set the labels at the center of the window.
+            a result.
+        by
+            This column must of dtype `{Date, Datetime}`
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_max=pl.col("A").rolling_max(window_size=2),
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 6), "1d", eager=True
+        ...         ),
+        ...         "a": [1, 2, 3, 4, 5, 6],
+        ...     }
         ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_max │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 2.0         │
-        │ 3.0 ┆ 3.0         │
-        │ 4.0 ┆ 4.0         │
-        │ 5.0 ┆ 5.0         │
-        │ 6.0 ┆ 6.0         │
-        └─────┴─────────────┘
-
-        Specify weights to multiply the values in the window with:
-
         >>> df.with_columns(
-        ...     rolling_max=pl.col("A").rolling_max(
-        ...         window_size=2, weights=[0.25, 0.75]
+        ...     rolling_product=pl.col("a").rolling_product_by(
+        ...         "date", window_size="3d"
         ...     ),
         ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_max │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.5         │
-        │ 3.0 ┆ 2.25        │
-        │ 4.0 ┆ 3.0         │
-        │ 5.0 ┆ 3.75        │
-        │ 6.0 ┆ 4.5         │
-        └─────┴─────────────┘
-
-        Center the values in the window
+        shape: (6, 3)
+        ┌────────────┬─────┬─────────────────┐
+        │ date       ┆ a   ┆ rolling_product │
+        │ ---        ┆ --- ┆ ---             │
+        │ date       ┆ i64 ┆ i64             │
+        ╞════════════╪═════╪═════════════════╡
+        │ 2001-01-01 ┆ 1   ┆ 1               │
+        │ 2001-01-02 ┆ 2   ┆ 2               │
+        │ 2001-01-03 ┆ 3   ┆ 6               │
+        │ 2001-01-04 ┆ 4   ┆ 24              │
+        │ 2001-01-05 ┆ 5   ┆ 60              │
+        │ 2001-01-06 ┆ 6   ┆ 120             │
+        └────────────┴─────┴─────────────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_product_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+            )
+        )
 
-        >>> df.with_columns(
-        ...     rolling_max=pl.col("A").rolling_max(window_size=3, center=True),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
+    @unstable()
+    def rolling_rank_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Compute a rolling rank based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The rank is expressed as a fraction of the number of non-null values
+        in the window, so that the largest value in the window gets a rank of
+        1.0. Ties are assigned the average of the ranks they span.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        by
+            This column must of dtype `{Date, Datetime}`
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 6), "1d", eager=True
+        ...         ),
+        ...         "a": [1, 4, 2, 2, 5, 0],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling_rank=pl.col("a").rolling_rank_by(
+        ...         "date", window_size="3d"
+        ...     ),
+        ... )
+        shape: (6, 3)
+        ┌────────────┬─────┬──────────────┐
+        │ date       ┆ a   ┆ rolling_rank │
+        │ ---        ┆ --- ┆ ---          │
+        │ date       ┆ i64 ┆ f64          │
+        ╞════════════╪═════╪══════════════╡
+        │ 2001-01-01 ┆ 1   ┆ 1.0          │
+        │ 2001-01-02 ┆ 4   ┆ 1.0          │
+        │ 2001-01-03 ┆ 2   ┆ 0.666667     │
+        │ 2001-01-04 ┆ 2   ┆ 0.5          │
+        │ 2001-01-05 ┆ 5   ┆ 1.0          │
+        │ 2001-01-06 ┆ 0   ┆ 0.333333     │
+        └────────────┴─────┴──────────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_rank_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+            )
+        )
+
+    @unstable()
+    def rolling_n_unique_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Count the unique values in a rolling window based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Null is counted as a distinct value.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        by
+            This column must of dtype `{Date, Datetime}`
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 6), "1d", eager=True
+        ...         ),
+        ...         "a": [1, 1, 2, 1, 3, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling_n_unique=pl.col("a").rolling_n_unique_by(
+        ...         "date", window_size="3d"
+        ...     ),
+        ... )
+        shape: (6, 3)
+        ┌────────────┬─────┬──────────────────┐
+        │ date       ┆ a   ┆ rolling_n_unique │
+        │ ---        ┆ --- ┆ ---              │
+        │ date       ┆ i64 ┆ u32              │
+        ╞════════════╪═════╪══════════════════╡
+        │ 2001-01-01 ┆ 1   ┆ 1                │
+        │ 2001-01-02 ┆ 1   ┆ 1                │
+        │ 2001-01-03 ┆ 2   ┆ 2                │
+        │ 2001-01-04 ┆ 1   ┆ 2                │
+        │ 2001-01-05 ┆ 3   ┆ 3                │
+        │ 2001-01-06 ┆ 3   ┆ 2                │
+        └────────────┴─────┴──────────────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_n_unique_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+            )
+        )
+
+    @unstable()
+    def rolling_arg_min_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Get the index of the rolling minimum based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The index is relative to the start of the window. If the minimum occurs
+        multiple times, the index of the first occurrence is returned.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        by
+            This column must of dtype `{Date, Datetime}`
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 6), "1d", eager=True
+        ...         ),
+        ...         "a": [4, 2, 6, 5, 1, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling_arg_min=pl.col("a").rolling_arg_min_by(
+        ...         "date", window_size="3d"
+        ...     ),
+        ... )
+        shape: (6, 3)
+        ┌────────────┬─────┬─────────────────┐
+        │ date       ┆ a   ┆ rolling_arg_min │
+        │ ---        ┆ --- ┆ ---             │
+        │ date       ┆ i64 ┆ u32             │
+        ╞════════════╪═════╪═════════════════╡
+        │ 2001-01-01 ┆ 4   ┆ 0               │
+        │ 2001-01-02 ┆ 2   ┆ 1               │
+        │ 2001-01-03 ┆ 6   ┆ 1               │
+        │ 2001-01-04 ┆ 5   ┆ 0               │
+        │ 2001-01-05 ┆ 1   ┆ 2               │
+        │ 2001-01-06 ┆ 3   ┆ 1               │
+        └────────────┴─────┴─────────────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_arg_min_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+            )
+        )
+
+    @unstable()
+    def rolling_arg_max_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_periods: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Get the index of the rolling maximum based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The index is relative to the start of the window. If the maximum occurs
+        multiple times, the index of the first occurrence is returned.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result.
+        by
+            This column must of dtype `{Date, Datetime}`
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": pl.date_range(
+        ...             date(2001, 1, 1), date(2001, 1, 6), "1d", eager=True
+        ...         ),
+        ...         "a": [4, 2, 6, 5, 1, 3],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rolling_arg_max=pl.col("a").rolling_arg_max_by(
+        ...         "date", window_size="3d"
+        ...     ),
+        ... )
+        shape: (6, 3)
+        ┌────────────┬─────┬─────────────────┐
+        │ date       ┆ a   ┆ rolling_arg_max │
+        │ ---        ┆ --- ┆ ---             │
+        │ date       ┆ i64 ┆ u32             │
+        ╞════════════╪═════╪═════════════════╡
+        │ 2001-01-01 ┆ 4   ┆ 0               │
+        │ 2001-01-02 ┆ 2   ┆ 0               │
+        │ 2001-01-03 ┆ 6   ┆ 2               │
+        │ 2001-01-04 ┆ 5   ┆ 1               │
+        │ 2001-01-05 ┆ 1   ┆ 0               │
+        │ 2001-01-06 ┆ 3   ┆ 0               │
+        └────────────┴─────┴─────────────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_arg_max_by(
+                by,
+                window_size,
+                min_periods,
+                closed,
+            )
+        )
+
+    @unstable()
+    def rolling_min(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling min (moving min) over the values in this array.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their min.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_min=pl.col("A").rolling_min(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_min │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.0         │
+        │ 3.0 ┆ 2.0         │
+        │ 4.0 ┆ 3.0         │
+        │ 5.0 ┆ 4.0         │
+        │ 6.0 ┆ 5.0         │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_min=pl.col("A").rolling_min(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_min │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.25        │
+        │ 3.0 ┆ 0.5         │
+        │ 4.0 ┆ 0.75        │
+        │ 5.0 ┆ 1.0         │
+        │ 6.0 ┆ 1.25        │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_min=pl.col("A").rolling_min(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_min │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.0         │
+        │ 3.0 ┆ 2.0         │
+        │ 4.0 ┆ 3.0         │
+        │ 5.0 ┆ 4.0         │
+        │ 6.0 ┆ null        │
+        └─────┴─────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_min(
+                window_size,
+                weights,
+                min_periods,
+                center=center,
+            )
+        )
+
+    @unstable()
+    def rolling_max(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling max (moving max) over the values in this array.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their max.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_max=pl.col("A").rolling_max(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_max │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 2.0         │
+        │ 3.0 ┆ 3.0         │
+        │ 4.0 ┆ 4.0         │
+        │ 5.0 ┆ 5.0         │
+        │ 6.0 ┆ 6.0         │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_max=pl.col("A").rolling_max(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_max │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.5         │
+        │ 3.0 ┆ 2.25        │
+        │ 4.0 ┆ 3.0         │
+        │ 5.0 ┆ 3.75        │
+        │ 6.0 ┆ 4.5         │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_max=pl.col("A").rolling_max(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
         │ A   ┆ rolling_max │
         │ --- ┆ ---         │
         │ f64 ┆ f64         │
         ╞═════╪═════════════╡
         │ 1.0 ┆ null        │
-        │ 2.0 ┆ 3.0         │
-        │ 3.0 ┆ 4.0         │
-        │ 4.0 ┆ 5.0         │
-        │ 5.0 ┆ 6.0         │
+        │ 2.0 ┆ 3.0         │
+        │ 3.0 ┆ 4.0         │
+        │ 4.0 ┆ 5.0         │
+        │ 5.0 ┆ 6.0         │
+        │ 6.0 ┆ null        │
+        └─────┴─────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_max(
+                window_size,
+                weights,
+                min_periods,
+                center,
+            )
+        )
+
+    @unstable()
+    def rolling_mean(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling mean (moving mean) over the values in this array.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their mean.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_mean=pl.col("A").rolling_mean(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬──────────────┐
+        │ A   ┆ rolling_mean │
+        │ --- ┆ ---          │
+        │ f64 ┆ f64          │
+        ╞═════╪══════════════╡
+        │ 1.0 ┆ null         │
+        │ 2.0 ┆ 1.5          │
+        │ 3.0 ┆ 2.5          │
+        │ 4.0 ┆ 3.5          │
+        │ 5.0 ┆ 4.5          │
+        │ 6.0 ┆ 5.5          │
+        └─────┴──────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_mean=pl.col("A").rolling_mean(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬──────────────┐
+        │ A   ┆ rolling_mean │
+        │ --- ┆ ---          │
+        │ f64 ┆ f64          │
+        ╞═════╪══════════════╡
+        │ 1.0 ┆ null         │
+        │ 2.0 ┆ 1.75         │
+        │ 3.0 ┆ 2.75         │
+        │ 4.0 ┆ 3.75         │
+        │ 5.0 ┆ 4.75         │
+        │ 6.0 ┆ 5.75         │
+        └─────┴──────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_mean=pl.col("A").rolling_mean(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬──────────────┐
+        │ A   ┆ rolling_mean │
+        │ --- ┆ ---          │
+        │ f64 ┆ f64          │
+        ╞═════╪══════════════╡
+        │ 1.0 ┆ null         │
+        │ 2.0 ┆ 2.0          │
+        │ 3.0 ┆ 3.0          │
+        │ 4.0 ┆ 4.0          │
+        │ 5.0 ┆ 5.0          │
+        │ 6.0 ┆ null         │
+        └─────┴──────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_mean(
+                window_size,
+                weights,
+                min_periods,
+                center,
+            )
+        )
+
+    @unstable()
+    def rolling_sum(
+        self,
+        window_size: int | timedelta,
+        weights: list[float] | None = None,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling sum (moving sum) over the values in this array.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their sum.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_sum=pl.col("A").rolling_sum(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_sum │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 3.0         │
+        │ 3.0 ┆ 5.0         │
+        │ 4.0 ┆ 7.0         │
+        │ 5.0 ┆ 9.0         │
+        │ 6.0 ┆ 11.0        │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_sum=pl.col("A").rolling_sum(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_sum │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.75        │
+        │ 3.0 ┆ 2.75        │
+        │ 4.0 ┆ 3.75        │
+        │ 5.0 ┆ 4.75        │
+        │ 6.0 ┆ 5.75        │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_sum=pl.col("A").rolling_sum(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_sum │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 6.0         │
+        │ 3.0 ┆ 9.0         │
+        │ 4.0 ┆ 12.0        │
+        │ 5.0 ┆ 15.0        │
+        │ 6.0 ┆ null        │
+        └─────┴─────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_sum(
+                window_size,
+                weights,
+                min_periods,
+                center,
+            )
+        )
+
+    @unstable()
+    def rolling_std(
+        self,
+        window_size: int | timedelta,
+        weights: list[float] | None = None,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> Expr:
+        """
+        Compute a rolling standard deviation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their std.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_std=pl.col("A").rolling_std(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_std │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.707107    │
+        │ 3.0 ┆ 0.707107    │
+        │ 4.0 ┆ 0.707107    │
+        │ 5.0 ┆ 0.707107    │
+        │ 6.0 ┆ 0.707107    │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_std=pl.col("A").rolling_std(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_std │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.433013    │
+        │ 3.0 ┆ 0.433013    │
+        │ 4.0 ┆ 0.433013    │
+        │ 5.0 ┆ 0.433013    │
+        │ 6.0 ┆ 0.433013    │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_std=pl.col("A").rolling_std(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_std │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.0         │
+        │ 3.0 ┆ 1.0         │
+        │ 4.0 ┆ 1.0         │
+        │ 5.0 ┆ 1.0         │
+        │ 6.0 ┆ null        │
+        └─────┴─────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_std(
+                window_size,
+                weights,
+                min_periods,
+                center=center,
+                ddof=ddof,
+            )
+        )
+
+    @unstable()
+    def rolling_var(
+        self,
+        window_size: int | timedelta,
+        weights: list[float] | None = None,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> Expr:
+        """
+        Compute a rolling variance.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their var.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_var=pl.col("A").rolling_var(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_var │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.5         │
+        │ 3.0 ┆ 0.5         │
+        │ 4.0 ┆ 0.5         │
+        │ 5.0 ┆ 0.5         │
+        │ 6.0 ┆ 0.5         │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_var=pl.col("A").rolling_var(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_var │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.1875      │
+        │ 3.0 ┆ 0.1875      │
+        │ 4.0 ┆ 0.1875      │
+        │ 5.0 ┆ 0.1875      │
+        │ 6.0 ┆ 0.1875      │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_var=pl.col("A").rolling_var(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_var │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.0         │
+        │ 3.0 ┆ 1.0         │
+        │ 4.0 ┆ 1.0         │
+        │ 5.0 ┆ 1.0         │
         │ 6.0 ┆ null        │
         └─────┴─────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_max(
+            self._pyexpr.rolling_var(
                 window_size,
                 weights,
                 min_periods,
-                center,
+                center=center,
+                ddof=ddof,
             )
         )
 
     @unstable()
-    def rolling_mean(
+    def rolling_median(
         self,
-        window_size: int,
+        window_size: int | timedelta,
         weights: list[float] | None = None,
         *,
         min_periods: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Apply a rolling mean (moving mean) over the values in this array.
+        Compute a rolling median.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
@@ -7561,7 +8658,7 @@ class Expr:
 
         A window of length `window_size` will traverse the array. The values that fill
         this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their mean.
+        `weights` vector. The resulting values will be aggregated to their median.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
@@ -7589,82 +8686,84 @@ class Expr:
         --------
         >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
         >>> df.with_columns(
-        ...     rolling_mean=pl.col("A").rolling_mean(window_size=2),
+        ...     rolling_median=pl.col("A").rolling_median(window_size=2),
         ... )
         shape: (6, 2)
-        ┌─────┬──────────────┐
-        │ A   ┆ rolling_mean │
-        │ --- ┆ ---          │
-        │ f64 ┆ f64          │
-        ╞═════╪══════════════╡
-        │ 1.0 ┆ null         │
-        │ 2.0 ┆ 1.5          │
-        │ 3.0 ┆ 2.5          │
-        │ 4.0 ┆ 3.5          │
-        │ 5.0 ┆ 4.5          │
-        │ 6.0 ┆ 5.5          │
-        └─────┴──────────────┘
+        ┌─────┬────────────────┐
+        │ A   ┆ rolling_median │
+        │ --- ┆ ---            │
+        │ f64 ┆ f64            │
+        ╞═════╪════════════════╡
+        │ 1.0 ┆ null           │
+        │ 2.0 ┆ 1.5            │
+        │ 3.0 ┆ 2.5            │
+        │ 4.0 ┆ 3.5            │
+        │ 5.0 ┆ 4.5            │
+        │ 6.0 ┆ 5.5            │
+        └─────┴────────────────┘
 
-        Specify weights to multiply the values in the window with:
+        Specify weights for the values in each window:
 
         >>> df.with_columns(
-        ...     rolling_mean=pl.col("A").rolling_mean(
+        ...     rolling_median=pl.col("A").rolling_median(
         ...         window_size=2, weights=[0.25, 0.75]
         ...     ),
         ... )
         shape: (6, 2)
-        ┌─────┬──────────────┐
-        │ A   ┆ rolling_mean │
-        │ --- ┆ ---          │
-        │ f64 ┆ f64          │
-        ╞═════╪══════════════╡
-        │ 1.0 ┆ null         │
-        │ 2.0 ┆ 1.75         │
-        │ 3.0 ┆ 2.75         │
-        │ 4.0 ┆ 3.75         │
-        │ 5.0 ┆ 4.75         │
-        │ 6.0 ┆ 5.75         │
-        └─────┴──────────────┘
+        ┌─────┬────────────────┐
+        │ A   ┆ rolling_median │
+        │ --- ┆ ---            │
+        │ f64 ┆ f64            │
+        ╞═════╪════════════════╡
+        │ 1.0 ┆ null           │
+        │ 2.0 ┆ 1.5            │
+        │ 3.0 ┆ 2.5            │
+        │ 4.0 ┆ 3.5            │
+        │ 5.0 ┆ 4.5            │
+        │ 6.0 ┆ 5.5            │
+        └─────┴────────────────┘
 
         Center the values in the window
 
         >>> df.with_columns(
-        ...     rolling_mean=pl.col("A").rolling_mean(window_size=3, center=True),
+        ...     rolling_median=pl.col("A").rolling_median(window_size=3, center=True),
         ... )
         shape: (6, 2)
-        ┌─────┬──────────────┐
-        │ A   ┆ rolling_mean │
-        │ --- ┆ ---          │
-        │ f64 ┆ f64          │
-        ╞═════╪══════════════╡
-        │ 1.0 ┆ null         │
-        │ 2.0 ┆ 2.0          │
-        │ 3.0 ┆ 3.0          │
-        │ 4.0 ┆ 4.0          │
-        │ 5.0 ┆ 5.0          │
-        │ 6.0 ┆ null         │
-        └─────┴──────────────┘
+        ┌─────┬────────────────┐
+        │ A   ┆ rolling_median │
+        │ --- ┆ ---            │
+        │ f64 ┆ f64            │
+        ╞═════╪════════════════╡
+        │ 1.0 ┆ null           │
+        │ 2.0 ┆ 2.0            │
+        │ 3.0 ┆ 3.0            │
+        │ 4.0 ┆ 4.0            │
+        │ 5.0 ┆ 5.0            │
+        │ 6.0 ┆ null           │
+        └─────┴────────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_mean(
+            self._pyexpr.rolling_median(
                 window_size,
                 weights,
                 min_periods,
-                center,
+                center=center,
             )
         )
 
     @unstable()
-    def rolling_sum(
+    def rolling_quantile(
         self,
-        window_size: int | timedelta,
+        quantile: float,
+        interpolation: RollingInterpolationMethod = "nearest",
+        window_size: int | timedelta = 2,
         weights: list[float] | None = None,
         *,
         min_periods: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Apply a rolling sum (moving sum) over the values in this array.
+        Compute a rolling quantile.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
@@ -7672,13 +8771,17 @@ class Expr:
 
         A window of length `window_size` will traverse the array. The values that fill
         this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their sum.
+        `weights` vector. The resulting values will be aggregated to their quantile.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
 
         Parameters
         ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear'}
+            Interpolation method.
         window_size
             The length of the window in number of elements.
         weights
@@ -7700,91 +8803,163 @@ class Expr:
         --------
         >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
         >>> df.with_columns(
-        ...     rolling_sum=pl.col("A").rolling_sum(window_size=2),
+        ...     rolling_quantile=pl.col("A").rolling_quantile(
+        ...         quantile=0.25, window_size=4
+        ...     ),
         ... )
         shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_sum │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 3.0         │
-        │ 3.0 ┆ 5.0         │
-        │ 4.0 ┆ 7.0         │
-        │ 5.0 ┆ 9.0         │
-        │ 6.0 ┆ 11.0        │
-        └─────┴─────────────┘
+        ┌─────┬──────────────────┐
+        │ A   ┆ rolling_quantile │
+        │ --- ┆ ---              │
+        │ f64 ┆ f64              │
+        ╞═════╪══════════════════╡
+        │ 1.0 ┆ null             │
+        │ 2.0 ┆ null             │
+        │ 3.0 ┆ null             │
+        │ 4.0 ┆ 2.0              │
+        │ 5.0 ┆ 3.0              │
+        │ 6.0 ┆ 4.0              │
+        └─────┴──────────────────┘
 
-        Specify weights to multiply the values in the window with:
+        Specify weights for the values in each window:
 
         >>> df.with_columns(
-        ...     rolling_sum=pl.col("A").rolling_sum(
-        ...         window_size=2, weights=[0.25, 0.75]
+        ...     rolling_quantile=pl.col("A").rolling_quantile(
+        ...         quantile=0.25, window_size=4, weights=[0.2, 0.4, 0.4, 0.2]
         ...     ),
         ... )
         shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_sum │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.75        │
-        │ 3.0 ┆ 2.75        │
-        │ 4.0 ┆ 3.75        │
-        │ 5.0 ┆ 4.75        │
-        │ 6.0 ┆ 5.75        │
-        └─────┴─────────────┘
+        ┌─────┬──────────────────┐
+        │ A   ┆ rolling_quantile │
+        │ --- ┆ ---              │
+        │ f64 ┆ f64              │
+        ╞═════╪══════════════════╡
+        │ 1.0 ┆ null             │
+        │ 2.0 ┆ null             │
+        │ 3.0 ┆ null             │
+        │ 4.0 ┆ 2.0              │
+        │ 5.0 ┆ 3.0              │
+        │ 6.0 ┆ 4.0              │
+        └─────┴──────────────────┘
+
+        Specify weights and interpolation method
+
+        >>> df.with_columns(
+        ...     rolling_quantile=pl.col("A").rolling_quantile(
+        ...         quantile=0.25,
+        ...         window_size=4,
+        ...         weights=[0.2, 0.4, 0.4, 0.2],
+        ...         interpolation="linear",
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬──────────────────┐
+        │ A   ┆ rolling_quantile │
+        │ --- ┆ ---              │
+        │ f64 ┆ f64              │
+        ╞═════╪══════════════════╡
+        │ 1.0 ┆ null             │
+        │ 2.0 ┆ null             │
+        │ 3.0 ┆ null             │
+        │ 4.0 ┆ 1.625            │
+        │ 5.0 ┆ 2.625            │
+        │ 6.0 ┆ 3.625            │
+        └─────┴──────────────────┘
 
         Center the values in the window
 
         >>> df.with_columns(
-        ...     rolling_sum=pl.col("A").rolling_sum(window_size=3, center=True),
+        ...     rolling_quantile=pl.col("A").rolling_quantile(
+        ...         quantile=0.2, window_size=5, center=True
+        ...     ),
         ... )
         shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_sum │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 6.0         │
-        │ 3.0 ┆ 9.0         │
-        │ 4.0 ┆ 12.0        │
-        │ 5.0 ┆ 15.0        │
-        │ 6.0 ┆ null        │
-        └─────┴─────────────┘
+        ┌─────┬──────────────────┐
+        │ A   ┆ rolling_quantile │
+        │ --- ┆ ---              │
+        │ f64 ┆ f64              │
+        ╞═════╪══════════════════╡
+        │ 1.0 ┆ null             │
+        │ 2.0 ┆ null             │
+        │ 3.0 ┆ 2.0              │
+        │ 4.0 ┆ 3.0              │
+        │ 5.0 ┆ null             │
+        │ 6.0 ┆ null             │
+        └─────┴──────────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_sum(
+            self._pyexpr.rolling_quantile(
+                quantile,
+                interpolation,
                 window_size,
                 weights,
                 min_periods,
-                center,
+                center=center,
             )
         )
 
     @unstable()
-    def rolling_std(
+    def rolling_skew(self, window_size: int, *, bias: bool = True) -> Expr:
+        """
+        Compute a rolling skew.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        bias
+            If False, the calculations are corrected for statistical bias.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").rolling_skew(3))
+        shape: (4, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ null     │
+        │ null     │
+        │ 0.381802 │
+        │ 0.47033  │
+        └──────────┘
+
+        Note how the values match the following:
+
+        >>> pl.Series([1, 4, 2]).skew(), pl.Series([4, 2, 9]).skew()
+        (0.38180177416060584, 0.47033046033698594)
+        """
+        return self._from_pyexpr(self._pyexpr.rolling_skew(window_size, bias))
+
+    @unstable()
+    def rolling_kurtosis(
         self,
-        window_size: int | timedelta,
-        weights: list[float] | None = None,
+        window_size: int,
         *,
         min_periods: int | None = None,
         center: bool = False,
-        ddof: int = 1,
+        fisher: bool = True,
+        bias: bool = True,
     ) -> Expr:
         """
-        Compute a rolling standard deviation.
+        Compute a rolling kurtosis.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their std.
+        Kurtosis is the fourth central moment divided by the square of the
+        variance. If Fisher's definition is used, then 3.0 is subtracted from
+        the result to give 0.0 for a normal distribution.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
@@ -7793,113 +8968,64 @@ class Expr:
         ----------
         window_size
             The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
         min_periods
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
-        ddof
-            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
-
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_std=pl.col("A").rolling_std(window_size=2),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_std │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.707107    │
-        │ 3.0 ┆ 0.707107    │
-        │ 4.0 ┆ 0.707107    │
-        │ 5.0 ┆ 0.707107    │
-        │ 6.0 ┆ 0.707107    │
-        └─────┴─────────────┘
-
-        Specify weights to multiply the values in the window with:
-
-        >>> df.with_columns(
-        ...     rolling_std=pl.col("A").rolling_std(
-        ...         window_size=2, weights=[0.25, 0.75]
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_std │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.433013    │
-        │ 3.0 ┆ 0.433013    │
-        │ 4.0 ┆ 0.433013    │
-        │ 5.0 ┆ 0.433013    │
-        │ 6.0 ┆ 0.433013    │
-        └─────┴─────────────┘
-
-        Center the values in the window
-
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 10, 1]})
         >>> df.with_columns(
-        ...     rolling_std=pl.col("A").rolling_std(window_size=3, center=True),
+        ...     rolling_kurtosis=pl.col("a").rolling_kurtosis(window_size=4),
         ... )
         shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_std │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.0         │
-        │ 3.0 ┆ 1.0         │
-        │ 4.0 ┆ 1.0         │
-        │ 5.0 ┆ 1.0         │
-        │ 6.0 ┆ null        │
-        └─────┴─────────────┘
+        ┌─────┬──────────────────┐
+        │ a   ┆ rolling_kurtosis │
+        │ --- ┆ ---              │
+        │ i64 ┆ f64              │
+        ╞═════╪══════════════════╡
+        │ 1   ┆ null             │
+        │ 2   ┆ null             │
+        │ 3   ┆ null             │
+        │ 4   ┆ -1.36            │
+        │ 10  ┆ -0.798085        │
+        │ 1   ┆ -0.885926        │
+        └─────┴──────────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_std(
+            self._pyexpr.rolling_kurtosis(
                 window_size,
-                weights,
                 min_periods,
-                center=center,
-                ddof=ddof,
+                center,
+                fisher,
+                bias,
             )
         )
 
     @unstable()
-    def rolling_var(
+    def rolling_product(
         self,
-        window_size: int | timedelta,
-        weights: list[float] | None = None,
+        window_size: int,
         *,
         min_periods: int | None = None,
         center: bool = False,
-        ddof: int = 1,
     ) -> Expr:
         """
-        Compute a rolling variance.
+        Apply a rolling product over the values in this array.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their var.
+        Boolean and integer types smaller than 64 bits are cast to Int64 before
+        computing the product, like in :meth:`product`.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
@@ -7908,112 +9034,58 @@ class Expr:
         ----------
         window_size
             The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
         min_periods
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
-        ddof
-            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
-
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_var=pl.col("A").rolling_var(window_size=2),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_var │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.5         │
-        │ 3.0 ┆ 0.5         │
-        │ 4.0 ┆ 0.5         │
-        │ 5.0 ┆ 0.5         │
-        │ 6.0 ┆ 0.5         │
-        └─────┴─────────────┘
-
-        Specify weights to multiply the values in the window with:
-
-        >>> df.with_columns(
-        ...     rolling_var=pl.col("A").rolling_var(
-        ...         window_size=2, weights=[0.25, 0.75]
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_var │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.1875      │
-        │ 3.0 ┆ 0.1875      │
-        │ 4.0 ┆ 0.1875      │
-        │ 5.0 ┆ 0.1875      │
-        │ 6.0 ┆ 0.1875      │
-        └─────┴─────────────┘
-
-        Center the values in the window
-
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5, 6]})
         >>> df.with_columns(
-        ...     rolling_var=pl.col("A").rolling_var(window_size=3, center=True),
+        ...     rolling_product=pl.col("a").rolling_product(window_size=3),
         ... )
         shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_var │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.0         │
-        │ 3.0 ┆ 1.0         │
-        │ 4.0 ┆ 1.0         │
-        │ 5.0 ┆ 1.0         │
-        │ 6.0 ┆ null        │
-        └─────┴─────────────┘
+        ┌─────┬─────────────────┐
+        │ a   ┆ rolling_product │
+        │ --- ┆ ---             │
+        │ i64 ┆ i64             │
+        ╞═════╪═════════════════╡
+        │ 1   ┆ null            │
+        │ 2   ┆ null            │
+        │ 3   ┆ 6               │
+        │ 4   ┆ 24              │
+        │ 5   ┆ 60              │
+        │ 6   ┆ 120             │
+        └─────┴─────────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_var(
+            self._pyexpr.rolling_product(
                 window_size,
-                weights,
                 min_periods,
-                center=center,
-                ddof=ddof,
+                center,
             )
         )
 
     @unstable()
-    def rolling_median(
+    def rolling_rank(
         self,
-        window_size: int | timedelta,
-        weights: list[float] | None = None,
+        window_size: int,
         *,
         min_periods: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Compute a rolling median.
+        Compute the rolling rank of each value within its window.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their median.
+        The rank is expressed as a fraction of the number of non-null values
+        in the window, so that the largest value in the window gets a rank of
+        1.0. Ties are assigned the average of the ranks they span.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
@@ -8022,278 +9094,215 @@ class Expr:
         ----------
         window_size
             The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
         min_periods
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
-
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_median=pl.col("A").rolling_median(window_size=2),
-        ... )
-        shape: (6, 2)
-        ┌─────┬────────────────┐
-        │ A   ┆ rolling_median │
-        │ --- ┆ ---            │
-        │ f64 ┆ f64            │
-        ╞═════╪════════════════╡
-        │ 1.0 ┆ null           │
-        │ 2.0 ┆ 1.5            │
-        │ 3.0 ┆ 2.5            │
-        │ 4.0 ┆ 3.5            │
-        │ 5.0 ┆ 4.5            │
-        │ 6.0 ┆ 5.5            │
-        └─────┴────────────────┘
-
-        Specify weights for the values in each window:
-
-        >>> df.with_columns(
-        ...     rolling_median=pl.col("A").rolling_median(
-        ...         window_size=2, weights=[0.25, 0.75]
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬────────────────┐
-        │ A   ┆ rolling_median │
-        │ --- ┆ ---            │
-        │ f64 ┆ f64            │
-        ╞═════╪════════════════╡
-        │ 1.0 ┆ null           │
-        │ 2.0 ┆ 1.5            │
-        │ 3.0 ┆ 2.5            │
-        │ 4.0 ┆ 3.5            │
-        │ 5.0 ┆ 4.5            │
-        │ 6.0 ┆ 5.5            │
-        └─────┴────────────────┘
-
-        Center the values in the window
-
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 2, 5, 0]})
         >>> df.with_columns(
-        ...     rolling_median=pl.col("A").rolling_median(window_size=3, center=True),
+        ...     rolling_rank=pl.col("a").rolling_rank(window_size=3),
         ... )
         shape: (6, 2)
-        ┌─────┬────────────────┐
-        │ A   ┆ rolling_median │
-        │ --- ┆ ---            │
-        │ f64 ┆ f64            │
-        ╞═════╪════════════════╡
-        │ 1.0 ┆ null           │
-        │ 2.0 ┆ 2.0            │
-        │ 3.0 ┆ 3.0            │
-        │ 4.0 ┆ 4.0            │
-        │ 5.0 ┆ 5.0            │
-        │ 6.0 ┆ null           │
-        └─────┴────────────────┘
+        ┌─────┬──────────────┐
+        │ a   ┆ rolling_rank │
+        │ --- ┆ ---          │
+        │ i64 ┆ f64          │
+        ╞═════╪══════════════╡
+        │ 1   ┆ null         │
+        │ 4   ┆ null         │
+        │ 2   ┆ 0.666667     │
+        │ 2   ┆ 0.5          │
+        │ 5   ┆ 1.0          │
+        │ 0   ┆ 0.333333     │
+        └─────┴──────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_median(
+            self._pyexpr.rolling_rank(
                 window_size,
-                weights,
                 min_periods,
-                center=center,
+                center,
             )
         )
 
     @unstable()
-    def rolling_quantile(
+    def rolling_n_unique(
         self,
-        quantile: float,
-        interpolation: RollingInterpolationMethod = "nearest",
-        window_size: int | timedelta = 2,
-        weights: list[float] | None = None,
+        window_size: int,
         *,
         min_periods: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Compute a rolling quantile.
+        Count the number of unique values in a rolling window.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their quantile.
+        Null is counted as a distinct value.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
 
         Parameters
         ----------
-        quantile
-            Quantile between 0.0 and 1.0.
-        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear'}
-            Interpolation method.
         window_size
             The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
         min_periods
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
-
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df = pl.DataFrame({"a": [1, 1, 2, 1, 3, 3]})
         >>> df.with_columns(
-        ...     rolling_quantile=pl.col("A").rolling_quantile(
-        ...         quantile=0.25, window_size=4
-        ...     ),
+        ...     rolling_n_unique=pl.col("a").rolling_n_unique(window_size=3),
         ... )
         shape: (6, 2)
         ┌─────┬──────────────────┐
-        │ A   ┆ rolling_quantile │
+        │ a   ┆ rolling_n_unique │
         │ --- ┆ ---              │
-        │ f64 ┆ f64              │
+        │ i64 ┆ u32              │
         ╞═════╪══════════════════╡
-        │ 1.0 ┆ null             │
-        │ 2.0 ┆ null             │
-        │ 3.0 ┆ null             │
-        │ 4.0 ┆ 2.0              │
-        │ 5.0 ┆ 3.0              │
-        │ 6.0 ┆ 4.0              │
+        │ 1   ┆ null             │
+        │ 1   ┆ null             │
+        │ 2   ┆ 2                │
+        │ 1   ┆ 2                │
+        │ 3   ┆ 3                │
+        │ 3   ┆ 2                │
         └─────┴──────────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_n_unique(
+                window_size,
+                min_periods,
+                center,
+            )
+        )
 
-        Specify weights for the values in each window:
+    @unstable()
+    def rolling_arg_min(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Get the index of the minimum value within a rolling window.
 
-        >>> df.with_columns(
-        ...     rolling_quantile=pl.col("A").rolling_quantile(
-        ...         quantile=0.25, window_size=4, weights=[0.2, 0.4, 0.4, 0.2]
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬──────────────────┐
-        │ A   ┆ rolling_quantile │
-        │ --- ┆ ---              │
-        │ f64 ┆ f64              │
-        ╞═════╪══════════════════╡
-        │ 1.0 ┆ null             │
-        │ 2.0 ┆ null             │
-        │ 3.0 ┆ null             │
-        │ 4.0 ┆ 2.0              │
-        │ 5.0 ┆ 3.0              │
-        │ 6.0 ┆ 4.0              │
-        └─────┴──────────────────┘
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
-        Specify weights and interpolation method
+        The index is relative to the start of the window. If the minimum occurs
+        multiple times, the index of the first occurrence is returned.
 
-        >>> df.with_columns(
-        ...     rolling_quantile=pl.col("A").rolling_quantile(
-        ...         quantile=0.25,
-        ...         window_size=4,
-        ...         weights=[0.2, 0.4, 0.4, 0.2],
-        ...         interpolation="linear",
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬──────────────────┐
-        │ A   ┆ rolling_quantile │
-        │ --- ┆ ---              │
-        │ f64 ┆ f64              │
-        ╞═════╪══════════════════╡
-        │ 1.0 ┆ null             │
-        │ 2.0 ┆ null             │
-        │ 3.0 ┆ null             │
-        │ 4.0 ┆ 1.625            │
-        │ 5.0 ┆ 2.625            │
-        │ 6.0 ┆ 3.625            │
-        └─────┴──────────────────┘
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
 
-        Center the values in the window
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
 
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [4, 2, 6, 5, 1, 3]})
         >>> df.with_columns(
-        ...     rolling_quantile=pl.col("A").rolling_quantile(
-        ...         quantile=0.2, window_size=5, center=True
-        ...     ),
+        ...     rolling_arg_min=pl.col("a").rolling_arg_min(window_size=3),
         ... )
         shape: (6, 2)
-        ┌─────┬──────────────────┐
-        │ A   ┆ rolling_quantile │
-        │ --- ┆ ---              │
-        │ f64 ┆ f64              │
-        ╞═════╪══════════════════╡
-        │ 1.0 ┆ null             │
-        │ 2.0 ┆ null             │
-        │ 3.0 ┆ 2.0              │
-        │ 4.0 ┆ 3.0              │
-        │ 5.0 ┆ null             │
-        │ 6.0 ┆ null             │
-        └─────┴──────────────────┘
+        ┌─────┬─────────────────┐
+        │ a   ┆ rolling_arg_min │
+        │ --- ┆ ---             │
+        │ i64 ┆ u32             │
+        ╞═════╪═════════════════╡
+        │ 4   ┆ null            │
+        │ 2   ┆ null            │
+        │ 6   ┆ 1               │
+        │ 5   ┆ 0               │
+        │ 1   ┆ 2               │
+        │ 3   ┆ 1               │
+        └─────┴─────────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_quantile(
-                quantile,
-                interpolation,
+            self._pyexpr.rolling_arg_min(
                 window_size,
-                weights,
                 min_periods,
-                center=center,
+                center,
             )
         )
 
     @unstable()
-    def rolling_skew(self, window_size: int, *, bias: bool = True) -> Expr:
+    def rolling_arg_max(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Expr:
         """
-        Compute a rolling skew.
+        Get the index of the maximum value within a rolling window.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
 
+        The index is relative to the start of the window. If the maximum occurs
+        multiple times, the index of the first occurrence is returned.
+
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
 
         Parameters
         ----------
         window_size
-            Integer size of the rolling window.
-        bias
-            If False, the calculations are corrected for statistical bias.
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
 
         Examples
         --------
-        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
-        >>> df.select(pl.col("a").rolling_skew(3))
-        shape: (4, 1)
-        ┌──────────┐
-        │ a        │
-        │ ---      │
-        │ f64      │
-        ╞══════════╡
-        │ null     │
-        │ null     │
-        │ 0.381802 │
-        │ 0.47033  │
-        └──────────┘
-
-        Note how the values match the following:
-
-        >>> pl.Series([1, 4, 2]).skew(), pl.Series([4, 2, 9]).skew()
-        (0.38180177416060584, 0.47033046033698594)
+        >>> df = pl.DataFrame({"a": [4, 2, 6, 5, 1, 3]})
+        >>> df.with_columns(
+        ...     rolling_arg_max=pl.col("a").rolling_arg_max(window_size=3),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────────┐
+        │ a   ┆ rolling_arg_max │
+        │ --- ┆ ---             │
+        │ i64 ┆ u32             │
+        ╞═════╪═════════════════╡
+        │ 4   ┆ null            │
+        │ 2   ┆ null            │
+        │ 6   ┆ 2               │
+        │ 5   ┆ 1               │
+        │ 1   ┆ 0               │
+        │ 3   ┆ 0               │
+        └─────┴─────────────────┘
         """
-        return self._from_pyexpr(self._pyexpr.rolling_skew(window_size, bias))
+        return self._from_pyexpr(
+            self._pyexpr.rolling_arg_max(
+                window_size,
+                min_periods,
+                center,
+            )
+        )
 
     @unstable()
     def rolling_map(
@@ -10691,6 +11700,15 @@ class Expr:
         """
         return ExprArrayNameSpace(self)
 
+    @property
+    def map(self) -> ExprMapNameSpace:
+        """
+        Create an object namespace of all map related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprMapNameSpace(self)
+
     @property
     def meta(self) -> ExprMetaNameSpace:
         """
@@ -0,0 +1,154 @@
+from __future__ import annotations
+
+from collections.abc import Sequence
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprMapNameSpace:
+    """Namespace for map related expressions."""
+
+    _accessor = "map"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def get(self, key: IntoExpr) -> Expr:
+        """
+        Get the value stored under a key in every map.
+
+        Maps that do not contain the key result in a null.
+
+        Parameters
+        ----------
+        key
+            Key to look up; accepts expression input. Strings are parsed as
+            literals, not as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]}
+        ... ).cast({"m": pl.Map(pl.String, pl.Int64)})
+        >>> df.select(pl.col("m").map.get("y"))
+        shape: (2, 1)
+        ┌──────┐
+        │ m    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ 2    │
+        │ null │
+        └──────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_get(key_pyexpr))
+
+    def keys(self) -> Expr:
+        """
+        Get the keys of every map as a list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]}
+        ... ).cast({"m": pl.Map(pl.String, pl.Int64)})
+        >>> df.select(pl.col("m").map.keys())
+        shape: (2, 1)
+        ┌────────────┐
+        │ m          │
+        │ ---        │
+        │ list[str]  │
+        ╞════════════╡
+        │ ["x", "y"] │
+        │ []         │
+        └────────────┘
+        """
+        return wrap_expr(self._pyexpr.map_keys())
+
+    def values(self) -> Expr:
+        """
+        Get the values of every map as a list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]}
+        ... ).cast({"m": pl.Map(pl.String, pl.Int64)})
+        >>> df.select(pl.col("m").map.values())
+        shape: (2, 1)
+        ┌───────────┐
+        │ m         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 2]    │
+        │ []        │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.map_values())
+
+    def contains_key(self, key: IntoExpr) -> Expr:
+        """
+        Check whether every map contains a key.
+
+        Parameters
+        ----------
+        key
+            Key to look up; accepts expression input. Strings are parsed as
+            literals, not as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]}
+        ... ).cast({"m": pl.Map(pl.String, pl.Int64)})
+        >>> df.select(pl.col("m").map.contains_key("x"))
+        shape: (2, 1)
+        ┌───────┐
+        │ m     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        └───────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_contains_key(key_pyexpr))
+
+    def to_struct(self, fields: Sequence[str]) -> Expr:
+        """
+        Convert every map to a struct with a field per given key.
+
+        The keys must be castable to the key type of the map. Maps that do not
+        contain a key get a null in the corresponding field.
+
+        Parameters
+        ----------
+        fields
+            The keys to turn into struct fields.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]}
+        ... ).cast({"m": pl.Map(pl.String, pl.Int64)})
+        >>> df.select(pl.col("m").map.to_struct(["x", "z"])).unnest("m")
+        shape: (2, 2)
+        ┌──────┬──────┐
+        │ x    ┆ z    │
+        │ ---  ┆ ---  │
+        │ i64  ┆ i64  │
+        ╞══════╪══════╡
+        │ 1    ┆ null │
+        │ null ┆ null │
+        └──────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.map_to_struct(list(fields)))
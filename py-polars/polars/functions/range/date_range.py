@@ -94,6 +94,7 @@ def date_range(
     `interval` is created according to the following string language:
 
     - 1d    (1 calendar day)
+    - 1bd   (1 business day, Monday to Friday)
     - 1w    (1 calendar week)
     - 1mo   (1 calendar month)
     - 1q    (1 calendar quarter)
@@ -106,6 +107,16 @@ def date_range(
     not be 24 hours, due to daylight savings). Similarly for "calendar week",
     "calendar month", "calendar quarter", and "calendar year".
 
+    Anchored units step between the ends (or starts) of calendar periods, and
+    can't be combined with other units:
+
+    - 1me   (1 month end)
+    - 1bme  (1 business month end)
+    - 1qe   (1 quarter end)
+    - 1bqe  (1 business quarter end)
+    - 1fy   (1 fiscal year start; append a month to start fiscal years in
+      that month, e.g. "1fyapr")
+
     Examples
     --------
     Using Polars duration string to specify the interval:
@@ -3795,6 +3795,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
            - 1m    (1 minute)
            - 1h    (1 hour)
            - 1d    (1 calendar day)
+           - 1bd   (1 business day, Monday to Friday)
            - 1w    (1 calendar week)
            - 1mo   (1 calendar month)
            - 1q    (1 calendar quarter)
@@ -3808,6 +3809,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
            not be 24 hours, due to daylight savings). Similarly for "calendar week",
            "calendar month", "calendar quarter", and "calendar year".
 
+           Anchored units step between the ends (or starts) of calendar periods, and
+           can't be combined with other units:
+
+           - 1me   (1 month end)
+           - 1bme  (1 business month end)
+           - 1qe   (1 quarter end)
+           - 1bqe  (1 business quarter end)
+           - 1fy   (1 fiscal year start; append a month to start fiscal years in
+             that month, e.g. "1fyapr")
+
            In case of a group_by_dynamic on an integer column, the windows are defined by:
 
            - "1i"      # length 1
@@ -0,0 +1,137 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from polars import Series
+    from polars._typing import IntoExpr
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class MapNameSpace:
+    """Namespace for map related methods."""
+
+    _accessor = "map"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def get(self, key: IntoExpr) -> Series:
+        """
+        Get the value stored under a key in every map.
+
+        Maps that do not contain the key result in a null.
+
+        Parameters
+        ----------
+        key
+            Key to look up; accepts expression input. Strings are parsed as
+            literals, not as column names.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]
+        ... ).cast(pl.Map(pl.String, pl.Int64))
+        >>> s.map.get("y")
+        shape: (2,)
+        Series: 'm' [i64]
+        [
+            2
+            null
+        ]
+        """
+
+    def keys(self) -> Series:
+        """
+        Get the keys of every map as a list.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]
+        ... ).cast(pl.Map(pl.String, pl.Int64))
+        >>> s.map.keys()
+        shape: (2,)
+        Series: 'm' [list[str]]
+        [
+            ["x", "y"]
+            []
+        ]
+        """
+
+    def values(self) -> Series:
+        """
+        Get the values of every map as a list.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]
+        ... ).cast(pl.Map(pl.String, pl.Int64))
+        >>> s.map.values()
+        shape: (2,)
+        Series: 'm' [list[i64]]
+        [
+            [1, 2]
+            []
+        ]
+        """
+
+    def contains_key(self, key: IntoExpr) -> Series:
+        """
+        Check whether every map contains a key.
+
+        Parameters
+        ----------
+        key
+            Key to look up; accepts expression input. Strings are parsed as
+            literals, not as column names.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]
+        ... ).cast(pl.Map(pl.String, pl.Int64))
+        >>> s.map.contains_key("x")
+        shape: (2,)
+        Series: 'm' [bool]
+        [
+            true
+            false
+        ]
+        """
+
+    def to_struct(self, fields: Sequence[str]) -> Series:
+        """
+        Convert every map to a struct with a field per given key.
+
+        The keys must be castable to the key type of the map. Maps that do not
+        contain a key get a null in the corresponding field.
+
+        Parameters
+        ----------
+        fields
+            The keys to turn into struct fields.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [[{"key": "x", "value": 1}, {"key": "y", "value": 2}], []]
+        ... ).cast(pl.Map(pl.String, pl.Int64))
+        >>> s.map.to_struct(["x", "y"]).struct.unnest()
+        shape: (2, 2)
+        ┌──────┬──────┐
+        │ x    ┆ y    │
+        │ ---  ┆ ---  │
+        │ i64  ┆ i64  │
+        ╞══════╪══════╡
+        │ 1    ┆ 2    │
+        │ null ┆ null │
+        └──────┴──────┘
+        """
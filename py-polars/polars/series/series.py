@@ -100,6 +100,7 @@ from polars.series.binary import BinaryNameSpace
 from polars.series.categorical import CatNameSpace
 from polars.series.datetime import DateTimeNameSpace
 from polars.series.list import ListNameSpace
+from polars.series.map import MapNameSpace
 from polars.series.plotting import SeriesPlot
 from polars.series.string import StringNameSpace
 from polars.series.struct import StructNameSpace
@@ -250,6 +251,7 @@ class Series:
         "cat",
         "dt",
         "list",
+        "map",
         "str",
         "bin",
         "struct",
@@ -5989,6 +5991,296 @@ class Series:
         (0.38180177416060584, 0.47033046033698594)
         """
 
+    @unstable()
+    def rolling_kurtosis(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+        fisher: bool = True,
+        bias: bool = True,
+    ) -> Series:
+        """
+        Compute a rolling kurtosis.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Kurtosis is the fourth central moment divided by the square of the
+        variance. If Fisher's definition is used, then 3.0 is subtracted from
+        the result to give 0.0 for a normal distribution.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3, 4, 10, 1])
+        >>> s.rolling_kurtosis(window_size=4)
+        shape: (6,)
+        Series: 'a' [f64]
+        [
+            null
+            null
+            null
+            -1.36
+            -0.798085
+            -0.885926
+        ]
+        """
+
+    @unstable()
+    def rolling_product(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Apply a rolling product over the values in this array.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Boolean and integer types smaller than 64 bits are cast to Int64 before
+        computing the product, like in :meth:`product`.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3, 4, 5, 6])
+        >>> s.rolling_product(window_size=3)
+        shape: (6,)
+        Series: 'a' [i64]
+        [
+            null
+            null
+            6
+            24
+            60
+            120
+        ]
+        """
+
+    @unstable()
+    def rolling_rank(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute the rolling rank of each value within its window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The rank is expressed as a fraction of the number of non-null values
+        in the window, so that the largest value in the window gets a rank of
+        1.0. Ties are assigned the average of the ranks they span.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 4, 2, 2, 5, 0])
+        >>> s.rolling_rank(window_size=3)
+        shape: (6,)
+        Series: 'a' [f64]
+        [
+            null
+            null
+            0.666667
+            0.5
+            1.0
+            0.333333
+        ]
+        """
+
+    @unstable()
+    def rolling_n_unique(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Count the number of unique values in a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Null is counted as a distinct value.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 1, 2, 1, 3, 3])
+        >>> s.rolling_n_unique(window_size=3)
+        shape: (6,)
+        Series: 'a' [u32]
+        [
+            null
+            null
+            2
+            2
+            3
+            2
+        ]
+        """
+
+    @unstable()
+    def rolling_arg_min(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Get the index of the minimum value within a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The index is relative to the start of the window. If the minimum occurs
+        multiple times, the index of the first occurrence is returned.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [4, 2, 6, 5, 1, 3])
+        >>> s.rolling_arg_min(window_size=3)
+        shape: (6,)
+        Series: 'a' [u32]
+        [
+            null
+            null
+            1
+            0
+            2
+            1
+        ]
+        """
+
+    @unstable()
+    def rolling_arg_max(
+        self,
+        window_size: int,
+        *,
+        min_periods: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Get the index of the maximum value within a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The index is relative to the start of the window. If the maximum occurs
+        multiple times, the index of the first occurrence is returned.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        min_periods
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [4, 2, 6, 5, 1, 3])
+        >>> s.rolling_arg_max(window_size=3)
+        shape: (6,)
+        Series: 'a' [u32]
+        [
+            null
+            null
+            2
+            1
+            0
+            0
+        ]
+        """
+
     def sample(
         self,
         n: int | None = None,
@@ -7458,6 +7750,11 @@ class Series:
         """Create an object namespace of all array related methods."""
         return ArrayNameSpace(self)
 
+    @property
+    def map(self) -> MapNameSpace:
+        """Create an object namespace of all map related methods."""
+        return MapNameSpace(self)
+
     @property
     def str(self) -> StringNameSpace:
         """Create an object namespace of all string related methods."""
@@ -120,6 +120,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
+        recursion_limit: int | None = ...,
         eager: Literal[False] = False,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
@@ -131,6 +132,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
+        recursion_limit: int | None = ...,
         eager: Literal[True],
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
@@ -142,6 +144,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
+        recursion_limit: int | None = ...,
         eager: bool,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
@@ -152,6 +155,7 @@ class SQLContext(Generic[FrameType]):
         frames: Mapping[str, CompatibleFrameType | None] | None = None,
         *,
         register_globals: bool | int = False,
+        recursion_limit: int | None = None,
         eager: bool = False,
         **named_frames: CompatibleFrameType | None,
     ) -> None:
@@ -169,6 +173,10 @@ class SQLContext(Generic[FrameType]):
             To register other objects (pandas/pyarrow data) pass them explicitly, or
             call the `execute_global` classmethod. If given an integer then only the
             most recent "n" objects found will be registered.
+        recursion_limit
+            The maximum number of iterations of a recursive CTE (`WITH RECURSIVE`);
+            a query whose recursive CTE does not complete within this limit raises
+            an error. If unset, the limit is 1000 iterations.
         eager
             If True, returns execution results as `DataFrame` instead of `LazyFrame`.
             (Note that the query itself is always executed in lazy-mode; this parameter
@@ -196,7 +204,7 @@ class SQLContext(Generic[FrameType]):
         issue_unstable_warning(
             "`SQLContext` is considered **unstable**, although it is close to being considered stable."
         )
-        self._ctxt = PySQLContext.new()
+        self._ctxt = PySQLContext.new(recursion_limit=recursion_limit)
         self._eager_execution = eager
 
         frames = dict(frames or {})
@@ -370,6 +378,12 @@ class SQLContext(Generic[FrameType]):
             Note that the query itself is always executed in lazy-mode; this
             parameter only impacts the type of the returned frame.
 
+        Notes
+        -----
+        A recursive CTE (`WITH RECURSIVE`) is evaluated eagerly, when the query is
+        executed, as each of its iterations depends on the result of the previous
+        one; see the `recursion_limit` parameter of :class:`SQLContext`.
+
         Examples
         --------
         Declare frame data and register with a SQLContext:
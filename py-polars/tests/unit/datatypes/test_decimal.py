@@ -334,9 +334,10 @@ def test_decimal_aggregations() -> None:
             "sum": [D("9110.33")],
             "min": [D("0.10")],
             "max": [D("9000.12")],
-            "mean": [2277.5825],
+            "mean": [D("2277.582500")],
             "median": [55.055],
-        }
+        },
+        schema_overrides={"mean": pl.Decimal(scale=6)},
     )
     assert_frame_equal(res, expected)
 
@@ -513,9 +514,9 @@ def test_decimal_supertype() -> None:
 
 def test_decimal_raise_oob_precision() -> None:
     df = pl.DataFrame({"a": [1.0]})
-    # max precision is 38.
+    # max precision is 76.
     with pytest.raises(pl.exceptions.InvalidOperationError):
-        df.select(b=pl.col("a").cast(pl.Decimal(76, 38)))
+        df.select(b=pl.col("a").cast(pl.Decimal(77, 38)))
 
 
 def test_decimal_dynamic_float_st() -> None:
@@ -529,3 +530,136 @@ def test_decimal_strict_scale_inference_17770() -> None:
     s = pl.Series(values, strict=True)
     assert s.dtype == pl.Decimal(precision=None, scale=4)
     assert s.to_list() == values
+
+
+def test_decimal_round_floor_ceil() -> None:
+    s = pl.Series([D("1.255"), D("-1.255"), D("2.999"), None], dtype=pl.Decimal(10, 3))
+
+    assert_series_equal(
+        s.round(2),
+        pl.Series([D("1.260"), D("-1.260"), D("3.000"), None], dtype=pl.Decimal(10, 3)),
+    )
+    assert_series_equal(
+        s.floor(),
+        pl.Series([D("1.000"), D("-2.000"), D("2.000"), None], dtype=pl.Decimal(10, 3)),
+    )
+    assert_series_equal(
+        s.ceil(),
+        pl.Series([D("2.000"), D("-1.000"), D("3.000"), None], dtype=pl.Decimal(10, 3)),
+    )
+
+    # Rounding up must still fit the precision.
+    with pytest.raises(pl.exceptions.ComputeError):
+        pl.Series([D("9.99")], dtype=pl.Decimal(3, 2)).round(0)
+
+
+def test_decimal_exact_statistics() -> None:
+    df = pl.DataFrame({"a": [D("0.1"), D("0.2"), D("0.2")]})
+    assert df["a"].mean() == D("0.16667")
+
+    out = df.select(mean=pl.col("a").mean(), var=pl.col("a").var())
+    assert out.schema == {"mean": pl.Decimal(scale=5), "var": pl.Decimal(scale=5)}
+    assert out.row(0) == (D("0.16667"), D("0.00333"))
+
+
+def test_decimal_overflow() -> None:
+    s = pl.Series([D("9" * 37), D("1")], dtype=pl.Decimal(38, 0))
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        s * 100
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        (s * 10).sum()
+
+
+@pytest.mark.parametrize(
+    ("op", "other", "expected"),
+    [
+        ("add", 1, [None, D("2"), None]),
+        ("sub", pl.col("b"), [None, D("0"), None]),
+        ("mul", 2, [None, D("2"), None]),
+        ("truediv", pl.col("b"), [None, D("1.0000"), None]),
+    ],
+)
+def test_decimal_arithmetic_strict(op: str, other: Any, expected: list[Any]) -> None:
+    df = pl.DataFrame(
+        {"a": [D("9" * 38), D("1"), None], "b": [D("-1"), D("1"), D("1")]},
+        schema={"a": pl.Decimal(38, 0), "b": pl.Decimal(38, 0)},
+    )
+    # the first row doesn't fit in a decimal
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        df.select(getattr(pl.col("a"), op)(other))
+    with pytest.raises(pl.exceptions.ComputeError, match="decimal overflow"):
+        df.select(getattr(pl.col("a"), op)(other, strict=True))
+
+    out = df.select(getattr(pl.col("a"), op)(other, strict=False))
+    scale = 4 if op == "truediv" else 0
+    assert out.schema == {"a": pl.Decimal(scale=scale)}
+    assert out["a"].to_list() == expected
+
+
+def test_decimal_arithmetic_non_strict_invalid_operand() -> None:
+    df = pl.DataFrame({"a": [D("1.5")], "b": [2.0]})
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.select(pl.col("a").add(pl.col("b"), strict=False))
+
+
+def test_decimal_cum_sum_rolling_sum() -> None:
+    s = pl.Series([D("0.1"), D("0.2"), None, D("0.3")], dtype=pl.Decimal(10, 1))
+
+    expected = pl.Series([D("0.1"), D("0.3"), None, D("0.6")], dtype=pl.Decimal(10, 1))
+    assert_series_equal(s.cum_sum(), expected)
+
+    expected = pl.Series(
+        [D("0.1"), D("0.3"), D("0.2"), D("0.3")], dtype=pl.Decimal(10, 1)
+    )
+    assert_series_equal(s.rolling_sum(2, min_periods=1), expected)
+
+
+def test_decimal_cast_scientific_notation() -> None:
+    s = pl.Series(["1.5e3", "-2.5E-2", "1e0"])
+    assert s.cast(pl.Decimal(10, 3)).to_list() == [
+        D("1500.000"),
+        D("-0.025"),
+        D("1.000"),
+    ]
+    assert s.cast(pl.Decimal).to_list() == [D("1500.000"), D("-0.025"), D("1.000")]
+
+
+def test_decimal256_construction_and_arithmetic() -> None:
+    big = D("9" * 50 + ".25")
+    s = pl.Series([big, D("-1.5"), None], dtype=pl.Decimal(60, 2))
+    assert s.dtype == pl.Decimal(60, 2)
+    assert s.to_list() == [big, D("-1.50"), None]
+
+    assert (s + s).to_list() == [D("1" + "9" * 49 + "8.50"), D("-3.00"), None]
+    assert s.sum() == D("9" * 49 + "7.75")
+    assert s.min() == D("-1.50")
+    assert s.max() == big
+
+    # Values that don't fit in 128 bits are inferred as 256-bit decimals.
+    assert pl.Series([big]).to_list() == [big]
+
+
+def test_decimal256_cast() -> None:
+    s = pl.Series(["1" + "0" * 45, "-2.5", None])
+    out = s.cast(pl.Decimal(50, 1))
+    assert out.to_list() == [D("1" + "0" * 45 + ".0"), D("-2.5"), None]
+    assert out.cast(pl.String).to_list() == ["1" + "0" * 45 + ".0", "-2.5", None]
+    assert out.cast(pl.Float64).to_list() == [1e45, -2.5, None]
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        out.cast(pl.Decimal(38, 1), strict=True)
+
+
+def test_decimal256_parquet_ipc_roundtrip() -> None:
+    df = pl.DataFrame(
+        {"a": [D("9" * 70 + ".123456"), D("-0.000001"), None]},
+        schema={"a": pl.Decimal(76, 6)},
+    )
+    for write, read in [
+        (pl.DataFrame.write_parquet, pl.read_parquet),
+        (pl.DataFrame.write_ipc, pl.read_ipc),
+    ]:
+        f = io.BytesIO()
+        write(df, f)
+        f.seek(0)
+        assert_frame_equal(read(f), df)
@@ -0,0 +1,121 @@
+from __future__ import annotations
+
+import io
+import math
+
+import numpy as np
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_float16_construction_rounds() -> None:
+    s = pl.Series("a", [0.1, 1 / 3, 70000.0, None], dtype=pl.Float16)
+    assert s.dtype == pl.Float16
+    assert s.dtype.is_float()
+    assert s.to_list() == [0.0999755859375, 0.333251953125, math.inf, None]
+
+    assert s.cast(pl.String).to_list() == ["0.1", "0.3333", "inf", None]
+    assert_series_equal(
+        pl.Series("a", ["0.1", "-2.5"]).cast(pl.Float16),
+        pl.Series("a", [0.1, -2.5], dtype=pl.Float16),
+    )
+
+
+def test_float16_cast_roundtrip() -> None:
+    s = pl.Series("a", [1.5, -2.0, None], dtype=pl.Float32)
+    out = s.cast(pl.Float16)
+    assert out.dtype == pl.Float16
+    assert_series_equal(out.cast(pl.Float32), s)
+    assert_series_equal(out.cast(pl.Int32), pl.Series("a", [1, -2, None], pl.Int32))
+
+
+def test_float16_arithmetic_and_comparison() -> None:
+    s = pl.Series("a", [1.0, 2.0, 2048.0], dtype=pl.Float16)
+
+    out = s + pl.Series([1.0, 1.0, 1.0], dtype=pl.Float16)
+    assert out.dtype == pl.Float16
+    # 2049 is not representable as f16.
+    assert out.to_list() == [2.0, 3.0, 2048.0]
+
+    assert (s > 1.5).to_list() == [False, True, True]
+    assert (s == 2.0).to_list() == [False, True, False]
+    assert s.abs().dtype == pl.Float16
+    assert s.is_nan().to_list() == [False, False, False]
+
+
+def test_float16_aggregations() -> None:
+    s = pl.Series("a", [1.0, 2.0, 2048.0, None], dtype=pl.Float16)
+    assert s.min() == 1.0
+    assert s.max() == 2048.0
+    assert s.sum() == 2051.0
+    assert s.mean() == pytest.approx(2051.0 / 3)
+
+    df = pl.DataFrame({"g": [1, 1, 2, 2], "a": s})
+    out = df.select(
+        pl.col("a").sum().alias("sum"),
+        pl.col("a").mean().alias("mean"),
+        pl.col("a").max().alias("max"),
+    )
+    assert out.schema == {"sum": pl.Float32, "mean": pl.Float32, "max": pl.Float16}
+    assert out.row(0) == (2051.0, pytest.approx(2051.0 / 3), 2048.0)
+
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").sum().alias("sum"), pl.col("a").min().alias("min")
+    )
+    assert out.schema == {"g": pl.Int64, "sum": pl.Float32, "min": pl.Float16}
+    assert out.rows() == [(1, 3.0, 1.0), (2, 2048.0, 2048.0)]
+
+
+def test_float16_numpy() -> None:
+    arr = np.array([0.5, -1.25, np.nan], dtype=np.float16)
+    s = pl.Series("a", arr)
+    assert s.dtype == pl.Float16
+
+    out = s.to_numpy()
+    assert out.dtype == np.float16
+    np.testing.assert_array_equal(out, arr)
+
+
+@pytest.mark.parametrize("format", ["parquet", "ipc"])
+def test_float16_io_roundtrip(format: str) -> None:
+    df = pl.DataFrame(
+        {
+            "x": pl.Series([0.1, None, -2.5, float("nan")], dtype=pl.Float16),
+            "list": pl.Series(
+                [[1.5, 2.0], None, [], [0.25]], dtype=pl.List(pl.Float16)
+            ),
+            "embedding": pl.Series(
+                [[0.1, 0.2], [0.3, 0.4], None, [0.5, 0.6]],
+                dtype=pl.Array(pl.Float16, 2),
+            ),
+        }
+    )
+
+    f = io.BytesIO()
+    getattr(df, f"write_{format}")(f)
+    f.seek(0)
+    out = getattr(pl, f"read_{format}")(f)
+    assert out.schema == df.schema
+    assert_frame_equal(out, df)
+
+
+
+def test_float16_read_foreign_files() -> None:
+    # half-precision columns that were not written by polars are read as Float32
+    table = pa.table({"x": pa.array(np.array([0.5, 1.5], dtype=np.float16))})
+    expected = pl.DataFrame({"x": [0.5, 1.5]}, schema={"x": pl.Float32})
+
+    f = io.BytesIO()
+    pq.write_table(table, f)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), expected)
+
+    f = io.BytesIO()
+    with pa.ipc.new_file(f, table.schema) as writer:
+        writer.write_table(table)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), expected)
@@ -0,0 +1,120 @@
+from __future__ import annotations
+
+from datetime import date, datetime, timedelta
+from typing import TYPE_CHECKING
+from zoneinfo import ZoneInfo
+
+import pytest
+
+import polars as pl
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def test_interval_sql_literal() -> None:
+    df = pl.sql("SELECT INTERVAL '1 month 2 days 3 seconds' AS i", eager=True)
+    assert df.schema == {"i": pl.Interval}
+    assert df.rows() == [((1, 2, 3_000_000_000),)]
+
+
+def test_interval_sql_fixed_length() -> None:
+    # intervals without calendar parts are durations (that compare with durations)
+    df = pl.DataFrame({"d": [timedelta(minutes=30), timedelta(hours=2), None]})
+    out = pl.SQLContext(df=df).execute(
+        """
+        SELECT
+          d < INTERVAL '1 hour' AS lt,
+          d + INTERVAL '90 minutes' AS plus,
+          INTERVAL '1 hour' AS fixed,
+          INTERVAL '1 day' AS calendar
+        FROM df
+        """,
+        eager=True,
+    )
+    assert out.schema == {
+        "lt": pl.Boolean,
+        "plus": pl.Duration("us"),
+        "fixed": pl.Duration("ns"),
+        "calendar": pl.Interval,
+    }
+    assert out.to_dict(as_series=False) == {
+        "lt": [True, False, None],
+        "plus": [timedelta(hours=2), timedelta(hours=3, minutes=30), None],
+        "fixed": [timedelta(hours=1)] * 3,
+        "calendar": [(0, 1, 0)] * 3,
+    }
+
+
+def test_interval_date_arithmetic() -> None:
+    df = pl.DataFrame({"dt": [date(2024, 1, 31), date(2024, 3, 1), None]})
+    out = pl.SQLContext(df=df).execute(
+        """
+        SELECT
+          dt + INTERVAL '1 month' AS plus_month,
+          dt - INTERVAL '1 month' AS minus_month,
+          INTERVAL '1 day' + dt AS plus_day
+        FROM df
+        """,
+        eager=True,
+    )
+    assert out.schema == dict.fromkeys(
+        ("plus_month", "minus_month", "plus_day"), pl.Date
+    )
+    assert out.to_dict(as_series=False) == {
+        # the day of the month is clamped to the end of the month
+        "plus_month": [date(2024, 2, 29), date(2024, 4, 1), None],
+        "minus_month": [date(2023, 12, 31), date(2024, 2, 1), None],
+        "plus_day": [date(2024, 2, 1), date(2024, 3, 2), None],
+    }
+
+
+def test_interval_datetime_arithmetic_dst() -> None:
+    tz = "Europe/Amsterdam"
+    df = pl.DataFrame({"ts": [datetime(2024, 3, 30, 12)]}).with_columns(
+        pl.col("ts").dt.replace_time_zone(tz)
+    )
+    out = pl.SQLContext(df=df).execute(
+        """
+        SELECT
+          ts + INTERVAL '1 day' AS plus_day,
+          ts + INTERVAL '24 hours' AS plus_hours,
+          ts - INTERVAL '1 month' AS minus_month
+        FROM df
+        """,
+        eager=True,
+    )
+    assert out.schema == dict.fromkeys(
+        ("plus_day", "plus_hours", "minus_month"), pl.Datetime("us", tz)
+    )
+    # a day keeps the wall-clock time across the DST transition, 24 hours do not
+    assert out.rows() == [
+        (
+            datetime(2024, 3, 31, 12, tzinfo=ZoneInfo(tz)),
+            datetime(2024, 3, 31, 13, tzinfo=ZoneInfo(tz)),
+            datetime(2024, 2, 29, 12, tzinfo=ZoneInfo(tz)),
+        )
+    ]
+
+
+@pytest.mark.parametrize("fmt", ["ipc", "parquet"])
+def test_interval_roundtrip(fmt: str, tmp_path: Path) -> None:
+    df = pl.sql(
+        """
+        SELECT
+          INTERVAL '1 year 2 months' AS a,
+          INTERVAL '3 weeks 100 ms' AS b,
+          CAST(NULL AS INTERVAL) AS c
+        """,
+        eager=True,
+    )
+    assert df.schema == dict.fromkeys(("a", "b", "c"), pl.Interval)
+
+    path = tmp_path / f"intervals.{fmt}"
+    getattr(df, f"write_{fmt}")(path)
+    for out in (
+        getattr(pl, f"read_{fmt}")(path),
+        getattr(pl, f"scan_{fmt}")(path).collect(),
+    ):
+        assert out.schema == df.schema
+        assert out.rows() == [((14, 0, 0), (0, 21, 100_000_000), None)]
@@ -0,0 +1,91 @@
+from __future__ import annotations
+
+import io
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+MAP_DTYPE = pl.Map(pl.String, pl.Int64)
+
+
+@pytest.fixture
+def map_series() -> pl.Series:
+    return pl.Series(
+        "m",
+        [
+            [{"key": "x", "value": 1}, {"key": "y", "value": 2}],
+            [],
+            None,
+            [{"key": "y", "value": None}],
+        ],
+    ).cast(MAP_DTYPE)
+
+
+def test_cast_list_map(map_series: pl.Series) -> None:
+    assert map_series.dtype == MAP_DTYPE
+    assert map_series.dtype != pl.Map(pl.String, pl.Int32)
+    assert map_series.dtype.key == pl.String
+    assert map_series.dtype.value == pl.Int64
+    assert map_series.to_list() == [
+        [{"key": "x", "value": 1}, {"key": "y", "value": 2}],
+        [],
+        None,
+        [{"key": "y", "value": None}],
+    ]
+
+
+def test_cast_list_map_renames_fields() -> None:
+    s = pl.Series([[{"a": 1, "b": "one"}]]).cast(pl.Map(pl.Int64, pl.String))
+    assert s.to_list() == [[{"key": 1, "value": "one"}]]
+
+
+def test_map_get(map_series: pl.Series) -> None:
+    assert_series_equal(
+        map_series.map.get("y"), pl.Series("m", [2, None, None, None])
+    )
+    assert_series_equal(
+        map_series.map.get("z"),
+        pl.Series("m", [None, None, None, None], dtype=pl.Int64),
+    )
+
+
+def test_map_get_expr_key(map_series: pl.Series) -> None:
+    df = pl.DataFrame([map_series, pl.Series("k", ["x", "y", "x", "y"])])
+    out = df.select(pl.col("m").map.get(pl.col("k")))
+    assert out["m"].to_list() == [1, None, None, None]
+
+
+def test_map_keys_values(map_series: pl.Series) -> None:
+    assert map_series.map.keys().to_list() == [["x", "y"], [], None, ["y"]]
+    assert map_series.map.values().to_list() == [[1, 2], [], None, [None]]
+
+
+def test_map_contains_key(map_series: pl.Series) -> None:
+    assert map_series.map.contains_key("y").to_list() == [True, False, None, True]
+
+
+def test_map_to_struct(map_series: pl.Series) -> None:
+    out = map_series.map.to_struct(["x", "y"])
+    assert out.dtype == pl.Struct({"x": pl.Int64, "y": pl.Int64})
+    assert out.to_list() == [
+        {"x": 1, "y": 2},
+        {"x": None, "y": None},
+        {"x": None, "y": None},
+        {"x": None, "y": None},
+    ]
+
+
+@pytest.mark.parametrize("fmt", ["parquet", "ipc"])
+def test_map_io_roundtrip(map_series: pl.Series, fmt: str) -> None:
+    df = pl.DataFrame([map_series, pl.Series("i", [1, 2, 3, 4])])
+
+    f = io.BytesIO()
+    getattr(df, f"write_{fmt}")(f)
+    f.seek(0)
+    out = getattr(pl, f"read_{fmt}")(f)
+
+    assert out.schema == df.schema
+    assert out["m"].to_list() == df["m"].to_list()
+    assert_frame_equal(out.drop("m"), df.drop("m"))
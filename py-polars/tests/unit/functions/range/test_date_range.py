@@ -163,6 +163,38 @@ def test_date_range_end_of_month_5441(
     assert_series_equal(result, expected)
 
 
+@pytest.mark.parametrize(
+    ("interval", "start", "stop", "expected_values"),
+    [
+        (
+            "1qe",
+            date(2024, 1, 15),
+            date(2024, 10, 15),
+            [date(2024, 3, 31), date(2024, 6, 30), date(2024, 9, 30)],
+        ),
+        (
+            "1bme",
+            date(2024, 2, 1),
+            date(2024, 4, 30),
+            [date(2024, 2, 29), date(2024, 3, 29), date(2024, 4, 30)],
+        ),
+        ("1fyapr", date(2024, 1, 15), date(2024, 10, 15), [date(2024, 4, 1)]),
+        (
+            "1bd",
+            date(2024, 5, 24),
+            date(2024, 5, 28),
+            [date(2024, 5, 24), date(2024, 5, 27), date(2024, 5, 28)],
+        ),
+    ],
+)
+def test_date_range_anchored_and_business_days(
+    interval: str, start: date, stop: date, expected_values: list[date]
+) -> None:
+    result = pl.date_range(start, stop, interval=interval, eager=True)
+    expected = pl.Series("literal", expected_values)
+    assert_series_equal(result, expected)
+
+
 def test_date_range_name() -> None:
     result_eager = pl.date_range(date(2020, 1, 1), date(2020, 1, 3), eager=True)
     assert result_eager.name == "literal"
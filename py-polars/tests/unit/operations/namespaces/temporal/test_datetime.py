@@ -791,6 +791,25 @@ def test_offset_by() -> None:
     assert df["date_min"].to_list() == expected_dates
 
 
+def test_offset_by_business_days_and_anchors() -> None:
+    # Friday, Saturday, and the middle of a quarter
+    s = pl.Series([datetime(2024, 5, 24, 9), datetime(2024, 5, 25, 9), None])
+    result = s.dt.offset_by("1bd")
+    expected = pl.Series([datetime(2024, 5, 27, 9), datetime(2024, 5, 27, 9), None])
+    assert_series_equal(result, expected)
+
+    result = s.dt.offset_by("-1bd")
+    expected = pl.Series([datetime(2024, 5, 23, 9), datetime(2024, 5, 24, 9), None])
+    assert_series_equal(result, expected)
+
+    result = s.dt.offset_by("1qe")
+    expected = pl.Series([datetime(2024, 6, 30, 9), datetime(2024, 6, 30, 9), None])
+    assert_series_equal(result, expected)
+
+    with pytest.raises(InvalidOperationError, match="anchored units"):
+        s.dt.offset_by("1qe1d")
+
+
 @pytest.mark.parametrize("time_zone", ["US/Central", None])
 def test_offset_by_crossing_dst(time_zone: str | None) -> None:
     ser = pl.Series([datetime(2021, 11, 7)]).dt.replace_time_zone(time_zone)
@@ -195,6 +195,89 @@ def test_rolling_skew() -> None:
     )
 
 
+
+def test_rolling_kurtosis() -> None:
+    s = pl.Series([1, 2, 3, 4, 10, 1])
+    assert s.rolling_kurtosis(4).to_list() == pytest.approx(
+        [None, None, None, -1.36, -0.7980853277835589, -0.885925925925926]
+    )
+    assert s.rolling_kurtosis(4, fisher=False).to_list() == pytest.approx(
+        [None, None, None, 1.64, 2.201914672216441, 2.114074074074074]
+    )
+    assert s.rolling_kurtosis(4, bias=False).to_list() == pytest.approx(
+        [None, None, None, -1.2, 3.014360041623309, 2.3555555555555543]
+    )
+    # windows are checked against the non-rolling implementation
+    for i in range(3, len(s)):
+        assert s.rolling_kurtosis(4)[i] == pytest.approx(s[i - 3 : i + 1].kurtosis())
+
+
+def test_rolling_product() -> None:
+    s = pl.Series([1, 2, 3, 0, 5, 6], dtype=pl.Int32)
+    result = s.rolling_product(2, min_periods=1)
+    assert result.dtype == pl.Int64
+    assert result.to_list() == [1, 2, 6, 0, 0, 30]
+
+    s = pl.Series([1.0, None, 3.0, 4.0])
+    assert s.rolling_product(2, min_periods=1).to_list() == [1.0, 1.0, 3.0, 12.0]
+
+    s = pl.Series([True, False, True, True])
+    assert s.rolling_product(2).to_list() == [None, 0, 0, 1]
+
+
+def test_rolling_rank_n_unique_arg_min_max() -> None:
+    s = pl.Series([1, 4, 2, 2, 5, 0])
+    assert s.rolling_rank(3).to_list() == pytest.approx(
+        [None, None, 2 / 3, 0.5, 1.0, 1 / 3]
+    )
+
+    s = pl.Series([1, None, 1, 2, None])
+    result = s.rolling_n_unique(3, min_periods=1)
+    assert result.dtype == pl.get_index_type()
+    assert result.to_list() == [1, 2, 2, 3, 3]
+
+    s = pl.Series([4, 2, 6, 5, 1, 3])
+    assert s.rolling_arg_min(3).to_list() == [None, None, 1, 0, 2, 1]
+    assert s.rolling_arg_max(3).to_list() == [None, None, 2, 1, 0, 0]
+    assert s.rolling_arg_max(3, center=True, min_periods=1).to_list() == [
+        0,
+        2,
+        1,
+        0,
+        0,
+        1,
+    ]
+
+
+def test_rolling_positional_by_unsorted() -> None:
+    df = pl.DataFrame({"a": [4, 2, 6, 5, 1], "by": [1, 2, 3, 4, 5]})
+    exprs = [
+        pl.col("a").rolling_rank_by("by", "3i").alias("rank"),
+        pl.col("a").rolling_n_unique_by("by", "3i").alias("n_unique"),
+        pl.col("a").rolling_arg_min_by("by", "3i").alias("arg_min"),
+        pl.col("a").rolling_arg_max_by("by", "3i").alias("arg_max"),
+        pl.col("a").rolling_product_by("by", "3i").alias("product"),
+    ]
+    expected = pl.DataFrame(
+        {
+            "a": [4, 2, 6, 5, 1],
+            "by": [1, 2, 3, 4, 5],
+            "rank": [1.0, 0.5, 1.0, 2 / 3, 1 / 3],
+            "n_unique": pl.Series([1, 2, 3, 3, 3], dtype=pl.get_index_type()),
+            "arg_min": pl.Series([0, 1, 1, 0, 2], dtype=pl.get_index_type()),
+            "arg_max": pl.Series([0, 0, 2, 1, 0], dtype=pl.get_index_type()),
+            "product": [4, 8, 48, 60, 30],
+        }
+    )
+    assert_frame_equal(df.with_columns(exprs), expected)
+
+    # the result is mapped back to the original row order
+    df_unsorted = df.select(pl.all().gather([3, 0, 4, 2, 1]))
+    assert_frame_equal(
+        df_unsorted.with_columns(exprs).sort("by"),
+        expected,
+    )
+
 @pytest.mark.parametrize("time_zone", [None, "US/Central"])
 @pytest.mark.parametrize(
     ("rolling_fn", "expected_values", "expected_dtype"),
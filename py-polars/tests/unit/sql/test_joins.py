@@ -312,27 +312,40 @@ def test_non_equi_joins(constraint: str) -> None:
 
 
 def test_implicit_joins() -> None:
-    # no support for this yet; ensure we catch it
-    with (
-        pytest.raises(
-            SQLInterfaceError,
-            match=r"not currently supported .* use explicit JOIN syntax instead",
-        ),
-        pl.SQLContext(
-            {
-                "tbl": pl.DataFrame(
-                    {"a": [1, 2, 3], "b": [4, 3, 2], "c": ["x", "y", "z"]}
-                )
-            }
-        ) as ctx,
-    ):
-        ctx.execute(
+    with pl.SQLContext(
+        {"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2], "c": ["x", "y", "z"]})}
+    ) as ctx:
+        # equality predicates become join keys
+        res = ctx.execute(
             """
-            SELECT t1.*
+            SELECT t1.*, t2.c AS c2
             FROM tbl AS t1, tbl AS t2
-            WHERE t1.a = t2.b
+            WHERE t1.a = t2.b AND t1.c <> 'x'
+            ORDER BY t1.a
+            """,
+            eager=True,
+        )
+        assert res.to_dict(as_series=False) == {
+            "a": [2, 3],
+            "b": [3, 2],
+            "c": ["y", "z"],
+            "c2": ["z", "y"],
+        }
+
+        # tables without equality predicates are cross-joined (and filtered)
+        res = ctx.execute(
             """
+            SELECT t1.a, t2.a AS a2
+            FROM tbl AS t1, tbl AS t2
+            WHERE t1.a < t2.b - 1
+            ORDER BY t1.a, a2
+            """,
+            eager=True,
         )
+        assert res.to_dict(as_series=False) == {
+            "a": [1, 1, 2],
+            "a2": [1, 2, 1],
+        }
 
 
 def test_natural_joins_01() -> None:
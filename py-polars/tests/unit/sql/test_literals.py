@@ -125,14 +125,23 @@ def test_intervals() -> None:
         )
         expected = pl.DataFrame(
             {
-                "i1": [timedelta(weeks=1, hours=2, minutes=3, seconds=4)],
                 "i2": [timedelta(microseconds=100100)],
-                "i3": [timedelta(weeks=1, hours=2, minutes=3, seconds=4)],
-                "i4": [timedelta(days=140, microseconds=987)],
             },
         ).cast(pl.Duration("ns"))
 
-        assert_frame_equal(expected, out)
+        assert_frame_equal(expected, out.select("i2"))
+
+        # intervals with calendar parts keep their (months, days, nanoseconds) components
+        assert out.select("i1", "i3", "i4").schema == dict.fromkeys(
+            ("i1", "i3", "i4"), pl.Interval
+        )
+        assert out.select("i1", "i3", "i4").rows() == [
+            (
+                (0, 7, 7_384_000_000_000),
+                (0, 7, 7_384_000_000_000),
+                (5, 0, 987_000),
+            )
+        ]
 
         # TODO: negative intervals
         with pytest.raises(
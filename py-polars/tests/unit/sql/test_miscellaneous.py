@@ -385,3 +385,21 @@ def test_invalid_cols(query: str) -> None:
     )
     with pytest.raises(ColumnNotFoundError, match="invalid_column"):
         df.sql(query)
+
+
+def test_recursive_cte_limit() -> None:
+    query = """
+        WITH RECURSIVE t(n) AS (
+          SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 5
+        )
+        SELECT n FROM t
+    """
+    ctx = pl.SQLContext(eager=True)
+    assert ctx.execute(query)["n"].to_list() == [1, 2, 3, 4, 5]
+
+    # the recursive CTE is evaluated when the query is executed
+    ctx = pl.SQLContext(recursion_limit=2)
+    with pytest.raises(
+        SQLInterfaceError, match="did not complete within 2 iterations"
+    ):
+        ctx.execute(query)
@@ -308,3 +308,24 @@ def test_flush_join_and_operation_19040() -> None:
         "B": [None, 1],
         "C": [None, 1],
     }
+
+
+@pytest.mark.parametrize("how", ["inner", "left"])
+def test_new_streaming_join_coalesce_expression_keys(
+    how: JoinStrategy, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    # the new streaming engine only coalesces plain column keys, so joins coalescing
+    # other key expressions fall back to the in-memory engine
+    monkeypatch.setenv("POLARS_AUTO_NEW_STREAMING", "1")
+    df1 = pl.LazyFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    df2 = pl.LazyFrame({"a": [0, 1, 2], "c": [10, 20, 30]})
+
+    q = df1.join(df2, left_on="a", right_on=pl.col("a") + 1, how=how, coalesce=True)
+    out = q.collect(new_streaming=True)
+    assert out.columns == ["a", "b", "c"]
+    assert_frame_equal(out, q.collect(), check_row_order=False)
+
+    q = df1.join(df2, on="a", how=how, coalesce=True)
+    out = q.collect(new_streaming=True)
+    assert out.columns == ["a", "b", "c"]
+    assert_frame_equal(out, q.collect(), check_row_order=False)